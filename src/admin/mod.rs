@@ -0,0 +1,237 @@
+use anyhow::{Context, Result};
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    http::header,
+    response::IntoResponse,
+    routing::{get, post},
+};
+use chrono::Duration;
+use serde::Deserialize;
+use serde_json::json;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+use tracing::info;
+
+#[cfg(feature = "profiling")]
+mod profiling;
+
+use crate::clock::SharedClock;
+use crate::config::admin::AdminConfig;
+use crate::geyser::GeyserClient;
+use crate::metrics;
+use crate::monitor::{
+    FeeTracker, LatencyGossip, LatencyTracker, LaunchTracker, ParserStats, drops,
+};
+use crate::parser::LaunchpadCapabilities;
+use crate::sink::{LaunchHistory, PausableSink, ThrottledSink};
+
+/// Shared handles exposed through the admin API
+#[derive(Clone)]
+pub struct AdminState {
+    pub geyser: GeyserClient,
+    pub publish: PausableSink,
+    pub throttle: ThrottledSink,
+    pub history: Option<LaunchHistory>,
+    pub fees: FeeTracker,
+    pub tracker: Option<LaunchTracker>,
+    pub stats: Option<ParserStats>,
+    pub latency: LatencyTracker,
+    pub gossip: Option<LatencyGossip>,
+    pub parsers: Arc<Vec<LaunchpadCapabilities>>,
+    /// Clock catch-up windows are measured back from
+    pub clock: SharedClock,
+}
+
+/// Starts the admin HTTP server in separate task
+pub fn start(config: AdminConfig, state: AdminState) -> JoinHandle<Result<()>> {
+    tokio::spawn(async move {
+        let app = Router::new()
+            .route("/metrics", get(metrics_handler))
+            .route("/debug/subscription", get(subscription_handler))
+            .route("/debug/drops", get(drops_handler))
+            .route("/stats/parsers", get(parser_stats_handler))
+            .route("/stats/latency", get(latency_handler))
+            .route("/control/publish", get(publish_status_handler))
+            .route("/control/pause", post(pause_handler))
+            .route("/control/resume", post(resume_handler))
+            .route("/launches", get(launches_handler))
+            .route("/parsers", get(parsers_handler))
+            .route("/tokens/:mint/fees", get(token_fees_handler))
+            .route("/leaderboard/graduation", get(graduation_handler));
+
+        #[cfg(feature = "profiling")]
+        let app = app.route(
+            "/debug/pprof/flamegraph",
+            get(profiling::flamegraph_handler),
+        );
+
+        let app = app.with_state(state);
+
+        let listener = TcpListener::bind(&config.bind_addr)
+            .await
+            .with_context(|| format!("Failed to bind admin server to {}", config.bind_addr))?;
+
+        info!("🛠️  Admin server listening on {}", config.bind_addr);
+
+        axum::serve(listener, app)
+            .await
+            .context("Admin server failed")?;
+
+        Ok(())
+    })
+}
+
+async fn metrics_handler() -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        metrics::global().render(),
+    )
+}
+
+/// Dumps the Geyser subscription request currently in effect
+async fn subscription_handler(State(state): State<AdminState>) -> impl IntoResponse {
+    Json(state.geyser.subscription_snapshot().await)
+}
+
+/// Per-parser counters of this build next to the previous build's
+async fn parser_stats_handler(State(state): State<AdminState>) -> impl IntoResponse {
+    match state.stats {
+        Some(stats) => (StatusCode::OK, Json(json!(stats.report()))),
+        None => (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(json!({ "error": "parser statistics are disabled" })),
+        ),
+    }
+}
+
+/// Detection latency percentiles per launchpad and their SLO state
+async fn latency_handler(State(state): State<AdminState>) -> impl IntoResponse {
+    let config = state.latency.config();
+    Json(json!({
+        "window_secs": config.window_secs,
+        "slo_ms": config.slo_ms,
+        "slo_percentile": config.slo_percentile,
+        "launchpads": state.latency.report(),
+        "peers": state.gossip.as_ref().map(|gossip| json!({
+            "source": gossip.source(),
+            "leads": gossip.report(),
+        })),
+    }))
+}
+
+#[derive(Debug, Deserialize)]
+struct DropsQuery {
+    signature: Option<String>,
+    reason: Option<String>,
+    limit: Option<usize>,
+}
+
+/// Drop counts by reason and the most recent drops (default 100), optionally of one
+/// signature or reason
+async fn drops_handler(Query(query): Query<DropsQuery>) -> impl IntoResponse {
+    Json(json!({
+        "counts": drops::counts(),
+        "recent": drops::recent(
+            query.signature.as_deref(),
+            query.reason.as_deref(),
+            query.limit.unwrap_or(100),
+        ),
+    }))
+}
+
+/// Reports whether publishing is paused, how many events are buffered and active throttling
+async fn publish_status_handler(State(state): State<AdminState>) -> impl IntoResponse {
+    Json(json!({
+        "paused": state.publish.is_paused(),
+        "buffered": state.publish.buffered().await,
+        "throttle": state.throttle.settings(),
+    }))
+}
+
+/// Pauses event publishing, ingestion continues
+async fn pause_handler(State(state): State<AdminState>) -> impl IntoResponse {
+    state.publish.pause();
+    publish_status_handler(State(state)).await
+}
+
+/// Resumes event publishing and flushes buffered events
+async fn resume_handler(State(state): State<AdminState>) -> impl IntoResponse {
+    state.publish.resume().await;
+    publish_status_handler(State(state)).await
+}
+
+#[derive(Debug, Deserialize)]
+struct LaunchesQuery {
+    hours: Option<i64>,
+}
+
+/// Events and metadata fields of each registered parser
+async fn parsers_handler(State(state): State<AdminState>) -> impl IntoResponse {
+    let parsers: Vec<&LaunchpadCapabilities> = state.parsers.iter().collect();
+    Json(json!({ "parsers": parsers }))
+}
+
+/// Catch-up snapshot of launches from the last `hours` (default 1, capped at retention)
+async fn launches_handler(
+    State(state): State<AdminState>,
+    Query(query): Query<LaunchesQuery>,
+) -> impl IntoResponse {
+    let Some(history) = state.history else {
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(json!({ "error": "launch history is disabled" })),
+        );
+    };
+
+    let hours = query.hours.unwrap_or(1).clamp(0, history.retention_hours());
+    let since = state.clock.now() - Duration::hours(hours);
+    let launches = history.since(since).await;
+
+    (
+        StatusCode::OK,
+        Json(json!({
+            "since": since,
+            "count": launches.len(),
+            "launches": launches,
+        })),
+    )
+}
+
+/// Cumulative protocol and creator fees paid on a token's trades
+async fn token_fees_handler(
+    State(state): State<AdminState>,
+    Path(mint): Path<String>,
+) -> impl IntoResponse {
+    match state.fees.get(&mint) {
+        Some(fees) => (StatusCode::OK, Json(json!(fees))),
+        None => (
+            StatusCode::NOT_FOUND,
+            Json(json!({ "error": "no trades seen for this token" })),
+        ),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct LeaderboardQuery {
+    limit: Option<usize>,
+}
+
+/// Tokens closest to graduation per launchpad (default 20 each, at most 100)
+async fn graduation_handler(
+    State(state): State<AdminState>,
+    Query(query): Query<LeaderboardQuery>,
+) -> impl IntoResponse {
+    let Some(tracker) = state.tracker else {
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(json!({ "error": "launch tracker is disabled" })),
+        );
+    };
+
+    let leaderboard = tracker.graduation_leaderboard(query.limit.unwrap_or(20).min(100));
+
+    (StatusCode::OK, Json(json!(leaderboard)))
+}
@@ -0,0 +1,45 @@
+use axum::{
+    extract::Query,
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use serde::Deserialize;
+use std::time::Duration;
+use tracing::info;
+
+const MAX_PROFILE_SECONDS: u64 = 60;
+
+#[derive(Debug, Deserialize)]
+pub struct ProfileParams {
+    seconds: Option<u64>,
+    frequency: Option<i32>,
+}
+
+/// Samples the process CPU for the requested duration and returns a flamegraph SVG
+pub async fn flamegraph_handler(Query(params): Query<ProfileParams>) -> Response {
+    let seconds = params.seconds.unwrap_or(10).clamp(1, MAX_PROFILE_SECONDS);
+    let frequency = params.frequency.unwrap_or(99);
+
+    info!("🔥 Profiling CPU for {}s at {}Hz", seconds, frequency);
+
+    match tokio::task::spawn_blocking(move || render_flamegraph(seconds, frequency)).await {
+        Ok(Ok(svg)) => ([(header::CONTENT_TYPE, "image/svg+xml")], svg).into_response(),
+        Ok(Err(e)) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+fn render_flamegraph(seconds: u64, frequency: i32) -> anyhow::Result<Vec<u8>> {
+    let guard = pprof::ProfilerGuardBuilder::default()
+        .frequency(frequency)
+        .blocklist(&["libc", "libgcc", "pthread", "vdso"])
+        .build()?;
+
+    std::thread::sleep(Duration::from_secs(seconds));
+
+    let report = guard.report().build()?;
+    let mut svg = Vec::new();
+    report.flamegraph(&mut svg)?;
+
+    Ok(svg)
+}
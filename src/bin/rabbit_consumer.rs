@@ -1,7 +1,7 @@
 use anyhow::Result;
 use rustls::crypto::{CryptoProvider, ring::default_provider};
-use task_ba::config::rabbit::RabbitMQConfig;
-use task_ba::rabbitmq::RabbitMQConsumer;
+use solana_launchpad_parser::config::rabbit::RabbitMQConfig;
+use solana_launchpad_parser::rabbitmq::{EventVerifier, RabbitMQConsumer};
 use tracing::info;
 
 #[tokio::main]
@@ -17,7 +17,16 @@ async fn main() -> Result<()> {
         cfg
     );
 
+    let verifier = cfg
+        .verify_pubkey
+        .as_deref()
+        .map(EventVerifier::from_pubkey)
+        .transpose()?;
+
     let mut consumer = RabbitMQConsumer::new(cfg);
+    if let Some(verifier) = verifier {
+        consumer = consumer.with_verifier(verifier);
+    }
     consumer.init().await?;
 
     // Start consuming in background
@@ -0,0 +1,24 @@
+//! Deprecated name of the `solana-launchpad-parser` service binary. Runs the renamed
+//! binary from the same directory with the same arguments, for deployments still
+//! starting `task_ba`.
+
+use std::process::{Command, ExitCode};
+
+fn main() -> ExitCode {
+    eprintln!("warning: `task_ba` is deprecated, run `solana-launchpad-parser` instead");
+
+    let binary = std::env::current_exe()
+        .ok()
+        .and_then(|exe| Some(exe.parent()?.join("solana-launchpad-parser")))
+        .unwrap_or_else(|| "solana-launchpad-parser".into());
+    match Command::new(&binary)
+        .args(std::env::args_os().skip(1))
+        .status()
+    {
+        Ok(status) => ExitCode::from(status.code().unwrap_or(1) as u8),
+        Err(e) => {
+            eprintln!("error: could not start {}: {}", binary.display(), e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,120 @@
+mod scaffold;
+mod schema;
+
+use anyhow::{Result, bail};
+use solana_launchpad_parser::idl::{self, Idl, Namespace};
+
+const USAGE: &str = "\
+Usage:
+  tools discriminator <name> [--event | --account]
+  tools discriminator --idl <path>
+  tools new-parser <Name> --idl <path> [--launch <instruction>] [--out <dir>]
+  tools schema [--out <dir>]";
+
+fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match args.first().map(String::as_str) {
+        Some("discriminator") => discriminator(&args[1..]),
+        Some("new-parser") => new_parser(&args[1..]),
+        Some("schema") => match &args[1..] {
+            [] => schema::write_schemas(None),
+            [flag, out] if flag == "--out" => schema::write_schemas(Some(out)),
+            _ => bail!("{}", USAGE),
+        },
+        _ => bail!("{}", USAGE),
+    }
+}
+
+/// Prints the discriminator of a single name, or of everything declared in an IDL
+fn discriminator(args: &[String]) -> Result<()> {
+    match args {
+        [flag, path] if flag == "--idl" => print_idl_discriminators(path),
+        [name] => print_discriminator(Namespace::Instruction, name),
+        [name, flag] | [flag, name] if flag == "--event" => {
+            print_discriminator(Namespace::Event, name)
+        }
+        [name, flag] | [flag, name] if flag == "--account" => {
+            print_discriminator(Namespace::Account, name)
+        }
+        _ => bail!("{}", USAGE),
+    }
+}
+
+/// Generates a parser module skeleton from an IDL
+fn new_parser(args: &[String]) -> Result<()> {
+    let Some((name, options)) = args.split_first() else {
+        bail!("{}", USAGE);
+    };
+
+    let mut idl = None;
+    let mut launch = None;
+    let mut out = "src/parser";
+    for pair in options.chunks(2) {
+        match pair {
+            [flag, value] if flag == "--idl" => idl = Some(value.as_str()),
+            [flag, value] if flag == "--launch" => launch = Some(value.as_str()),
+            [flag, value] if flag == "--out" => out = value.as_str(),
+            _ => bail!("{}", USAGE),
+        }
+    }
+    let Some(idl) = idl else {
+        bail!("{}", USAGE);
+    };
+
+    scaffold::new_parser(name, idl, launch, out)
+}
+
+fn print_discriminator(namespace: Namespace, name: &str) -> Result<()> {
+    println!("{:?}", idl::discriminator(namespace, name));
+    Ok(())
+}
+
+fn print_idl_discriminators(path: &str) -> Result<()> {
+    let idl = Idl::load(path)?;
+
+    println!(
+        "# {} ({})",
+        idl.program_name().unwrap_or("unknown"),
+        idl.program_address().unwrap_or("no address")
+    );
+
+    for instruction in &idl.instructions {
+        print_entry(
+            "instruction",
+            &instruction.name,
+            instruction.discriminator(),
+            idl::discriminator(Namespace::Instruction, &instruction.name),
+        );
+    }
+    for event in &idl.events {
+        print_entry(
+            "event",
+            &event.name,
+            event.discriminator(Namespace::Event),
+            idl::discriminator(Namespace::Event, &event.name),
+        );
+    }
+    for account in &idl.accounts {
+        print_entry(
+            "account",
+            &account.name,
+            account.discriminator(Namespace::Account),
+            idl::discriminator(Namespace::Account, &account.name),
+        );
+    }
+
+    Ok(())
+}
+
+/// Prints one IDL entry, flagging declared discriminators that don't match the Anchor derivation
+fn print_entry(kind: &str, name: &str, declared: [u8; 8], computed: [u8; 8]) {
+    if declared == computed {
+        println!("{:<12} {:<45} {:?}", kind, name, declared);
+    } else {
+        println!(
+            "{:<12} {:<45} {:?} (computed {:?}, custom discriminator?)",
+            kind, name, declared, computed
+        );
+    }
+}
@@ -0,0 +1,160 @@
+use anyhow::{Context, Result, bail};
+use serde_json::Value;
+use solana_launchpad_parser::idl::{Idl, IdlInstruction, to_snake_case};
+use std::fs;
+use std::path::Path;
+
+const TEMPLATE: &str = include_str!("parser.rs.tmpl");
+
+/// Instructions tried, in order, when no launch instruction is given
+const LAUNCH_CANDIDATES: &[&str] = &["create", "initialize", "create_token", "launch"];
+
+/// Generates `src/parser/<name>.rs` from an IDL and prints the registration snippet
+pub fn new_parser(name: &str, idl_path: &str, launch: Option<&str>, out_dir: &str) -> Result<()> {
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric()) {
+        bail!("Parser name must be alphanumeric PascalCase, e.g. Moonshot");
+    }
+    let name = pascal_case(name);
+    let module = to_snake_case(&name);
+
+    let idl = Idl::load(idl_path)?;
+    let program_id = idl
+        .program_address()
+        .context("IDL has no program address")?;
+
+    let launch = match launch {
+        Some(launch) => idl
+            .instructions
+            .iter()
+            .find(|i| i.name == launch)
+            .with_context(|| format!("Instruction {} not found in IDL", launch))?,
+        None => LAUNCH_CANDIDATES
+            .iter()
+            .find_map(|candidate| idl.instructions.iter().find(|i| i.name == *candidate))
+            .context("No launch instruction found, pass --launch <instruction>")?,
+    };
+
+    let source = render(&name, program_id, idl.program_name(), &idl, launch);
+
+    let path = Path::new(out_dir).join(format!("{}.rs", module));
+    if path.exists() {
+        bail!("{} already exists", path.display());
+    }
+    fs::write(&path, source).with_context(|| format!("Failed to write {}", path.display()))?;
+
+    println!("Created {}", path.display());
+    println!();
+    println!("Finish registration:");
+    println!();
+    println!("// src/parser/mod.rs");
+    println!("pub mod {};", module);
+    println!();
+    println!("// src/parser/launchpad_parser.rs, in `enum LaunchpadType`");
+    println!("    {},", name);
+    println!();
+    println!("// src/parser/manager.rs, in `ParserManager::new` (give it a section in");
+    println!(
+        "// src/config/parsers.rs and pass `config.{}.clone()` once it has options)",
+        module
+    );
+    println!("        // Add {} parser", name);
+    println!(
+        "        let {module}_parser = Box::new(crate::parser::{module}::{name}Parser::new());"
+    );
+    println!("        let parser_index = parsers.len();");
+    println!("        for program_id in {module}_parser.get_program_ids() {{");
+    println!("            program_id_to_parser.insert(program_id, parser_index);");
+    println!("        }}");
+    println!("        parsers.push({module}_parser);");
+    println!();
+    println!(
+        "Then run `cargo fmt`, review MINT_ACCOUNT_INDEX / CREATOR_ACCOUNT_INDEX and metadata extraction,"
+    );
+    println!("and replace the ignored fixture test with a captured transaction.");
+
+    Ok(())
+}
+
+fn render(
+    name: &str,
+    program_id: &str,
+    idl_name: Option<&str>,
+    idl: &Idl,
+    launch: &IdlInstruction,
+) -> String {
+    let table: String = idl
+        .instructions
+        .iter()
+        .filter(|i| i.name != launch.name)
+        .map(|i| format!("    (\"{}\", {:?}),\n", i.name, i.discriminator()))
+        .collect();
+
+    let accounts = launch.account_names();
+    let mint_index = find_account(&accounts, &["mint", "base_mint", "token_mint"], "mint");
+    let creator_index = find_account(&accounts, &["creator", "user", "payer"], "creator");
+
+    let args: Vec<String> = launch
+        .args
+        .iter()
+        .map(|arg| format!("{}: {}", arg.name, type_name(&arg.ty)))
+        .collect();
+    let leading_strings = launch
+        .args
+        .iter()
+        .take(3)
+        .all(|arg| arg.ty.as_str() == Some("string"));
+    let metadata_note = if leading_strings && !launch.args.is_empty() {
+        String::new()
+    } else {
+        "        // TODO: args don't start with name/symbol/uri strings, adjust decoding\n"
+            .to_string()
+    };
+
+    TEMPLATE
+        .replace("__LAUNCH_CONST__", &launch.name.to_uppercase())
+        .replace("__LAUNCH__", &launch.name)
+        .replace("__NAME__", name)
+        .replace("__PROGRAM_ID__", program_id)
+        .replace("__IDL_NAME__", idl_name.unwrap_or("program"))
+        .replace(
+            "__DISCRIMINATOR__",
+            &format!("{:?}", launch.discriminator()),
+        )
+        .replace("__INSTRUCTION_TABLE__", &table)
+        .replace("__MINT_INDEX__", &mint_index)
+        .replace("__CREATOR_INDEX__", &creator_index)
+        .replace("__ARGS__", &args.join(", "))
+        .replace("__METADATA_NOTE__\n", &metadata_note)
+        .replace("__ACCOUNT_COUNT__", &accounts.len().max(1).to_string())
+}
+
+/// Index of the first account named like one of `names`, with a TODO marker when absent
+fn find_account(accounts: &[String], names: &[&str], what: &str) -> String {
+    match accounts
+        .iter()
+        .position(|account| names.contains(&account.as_str()))
+    {
+        Some(index) => format!("{}; // `{}`", index, accounts[index]),
+        None => format!("0; // TODO: no {} account found in IDL, set manually", what),
+    }
+}
+
+fn type_name(ty: &Value) -> String {
+    match ty {
+        Value::String(s) => s.clone(),
+        Value::Object(map) => match map.get("defined") {
+            Some(Value::String(s)) => s.clone(),
+            Some(defined) => defined["name"].as_str().unwrap_or("?").to_string(),
+            None => ty.to_string(),
+        },
+        _ => ty.to_string(),
+    }
+}
+
+fn pascal_case(name: &str) -> String {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+        .unwrap_or_default()
+}
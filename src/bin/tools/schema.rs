@@ -0,0 +1,981 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{self, Serializer};
+use serde_json::{Map, Value, json};
+use solana_launchpad_parser::geyser::TransactionVersion;
+use solana_launchpad_parser::monitor::{
+    CreatorSell, Digest, OpsError, OpsErrorKind, OpsSeverity, SellPressure, TokenAlert, TokenFees,
+    TokenStatus, WalletTrades, digest::DigestEntry,
+};
+use solana_launchpad_parser::parser::{
+    AuthorityRevoked, AuthorityType, CreatorTransfer, CurveProgress, DevSell, EnrichmentError,
+    FeeClaim, FeeClaimKind, FirstBuyer, FirstBuyers, Graduation, GraduationTrigger, LaunchpadType,
+    Links, Liquidity, LiquidityAction, LpBurned, Migration, PartialParse, ProgramEvent,
+    TokenExtensions, TokenLaunch, Trade, TradeFees,
+    launchpad_parser::{LaunchMetadata, NormalizedMetadata},
+};
+use solana_launchpad_parser::sink::{RawTransaction, SlotBatch, SlotEvent, TransactionBatch};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+const ADDRESS: &str = "So11111111111111111111111111111111111111112";
+const SIGNATURE: &str =
+    "5VERv8NMvzbJMEkV8xnrLkEaWRtSz9CosKDYjCJjBRnbJLgp8uirBgmQpjKhoR4tjF3ZpRzrFmBV6UjKdiSZkQUW";
+
+/// Prints the JSON Schema of every published event, or writes one `<event>.schema.json`
+/// per event into `out`
+pub fn write_schemas(out: Option<&str>) -> Result<()> {
+    let schemas = schemas()?;
+    let Some(out) = out else {
+        println!("{}", serde_json::to_string_pretty(&schemas)?);
+        return Ok(());
+    };
+
+    fs::create_dir_all(out).with_context(|| format!("Failed to create {}", out))?;
+    for (event, schema) in schemas {
+        let path = Path::new(out).join(format!("{}.schema.json", event));
+        fs::write(&path, serde_json::to_string_pretty(&schema)? + "\n")
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        println!("Wrote {}", path.display());
+    }
+    Ok(())
+}
+
+/// Schemas by event name, derived from the events' `Serialize`
+/// implementations. Every field of the samples is filled, so `Option`s show up as
+/// nullable and the samples stop compiling when an event gains a field.
+fn schemas() -> Result<BTreeMap<&'static str, Value>> {
+    let alerts = [
+        TokenAlert::SellPressure(sell_pressure()),
+        TokenAlert::CreatorSell(creator_sell()),
+    ];
+    let events = [
+        SlotEvent::TokenLaunch(token_launch()),
+        SlotEvent::PartialParse(partial_parse()),
+        SlotEvent::FeeClaim(fee_claim()),
+        SlotEvent::Migration(migration()),
+        SlotEvent::Graduation(graduation()),
+        SlotEvent::Liquidity(liquidity()),
+        SlotEvent::LpBurned(lp_burned()),
+        SlotEvent::AuthorityRevoked(authority_revoked()),
+        SlotEvent::DevSell(dev_sell()),
+        SlotEvent::CreatorTransfer(creator_transfer()),
+        SlotEvent::Trade(trade()),
+        SlotEvent::ProgramEvent(program_event()),
+    ];
+
+    let mut schemas = BTreeMap::new();
+    schemas.insert("token_launch", schema("TokenLaunch", &token_launch())?);
+    schemas.insert("partial_parse", schema("PartialParse", &partial_parse())?);
+    schemas.insert("fee_claim", schema("FeeClaim", &fee_claim())?);
+    schemas.insert("migration", schema("Migration", &migration())?);
+    schemas.insert("graduation", schema("Graduation", &graduation())?);
+    schemas.insert("liquidity", schema("Liquidity", &liquidity())?);
+    schemas.insert("lp_burned", schema("LpBurned", &lp_burned())?);
+    schemas.insert(
+        "authority_revoked",
+        schema("AuthorityRevoked", &authority_revoked())?,
+    );
+    schemas.insert("dev_sell", schema("DevSell", &dev_sell())?);
+    schemas.insert(
+        "creator_transfer",
+        schema("CreatorTransfer", &creator_transfer())?,
+    );
+    schemas.insert("first_buyers", schema("FirstBuyers", &first_buyers())?);
+    schemas.insert(
+        "curve_progress",
+        schema("CurveProgress", &curve_progress())?,
+    );
+    schemas.insert("trade", schema("Trade", &trade())?);
+    schemas.insert("event", schema("ProgramEvent", &program_event())?);
+    schemas.insert("token_status", schema("TokenStatus", &token_status())?);
+    schemas.insert("alert", one_of("TokenAlert", &alerts)?);
+    schemas.insert("digest", schema("Digest", &digest())?);
+    schemas.insert("errors", schema("OpsError", &ops_error())?);
+    schemas.insert("raw", schema("RawTransaction", &raw_transaction())?);
+
+    // Batches hold any of the slot events
+    let event_schema = one_of("SlotEvent", &events)?;
+    for (key, title, mut batch) in [
+        (
+            "slot_batch",
+            "SlotBatch",
+            schema("SlotBatch", &slot_batch())?,
+        ),
+        (
+            "transaction_batch",
+            "TransactionBatch",
+            schema("TransactionBatch", &transaction_batch())?,
+        ),
+    ] {
+        batch["properties"]["events"]["items"] = without_header(event_schema.clone());
+        batch["title"] = json!(title);
+        schemas.insert(key, batch);
+    }
+    Ok(schemas)
+}
+
+fn schema<T: Serialize>(title: &str, sample: &T) -> Result<Value> {
+    let mut schema = sample.serialize(SchemaSerializer)?;
+    let object = schema.as_object_mut().context("Schema is not an object")?;
+    object.insert(
+        "$schema".to_string(),
+        json!("https://json-schema.org/draft/2020-12/schema"),
+    );
+    object.insert("title".to_string(), json!(title));
+    Ok(schema)
+}
+
+/// Schema accepting any of the variants, one sample each
+fn one_of<T: Serialize>(title: &str, variants: &[T]) -> Result<Value> {
+    let variants = variants
+        .iter()
+        .map(|variant| variant.serialize(SchemaSerializer).map_err(Into::into))
+        .collect::<Result<Vec<Value>>>()?;
+    Ok(json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": title,
+        "oneOf": variants,
+    }))
+}
+
+fn without_header(mut schema: Value) -> Value {
+    if let Some(object) = schema.as_object_mut() {
+        object.remove("$schema");
+        object.remove("title");
+    }
+    schema
+}
+
+#[derive(Debug)]
+struct SchemaError(String);
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for SchemaError {}
+
+impl ser::Error for SchemaError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+impl de::Error for SchemaError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+/// Serializer producing the JSON Schema of the value's type instead of the value
+struct SchemaSerializer;
+
+fn of_type(ty: &str) -> Result<Value, SchemaError> {
+    Ok(json!({ "type": ty }))
+}
+
+/// Allows `null` besides the schema
+fn nullable(mut schema: Value) -> Value {
+    match schema.get("type").cloned() {
+        Some(Value::String(ty)) => {
+            schema["type"] = json!([ty, "null"]);
+            schema
+        }
+        _ => json!({ "anyOf": [schema, { "type": "null" }] }),
+    }
+}
+
+fn is_nullable(schema: &Value) -> bool {
+    schema["type"]
+        .as_array()
+        .is_some_and(|types| types.contains(&json!("null")))
+        || schema["anyOf"]
+            .as_array()
+            .is_some_and(|any| any.contains(&json!({ "type": "null" })))
+}
+
+/// Variants of the unit enums on the events, which serializing one sample can't reveal
+fn enum_variants(name: &str) -> Option<Vec<&'static str>> {
+    match name {
+        "FeeClaimKind" => variants::<FeeClaimKind>(),
+        "OpsErrorKind" => variants::<OpsErrorKind>(),
+        "OpsSeverity" => variants::<OpsSeverity>(),
+        "TransactionVersion" => variants::<TransactionVersion>(),
+        _ => None,
+    }
+}
+
+/// Serialized variant names of an externally tagged enum, from its `Deserialize` impl
+fn variants<'de, T: Deserialize<'de>>() -> Option<Vec<&'static str>> {
+    struct Probe<'a>(&'a mut Option<&'static [&'static str]>);
+
+    impl<'de> Deserializer<'de> for Probe<'_> {
+        type Error = SchemaError;
+
+        fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, SchemaError> {
+            Err(de::Error::custom("not an enum"))
+        }
+
+        fn deserialize_enum<V: Visitor<'de>>(
+            self,
+            _: &'static str,
+            variants: &'static [&'static str],
+            _: V,
+        ) -> Result<V::Value, SchemaError> {
+            *self.0 = Some(variants);
+            Err(de::Error::custom("probed"))
+        }
+
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes
+            byte_buf option unit unit_struct newtype_struct seq tuple tuple_struct map
+            struct identifier ignored_any
+        }
+    }
+
+    let mut found = None;
+    let _ = T::deserialize(Probe(&mut found));
+    found.map(<[&str]>::to_vec)
+}
+
+impl Serializer for SchemaSerializer {
+    type Ok = Value;
+    type Error = SchemaError;
+    type SerializeSeq = SeqSchema;
+    type SerializeTuple = SeqSchema;
+    type SerializeTupleStruct = SeqSchema;
+    type SerializeTupleVariant = ser::Impossible<Value, SchemaError>;
+    type SerializeMap = MapSchema;
+    type SerializeStruct = StructSchema;
+    type SerializeStructVariant = ser::Impossible<Value, SchemaError>;
+
+    fn serialize_bool(self, _: bool) -> Result<Value, SchemaError> {
+        of_type("boolean")
+    }
+
+    fn serialize_i8(self, _: i8) -> Result<Value, SchemaError> {
+        of_type("integer")
+    }
+
+    fn serialize_i16(self, _: i16) -> Result<Value, SchemaError> {
+        of_type("integer")
+    }
+
+    fn serialize_i32(self, _: i32) -> Result<Value, SchemaError> {
+        of_type("integer")
+    }
+
+    fn serialize_i64(self, _: i64) -> Result<Value, SchemaError> {
+        of_type("integer")
+    }
+
+    fn serialize_u8(self, _: u8) -> Result<Value, SchemaError> {
+        Ok(json!({ "type": "integer", "minimum": 0 }))
+    }
+
+    fn serialize_u16(self, _: u16) -> Result<Value, SchemaError> {
+        Ok(json!({ "type": "integer", "minimum": 0 }))
+    }
+
+    fn serialize_u32(self, _: u32) -> Result<Value, SchemaError> {
+        Ok(json!({ "type": "integer", "minimum": 0 }))
+    }
+
+    fn serialize_u64(self, _: u64) -> Result<Value, SchemaError> {
+        Ok(json!({ "type": "integer", "minimum": 0 }))
+    }
+
+    fn serialize_f32(self, _: f32) -> Result<Value, SchemaError> {
+        of_type("number")
+    }
+
+    fn serialize_f64(self, _: f64) -> Result<Value, SchemaError> {
+        of_type("number")
+    }
+
+    fn serialize_char(self, _: char) -> Result<Value, SchemaError> {
+        of_type("string")
+    }
+
+    fn serialize_str(self, value: &str) -> Result<Value, SchemaError> {
+        // chrono serializes timestamps as RFC 3339 strings
+        if DateTime::parse_from_rfc3339(value).is_ok() {
+            return Ok(json!({ "type": "string", "format": "date-time" }));
+        }
+        of_type("string")
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> Result<Value, SchemaError> {
+        Ok(json!({ "type": "array", "items": { "type": "integer", "minimum": 0 } }))
+    }
+
+    fn serialize_none(self) -> Result<Value, SchemaError> {
+        of_type("null")
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Value, SchemaError> {
+        Ok(nullable(value.serialize(self)?))
+    }
+
+    fn serialize_unit(self) -> Result<Value, SchemaError> {
+        of_type("null")
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<Value, SchemaError> {
+        of_type("null")
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        _: u32,
+        _: &'static str,
+    ) -> Result<Value, SchemaError> {
+        match enum_variants(name) {
+            Some(variants) => Ok(json!({ "type": "string", "enum": variants })),
+            None => of_type("string"),
+        }
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<Value, SchemaError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Value, SchemaError> {
+        Ok(json!({
+            "type": "object",
+            "properties": { variant: value.serialize(self)? },
+            "required": [variant],
+        }))
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<SeqSchema, SchemaError> {
+        Ok(SeqSchema { items: None })
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<SeqSchema, SchemaError> {
+        Ok(SeqSchema { items: None })
+    }
+
+    fn serialize_tuple_struct(self, _: &'static str, _: usize) -> Result<SeqSchema, SchemaError> {
+        Ok(SeqSchema { items: None })
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, SchemaError> {
+        Err(ser::Error::custom(format!(
+            "unsupported tuple variant in {}",
+            name
+        )))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<MapSchema, SchemaError> {
+        Ok(MapSchema { values: None })
+    }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<StructSchema, SchemaError> {
+        Ok(StructSchema {
+            properties: Map::new(),
+            required: Vec::new(),
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, SchemaError> {
+        Err(ser::Error::custom(format!(
+            "unsupported struct variant in {}",
+            name
+        )))
+    }
+}
+
+/// Arrays, typed after their first element
+struct SeqSchema {
+    items: Option<Value>,
+}
+
+impl SeqSchema {
+    fn add<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SchemaError> {
+        if self.items.is_none() {
+            self.items = Some(value.serialize(SchemaSerializer)?);
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Value, SchemaError> {
+        Ok(json!({ "type": "array", "items": self.items.unwrap_or_else(|| json!({})) }))
+    }
+}
+
+impl ser::SerializeSeq for SeqSchema {
+    type Ok = Value;
+    type Error = SchemaError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SchemaError> {
+        self.add(value)
+    }
+
+    fn end(self) -> Result<Value, SchemaError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for SeqSchema {
+    type Ok = Value;
+    type Error = SchemaError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SchemaError> {
+        self.add(value)
+    }
+
+    fn end(self) -> Result<Value, SchemaError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqSchema {
+    type Ok = Value;
+    type Error = SchemaError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SchemaError> {
+        self.add(value)
+    }
+
+    fn end(self) -> Result<Value, SchemaError> {
+        self.finish()
+    }
+}
+
+/// Maps with string keys, typed after their first value
+struct MapSchema {
+    values: Option<Value>,
+}
+
+impl ser::SerializeMap for MapSchema {
+    type Ok = Value;
+    type Error = SchemaError;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, _: &T) -> Result<(), SchemaError> {
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SchemaError> {
+        if self.values.is_none() {
+            self.values = Some(value.serialize(SchemaSerializer)?);
+        }
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, SchemaError> {
+        Ok(json!({
+            "type": "object",
+            "additionalProperties": self.values.unwrap_or_else(|| json!({})),
+        }))
+    }
+}
+
+/// Objects with every field listed, the nullable ones optional
+struct StructSchema {
+    properties: Map<String, Value>,
+    required: Vec<&'static str>,
+}
+
+impl ser::SerializeStruct for StructSchema {
+    type Ok = Value;
+    type Error = SchemaError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), SchemaError> {
+        // The tag of internally tagged enums names the variant
+        let schema = match serde_json::to_value(value) {
+            Ok(Value::String(tag)) if key == "type" => json!({ "const": tag }),
+            _ => value.serialize(SchemaSerializer)?,
+        };
+        if !is_nullable(&schema) {
+            self.required.push(key);
+        }
+        self.properties.insert(key.to_string(), schema);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, SchemaError> {
+        Ok(json!({
+            "type": "object",
+            "properties": self.properties,
+            "required": self.required,
+        }))
+    }
+}
+
+fn links() -> Option<Links> {
+    Some(Links {
+        transaction: format!("https://solscan.io/tx/{}", SIGNATURE),
+        mint: Some(format!("https://solscan.io/token/{}", ADDRESS)),
+        creator: Some(format!("https://solscan.io/account/{}", ADDRESS)),
+    })
+}
+
+fn metadata() -> LaunchMetadata {
+    LaunchMetadata {
+        name: Some("Token".to_string()),
+        symbol: Some("TKN".to_string()),
+        uri: Some("https://example.com/token.json".to_string()),
+        initial_supply: Some(1_000_000_000),
+        mint_authority: Some(ADDRESS.to_string()),
+        fee_recipient: Some(ADDRESS.to_string()),
+        token_extensions: Some(TokenExtensions {
+            transfer_fee_basis_points: Some(100),
+            maximum_transfer_fee: Some(1_000),
+            permanent_delegate: Some(ADDRESS.to_string()),
+            transfer_hook_program: Some(ADDRESS.to_string()),
+        }),
+        normalized: Some(NormalizedMetadata {
+            name: Some("Token".to_string()),
+            symbol: Some("TKN".to_string()),
+            bidi_override: false,
+            control_characters: false,
+        }),
+    }
+}
+
+/// Spelled out rather than built with `TokenLaunch::new`, so a new field fails to compile
+/// here until it has a non-empty sample to type its schema from
+fn token_launch() -> TokenLaunch {
+    TokenLaunch {
+        launchpad: LaunchpadType::Pumpfun,
+        token_address: ADDRESS.to_string(),
+        creator: Some(ADDRESS.to_string()),
+        pool: Some(ADDRESS.to_string()),
+        signature: SIGNATURE.to_string(),
+        slot: 1,
+        transaction_index: Some(0),
+        timestamp: Utc::now(),
+        block_time: Some(Utc::now()),
+        backfilled: false,
+        metadata: metadata(),
+        links: links(),
+        cluster_id: Some(ADDRESS.to_string()),
+        enrichment_errors: vec![EnrichmentError {
+            field: "initial_supply".to_string(),
+            error: "mint account not found".to_string(),
+        }],
+        enrichment_update: false,
+        bundled: true,
+        price_sol: Some(1.0),
+        market_cap_sol: Some(1.0),
+        initial_buy_sol: Some(1),
+        initial_buy_tokens: Some(1),
+    }
+}
+
+fn partial_parse() -> PartialParse {
+    PartialParse {
+        launchpad: LaunchpadType::Pumpfun,
+        instruction: "create".to_string(),
+        token_address: Some(ADDRESS.to_string()),
+        creator: Some(ADDRESS.to_string()),
+        signature: SIGNATURE.to_string(),
+        slot: 1,
+        transaction_index: Some(0),
+        timestamp: Utc::now(),
+        block_time: Some(Utc::now()),
+        backfilled: false,
+        version: TransactionVersion::V0,
+        is_vote: false,
+        metadata: metadata(),
+        confidence: 0.5,
+        missing_fields: vec!["name".to_string()],
+        links: links(),
+    }
+}
+
+fn fee_claim() -> FeeClaim {
+    FeeClaim {
+        launchpad: LaunchpadType::Meteora,
+        kind: FeeClaimKind::PartnerTradingFee,
+        pool: ADDRESS.to_string(),
+        claimer: Some(ADDRESS.to_string()),
+        base_mint: Some(ADDRESS.to_string()),
+        base_amount: 1,
+        quote_amount: 1,
+        signature: SIGNATURE.to_string(),
+        slot: 1,
+        transaction_index: Some(0),
+        timestamp: Utc::now(),
+        block_time: Some(Utc::now()),
+        backfilled: false,
+        links: links(),
+    }
+}
+
+fn migration() -> Migration {
+    Migration {
+        launchpad: LaunchpadType::PumpSwap,
+        token_address: ADDRESS.to_string(),
+        pool: ADDRESS.to_string(),
+        quote_mint: Some(ADDRESS.to_string()),
+        creator: Some(ADDRESS.to_string()),
+        bin_step: Some(25),
+        bonding_curve: Some(ADDRESS.to_string()),
+        base_reserve: Some(1),
+        quote_reserve: Some(1),
+        lp_mint: Some(ADDRESS.to_string()),
+        signature: SIGNATURE.to_string(),
+        slot: 1,
+        transaction_index: Some(0),
+        timestamp: Utc::now(),
+        block_time: Some(Utc::now()),
+        backfilled: false,
+        links: links(),
+    }
+}
+
+fn graduation() -> Graduation {
+    Graduation {
+        launchpad: LaunchpadType::Pumpfun,
+        token_address: ADDRESS.to_string(),
+        bonding_curve: Some(ADDRESS.to_string()),
+        user: Some(ADDRESS.to_string()),
+        trigger: GraduationTrigger::CurveComplete,
+        signature: SIGNATURE.to_string(),
+        slot: 1,
+        transaction_index: Some(0),
+        timestamp: Utc::now(),
+        block_time: Some(Utc::now()),
+        backfilled: false,
+        links: links(),
+    }
+}
+
+fn authority_revoked() -> AuthorityRevoked {
+    AuthorityRevoked {
+        launchpad: LaunchpadType::Pumpfun,
+        token_address: ADDRESS.to_string(),
+        authority: AuthorityType::Freeze,
+        revoked_by: Some(ADDRESS.to_string()),
+        signature: SIGNATURE.to_string(),
+        slot: 1,
+        transaction_index: Some(0),
+        timestamp: Utc::now(),
+        block_time: Some(Utc::now()),
+        backfilled: false,
+        links: links(),
+    }
+}
+
+fn dev_sell() -> DevSell {
+    DevSell {
+        launchpad: LaunchpadType::Pumpfun,
+        token_address: ADDRESS.to_string(),
+        creator: ADDRESS.to_string(),
+        token_amount: 1,
+        sol_amount: 1,
+        launch_signature: SIGNATURE.to_string(),
+        elapsed_ms: 1,
+        signature: SIGNATURE.to_string(),
+        slot: 1,
+        transaction_index: Some(0),
+        timestamp: Utc::now(),
+        block_time: Some(Utc::now()),
+        links: links(),
+    }
+}
+
+fn creator_transfer() -> CreatorTransfer {
+    CreatorTransfer {
+        launchpad: LaunchpadType::Pumpfun,
+        token_address: ADDRESS.to_string(),
+        creator: ADDRESS.to_string(),
+        source: ADDRESS.to_string(),
+        destination: ADDRESS.to_string(),
+        recipient: Some(ADDRESS.to_string()),
+        amount: 1,
+        percent_of_supply: 5.0,
+        signature: SIGNATURE.to_string(),
+        slot: 1,
+        transaction_index: Some(0),
+        timestamp: Utc::now(),
+        block_time: Some(Utc::now()),
+        backfilled: false,
+        links: links(),
+    }
+}
+
+fn first_buyers() -> FirstBuyers {
+    FirstBuyers {
+        launchpad: LaunchpadType::Pumpfun,
+        token_address: ADDRESS.to_string(),
+        creator: Some(ADDRESS.to_string()),
+        launch_signature: SIGNATURE.to_string(),
+        launch_slot: 1,
+        window_slots: 2,
+        buyers: vec![FirstBuyer {
+            wallet: ADDRESS.to_string(),
+            buys: 1,
+            sol_amount: 1,
+            token_amount: 1,
+            first_slot: 1,
+            first_transaction_index: Some(0),
+            in_launch_transaction: true,
+            is_creator: true,
+        }],
+        total_sol_amount: 1,
+        timestamp: Utc::now(),
+        block_time: Some(Utc::now()),
+        links: links(),
+    }
+}
+
+fn lp_burned() -> LpBurned {
+    LpBurned {
+        launchpad: LaunchpadType::Pumpfun,
+        pool: ADDRESS.to_string(),
+        token_address: ADDRESS.to_string(),
+        lp_mint: ADDRESS.to_string(),
+        owner: Some(ADDRESS.to_string()),
+        amount: 1,
+        signature: SIGNATURE.to_string(),
+        slot: 1,
+        transaction_index: Some(0),
+        timestamp: Utc::now(),
+        block_time: Some(Utc::now()),
+        backfilled: false,
+        links: links(),
+    }
+}
+
+fn liquidity() -> Liquidity {
+    Liquidity {
+        launchpad: LaunchpadType::PumpSwap,
+        action: LiquidityAction::Remove,
+        pool: ADDRESS.to_string(),
+        token_address: ADDRESS.to_string(),
+        quote_mint: Some(ADDRESS.to_string()),
+        provider: Some(ADDRESS.to_string()),
+        lp_amount: 1,
+        token_amount: Some(1),
+        quote_amount: Some(1),
+        signature: SIGNATURE.to_string(),
+        slot: 1,
+        transaction_index: Some(0),
+        timestamp: Utc::now(),
+        block_time: Some(Utc::now()),
+        backfilled: false,
+        links: links(),
+    }
+}
+
+fn trade() -> Trade {
+    Trade {
+        launchpad: LaunchpadType::Pumpfun,
+        token_address: ADDRESS.to_string(),
+        trader: ADDRESS.to_string(),
+        amount: 1,
+        token_amount: 1,
+        is_buy: true,
+        fees: Some(TradeFees {
+            fee_recipient: ADDRESS.to_string(),
+            protocol_fee: 1,
+            creator: Some(ADDRESS.to_string()),
+            creator_fee: 1,
+        }),
+        curve_progress: Some(1.0),
+        price_sol: Some(1.0),
+        market_cap_sol: Some(1.0),
+        estimated: false,
+        signature: SIGNATURE.to_string(),
+        slot: 1,
+        transaction_index: Some(0),
+        timestamp: Utc::now(),
+        block_time: Some(Utc::now()),
+    }
+}
+
+fn curve_progress() -> CurveProgress {
+    CurveProgress {
+        launchpad: LaunchpadType::Pumpfun,
+        token_address: ADDRESS.to_string(),
+        bonding_curve: ADDRESS.to_string(),
+        percent_complete: 1.0,
+        virtual_sol_reserves: 1,
+        virtual_token_reserves: 1,
+        real_sol_reserves: 1,
+        real_token_reserves: 1,
+        complete: false,
+        slot: 1,
+        timestamp: Utc::now(),
+    }
+}
+
+fn program_event() -> ProgramEvent {
+    ProgramEvent {
+        launchpad: LaunchpadType::Pumpfun,
+        event_type: "set_params".to_string(),
+        detail: Some("fee_basis_points=95".to_string()),
+        signature: SIGNATURE.to_string(),
+        slot: 1,
+        transaction_index: Some(0),
+        timestamp: Utc::now(),
+        block_time: Some(Utc::now()),
+    }
+}
+
+fn token_status() -> TokenStatus {
+    TokenStatus {
+        launchpad: LaunchpadType::Pumpfun,
+        token_address: ADDRESS.to_string(),
+        creator: Some(ADDRESS.to_string()),
+        launch_signature: SIGNATURE.to_string(),
+        launch_slot: 1,
+        launched_at: Utc::now(),
+        window_secs: 60,
+        buys: 1,
+        sells: 1,
+        unique_wallets: 1,
+        sol_bought: 1,
+        sol_sold: 1,
+        early_buyers: vec![WalletTrades {
+            wallet: ADDRESS.to_string(),
+            first_buy_slot: Some(1),
+            first_buy_transaction_index: Some(0),
+            buys: 1,
+            sells: 1,
+            sol_bought: 1,
+            sol_sold: 1,
+            tokens_bought: 1,
+            tokens_sold: 1,
+        }],
+        fees: Some(TokenFees {
+            launchpad: LaunchpadType::Pumpfun,
+            token_address: ADDRESS.to_string(),
+            creator: Some(ADDRESS.to_string()),
+            trades: 1,
+            protocol_fees: 1,
+            creator_fees: 1,
+            last_trade: Utc::now(),
+        }),
+    }
+}
+
+fn sell_pressure() -> SellPressure {
+    SellPressure {
+        launchpad: LaunchpadType::Pumpfun,
+        token_address: ADDRESS.to_string(),
+        window_secs: 60,
+        buys: 1,
+        sells: 1,
+        sol_bought: 1,
+        sol_sold: 1,
+        signature: SIGNATURE.to_string(),
+        slot: 1,
+        timestamp: Utc::now(),
+        block_time: Some(Utc::now()),
+    }
+}
+
+fn creator_sell() -> CreatorSell {
+    CreatorSell {
+        launchpad: LaunchpadType::Pumpfun,
+        token_address: ADDRESS.to_string(),
+        creator: ADDRESS.to_string(),
+        seller: ADDRESS.to_string(),
+        funded_wallet: false,
+        token_amount: 1,
+        sol_amount: 1,
+        initial_allocation: 1,
+        total_sold: 1,
+        percent_sold: Some(50.0),
+        signature: SIGNATURE.to_string(),
+        slot: 1,
+        timestamp: Utc::now(),
+        block_time: Some(Utc::now()),
+    }
+}
+
+fn digest() -> Digest {
+    Digest {
+        interval_start: Utc::now(),
+        interval_end: Utc::now(),
+        total_launches: 1,
+        launches_per_launchpad: BTreeMap::from([("Pumpfun".to_string(), 1)]),
+        top_launches: vec![DigestEntry {
+            launchpad: LaunchpadType::Pumpfun,
+            token_address: ADDRESS.to_string(),
+            name: Some("Token".to_string()),
+            symbol: Some("TKN".to_string()),
+            signature: Some(SIGNATURE.to_string()),
+            trades: 1,
+            volume: 1,
+        }],
+    }
+}
+
+fn ops_error() -> OpsError {
+    OpsError {
+        kind: OpsErrorKind::ParserFailure,
+        severity: OpsSeverity::Warning,
+        component: "parser".to_string(),
+        message: "failed".to_string(),
+        signature: Some(SIGNATURE.to_string()),
+        slot: Some(1),
+        downtime_secs: Some(1.0),
+        timestamp: Utc::now(),
+    }
+}
+
+fn slot_batch() -> SlotBatch {
+    SlotBatch {
+        slot: 1,
+        events: Vec::new(),
+    }
+}
+
+fn transaction_batch() -> TransactionBatch {
+    TransactionBatch {
+        signature: SIGNATURE.to_string(),
+        slot: 1,
+        transaction_index: Some(0),
+        events: Vec::new(),
+    }
+}
+
+fn raw_transaction() -> RawTransaction {
+    RawTransaction {
+        signature: SIGNATURE.to_string(),
+        slot: 1,
+        transaction_index: Some(0),
+        filters: vec!["transactions".to_string()],
+        received_time: Utc::now(),
+        data: vec![0],
+    }
+}
@@ -0,0 +1,32 @@
+use futures::FutureExt;
+use futures::future::BoxFuture;
+use std::time::Duration;
+
+use crate::cache::Cache;
+use crate::config::cache::CacheConfig;
+
+/// In-process cache with TTL and bounded size, local to one instance
+pub struct MemoryCache {
+    entries: moka::future::Cache<String, String>,
+}
+
+impl MemoryCache {
+    pub fn new(config: &CacheConfig) -> Self {
+        let entries = moka::future::Cache::builder()
+            .max_capacity(config.max_entries)
+            .time_to_live(Duration::from_secs(config.ttl_secs))
+            .build();
+
+        Self { entries }
+    }
+}
+
+impl Cache for MemoryCache {
+    fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<String>> {
+        self.entries.get(key).boxed()
+    }
+
+    fn set<'a>(&'a self, key: &'a str, value: String) -> BoxFuture<'a, ()> {
+        self.entries.insert(key.to_string(), value).boxed()
+    }
+}
@@ -0,0 +1,55 @@
+pub mod memory;
+pub mod redis;
+
+use anyhow::{Result, bail};
+use futures::future::BoxFuture;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+use crate::config::cache::CacheConfig;
+
+pub use memory::MemoryCache;
+pub use redis::RedisCache;
+
+/// Key-value store for enrichment lookups.
+/// Backend failures are logged and behave like a miss, so a cache outage never blocks publishing.
+pub trait Cache: Send + Sync {
+    fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<String>>;
+
+    fn set<'a>(&'a self, key: &'a str, value: String) -> BoxFuture<'a, ()>;
+}
+
+/// Reads a JSON value from the cache, treating undecodable entries as a miss
+pub async fn get_json<T: DeserializeOwned>(cache: &dyn Cache, key: &str) -> Option<T> {
+    let raw = cache.get(key).await?;
+    match serde_json::from_str(&raw) {
+        Ok(value) => Some(value),
+        Err(e) => {
+            warn!("⚠️ Ignoring undecodable cache entry {}: {}", key, e);
+            None
+        }
+    }
+}
+
+/// Writes a value to the cache as JSON
+pub async fn set_json<T: Serialize>(cache: &dyn Cache, key: &str, value: &T) {
+    match serde_json::to_string(value) {
+        Ok(raw) => cache.set(key, raw).await,
+        Err(e) => warn!("⚠️ Failed to encode cache entry {}: {}", key, e),
+    }
+}
+
+/// Builds the configured cache backend, `None` when caching is disabled
+pub async fn from_config(config: &CacheConfig) -> Result<Option<Arc<dyn Cache>>> {
+    let cache: Arc<dyn Cache> = match config.backend.as_str() {
+        "none" | "off" => return Ok(None),
+        "memory" => Arc::new(MemoryCache::new(config)),
+        "redis" => Arc::new(RedisCache::connect(config).await?),
+        other => bail!("Unknown cache backend: {}", other),
+    };
+
+    info!("🗄️ Enrichment cache backend: {}", config.backend);
+    Ok(Some(cache))
+}
@@ -0,0 +1,64 @@
+use anyhow::{Context, Result};
+use futures::FutureExt;
+use futures::future::BoxFuture;
+use redis::AsyncCommands;
+use redis::aio::ConnectionManager;
+use tracing::{info, warn};
+
+use crate::cache::Cache;
+use crate::config::cache::CacheConfig;
+
+/// Redis-backed cache shared between instances and surviving restarts
+pub struct RedisCache {
+    connection: ConnectionManager,
+    ttl_secs: u64,
+}
+
+impl RedisCache {
+    pub async fn connect(config: &CacheConfig) -> Result<Self> {
+        info!("Connecting to Redis cache");
+
+        let client = redis::Client::open(config.redis_url.as_str()).context("Invalid Redis URL")?;
+        let connection = client
+            .get_connection_manager()
+            .await
+            .context("Cannot connect to Redis")?;
+
+        info!("✅ Connected to Redis cache");
+
+        Ok(Self {
+            connection,
+            ttl_secs: config.ttl_secs,
+        })
+    }
+}
+
+impl Cache for RedisCache {
+    fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<String>> {
+        // ConnectionManager is a cheap handle onto a shared multiplexed connection
+        let mut connection = self.connection.clone();
+        async move {
+            match connection.get::<_, Option<String>>(key).await {
+                Ok(value) => value,
+                Err(e) => {
+                    warn!("⚠️ Redis GET {} failed: {}", key, e);
+                    None
+                }
+            }
+        }
+        .boxed()
+    }
+
+    fn set<'a>(&'a self, key: &'a str, value: String) -> BoxFuture<'a, ()> {
+        let mut connection = self.connection.clone();
+        async move {
+            if let Err(e) = connection
+                .set_ex::<_, _, ()>(key, value, self.ttl_secs)
+                .await
+            {
+                warn!("⚠️ Redis SET {} failed: {}", key, e);
+            }
+        }
+        .boxed()
+    }
+}
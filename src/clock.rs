@@ -0,0 +1,75 @@
+use chrono::{DateTime, Utc};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Source of the current time. Components with age cutoffs, windows or TTLs take one so
+/// tests can drive them with a [`MockClock`] instead of sleeping.
+pub trait Clock: Send + Sync {
+    /// Wall clock time, stamped on events
+    fn now(&self) -> DateTime<Utc>;
+
+    /// Monotonic time, for windows and TTLs
+    fn instant(&self) -> Instant;
+}
+
+/// Clock shared between the components of a pipeline
+pub type SharedClock = Arc<dyn Clock>;
+
+/// The system clock, used unless another one is injected
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    fn instant(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Shared handle to the system clock
+pub fn system() -> SharedClock {
+    Arc::new(SystemClock)
+}
+
+/// Clock that only moves when advanced. Clones share the same time.
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    start: DateTime<Utc>,
+    start_instant: Instant,
+    elapsed: Arc<Mutex<Duration>>,
+}
+
+impl MockClock {
+    /// A clock standing at `start`
+    pub fn new(start: DateTime<Utc>) -> Self {
+        Self {
+            start,
+            start_instant: Instant::now(),
+            elapsed: Arc::new(Mutex::new(Duration::ZERO)),
+        }
+    }
+
+    /// Moves the clock forward
+    pub fn advance(&self, by: Duration) {
+        *self.elapsed.lock().unwrap() += by;
+    }
+
+    /// Handle for injecting the clock
+    pub fn shared(&self) -> SharedClock {
+        Arc::new(self.clone())
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> DateTime<Utc> {
+        let elapsed = *self.elapsed.lock().unwrap();
+        self.start + chrono::Duration::from_std(elapsed).unwrap_or(chrono::TimeDelta::MAX)
+    }
+
+    fn instant(&self) -> Instant {
+        self.start_instant + *self.elapsed.lock().unwrap()
+    }
+}
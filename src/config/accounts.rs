@@ -0,0 +1,83 @@
+use crate::config::error::Result;
+use std::env;
+use tracing::{debug, info};
+
+#[derive(Debug, Clone)]
+pub struct AccountTrackingConfig {
+    pub enabled: bool,
+    /// How long a launch's accounts stay subscribed when it does not graduate
+    pub ttl_secs: u64,
+    /// Most accounts subscribed at once, new launches are skipped beyond it
+    pub max_accounts: usize,
+    /// Shortest interval between two subscription updates, changes in between are batched
+    pub update_interval_ms: u64,
+    /// Also follow the token account of each launch's creator for transfers out of it
+    pub creator_transfers: bool,
+    /// Share of the supply in percent a creator transfer must move to be published
+    pub creator_transfer_min_percent: f64,
+    /// Publish the progress of tracked pump.fun bonding curves from their account updates
+    pub curve_progress: bool,
+    /// Interval between two rounds of curve progress, only changed curves are published
+    pub curve_progress_interval_ms: u64,
+}
+
+impl AccountTrackingConfig {
+    /// Load account tracking configuration from environment variables, providing sensible defaults
+    pub async fn from_env() -> Result<Self> {
+        info!("Loading account tracking configuration from environment");
+
+        debug!("Getting ACCOUNT_TRACKING_ENABLED from env");
+        let enabled = env::var("ACCOUNT_TRACKING_ENABLED")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        debug!("Getting ACCOUNT_TRACKING_TTL_SECS from env");
+        let ttl_secs = env::var("ACCOUNT_TRACKING_TTL_SECS")
+            .unwrap_or_else(|_| "3600".to_string())
+            .parse()?;
+
+        debug!("Getting ACCOUNT_TRACKING_MAX_ACCOUNTS from env");
+        let max_accounts = env::var("ACCOUNT_TRACKING_MAX_ACCOUNTS")
+            .unwrap_or_else(|_| "1000".to_string())
+            .parse()?;
+
+        debug!("Getting ACCOUNT_TRACKING_UPDATE_INTERVAL_MS from env");
+        let update_interval_ms = env::var("ACCOUNT_TRACKING_UPDATE_INTERVAL_MS")
+            .unwrap_or_else(|_| "1000".to_string())
+            .parse::<u64>()?
+            .max(100);
+
+        debug!("Getting ACCOUNT_TRACKING_CREATOR_TRANSFERS from env");
+        let creator_transfers = env::var("ACCOUNT_TRACKING_CREATOR_TRANSFERS")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        debug!("Getting ACCOUNT_TRACKING_CREATOR_TRANSFER_MIN_PERCENT from env");
+        let creator_transfer_min_percent =
+            env::var("ACCOUNT_TRACKING_CREATOR_TRANSFER_MIN_PERCENT")
+                .unwrap_or_else(|_| "5".to_string())
+                .parse()?;
+
+        debug!("Getting ACCOUNT_TRACKING_CURVE_PROGRESS from env");
+        let curve_progress = env::var("ACCOUNT_TRACKING_CURVE_PROGRESS")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        debug!("Getting ACCOUNT_TRACKING_CURVE_PROGRESS_INTERVAL_MS from env");
+        let curve_progress_interval_ms = env::var("ACCOUNT_TRACKING_CURVE_PROGRESS_INTERVAL_MS")
+            .unwrap_or_else(|_| "5000".to_string())
+            .parse::<u64>()?
+            .max(100);
+
+        Ok(Self {
+            enabled,
+            ttl_secs,
+            max_accounts,
+            update_interval_ms,
+            creator_transfers,
+            creator_transfer_min_percent,
+            curve_progress,
+            curve_progress_interval_ms,
+        })
+    }
+}
@@ -0,0 +1,27 @@
+use crate::config::error::Result;
+use std::env;
+use tracing::{debug, info};
+
+#[derive(Debug, Clone)]
+pub struct AdminConfig {
+    pub enabled: bool,
+    pub bind_addr: String,
+}
+
+impl AdminConfig {
+    /// Load admin HTTP server configuration from environment variables, providing sensible defaults
+    pub async fn from_env() -> Result<Self> {
+        info!("Loading admin server configuration from environment");
+
+        debug!("Getting ADMIN_ENABLED from env");
+        let enabled = env::var("ADMIN_ENABLED")
+            .map(|v| v != "false" && v != "0")
+            .unwrap_or(true);
+
+        debug!("Getting ADMIN_BIND_ADDR from env");
+        let bind_addr =
+            env::var("ADMIN_BIND_ADDR").unwrap_or_else(|_| "127.0.0.1:9090".to_string());
+
+        Ok(Self { enabled, bind_addr })
+    }
+}
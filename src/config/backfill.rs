@@ -0,0 +1,40 @@
+use crate::config::error::Result;
+use std::env;
+use tracing::{debug, info};
+
+#[derive(Debug, Clone)]
+pub struct BackfillConfig {
+    pub enabled: bool,
+    pub max_slots: u64,
+    pub delay_ms: u64,
+}
+
+impl BackfillConfig {
+    /// Load gap backfill configuration from environment variables, providing sensible defaults
+    pub async fn from_env() -> Result<Self> {
+        info!("Loading backfill configuration from environment");
+
+        debug!("Getting BACKFILL_ENABLED from env");
+        let enabled = env::var("BACKFILL_ENABLED")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        debug!("Getting BACKFILL_MAX_SLOTS from env");
+        let max_slots = env::var("BACKFILL_MAX_SLOTS")
+            .unwrap_or_else(|_| "1000".to_string())
+            .parse::<u64>()?
+            .max(1);
+
+        // Missing blocks are only served by RPC once confirmed
+        debug!("Getting BACKFILL_DELAY_MS from env");
+        let delay_ms = env::var("BACKFILL_DELAY_MS")
+            .unwrap_or_else(|_| "5000".to_string())
+            .parse()?;
+
+        Ok(Self {
+            enabled,
+            max_slots,
+            delay_ms,
+        })
+    }
+}
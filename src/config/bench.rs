@@ -0,0 +1,42 @@
+use crate::config::error::Result;
+use std::env;
+use tracing::{debug, info};
+
+#[derive(Debug, Clone)]
+pub struct BenchConfig {
+    pub enabled: bool,
+    /// Time between benchmark runs, the first runs on startup
+    pub interval_secs: u64,
+    /// How long each run keeps parsing fixtures
+    pub duration_ms: u64,
+}
+
+impl BenchConfig {
+    /// Load parse benchmark configuration from environment variables, providing sensible defaults
+    pub async fn from_env() -> Result<Self> {
+        info!("Loading benchmark configuration from environment");
+
+        debug!("Getting BENCH_ENABLED from env");
+        let enabled = env::var("BENCH_ENABLED")
+            .map(|v| v != "false" && v != "0")
+            .unwrap_or(true);
+
+        debug!("Getting BENCH_INTERVAL_SECS from env");
+        let interval_secs = env::var("BENCH_INTERVAL_SECS")
+            .unwrap_or_else(|_| "3600".to_string())
+            .parse::<u64>()?
+            .max(1);
+
+        debug!("Getting BENCH_DURATION_MS from env");
+        let duration_ms = env::var("BENCH_DURATION_MS")
+            .unwrap_or_else(|_| "500".to_string())
+            .parse::<u64>()?
+            .max(1);
+
+        Ok(Self {
+            enabled,
+            interval_secs,
+            duration_ms,
+        })
+    }
+}
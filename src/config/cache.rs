@@ -0,0 +1,45 @@
+use crate::config::error::Result;
+use std::env;
+use tracing::{debug, info};
+
+#[derive(Debug, Clone)]
+pub struct CacheConfig {
+    /// `memory`, `redis` or `none`
+    pub backend: String,
+    pub redis_url: String,
+    pub ttl_secs: u64,
+    pub max_entries: u64,
+}
+
+impl CacheConfig {
+    /// Load enrichment cache configuration from environment variables, providing sensible defaults
+    pub async fn from_env() -> Result<Self> {
+        info!("Loading cache configuration from environment");
+
+        debug!("Getting CACHE_BACKEND from env");
+        let backend = env::var("CACHE_BACKEND")
+            .unwrap_or_else(|_| "memory".to_string())
+            .to_lowercase();
+
+        debug!("Getting CACHE_REDIS_URL from env");
+        let redis_url =
+            env::var("CACHE_REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string());
+
+        debug!("Getting CACHE_TTL_SECS from env");
+        let ttl_secs = env::var("CACHE_TTL_SECS")
+            .unwrap_or_else(|_| "3600".to_string())
+            .parse()?;
+
+        debug!("Getting CACHE_MAX_ENTRIES from env");
+        let max_entries = env::var("CACHE_MAX_ENTRIES")
+            .unwrap_or_else(|_| "100000".to_string())
+            .parse()?;
+
+        Ok(Self {
+            backend,
+            redis_url,
+            ttl_secs,
+            max_entries,
+        })
+    }
+}
@@ -0,0 +1,50 @@
+use crate::config::error::Result;
+use std::env;
+use tracing::{debug, info};
+
+#[derive(Debug, Clone)]
+pub struct ClusterConfig {
+    pub enabled: bool,
+    /// How long a wallet, name, symbol or uri keeps linking new launches to its cluster
+    pub window_secs: u64,
+    /// Also link launches sharing a name, symbol or metadata uri, not only wallets
+    pub match_metadata: bool,
+    /// Most linking keys kept, the least recently seen are dropped beyond it
+    pub max_keys: usize,
+}
+
+impl ClusterConfig {
+    /// Load launch clustering configuration from environment variables, providing sensible defaults
+    pub async fn from_env() -> Result<Self> {
+        info!("Loading cluster configuration from environment");
+
+        debug!("Getting CLUSTER_ENABLED from env");
+        let enabled = env::var("CLUSTER_ENABLED")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        debug!("Getting CLUSTER_WINDOW_SECS from env");
+        let window_secs = env::var("CLUSTER_WINDOW_SECS")
+            .unwrap_or_else(|_| "3600".to_string())
+            .parse::<u64>()?
+            .max(1);
+
+        debug!("Getting CLUSTER_MATCH_METADATA from env");
+        let match_metadata = env::var("CLUSTER_MATCH_METADATA")
+            .map(|v| v != "false" && v != "0")
+            .unwrap_or(true);
+
+        debug!("Getting CLUSTER_MAX_KEYS from env");
+        let max_keys = env::var("CLUSTER_MAX_KEYS")
+            .unwrap_or_else(|_| "100000".to_string())
+            .parse::<usize>()?
+            .max(1);
+
+        Ok(Self {
+            enabled,
+            window_secs,
+            match_metadata,
+            max_keys,
+        })
+    }
+}
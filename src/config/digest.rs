@@ -0,0 +1,39 @@
+use crate::config::error::Result;
+use std::env;
+use tracing::{debug, info};
+
+#[derive(Debug, Clone)]
+pub struct DigestConfig {
+    pub enabled: bool,
+    pub interval_secs: u64,
+    pub top_n: usize,
+}
+
+impl DigestConfig {
+    /// Load digest configuration from environment variables, providing sensible defaults
+    pub async fn from_env() -> Result<Self> {
+        info!("Loading digest configuration from environment");
+
+        debug!("Getting DIGEST_ENABLED from env");
+        let enabled = env::var("DIGEST_ENABLED")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        debug!("Getting DIGEST_INTERVAL_SECS from env");
+        let interval_secs = env::var("DIGEST_INTERVAL_SECS")
+            .unwrap_or_else(|_| "300".to_string())
+            .parse::<u64>()?
+            .max(1);
+
+        debug!("Getting DIGEST_TOP_N from env");
+        let top_n = env::var("DIGEST_TOP_N")
+            .unwrap_or_else(|_| "10".to_string())
+            .parse()?;
+
+        Ok(Self {
+            enabled,
+            interval_secs,
+            top_n,
+        })
+    }
+}
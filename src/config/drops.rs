@@ -0,0 +1,23 @@
+use crate::config::error::Result;
+use std::env;
+use tracing::{debug, info};
+
+#[derive(Debug, Clone)]
+pub struct DropLogConfig {
+    /// Recent transactions kept with the reason they produced no event, 0 only counts them
+    pub capacity: usize,
+}
+
+impl DropLogConfig {
+    /// Load drop log configuration from environment variables, providing sensible defaults
+    pub async fn from_env() -> Result<Self> {
+        info!("Loading drop log configuration from environment");
+
+        debug!("Getting DROP_LOG_CAPACITY from env");
+        let capacity = env::var("DROP_LOG_CAPACITY")
+            .unwrap_or_else(|_| "1000".to_string())
+            .parse()?;
+
+        Ok(Self { capacity })
+    }
+}
@@ -0,0 +1,78 @@
+use crate::config::error::Result;
+use std::env;
+use tracing::{debug, info};
+
+#[derive(Debug, Clone)]
+pub struct EnrichmentConfig {
+    pub enabled: bool,
+    pub batch_size: usize,
+    pub batch_window_ms: u64,
+    pub max_concurrency: usize,
+    pub max_retries: u32,
+    pub retry_base_ms: u64,
+    /// Background retries of launches published with enrichment errors, 0 disables them
+    pub retry_attempts: u32,
+    /// Delay before the first retry, multiplied by the attempt number for later ones
+    pub retry_delay_ms: u64,
+}
+
+impl EnrichmentConfig {
+    /// Load RPC enrichment configuration from environment variables, providing sensible defaults
+    pub async fn from_env() -> Result<Self> {
+        info!("Loading enrichment configuration from environment");
+
+        debug!("Getting ENRICHMENT_ENABLED from env");
+        let enabled = env::var("ENRICHMENT_ENABLED")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        // getMultipleAccounts accepts at most 100 keys per call
+        debug!("Getting ENRICHMENT_BATCH_SIZE from env");
+        let batch_size = env::var("ENRICHMENT_BATCH_SIZE")
+            .unwrap_or_else(|_| "100".to_string())
+            .parse::<usize>()?
+            .clamp(1, 100);
+
+        debug!("Getting ENRICHMENT_BATCH_WINDOW_MS from env");
+        let batch_window_ms = env::var("ENRICHMENT_BATCH_WINDOW_MS")
+            .unwrap_or_else(|_| "200".to_string())
+            .parse()?;
+
+        debug!("Getting RPC_MAX_CONCURRENCY from env");
+        let max_concurrency = env::var("RPC_MAX_CONCURRENCY")
+            .unwrap_or_else(|_| "4".to_string())
+            .parse::<usize>()?
+            .max(1);
+
+        debug!("Getting RPC_MAX_RETRIES from env");
+        let max_retries = env::var("RPC_MAX_RETRIES")
+            .unwrap_or_else(|_| "3".to_string())
+            .parse()?;
+
+        debug!("Getting RPC_RETRY_BASE_MS from env");
+        let retry_base_ms = env::var("RPC_RETRY_BASE_MS")
+            .unwrap_or_else(|_| "250".to_string())
+            .parse()?;
+
+        debug!("Getting ENRICHMENT_RETRY_ATTEMPTS from env");
+        let retry_attempts = env::var("ENRICHMENT_RETRY_ATTEMPTS")
+            .unwrap_or_else(|_| "3".to_string())
+            .parse()?;
+
+        debug!("Getting ENRICHMENT_RETRY_DELAY_MS from env");
+        let retry_delay_ms = env::var("ENRICHMENT_RETRY_DELAY_MS")
+            .unwrap_or_else(|_| "2000".to_string())
+            .parse()?;
+
+        Ok(Self {
+            enabled,
+            batch_size,
+            batch_window_ms,
+            max_concurrency,
+            max_retries,
+            retry_base_ms,
+            retry_attempts,
+            retry_delay_ms,
+        })
+    }
+}
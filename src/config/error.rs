@@ -16,4 +16,31 @@ pub enum ErrorConfig {
 
     #[error(transparent)]
     SerdeJsoncError(#[from] serde_jsonc::Error),
+
+    #[error(transparent)]
+    ParseIntError(#[from] std::num::ParseIntError),
+
+    #[error(transparent)]
+    ParseFloatError(#[from] std::num::ParseFloatError),
+
+    #[error("environment variable {0} referenced in config is not set")]
+    MissingEnvVar(String),
+
+    #[error("unterminated ${{...}} in config")]
+    UnterminatedInterpolation,
+
+    #[error("config profile {0} not found in `profiles`")]
+    UnknownProfile(String),
+
+    #[error("invalid partition: {0}")]
+    InvalidPartition(String),
+
+    #[error("unknown explorer {0}, expected solscan, solana.fm, xray or none")]
+    InvalidExplorer(String),
+
+    #[error("invalid pre-filter config: {0}")]
+    InvalidPreFilter(String),
+
+    #[error("transaction filters exceed the provider limits: {0}")]
+    FilterPlanExceedsLimits(String),
 }
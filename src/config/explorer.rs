@@ -0,0 +1,72 @@
+use crate::config::error::{ErrorConfig, Result};
+use std::env;
+use tracing::{debug, info};
+
+/// Block explorer used for the links on published events
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Explorer {
+    Solscan,
+    SolanaFm,
+    Xray,
+}
+
+impl Explorer {
+    fn base_url(&self) -> &'static str {
+        match self {
+            Explorer::Solscan => "https://solscan.io",
+            Explorer::SolanaFm => "https://solana.fm",
+            Explorer::Xray => "https://xray.helius.xyz",
+        }
+    }
+
+    /// Transaction page, the same for legacy and v0 transactions
+    pub fn transaction_url(&self, signature: &str) -> String {
+        format!("{}/tx/{}", self.base_url(), signature)
+    }
+
+    /// Token page of a mint
+    pub fn token_url(&self, mint: &str) -> String {
+        match self {
+            Explorer::Solscan | Explorer::Xray => format!("{}/token/{}", self.base_url(), mint),
+            Explorer::SolanaFm => format!("{}/address/{}", self.base_url(), mint),
+        }
+    }
+
+    /// Account page of a wallet or program account
+    pub fn account_url(&self, address: &str) -> String {
+        match self {
+            Explorer::Solscan | Explorer::Xray => {
+                format!("{}/account/{}", self.base_url(), address)
+            }
+            Explorer::SolanaFm => format!("{}/address/{}", self.base_url(), address),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ExplorerConfig {
+    /// `None` leaves links off published events
+    pub explorer: Option<Explorer>,
+}
+
+impl ExplorerConfig {
+    /// Load explorer link configuration from environment variables, providing sensible defaults
+    pub async fn from_env() -> Result<Self> {
+        info!("Loading explorer configuration from environment");
+
+        debug!("Getting EXPLORER from env");
+        let explorer = match env::var("EXPLORER")
+            .unwrap_or_else(|_| "solscan".to_string())
+            .to_lowercase()
+            .as_str()
+        {
+            "solscan" => Some(Explorer::Solscan),
+            "solana.fm" | "solanafm" | "solana_fm" => Some(Explorer::SolanaFm),
+            "xray" => Some(Explorer::Xray),
+            "none" | "" => None,
+            other => return Err(ErrorConfig::InvalidExplorer(other.to_string())),
+        };
+
+        Ok(Self { explorer })
+    }
+}
@@ -0,0 +1,50 @@
+use crate::config::error::Result;
+use std::env;
+use tracing::{debug, info};
+
+/// Geyser provider limits the transaction filters compiled from the parsers must fit.
+/// A limit of 0 means unlimited.
+#[derive(Debug, Clone)]
+pub struct FilterPlanConfig {
+    pub enabled: bool,
+    /// Accounts in one transaction filter's `account_include`
+    pub max_accounts: usize,
+    /// Transaction filters in one subscription
+    pub max_filters: usize,
+    /// Concurrent subscriptions (streams) to the provider
+    pub max_streams: usize,
+}
+
+impl FilterPlanConfig {
+    /// Load filter plan configuration from environment variables, providing sensible defaults
+    pub async fn from_env() -> Result<Self> {
+        info!("Loading filter plan configuration from environment");
+
+        debug!("Getting FILTER_COMPILER_ENABLED from env");
+        let enabled = env::var("FILTER_COMPILER_ENABLED")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        debug!("Getting FILTER_MAX_ACCOUNTS from env");
+        let max_accounts = env::var("FILTER_MAX_ACCOUNTS")
+            .unwrap_or_else(|_| "0".to_string())
+            .parse()?;
+
+        debug!("Getting FILTER_MAX_FILTERS from env");
+        let max_filters = env::var("FILTER_MAX_FILTERS")
+            .unwrap_or_else(|_| "0".to_string())
+            .parse()?;
+
+        debug!("Getting FILTER_MAX_STREAMS from env");
+        let max_streams = env::var("FILTER_MAX_STREAMS")
+            .unwrap_or_else(|_| "1".to_string())
+            .parse()?;
+
+        Ok(Self {
+            enabled,
+            max_accounts,
+            max_filters,
+            max_streams,
+        })
+    }
+}
@@ -0,0 +1,115 @@
+use crate::config::error::{ErrorConfig, Result};
+use std::env;
+use tracing::{debug, info};
+
+/// Pre-filter applied to incoming transactions before they are decoded and queued
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreFilterKind {
+    /// Keeps transactions matching a configured transaction filter's accounts
+    Accounts,
+    /// Keeps transactions with an instruction starting with a listed discriminator
+    Discriminators,
+    /// Drops failed transactions
+    Failed,
+    /// Drops transactions from slots too far behind the newest one seen
+    Age,
+    /// Queues transactions touching a watchlisted account, skipping later filters
+    Watchlist,
+}
+
+impl PreFilterKind {
+    fn parse(name: &str) -> Result<Self> {
+        match name.to_lowercase().as_str() {
+            "accounts" => Ok(Self::Accounts),
+            "discriminators" => Ok(Self::Discriminators),
+            "failed" => Ok(Self::Failed),
+            "age" => Ok(Self::Age),
+            "watchlist" => Ok(Self::Watchlist),
+            _ => Err(ErrorConfig::InvalidPreFilter(format!(
+                "unknown filter {}, expected accounts, discriminators, failed, age or watchlist",
+                name
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PreFilterConfig {
+    /// Filters in the order they are applied
+    pub filters: Vec<PreFilterKind>,
+    /// Instruction discriminator prefixes for `discriminators`
+    pub discriminators: Vec<Vec<u8>>,
+    /// Slots a transaction may lag behind the newest seen for `age`
+    pub max_slot_lag: u64,
+    /// Accounts for `watchlist`
+    pub watchlist: Vec<String>,
+}
+
+impl PreFilterConfig {
+    /// Load pre-filter configuration from environment variables, providing sensible defaults
+    pub async fn from_env() -> Result<Self> {
+        info!("Loading pre-filter configuration from environment");
+
+        debug!("Getting PRE_FILTERS from env");
+        let filters = list(&env::var("PRE_FILTERS").unwrap_or_else(|_| "accounts".to_string()))
+            .iter()
+            .map(|name| PreFilterKind::parse(name))
+            .collect::<Result<Vec<_>>>()?;
+
+        debug!("Getting PRE_FILTER_DISCRIMINATORS from env");
+        let discriminators = list(&env::var("PRE_FILTER_DISCRIMINATORS").unwrap_or_default())
+            .iter()
+            .map(|hex| decode_hex(hex))
+            .collect::<Result<Vec<_>>>()?;
+
+        debug!("Getting PRE_FILTER_MAX_SLOT_LAG from env");
+        let max_slot_lag = env::var("PRE_FILTER_MAX_SLOT_LAG")
+            .unwrap_or_else(|_| "150".to_string())
+            .parse()?;
+
+        debug!("Getting PRE_FILTER_WATCHLIST from env");
+        let watchlist = list(&env::var("PRE_FILTER_WATCHLIST").unwrap_or_default());
+
+        if filters.contains(&PreFilterKind::Discriminators) && discriminators.is_empty() {
+            return Err(ErrorConfig::InvalidPreFilter(
+                "discriminators filter needs PRE_FILTER_DISCRIMINATORS".to_string(),
+            ));
+        }
+        if filters.contains(&PreFilterKind::Watchlist) && watchlist.is_empty() {
+            return Err(ErrorConfig::InvalidPreFilter(
+                "watchlist filter needs PRE_FILTER_WATCHLIST".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            filters,
+            discriminators,
+            max_slot_lag,
+            watchlist,
+        })
+    }
+}
+
+fn list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+fn decode_hex(hex: &str) -> Result<Vec<u8>> {
+    let invalid = || ErrorConfig::InvalidPreFilter(format!("invalid discriminator {}", hex));
+    if hex.is_empty() || !hex.len().is_multiple_of(2) {
+        return Err(invalid());
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            hex.get(i..i + 2)
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                .ok_or_else(invalid)
+        })
+        .collect()
+}
@@ -0,0 +1,48 @@
+use crate::config::error::Result;
+use std::env;
+use tracing::{debug, info};
+
+#[derive(Debug, Clone)]
+pub struct FirstBuyersConfig {
+    pub enabled: bool,
+    /// Slots after the launch slot whose buys are collected
+    pub window_slots: u64,
+    /// Most wallets listed per launch, later buyers are left out
+    pub max_buyers: usize,
+    /// Most launches collected at once, new launches are skipped beyond it
+    pub max_pending: usize,
+}
+
+impl FirstBuyersConfig {
+    /// Load first buyers configuration from environment variables, providing sensible defaults
+    pub async fn from_env() -> Result<Self> {
+        info!("Loading first buyers configuration from environment");
+
+        debug!("Getting FIRST_BUYERS_ENABLED from env");
+        let enabled = env::var("FIRST_BUYERS_ENABLED")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        debug!("Getting FIRST_BUYERS_WINDOW_SLOTS from env");
+        let window_slots = env::var("FIRST_BUYERS_WINDOW_SLOTS")
+            .unwrap_or_else(|_| "2".to_string())
+            .parse()?;
+
+        debug!("Getting FIRST_BUYERS_MAX_BUYERS from env");
+        let max_buyers = env::var("FIRST_BUYERS_MAX_BUYERS")
+            .unwrap_or_else(|_| "20".to_string())
+            .parse()?;
+
+        debug!("Getting FIRST_BUYERS_MAX_PENDING from env");
+        let max_pending = env::var("FIRST_BUYERS_MAX_PENDING")
+            .unwrap_or_else(|_| "10000".to_string())
+            .parse()?;
+
+        Ok(Self {
+            enabled,
+            window_slots,
+            max_buyers,
+            max_pending,
+        })
+    }
+}
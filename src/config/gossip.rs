@@ -0,0 +1,68 @@
+use crate::config::error::Result;
+use std::env;
+use tracing::{debug, info};
+
+#[derive(Debug, Clone)]
+pub struct GossipConfig {
+    /// Exchange launch sightings with other instances through the broker
+    pub enabled: bool,
+    /// Where this instance runs, e.g. `fra`
+    pub region: String,
+    /// Geyser vendor this instance streams from
+    pub provider: String,
+    pub routing_key: String,
+    /// How often the sightings seen since are published
+    pub flush_interval_ms: u64,
+    /// Rolling window relative latencies are compared over, and how long a sighting waits
+    /// for the other instances'
+    pub window_secs: u64,
+}
+
+impl GossipConfig {
+    /// Load latency gossip configuration from environment variables, providing sensible defaults
+    pub async fn from_env() -> Result<Self> {
+        info!("Loading latency gossip configuration from environment");
+
+        debug!("Getting LATENCY_GOSSIP_ENABLED from env");
+        let enabled = env::var("LATENCY_GOSSIP_ENABLED")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        debug!("Getting LATENCY_GOSSIP_REGION from env");
+        let region = env::var("LATENCY_GOSSIP_REGION").unwrap_or_else(|_| "default".to_string());
+
+        debug!("Getting LATENCY_GOSSIP_PROVIDER from env");
+        let provider =
+            env::var("LATENCY_GOSSIP_PROVIDER").unwrap_or_else(|_| "default".to_string());
+
+        debug!("Getting LATENCY_GOSSIP_ROUTING_KEY from env");
+        let routing_key =
+            env::var("LATENCY_GOSSIP_ROUTING_KEY").unwrap_or_else(|_| "latency.gossip".to_string());
+
+        debug!("Getting LATENCY_GOSSIP_FLUSH_MS from env");
+        let flush_interval_ms = env::var("LATENCY_GOSSIP_FLUSH_MS")
+            .unwrap_or_else(|_| "1000".to_string())
+            .parse::<u64>()?
+            .max(1);
+
+        debug!("Getting LATENCY_GOSSIP_WINDOW_SECS from env");
+        let window_secs = env::var("LATENCY_GOSSIP_WINDOW_SECS")
+            .unwrap_or_else(|_| "300".to_string())
+            .parse::<u64>()?
+            .max(1);
+
+        Ok(Self {
+            enabled,
+            region,
+            provider,
+            routing_key,
+            flush_interval_ms,
+            window_secs,
+        })
+    }
+
+    /// Name of this instance in the gossip, `region/provider`
+    pub fn source(&self) -> String {
+        format!("{}/{}", self.region, self.provider)
+    }
+}
@@ -1,4 +1,5 @@
 use serde::Deserialize;
+use serde_jsonc::Value;
 use solana_stream_sdk::GeyserCommitmentLevel;
 use solana_stream_sdk::{
     GeyserAccountsFilterEnum, GeyserLamportsCmp, GeyserMemcmpData,
@@ -12,15 +13,37 @@ use std::collections::HashMap;
 use std::{env, fs};
 use tracing::{debug, info};
 
-use crate::config::error::Result;
+use crate::config::error::{ErrorConfig, Result};
+use crate::config::parsers::ParsersConfig;
 
 pub async fn config_grpc() -> Result<(GeyserConfig, Config)> {
     info!("Config GRPC");
-    debug!("Getting CONFIG_PATH from env");
-    let config_path = env::var("CONFIG_PATH")?;
+    // CONFIG_JSON carries the whole config inline, for containers where mounting files is awkward
+    debug!("Getting CONFIG_JSON from env");
+    let config_content = match env::var("CONFIG_JSON") {
+        Ok(json) => json,
+        Err(_) => {
+            debug!("Getting CONFIG_PATH from env");
+            let config_path = env::var("CONFIG_PATH")?;
 
-    debug!("Reading config file");
-    let config_content = fs::read_to_string(config_path)?;
+            debug!("Reading config file");
+            fs::read_to_string(config_path)?
+        }
+    };
+
+    // The profile is applied before interpolation, so variables only referenced by other
+    // profiles don't have to be set
+    debug!("Getting CONFIG_PROFILE from env");
+    let config_content = match env::var("CONFIG_PROFILE").ok().filter(|v| !v.is_empty()) {
+        Some(profile) => {
+            info!("Applying config profile {}", profile);
+            apply_profile(&config_content, &profile)?
+        }
+        None => config_content,
+    };
+
+    debug!("Interpolating environment variables in config");
+    let config_content = interpolate_env(&config_content)?;
 
     debug!("Parsing config file");
     let config: Config = serde_jsonc::from_str(&config_content)?;
@@ -34,10 +57,48 @@ pub async fn config_grpc() -> Result<(GeyserConfig, Config)> {
     debug!("Getting SOLANA_RPC_ENDPOINT from env");
     let rpc_endpoint = env::var("SOLANA_RPC_ENDPOINT")?;
 
+    debug!("Getting GRPC_CA_CERT_PATH from env");
+    let ca_cert_path = env::var("GRPC_CA_CERT_PATH").ok().filter(|v| !v.is_empty());
+
+    debug!("Getting GRPC_CLIENT_CERT_PATH from env");
+    let client_cert_path = env::var("GRPC_CLIENT_CERT_PATH")
+        .ok()
+        .filter(|v| !v.is_empty());
+
+    debug!("Getting GRPC_CLIENT_KEY_PATH from env");
+    let client_key_path = env::var("GRPC_CLIENT_KEY_PATH")
+        .ok()
+        .filter(|v| !v.is_empty());
+
+    debug!("Getting GRPC_PROXY from env");
+    let proxy = env::var("GRPC_PROXY").ok().filter(|v| !v.is_empty());
+
+    debug!("Getting GRPC_RECONNECT_ALERT_AFTER from env");
+    let reconnect_alert_after = env::var("GRPC_RECONNECT_ALERT_AFTER")
+        .unwrap_or_else(|_| "5".to_string())
+        .parse::<u32>()?;
+
+    debug!("Getting GRPC_RECONNECT_MAX_ATTEMPTS from env");
+    let reconnect_max_attempts = env::var("GRPC_RECONNECT_MAX_ATTEMPTS")
+        .unwrap_or_else(|_| "0".to_string())
+        .parse::<u32>()?;
+
+    debug!("Getting GRPC_SLOT_CLOCK from env");
+    let slot_clock = env::var("GRPC_SLOT_CLOCK")
+        .map(|v| v == "true" || v == "1")
+        .unwrap_or(false);
+
     let geyser_config = GeyserConfig {
         grpc_endpoint,
         x_token,
         rpc_endpoint,
+        ca_cert_path,
+        client_cert_path,
+        client_key_path,
+        proxy,
+        reconnect_alert_after,
+        reconnect_max_attempts: (reconnect_max_attempts > 0).then_some(reconnect_max_attempts),
+        slot_clock,
     };
 
     info!("Config GRPC done");
@@ -45,11 +106,86 @@ pub async fn config_grpc() -> Result<(GeyserConfig, Config)> {
     Ok((geyser_config, config))
 }
 
+/// Merges the named entry of the `profiles` section over the rest of the config and drops
+/// the section. Objects are merged key by key, any other value replaces the base one.
+fn apply_profile(content: &str, profile: &str) -> Result<String> {
+    let mut config: Value = serde_jsonc::from_str(content)?;
+    let overrides = config
+        .as_object_mut()
+        .and_then(|config| config.remove("profiles"))
+        .and_then(|mut profiles| profiles.get_mut(profile).map(Value::take))
+        .ok_or_else(|| ErrorConfig::UnknownProfile(profile.to_string()))?;
+
+    merge(&mut config, overrides);
+    Ok(serde_jsonc::to_string(&config)?)
+}
+
+fn merge(base: &mut Value, overrides: Value) {
+    match (base, overrides) {
+        (Value::Object(base), Value::Object(overrides)) => {
+            for (key, value) in overrides {
+                match base.get_mut(&key) {
+                    Some(existing) => merge(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overrides) => *base = overrides,
+    }
+}
+
+/// Replaces `${VAR}` with the value of environment variable `VAR`,
+/// or `${VAR:-default}` with `default` when it is unset
+fn interpolate_env(content: &str) -> Result<String> {
+    let mut out = String::with_capacity(content.len());
+    let mut rest = content;
+
+    while let Some(start) = rest.find("${") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let end = after
+            .find('}')
+            .ok_or(ErrorConfig::UnterminatedInterpolation)?;
+        let expr = &after[..end];
+
+        let (name, default) = match expr.split_once(":-") {
+            Some((name, default)) => (name, Some(default)),
+            None => (expr, None),
+        };
+        let value = match (env::var(name), default) {
+            (Ok(value), _) => value,
+            (Err(_), Some(default)) => default.to_string(),
+            (Err(_), None) => return Err(ErrorConfig::MissingEnvVar(name.to_string())),
+        };
+        out.push_str(&value);
+
+        rest = &after[end + 1..];
+    }
+
+    out.push_str(rest);
+    Ok(out)
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct GeyserConfig {
     pub grpc_endpoint: String,
     pub x_token: String,
     pub rpc_endpoint: String,
+    /// PEM bundle trusted in addition to the native roots
+    pub ca_cert_path: Option<String>,
+    /// PEM client certificate and key for mutual TLS
+    pub client_cert_path: Option<String>,
+    pub client_key_path: Option<String>,
+    /// `http://[user:pass@]host:port` or `socks5://[user:pass@]host:port`
+    pub proxy: Option<String>,
+    /// Consecutive failed connection attempts before a critical alert, 0 never alerts
+    pub reconnect_alert_after: u32,
+    /// Consecutive failed connection attempts before the client gives up, unlimited if unset
+    pub reconnect_max_attempts: Option<u32>,
+    /// Subscribes to block metas for `block_time` when `blocks_meta` has no filter
+    pub slot_clock: bool,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -61,6 +197,27 @@ pub struct Config {
     pub blocks: HashMap<String, BlockFilter>,
     pub blocks_meta: HashMap<String, BlockMetaFilter>,
     pub entry: HashMap<String, EntryFilter>,
+    #[serde(default)]
+    pub parsers: ParsersConfig,
+}
+
+impl Config {
+    /// Narrows transaction and block filters to the given accounts. Filters left without
+    /// any included account are dropped, an empty include list would match everything.
+    pub fn restrict_accounts(&mut self, accounts: &[String]) {
+        let restrict = |include: &mut Option<Vec<String>>| match include {
+            Some(include) => {
+                include.retain(|account| accounts.contains(account));
+                !include.is_empty()
+            }
+            None => true,
+        };
+
+        self.transactions
+            .retain(|_, filter| restrict(&mut filter.account_include));
+        self.blocks
+            .retain(|_, filter| restrict(&mut filter.account_include));
+    }
 }
 
 #[derive(Debug, Deserialize, Clone)]
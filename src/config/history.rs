@@ -0,0 +1,36 @@
+use crate::config::error::Result;
+use std::env;
+use tracing::{debug, info};
+
+#[derive(Debug, Clone)]
+pub struct HistoryConfig {
+    pub enabled: bool,
+    pub path: String,
+    pub retention_hours: u64,
+}
+
+impl HistoryConfig {
+    /// Load launch history configuration from environment variables, providing sensible defaults
+    pub async fn from_env() -> Result<Self> {
+        info!("Loading launch history configuration from environment");
+
+        debug!("Getting HISTORY_ENABLED from env");
+        let enabled = env::var("HISTORY_ENABLED")
+            .map(|v| v != "false" && v != "0")
+            .unwrap_or(true);
+
+        debug!("Getting HISTORY_PATH from env");
+        let path = env::var("HISTORY_PATH").unwrap_or_else(|_| "data/launches.jsonl".to_string());
+
+        debug!("Getting HISTORY_RETENTION_HOURS from env");
+        let retention_hours = env::var("HISTORY_RETENTION_HOURS")
+            .unwrap_or_else(|_| "24".to_string())
+            .parse()?;
+
+        Ok(Self {
+            enabled,
+            path,
+            retention_hours,
+        })
+    }
+}
@@ -0,0 +1,51 @@
+use crate::config::error::Result;
+use std::env;
+use tracing::{debug, info};
+
+#[derive(Debug, Clone)]
+pub struct LatencyConfig {
+    /// Rolling window the percentiles are computed over
+    pub window_secs: u64,
+    /// Latency the SLO percentile must stay below, 0 disables the SLO
+    pub slo_ms: u64,
+    pub slo_percentile: u8,
+    /// Launches needed in the window before a launchpad's SLO is evaluated
+    pub min_samples: usize,
+}
+
+impl LatencyConfig {
+    /// Load detection latency configuration from environment variables, providing sensible defaults
+    pub async fn from_env() -> Result<Self> {
+        info!("Loading latency configuration from environment");
+
+        debug!("Getting LATENCY_WINDOW_SECS from env");
+        let window_secs = env::var("LATENCY_WINDOW_SECS")
+            .unwrap_or_else(|_| "300".to_string())
+            .parse::<u64>()?
+            .max(1);
+
+        debug!("Getting LATENCY_SLO_MS from env");
+        let slo_ms = env::var("LATENCY_SLO_MS")
+            .unwrap_or_else(|_| "800".to_string())
+            .parse()?;
+
+        debug!("Getting LATENCY_SLO_PERCENTILE from env");
+        let slo_percentile = env::var("LATENCY_SLO_PERCENTILE")
+            .unwrap_or_else(|_| "95".to_string())
+            .parse::<u8>()?
+            .clamp(1, 100);
+
+        debug!("Getting LATENCY_SLO_MIN_SAMPLES from env");
+        let min_samples = env::var("LATENCY_SLO_MIN_SAMPLES")
+            .unwrap_or_else(|_| "20".to_string())
+            .parse::<usize>()?
+            .max(1);
+
+        Ok(Self {
+            window_secs,
+            slo_ms,
+            slo_percentile,
+            min_samples,
+        })
+    }
+}
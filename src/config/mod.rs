@@ -1,6 +1,31 @@
+pub mod accounts;
+pub mod admin;
+pub mod backfill;
+pub mod bench;
+pub mod cache;
+pub mod clusters;
+pub mod digest;
+pub mod drops;
+pub mod enrichment;
 pub mod error;
+pub mod explorer;
+pub mod filter_plan;
+pub mod filters;
+pub mod first_buyers;
+pub mod gossip;
 pub mod grpc;
+pub mod history;
+pub mod latency;
+pub mod parsers;
+pub mod partition;
+pub mod publish;
+pub mod queue;
 pub mod rabbit;
+pub mod recorder;
+pub mod refetch;
+pub mod stats;
+pub mod tracker;
+pub mod watchdog;
 
 use tracing::{debug, error, info, trace, warn};
 use tracing_subscriber;
@@ -17,11 +42,20 @@ pub async fn init() -> Result<((GeyserConfig, Config), RabbitMQConfig)> {
 
     let result = config_grpc();
     let rabbitmq_config = rabbit::RabbitMQConfig::from_env();
+    #[cfg(feature = "tokio-console")]
+    {
+        use tracing_subscriber::prelude::*;
+        tracing_subscriber::registry()
+            .with(console_subscriber::spawn())
+            .with(tracing_subscriber::fmt::layer())
+            .init();
+    }
+    #[cfg(not(feature = "tokio-console"))]
     tracing_subscriber::fmt::init();
     // tracing_log::LogTracer::init()?;
 
     // mock for testing purposes
-    info!("Starting task-ba");
+    info!("Starting solana-launchpad-parser");
     debug!("Debug message");
     error!("Error message");
     warn!("Warn message");
@@ -0,0 +1,285 @@
+use crate::idl::IdlField;
+use crate::parser::LaunchpadType;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// Parser specific options, the `parsers` section of the config file
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ParsersConfig {
+    pub pumpfun: PumpfunConfig,
+    pub meteora: MeteoraConfig,
+    pub launchlab: LaunchLabConfig,
+    pub believe: BelieveConfig,
+    pub daos_fun: DaosFunConfig,
+    pub bags: BagsConfig,
+    pub virtuals: VirtualsConfig,
+    pub time_fun: TimeFunConfig,
+    pub spl_mint: SplMintConfig,
+    /// Launchpads parsed from configuration alone, one `GenericAnchorParser` each
+    pub generic: Vec<GenericAnchorConfig>,
+    /// Launch instructions known by discriminator alone, one `DiscriminatorTableParser`
+    /// per program
+    pub discriminator_table: Vec<DiscriminatorEntry>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PumpfunConfig {
+    /// Report trades from `TradeEvent`
+    pub emit_trades: bool,
+    /// Launches where the creator bought less SOL in the create transaction are skipped
+    pub min_dev_buy_sol: f64,
+}
+
+impl Default for PumpfunConfig {
+    fn default() -> Self {
+        Self {
+            emit_trades: true,
+            min_dev_buy_sol: 0.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct MeteoraConfig {
+    /// Report partner and creator fee claims
+    pub emit_fee_claims: bool,
+    /// Known DBC pool configs by config account address
+    pub partner_configs: HashMap<String, PartnerConfig>,
+    /// Skip launches on pool configs missing from `partner_configs`
+    pub only_listed_partners: bool,
+}
+
+impl Default for MeteoraConfig {
+    fn default() -> Self {
+        Self {
+            emit_fee_claims: true,
+            partner_configs: HashMap::new(),
+            only_listed_partners: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LaunchLabConfig {
+    /// Report buys and sells of SOL quoted pools from `TradeEvent`
+    pub emit_trades: bool,
+    /// Platform config accounts of LetsBonk.fun, launches on them are reported as `LetsBonk`
+    pub letsbonk_platform_configs: Vec<String>,
+}
+
+impl Default for LaunchLabConfig {
+    fn default() -> Self {
+        Self {
+            emit_trades: true,
+            letsbonk_platform_configs: vec![
+                "FfYek5vEz23cMkWsdJwG2oa6EphsvXSHrGpdALN4g6W1".to_string(),
+            ],
+        }
+    }
+}
+
+/// Believe (Launchcoin) launches. Its launch program is not pinned here, the parser
+/// stays inactive until `program_ids` is set.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BelieveConfig {
+    /// Believe launch program IDs, also to be added to a transaction filter
+    pub program_ids: Vec<String>,
+    /// Discriminator of the create instruction
+    pub create_discriminator: [u8; 8],
+    /// Account index of the mint in the create instruction, by default the mint
+    /// initialized by the token program in the same transaction is used
+    pub mint_account_index: Option<usize>,
+    /// Account index of the creator in the create instruction, by default the
+    /// instruction's signer other than the fee payer, or the fee payer
+    pub creator_account_index: Option<usize>,
+}
+
+impl Default for BelieveConfig {
+    fn default() -> Self {
+        Self {
+            program_ids: Vec::new(),
+            // Anchor `global:create`
+            create_discriminator: [24, 30, 200, 40, 5, 28, 7, 119],
+            mint_account_index: None,
+            creator_account_index: None,
+        }
+    }
+}
+
+/// Daos.fun fund launches, reported for Daos.fun transactions initializing a mint
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DaosFunConfig {
+    pub program_ids: Vec<String>,
+    /// Only instructions starting with this discriminator count as fund creation
+    pub create_discriminator: Option<[u8; 8]>,
+}
+
+impl Default for DaosFunConfig {
+    fn default() -> Self {
+        Self {
+            program_ids: vec!["5jnapfrAN47UYkLkEf7HnprPPBCQLvkYWGZDeKkaP5hv".to_string()],
+            create_discriminator: None,
+        }
+    }
+}
+
+/// Bags.fm launches. Like Believe, its program is not pinned here and the parser
+/// stays inactive until `program_ids` is set.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BagsConfig {
+    /// Bags launch program IDs, also to be added to a transaction filter
+    pub program_ids: Vec<String>,
+    /// Only instructions starting with this discriminator count as token creation,
+    /// any instruction in a transaction initializing a mint otherwise
+    pub create_discriminator: Option<[u8; 8]>,
+    /// Account index of the mint in the create instruction, by default the mint
+    /// initialized by the token program in the same transaction is used
+    pub mint_account_index: Option<usize>,
+    /// Account index of the creator in the create instruction, by default the
+    /// instruction's signer other than the fee payer, or the fee payer
+    pub creator_account_index: Option<usize>,
+    /// Account index of the royalty wallet in the create instruction, reported as
+    /// `fee_recipient` when it differs from the creator
+    pub fee_recipient_account_index: Option<usize>,
+}
+
+/// Virtuals Protocol agent token launches on Solana. Like Believe, its program is not
+/// pinned here and the parser stays inactive until `program_ids` is set.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct VirtualsConfig {
+    /// Virtuals launch program IDs, also to be added to a transaction filter
+    pub program_ids: Vec<String>,
+    /// Only instructions starting with this discriminator count as agent launches,
+    /// any instruction in a transaction initializing a mint otherwise
+    pub launch_discriminator: Option<[u8; 8]>,
+    /// Account index of the agent token mint in the launch instruction, by default the
+    /// mint initialized by the token program in the same transaction is used
+    pub mint_account_index: Option<usize>,
+    /// Account index of the deployer in the launch instruction, by default the
+    /// instruction's signer other than the fee payer, or the fee payer
+    pub deployer_account_index: Option<usize>,
+    /// Account index of the agent token's pool or bonding curve in the launch instruction
+    pub pool_account_index: Option<usize>,
+}
+
+/// Time.fun creator token launches. Like Believe, its program is not pinned here and the
+/// parser stays inactive until `program_ids` is set.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TimeFunConfig {
+    /// Time.fun program IDs, also to be added to a transaction filter
+    pub program_ids: Vec<String>,
+    /// Only instructions starting with this discriminator count as creator token minting,
+    /// any instruction in a transaction initializing a mint otherwise
+    pub create_discriminator: Option<[u8; 8]>,
+    /// Account index of the mint in the create instruction, by default the mint
+    /// initialized by the token program in the same transaction is used
+    pub mint_account_index: Option<usize>,
+    /// Account index of the creator in the create instruction, by default the
+    /// instruction's signer other than the fee payer, or the fee payer
+    pub creator_account_index: Option<usize>,
+}
+
+/// Mints initialized outside the known launchpads, reported as `Unknown`. Off by default,
+/// the token programs then also need to be in a transaction filter.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SplMintConfig {
+    pub enabled: bool,
+    /// Also report mints with zero decimals
+    pub include_nfts: bool,
+}
+
+/// An Anchor launchpad described by configuration instead of code. Everything but `name`,
+/// `instruction` and `mint_account` can come from the Anchor IDL in `idl`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GenericAnchorConfig {
+    /// Reported as the events' `launchpad`
+    pub name: String,
+    /// Anchor IDL JSON file
+    #[serde(default)]
+    pub idl: Option<String>,
+    /// Program ID, by default the IDL's address
+    #[serde(default)]
+    pub program_id: Option<String>,
+    /// Create instruction reported as a launch
+    pub instruction: String,
+    /// By default the IDL's, or computed from `instruction`
+    #[serde(default)]
+    pub discriminator: Option<[u8; 8]>,
+    pub mint_account: AccountRef,
+    /// By default the instruction's signer other than the fee payer, or the fee payer
+    #[serde(default)]
+    pub creator_account: Option<AccountRef>,
+    #[serde(default)]
+    pub pool_account: Option<AccountRef>,
+    /// Instruction arguments in order, as in an IDL. By default the IDL's.
+    #[serde(default)]
+    pub args: Option<Vec<IdlField>>,
+    /// Arguments holding the token's name, symbol and uri
+    #[serde(default)]
+    pub metadata_args: MetadataArgs,
+}
+
+/// Launch instruction of a niche launchpad, recognized by its program and data prefix.
+/// Metadata comes from the Metaplex account created in the transaction, if any.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DiscriminatorEntry {
+    pub program_id: String,
+    /// Instruction data prefix, the 8 byte discriminator for Anchor programs
+    pub discriminator: Vec<u8>,
+    pub mint_account_index: usize,
+    /// By default the instruction's signer other than the fee payer, or the fee payer
+    #[serde(default)]
+    pub creator_account_index: Option<usize>,
+    /// Reported as the events' `launchpad`
+    pub launchpad_label: String,
+}
+
+/// Account of an instruction, by position or by IDL name
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum AccountRef {
+    Index(usize),
+    Name(String),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct MetadataArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+impl Default for MetadataArgs {
+    fn default() -> Self {
+        Self {
+            name: "name".to_string(),
+            symbol: "symbol".to_string(),
+            uri: "uri".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PartnerConfig {
+    pub name: String,
+    /// Skip launches on this pool config
+    #[serde(default)]
+    pub skip: bool,
+    /// Launchpad reported for launches on this pool config instead of `Meteora`
+    #[serde(default)]
+    pub launchpad: Option<LaunchpadType>,
+}
@@ -0,0 +1,78 @@
+use crate::config::error::{ErrorConfig, Result};
+use std::env;
+use tracing::{debug, info};
+
+/// Splits launchpads between instances sharing one broker
+#[derive(Debug, Clone)]
+pub struct PartitionConfig {
+    /// Launchpads handled by this instance, overrides the index/count assignment
+    pub launchpads: Vec<String>,
+    /// Position of this instance when launchpads are spread over `count` instances
+    pub index: usize,
+    pub count: usize,
+}
+
+impl PartitionConfig {
+    /// Load partition configuration from environment variables, providing sensible defaults
+    pub async fn from_env() -> Result<Self> {
+        info!("Loading partition configuration from environment");
+
+        debug!("Getting PARTITION_LAUNCHPADS from env");
+        let launchpads = env::var("PARTITION_LAUNCHPADS")
+            .map(|v| {
+                v.split(',')
+                    .map(str::trim)
+                    .filter(|s| !s.is_empty())
+                    .map(String::from)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        debug!("Getting PARTITION_INDEX from env");
+        let index = env::var("PARTITION_INDEX")
+            .unwrap_or_else(|_| "0".to_string())
+            .parse()?;
+
+        debug!("Getting PARTITION_COUNT from env");
+        let count = env::var("PARTITION_COUNT")
+            .unwrap_or_else(|_| "1".to_string())
+            .parse::<usize>()?
+            .max(1);
+
+        if index >= count {
+            return Err(ErrorConfig::InvalidPartition(format!(
+                "PARTITION_INDEX {} must be below PARTITION_COUNT {}",
+                index, count
+            )));
+        }
+
+        Ok(Self {
+            launchpads,
+            index,
+            count,
+        })
+    }
+
+    /// Whether this instance handles only part of the launchpads
+    pub fn is_partitioned(&self) -> bool {
+        !self.launchpads.is_empty() || self.count > 1
+    }
+
+    /// Whether `launchpad` belongs to this instance. Without an explicit list, launchpads
+    /// are sorted by name and dealt round robin, so every instance must know the same set.
+    pub fn assigns(&self, launchpad: &str, all: &[String]) -> bool {
+        if !self.launchpads.is_empty() {
+            return self
+                .launchpads
+                .iter()
+                .any(|assigned| assigned.eq_ignore_ascii_case(launchpad));
+        }
+
+        let mut sorted: Vec<&String> = all.iter().collect();
+        sorted.sort();
+        sorted
+            .iter()
+            .position(|name| *name == launchpad)
+            .is_some_and(|position| position % self.count == self.index)
+    }
+}
@@ -0,0 +1,104 @@
+use crate::config::error::Result;
+use std::env;
+use tracing::{debug, info};
+
+#[derive(Debug, Clone)]
+pub struct PublishConfig {
+    pub pause_buffer: usize,
+    /// Rate limited events waiting for a slot, newer ones are dropped beyond it
+    pub throttle_buffer: usize,
+    /// Publish parsed trades, they feed the fee tracker, tracker and digest either way
+    pub trades_enabled: bool,
+    /// Publish other recognized instructions rather than recording them as dropped
+    pub program_events_enabled: bool,
+    /// Also emit one batch message per slot
+    pub slot_batch_enabled: bool,
+    /// Newer slots seen before a slot's batch is emitted
+    pub slot_batch_delay_slots: u64,
+    /// Longest a slot's batch waits for more events
+    pub slot_batch_max_wait_ms: u64,
+    /// Also emit one batch message per transaction
+    pub transaction_batch_enabled: bool,
+    /// How long a transaction's batch collects events after the first one
+    pub transaction_batch_wait_ms: u64,
+    /// Forward matched transactions undecoded on their own routing key
+    pub raw_passthrough_enabled: bool,
+    /// Raw transactions waiting to be published, newer ones are dropped beyond it
+    pub raw_passthrough_buffer: usize,
+}
+
+impl PublishConfig {
+    /// Load event publishing configuration from environment variables, providing sensible defaults
+    pub async fn from_env() -> Result<Self> {
+        info!("Loading publish configuration from environment");
+
+        debug!("Getting PUBLISH_PAUSE_BUFFER from env");
+        let pause_buffer = env::var("PUBLISH_PAUSE_BUFFER")
+            .unwrap_or_else(|_| "10000".to_string())
+            .parse()?;
+
+        debug!("Getting PUBLISH_THROTTLE_BUFFER from env");
+        let throttle_buffer = env::var("PUBLISH_THROTTLE_BUFFER")
+            .unwrap_or_else(|_| "10000".to_string())
+            .parse()?;
+
+        debug!("Getting PUBLISH_TRADES from env");
+        let trades_enabled = env::var("PUBLISH_TRADES")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(true);
+
+        debug!("Getting PUBLISH_PROGRAM_EVENTS from env");
+        let program_events_enabled = env::var("PUBLISH_PROGRAM_EVENTS")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        debug!("Getting SLOT_BATCH_ENABLED from env");
+        let slot_batch_enabled = env::var("SLOT_BATCH_ENABLED")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        debug!("Getting SLOT_BATCH_DELAY_SLOTS from env");
+        let slot_batch_delay_slots = env::var("SLOT_BATCH_DELAY_SLOTS")
+            .unwrap_or_else(|_| "2".to_string())
+            .parse()?;
+
+        debug!("Getting SLOT_BATCH_MAX_WAIT_MS from env");
+        let slot_batch_max_wait_ms = env::var("SLOT_BATCH_MAX_WAIT_MS")
+            .unwrap_or_else(|_| "2000".to_string())
+            .parse()?;
+
+        debug!("Getting TRANSACTION_BATCH_ENABLED from env");
+        let transaction_batch_enabled = env::var("TRANSACTION_BATCH_ENABLED")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        debug!("Getting TRANSACTION_BATCH_WAIT_MS from env");
+        let transaction_batch_wait_ms = env::var("TRANSACTION_BATCH_WAIT_MS")
+            .unwrap_or_else(|_| "1000".to_string())
+            .parse()?;
+
+        debug!("Getting RAW_PASSTHROUGH_ENABLED from env");
+        let raw_passthrough_enabled = env::var("RAW_PASSTHROUGH_ENABLED")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        debug!("Getting RAW_PASSTHROUGH_BUFFER from env");
+        let raw_passthrough_buffer = env::var("RAW_PASSTHROUGH_BUFFER")
+            .unwrap_or_else(|_| "10000".to_string())
+            .parse()?;
+
+        Ok(Self {
+            pause_buffer,
+            throttle_buffer,
+            trades_enabled,
+            program_events_enabled,
+            slot_batch_enabled,
+            slot_batch_delay_slots,
+            slot_batch_max_wait_ms,
+            transaction_batch_enabled,
+            transaction_batch_wait_ms,
+            raw_passthrough_enabled,
+            raw_passthrough_buffer,
+        })
+    }
+}
@@ -0,0 +1,53 @@
+use crate::config::error::Result;
+use std::env;
+use tracing::{debug, info};
+
+#[derive(Debug, Clone)]
+pub struct QueueConfig {
+    pub max_items: usize,
+    pub max_bytes: usize,
+    pub max_instruction_data_bytes: usize,
+    pub max_transaction_data_bytes: usize,
+    /// Queue simple vote transactions instead of dropping them
+    pub keep_votes: bool,
+}
+
+impl QueueConfig {
+    /// Load transaction queue limits from environment variables, providing sensible defaults
+    pub async fn from_env() -> Result<Self> {
+        info!("Loading queue configuration from environment");
+
+        debug!("Getting QUEUE_MAX_ITEMS from env");
+        let max_items = env::var("QUEUE_MAX_ITEMS")
+            .unwrap_or_else(|_| "5000".to_string())
+            .parse()?;
+
+        debug!("Getting QUEUE_MAX_BYTES from env");
+        let max_bytes = env::var("QUEUE_MAX_BYTES")
+            .unwrap_or_else(|_| (256 * 1024 * 1024).to_string())
+            .parse()?;
+
+        debug!("Getting MAX_INSTRUCTION_DATA_BYTES from env");
+        let max_instruction_data_bytes = env::var("MAX_INSTRUCTION_DATA_BYTES")
+            .unwrap_or_else(|_| "4096".to_string())
+            .parse()?;
+
+        debug!("Getting MAX_TRANSACTION_DATA_BYTES from env");
+        let max_transaction_data_bytes = env::var("MAX_TRANSACTION_DATA_BYTES")
+            .unwrap_or_else(|_| "65536".to_string())
+            .parse()?;
+
+        debug!("Getting QUEUE_KEEP_VOTE_TRANSACTIONS from env");
+        let keep_votes = env::var("QUEUE_KEEP_VOTE_TRANSACTIONS")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        Ok(Self {
+            max_items,
+            max_bytes,
+            max_instruction_data_bytes,
+            max_transaction_data_bytes,
+            keep_votes,
+        })
+    }
+}
@@ -0,0 +1,36 @@
+use crate::config::error::Result;
+use std::env;
+use tracing::{debug, info};
+
+#[derive(Debug, Clone)]
+pub struct RecorderConfig {
+    pub enabled: bool,
+    pub dir: String,
+    pub max_samples: usize,
+}
+
+impl RecorderConfig {
+    /// Load error sample recorder configuration from environment variables, providing sensible defaults
+    pub async fn from_env() -> Result<Self> {
+        info!("Loading recorder configuration from environment");
+
+        debug!("Getting RECORDER_ENABLED from env");
+        let enabled = env::var("RECORDER_ENABLED")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        debug!("Getting RECORDER_DIR from env");
+        let dir = env::var("RECORDER_DIR").unwrap_or_else(|_| "recordings".to_string());
+
+        debug!("Getting RECORDER_MAX_SAMPLES from env");
+        let max_samples = env::var("RECORDER_MAX_SAMPLES")
+            .unwrap_or_else(|_| "100".to_string())
+            .parse()?;
+
+        Ok(Self {
+            enabled,
+            dir,
+            max_samples,
+        })
+    }
+}
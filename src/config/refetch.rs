@@ -0,0 +1,49 @@
+use crate::config::error::Result;
+use std::env;
+use tracing::{debug, info};
+
+#[derive(Debug, Clone)]
+pub struct RefetchConfig {
+    pub enabled: bool,
+    pub delay_ms: u64,
+    pub attempts: u32,
+    /// Refetches in flight, further empty transactions wait and are dropped once the buffer fills
+    pub concurrency: usize,
+}
+
+impl RefetchConfig {
+    /// Load refetch configuration from environment variables, providing sensible defaults
+    pub async fn from_env() -> Result<Self> {
+        info!("Loading refetch configuration from environment");
+
+        debug!("Getting REFETCH_EMPTY_TRANSACTIONS from env");
+        let enabled = env::var("REFETCH_EMPTY_TRANSACTIONS")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        // Transactions are only served by RPC once confirmed
+        debug!("Getting REFETCH_DELAY_MS from env");
+        let delay_ms = env::var("REFETCH_DELAY_MS")
+            .unwrap_or_else(|_| "2000".to_string())
+            .parse()?;
+
+        debug!("Getting REFETCH_ATTEMPTS from env");
+        let attempts = env::var("REFETCH_ATTEMPTS")
+            .unwrap_or_else(|_| "3".to_string())
+            .parse::<u32>()?
+            .max(1);
+
+        debug!("Getting REFETCH_CONCURRENCY from env");
+        let concurrency = env::var("REFETCH_CONCURRENCY")
+            .unwrap_or_else(|_| "16".to_string())
+            .parse::<usize>()?
+            .max(1);
+
+        Ok(Self {
+            enabled,
+            delay_ms,
+            attempts,
+            concurrency,
+        })
+    }
+}
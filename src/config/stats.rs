@@ -0,0 +1,48 @@
+use crate::config::error::Result;
+use std::env;
+use tracing::{debug, info};
+
+#[derive(Debug, Clone)]
+pub struct StatsConfig {
+    pub enabled: bool,
+    pub path: String,
+    /// How often counters are written to disk
+    pub flush_secs: u64,
+    /// Build the counters belong to, counters of a different build become the previous run
+    pub build: String,
+}
+
+impl StatsConfig {
+    /// Load parser statistics configuration from environment variables, providing sensible defaults
+    pub async fn from_env() -> Result<Self> {
+        info!("Loading parser statistics configuration from environment");
+
+        debug!("Getting PARSER_STATS_ENABLED from env");
+        let enabled = env::var("PARSER_STATS_ENABLED")
+            .map(|v| v != "false" && v != "0")
+            .unwrap_or(true);
+
+        debug!("Getting PARSER_STATS_PATH from env");
+        let path =
+            env::var("PARSER_STATS_PATH").unwrap_or_else(|_| "data/parser_stats.json".to_string());
+
+        debug!("Getting PARSER_STATS_FLUSH_SECS from env");
+        let flush_secs = env::var("PARSER_STATS_FLUSH_SECS")
+            .unwrap_or_else(|_| "30".to_string())
+            .parse::<u64>()?
+            .max(1);
+
+        debug!("Getting PARSER_STATS_BUILD from env");
+        let build = env::var("PARSER_STATS_BUILD")
+            .ok()
+            .filter(|v| !v.is_empty())
+            .unwrap_or_else(|| env!("CARGO_PKG_VERSION").to_string());
+
+        Ok(Self {
+            enabled,
+            path,
+            flush_secs,
+            build,
+        })
+    }
+}
@@ -0,0 +1,83 @@
+use crate::config::error::Result;
+use std::env;
+use tracing::{debug, info};
+
+#[derive(Debug, Clone)]
+pub struct TrackerConfig {
+    pub enabled: bool,
+    /// Time after launch when the token status is published
+    pub status_window_secs: u64,
+    /// Number of earliest buyers listed on the status
+    pub early_buyers: usize,
+    /// How long a launch is followed, at least the status window
+    pub track_secs: u64,
+    /// Sliding window for sell pressure alerts
+    pub sell_pressure_window_secs: u64,
+    /// SOL sold beyond SOL bought within the window that raises an alert, 0 disables alerts
+    pub sell_pressure_lamports: u64,
+    /// Also report sells by wallets the creator sent SOL to
+    pub creator_funded_wallets: bool,
+    /// Most tokens tracked at once, new launches are skipped beyond it
+    pub max_tokens: usize,
+}
+
+impl TrackerConfig {
+    /// Load launch tracker configuration from environment variables, providing sensible defaults
+    pub async fn from_env() -> Result<Self> {
+        info!("Loading tracker configuration from environment");
+
+        debug!("Getting TRACKER_ENABLED from env");
+        let enabled = env::var("TRACKER_ENABLED")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        debug!("Getting TRACKER_STATUS_WINDOW_SECS from env");
+        let status_window_secs = env::var("TRACKER_STATUS_WINDOW_SECS")
+            .unwrap_or_else(|_| "60".to_string())
+            .parse::<u64>()?
+            .max(1);
+
+        debug!("Getting TRACKER_EARLY_BUYERS from env");
+        let early_buyers = env::var("TRACKER_EARLY_BUYERS")
+            .unwrap_or_else(|_| "20".to_string())
+            .parse()?;
+
+        debug!("Getting TRACKER_TRACK_SECS from env");
+        let track_secs = env::var("TRACKER_TRACK_SECS")
+            .unwrap_or_else(|_| "600".to_string())
+            .parse::<u64>()?
+            .max(status_window_secs);
+
+        debug!("Getting TRACKER_SELL_PRESSURE_WINDOW_SECS from env");
+        let sell_pressure_window_secs = env::var("TRACKER_SELL_PRESSURE_WINDOW_SECS")
+            .unwrap_or_else(|_| "30".to_string())
+            .parse::<u64>()?
+            .max(1);
+
+        debug!("Getting TRACKER_SELL_PRESSURE_LAMPORTS from env");
+        let sell_pressure_lamports = env::var("TRACKER_SELL_PRESSURE_LAMPORTS")
+            .unwrap_or_else(|_| "5000000000".to_string())
+            .parse()?;
+
+        debug!("Getting TRACKER_CREATOR_FUNDED_WALLETS from env");
+        let creator_funded_wallets = env::var("TRACKER_CREATOR_FUNDED_WALLETS")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        debug!("Getting TRACKER_MAX_TOKENS from env");
+        let max_tokens = env::var("TRACKER_MAX_TOKENS")
+            .unwrap_or_else(|_| "10000".to_string())
+            .parse()?;
+
+        Ok(Self {
+            enabled,
+            status_window_secs,
+            early_buyers,
+            track_secs,
+            sell_pressure_window_secs,
+            sell_pressure_lamports,
+            creator_funded_wallets,
+            max_tokens,
+        })
+    }
+}
@@ -0,0 +1,60 @@
+use crate::config::error::Result;
+use std::env;
+use tracing::{debug, info};
+
+#[derive(Debug, Clone)]
+pub struct WatchdogConfig {
+    pub enabled: bool,
+    pub window_minutes: u64,
+    pub launchpads: Vec<String>,
+    pub webhook_url: Option<String>,
+}
+
+impl WatchdogConfig {
+    /// Load launch watchdog configuration from environment variables, providing sensible defaults
+    pub async fn from_env() -> Result<Self> {
+        info!("Loading watchdog configuration from environment");
+
+        debug!("Getting WATCHDOG_ENABLED from env");
+        let enabled = env::var("WATCHDOG_ENABLED")
+            .map(|v| v != "false" && v != "0")
+            .unwrap_or(true);
+
+        debug!("Getting WATCHDOG_WINDOW_MINUTES from env");
+        let window_minutes = env::var("WATCHDOG_WINDOW_MINUTES")
+            .unwrap_or_else(|_| "10".to_string())
+            .parse()?;
+
+        debug!("Getting WATCHDOG_LAUNCHPADS from env");
+        let launchpads = env::var("WATCHDOG_LAUNCHPADS")
+            .unwrap_or_else(|_| "Pumpfun".to_string())
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+
+        debug!("Getting WATCHDOG_WEBHOOK_URL from env");
+        let webhook_url = env::var("WATCHDOG_WEBHOOK_URL")
+            .ok()
+            .filter(|s| !s.is_empty());
+
+        Ok(Self {
+            enabled,
+            window_minutes,
+            launchpads,
+            webhook_url,
+        })
+    }
+
+    /// Keeps only the launchpads with a parser registered on this instance, the others
+    /// never launch here and would always look silent
+    pub fn restrict_launchpads(&mut self, registered: &[String]) {
+        self.launchpads.retain(|launchpad| {
+            let known = registered.contains(launchpad);
+            if !known {
+                info!("🐕 Not watching {}, no parser registered for it", launchpad);
+            }
+            known
+        });
+    }
+}
@@ -0,0 +1,257 @@
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio::time::{Duration, Instant, sleep, timeout_at};
+use tracing::{debug, info, warn};
+
+use crate::cache::{self, Cache};
+use crate::config::enrichment::EnrichmentConfig;
+use crate::metrics;
+use crate::monitor::{OpsError, OpsErrorKind, ops};
+use crate::parser::{EnrichmentError, TokenLaunch};
+use crate::rpc::RpcClient;
+use crate::sink::EventSink;
+
+const CHANNEL_CAPACITY: usize = 10_000;
+
+/// Sends launches to the mint enrichment worker
+#[derive(Clone)]
+pub struct EnrichmentHandle {
+    sender: mpsc::Sender<TokenLaunch>,
+}
+
+impl EnrichmentHandle {
+    /// Queues a launch for enrichment; it is published once its mint has been fetched
+    pub async fn submit(&self, launch: TokenLaunch) {
+        if let Err(e) = self.sender.send(launch).await {
+            warn!(
+                "⚠️ Enrichment worker stopped, dropping launch {}",
+                e.0.signature
+            );
+        }
+    }
+}
+
+/// Fills mint supply and authority on launches, coalescing bursts into getMultipleAccounts calls
+pub struct MintEnricher {
+    rpc: RpcClient,
+    config: EnrichmentConfig,
+    sink: Option<Arc<dyn EventSink>>,
+    cache: Option<Arc<dyn Cache>>,
+}
+
+impl MintEnricher {
+    pub fn new(rpc: RpcClient, config: EnrichmentConfig, sink: Option<Arc<dyn EventSink>>) -> Self {
+        Self {
+            rpc,
+            config,
+            sink,
+            cache: None,
+        }
+    }
+
+    /// Serves mint lookups from `cache` before asking the RPC
+    pub fn with_cache(mut self, cache: Arc<dyn Cache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Starts the enrichment worker in a separate task
+    pub fn start(self) -> (EnrichmentHandle, JoinHandle<()>) {
+        let (sender, receiver) = mpsc::channel(CHANNEL_CAPACITY);
+        let handle = tokio::spawn(Arc::new(self).run(receiver));
+        (EnrichmentHandle { sender }, handle)
+    }
+
+    async fn run(self: Arc<Self>, mut receiver: mpsc::Receiver<TokenLaunch>) {
+        info!(
+            "🔎 Mint enrichment started (batch {} / {}ms)",
+            self.config.batch_size, self.config.batch_window_ms
+        );
+        let window = Duration::from_millis(self.config.batch_window_ms);
+
+        while let Some(first) = receiver.recv().await {
+            // Collect whatever else arrives within the window into the same batch
+            let mut batch = vec![first];
+            let deadline = Instant::now() + window;
+            while batch.len() < self.config.batch_size {
+                match timeout_at(deadline, receiver.recv()).await {
+                    Ok(Some(launch)) => batch.push(launch),
+                    _ => break,
+                }
+            }
+
+            // Batches run concurrently, the RPC client bounds in-flight requests
+            let enricher = self.clone();
+            tokio::spawn(async move { enricher.enrich_and_publish(batch).await });
+        }
+    }
+
+    async fn enrich_and_publish(self: Arc<Self>, mut batch: Vec<TokenLaunch>) {
+        metrics::global().inc_counter("enrichment_batches_total", &[]);
+        self.enrich(&mut batch).await;
+
+        // Launches are published even when enrichment failed, with the fields it missed
+        for launch in &batch {
+            self.publish(launch).await;
+        }
+
+        let failed: Vec<TokenLaunch> = batch
+            .into_iter()
+            .filter(|launch| !launch.enrichment_errors.is_empty())
+            .collect();
+        if !failed.is_empty() && self.config.retry_attempts > 0 {
+            tokio::spawn(self.retry(failed));
+        }
+    }
+
+    /// Retries launches published with enrichment errors, republishing each as an update
+    /// once its mint could be fetched
+    async fn retry(self: Arc<Self>, mut pending: Vec<TokenLaunch>) {
+        for attempt in 1..=self.config.retry_attempts {
+            sleep(Duration::from_millis(
+                self.config.retry_delay_ms * attempt as u64,
+            ))
+            .await;
+            self.enrich(&mut pending).await;
+
+            let (enriched, failed): (Vec<_>, Vec<_>) = pending
+                .into_iter()
+                .partition(|launch| launch.enrichment_errors.is_empty());
+            for mut launch in enriched {
+                metrics::global().inc_counter("enrichment_retries_total", &[("result", "success")]);
+                debug!("Enriched launch {} on retry {}", launch.signature, attempt);
+                launch.enrichment_update = true;
+                self.publish(&launch).await;
+            }
+            pending = failed;
+            if pending.is_empty() {
+                return;
+            }
+        }
+
+        for launch in &pending {
+            metrics::global().inc_counter("enrichment_retries_total", &[("result", "exhausted")]);
+            warn!(
+                "⚠️ Giving up enrichment of launch {} after {} retries",
+                launch.signature, self.config.retry_attempts
+            );
+        }
+    }
+
+    async fn publish(&self, launch: &TokenLaunch) {
+        if let Some(sink) = &self.sink
+            && let Err(e) = sink.publish_token_launch(launch).await
+        {
+            warn!("Failed to publish token launch: {}", e);
+            ops::report(
+                OpsError::new(OpsErrorKind::SinkFailure, "enrichment", &e)
+                    .with_transaction(&launch.signature, launch.slot),
+            );
+        }
+    }
+
+    /// Fills the mints of `batch`, setting `enrichment_errors` on launches whose mint
+    /// could not be fetched
+    async fn enrich(&self, batch: &mut [TokenLaunch]) {
+        let mut mints: Vec<String> = batch.iter().map(|l| l.token_address.clone()).collect();
+        mints.sort();
+        mints.dedup();
+
+        debug!("Enriching {} launches ({} mints)", batch.len(), mints.len());
+
+        let mut mint_infos: HashMap<String, Value> = HashMap::new();
+        let mut failures: HashMap<String, String> = HashMap::new();
+        let mut misses = Vec::new();
+        for mint in mints {
+            match self.cached_mint_info(&mint).await {
+                Some(info) => {
+                    mint_infos.insert(mint, info);
+                }
+                None => misses.push(mint),
+            }
+        }
+
+        if !misses.is_empty() {
+            match self.rpc.get_multiple_accounts(&misses).await {
+                Ok(accounts) => {
+                    for (mint, account) in misses.into_iter().zip(accounts) {
+                        // Mints not yet visible to the RPC are retried later
+                        let Some(mut account) = account else {
+                            failures.insert(mint, "mint account not found".to_string());
+                            continue;
+                        };
+                        let info = account["data"]["parsed"]["info"].take();
+                        if let Some(cache) = &self.cache {
+                            cache::set_json(cache.as_ref(), &cache_key(&mint), &info).await;
+                        }
+                        mint_infos.insert(mint, info);
+                    }
+                }
+                Err(e) => {
+                    metrics::global().inc_counter("enrichment_failures_total", &[]);
+                    warn!(
+                        "⚠️ Mint enrichment failed for {} launches: {:#}",
+                        batch.len(),
+                        e
+                    );
+                    let error = format!("rpc error: {:#}", e);
+                    failures.extend(misses.into_iter().map(|mint| (mint, error.clone())));
+                }
+            }
+        }
+
+        for launch in batch.iter_mut() {
+            launch.enrichment_errors.clear();
+            if let Some(info) = mint_infos.get(&launch.token_address) {
+                apply_mint_info(launch, info);
+                metrics::global().inc_counter("enriched_launches_total", &[]);
+            } else if let Some(error) = failures.get(&launch.token_address) {
+                launch.enrichment_errors = missing_fields(launch)
+                    .into_iter()
+                    .map(|field| EnrichmentError {
+                        field: field.to_string(),
+                        error: error.clone(),
+                    })
+                    .collect();
+            }
+        }
+    }
+
+    async fn cached_mint_info(&self, mint: &str) -> Option<Value> {
+        let cache = self.cache.as_ref()?;
+        let info = cache::get_json(cache.as_ref(), &cache_key(mint)).await;
+        let result = if info.is_some() { "hit" } else { "miss" };
+        metrics::global().inc_counter("enrichment_cache_total", &[("result", result)]);
+        info
+    }
+}
+
+fn cache_key(mint: &str) -> String {
+    format!("mint:{}", mint)
+}
+
+/// Fields enrichment would fill that the parser left empty
+fn missing_fields(launch: &TokenLaunch) -> Vec<&'static str> {
+    let mut fields = Vec::new();
+    if launch.metadata.initial_supply.is_none() {
+        fields.push("initial_supply");
+    }
+    if launch.metadata.mint_authority.is_none() {
+        fields.push("mint_authority");
+    }
+    fields
+}
+
+/// Copies supply and mint authority from the parsed info of a `jsonParsed` mint account,
+/// keeping values the parser already set
+fn apply_mint_info(launch: &mut TokenLaunch, info: &Value) {
+    if launch.metadata.initial_supply.is_none() {
+        launch.metadata.initial_supply = info["supply"].as_str().and_then(|s| s.parse().ok());
+    }
+    if launch.metadata.mint_authority.is_none() {
+        launch.metadata.mint_authority = info["mintAuthority"].as_str().map(str::to_string);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod mint;
+
+pub use mint::{EnrichmentHandle, MintEnricher};
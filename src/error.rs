@@ -8,4 +8,10 @@ pub type Result<T> = std::result::Result<T, Error>;
 pub enum Error {
     #[error(transparent)]
     Config(#[from] ErrorConfig),
+
+    #[error("invalid event signing key: {0:#}")]
+    SigningKey(anyhow::Error),
+
+    #[error("geyser client stopped: {0:#}")]
+    GeyserStopped(anyhow::Error),
 }
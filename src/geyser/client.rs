@@ -1,17 +1,52 @@
 use anyhow::{Context, Result};
-use bs58;
-use chrono::Utc;
 use futures::{SinkExt, StreamExt};
+use prost::Message;
+use serde_json::{Value, json};
+use solana_stream_sdk::yellowstone_grpc_proto::prelude::{Transaction, TransactionStatusMeta};
 use solana_stream_sdk::{
     GeyserGrpcClient, GeyserSubscribeRequest, GeyserSubscribeUpdate, GeyserUpdateOneof,
 };
+use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Duration;
+use tokio::sync::RwLock;
 use tokio::task::JoinHandle;
-use tonic::transport::ClientTlsConfig;
-use tracing::{error, info, warn};
+use tokio::time::{MissedTickBehavior, interval};
+use tonic::codegen::http::Uri;
+use tonic::transport::{Certificate, ClientTlsConfig, Identity};
+use tracing::{debug, error, info, warn};
 
+use crate::clock::{self, SharedClock};
 use crate::config::grpc::{Config, GeyserConfig, commitment_from_str};
-use crate::geyser::queue::{QueuedTransaction, TransactionInstruction, TransactionQueue};
+use crate::config::queue::QueueConfig;
+use crate::geyser::clock::SlotClock;
+use crate::geyser::encoding::{encode_pubkey, encode_signature};
+use crate::geyser::filters::{AccountFilter, Candidate, PreFilterChain};
+use crate::geyser::gaps::GapDetector;
+use crate::geyser::health::ConnectionHealth;
+use crate::geyser::proxy;
+use crate::geyser::queue::{
+    QueuedTransaction, TransactionInstruction, TransactionQueue, TransactionVersion,
+};
+use crate::geyser::rejection::{FilterRejection, SubscriptionReductions};
+use crate::geyser::snapshot::subscribe_request_to_json;
+use crate::geyser::subscriptions::AccountSubscriptions;
+use crate::metrics;
+use crate::monitor::{DropReason, DroppedTransaction, OpsError, OpsErrorKind, drops, ops};
+#[cfg(feature = "enrichment")]
+use crate::rpc::Refetcher;
+use crate::sink::{RawPassthrough, RawTransaction};
+
+/// Accounts filter carrying the pool accounts of tracked launches
+const TRACKED_ACCOUNTS_FILTER: &str = "tracked_accounts";
+/// Transactions filter carrying the followed creator token accounts, so transfers out
+/// of them are received
+const CREATOR_ACCOUNTS_FILTER: &str = "tracked_creator_accounts";
+/// Block meta filter feeding the slot clock and gap detection, added on request when none
+/// is configured
+const SLOT_CLOCK_FILTER: &str = "slot_clock";
+/// Longest wait between attempts once a rejected subscription cannot be reduced further
+const MAX_REJECTION_BACKOFF: Duration = Duration::from_secs(300);
 
 /// Main Geyser client
 #[derive(Clone)]
@@ -19,15 +54,114 @@ pub struct GeyserClient {
     geyser_config: GeyserConfig,
     config: Config,
     transaction_queue: TransactionQueue,
+    active_request: Arc<RwLock<Option<GeyserSubscribeRequest>>>,
+    capture_raw: bool,
+    keep_votes: bool,
+    pre_filters: Arc<PreFilterChain>,
+    account_subscriptions: Option<AccountSubscriptions>,
+    raw_passthrough: Option<RawPassthrough>,
+    gaps: Option<GapDetector>,
+    #[cfg(feature = "enrichment")]
+    refetcher: Option<Refetcher>,
+    slot_clock: SlotClock,
+    clock: SharedClock,
+    reductions: SubscriptionReductions,
+    health: ConnectionHealth,
 }
 
 impl GeyserClient {
-    /// Creates a new Geyser client
-    pub fn new(geyser_config: GeyserConfig, config: Config, queue_size: usize) -> Self {
+    /// Creates a new Geyser client with a queue enforcing the configured limits
+    pub fn new(geyser_config: GeyserConfig, config: Config, queue_config: &QueueConfig) -> Self {
+        // Without configured pre-filters, queue what matches the transaction filters' accounts
+        let pre_filters =
+            PreFilterChain::default().with_filter(AccountFilter::new(&config.transactions));
+
+        let health = ConnectionHealth::new(
+            geyser_config.reconnect_alert_after,
+            geyser_config.reconnect_max_attempts,
+        );
+
         Self {
             geyser_config,
             config,
-            transaction_queue: TransactionQueue::new(queue_size),
+            transaction_queue: TransactionQueue::from_config(queue_config),
+            active_request: Arc::new(RwLock::new(None)),
+            capture_raw: false,
+            keep_votes: queue_config.keep_votes,
+            pre_filters: Arc::new(pre_filters),
+            account_subscriptions: None,
+            raw_passthrough: None,
+            gaps: None,
+            #[cfg(feature = "enrichment")]
+            refetcher: None,
+            slot_clock: SlotClock::new(),
+            clock: clock::system(),
+            reductions: SubscriptionReductions::default(),
+            health,
+        }
+    }
+
+    /// Keeps the raw protobuf of queued transactions so failing samples can be recorded
+    pub fn with_raw_capture(mut self, enabled: bool) -> Self {
+        self.capture_raw = enabled;
+        self
+    }
+
+    /// Replaces the pre-filters deciding which incoming transactions are queued
+    pub fn with_pre_filters(mut self, pre_filters: PreFilterChain) -> Self {
+        self.pre_filters = Arc::new(pre_filters);
+        self
+    }
+
+    /// Adds the accounts of tracked launches to the subscription and updates it as they change
+    pub fn with_account_subscriptions(mut self, subscriptions: AccountSubscriptions) -> Self {
+        self.account_subscriptions = Some(subscriptions);
+        self
+    }
+
+    /// Forwards every matched transaction undecoded, before parsing
+    pub fn with_raw_passthrough(mut self, passthrough: RawPassthrough) -> Self {
+        self.raw_passthrough = Some(passthrough);
+        self
+    }
+
+    /// Reports blocks the stream skipped, from the parent slot of received blocks
+    pub fn with_gap_detector(mut self, gaps: GapDetector) -> Self {
+        self.gaps = Some(gaps);
+        self
+    }
+
+    /// Fetches matched transactions that arrive without instructions from RPC instead of
+    /// dropping them
+    #[cfg(feature = "enrichment")]
+    pub fn with_refetcher(mut self, refetcher: Refetcher) -> Self {
+        self.refetcher = Some(refetcher);
+        self
+    }
+
+    /// Replaces the clock stamping `received_time` on queued transactions and measuring
+    /// how long they wait in the queue
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.transaction_queue = self.transaction_queue.with_clock(clock.clone());
+        self.clock = clock;
+        self
+    }
+
+    /// Client for another stream of a filter plan, subscribing with `config` and queueing
+    /// into the same queue. Tracked accounts and gap detection stay with the first stream.
+    pub fn for_stream(&self, config: Config, pre_filters: PreFilterChain) -> Self {
+        Self {
+            config,
+            active_request: Arc::new(RwLock::new(None)),
+            pre_filters: Arc::new(pre_filters),
+            account_subscriptions: None,
+            gaps: None,
+            reductions: SubscriptionReductions::default(),
+            health: ConnectionHealth::new(
+                self.geyser_config.reconnect_alert_after,
+                self.geyser_config.reconnect_max_attempts,
+            ),
+            ..self.clone()
         }
     }
 
@@ -36,6 +170,22 @@ impl GeyserClient {
         &self.transaction_queue
     }
 
+    /// Returns the subscription request currently in effect as JSON.
+    /// When not connected, returns the request that would be sent on the next connect.
+    pub async fn subscription_snapshot(&self) -> Value {
+        let active = self.active_request.read().await;
+        let request = match active.as_ref() {
+            Some(request) => subscribe_request_to_json(request),
+            None => subscribe_request_to_json(&self.build_subscribe_request()),
+        };
+        json!({
+            "active": active.is_some(),
+            "request": request,
+            // Filters and accounts dropped after the provider rejected the subscription
+            "reduced": self.reductions.to_json(),
+        })
+    }
+
     /// Builds subscription request based on configuration
     fn build_subscribe_request(&self) -> GeyserSubscribeRequest {
         use solana_stream_sdk::{
@@ -44,20 +194,64 @@ impl GeyserClient {
             GeyserSubscribeRequestFilterSlots, GeyserSubscribeRequestFilterTransactions,
         };
 
-        GeyserSubscribeRequest {
+        let mut accounts: HashMap<_, _> = self
+            .config
+            .accounts
+            .iter()
+            .map(|(k, v)| (k.clone(), GeyserSubscribeRequestFilterAccounts::from(v)))
+            .collect();
+        if let Some(subscriptions) = &self.account_subscriptions {
+            let tracked = subscriptions.accounts();
+            if !tracked.is_empty() {
+                accounts.insert(
+                    TRACKED_ACCOUNTS_FILTER.to_string(),
+                    GeyserSubscribeRequestFilterAccounts {
+                        account: tracked,
+                        ..Default::default()
+                    },
+                );
+            }
+        }
+
+        let mut transactions: HashMap<_, _> = self
+            .config
+            .transactions
+            .iter()
+            .map(|(k, v)| (k.clone(), GeyserSubscribeRequestFilterTransactions::from(v)))
+            .collect();
+        if let Some(subscriptions) = &self.account_subscriptions {
+            let creator_accounts = subscriptions.creator_accounts();
+            if !creator_accounts.is_empty() {
+                transactions.insert(
+                    CREATOR_ACCOUNTS_FILTER.to_string(),
+                    GeyserSubscribeRequestFilterTransactions {
+                        account_include: creator_accounts,
+                        vote: Some(false),
+                        failed: Some(false),
+                        ..Default::default()
+                    },
+                );
+            }
+        }
+
+        let mut blocks_meta: HashMap<_, _> = self
+            .config
+            .blocks_meta
+            .iter()
+            .map(|(k, v)| (k.clone(), GeyserSubscribeRequestFilterBlocksMeta::from(v)))
+            .collect();
+        // Block times for `block_time` on events, parent slots for gap detection
+        if blocks_meta.is_empty() && (self.geyser_config.slot_clock || self.gaps.is_some()) {
+            blocks_meta.insert(
+                SLOT_CLOCK_FILTER.to_string(),
+                GeyserSubscribeRequestFilterBlocksMeta::default(),
+            );
+        }
+
+        let mut request = GeyserSubscribeRequest {
             commitment: self.config.commitment.as_deref().map(commitment_from_str),
-            transactions: self
-                .config
-                .transactions
-                .iter()
-                .map(|(k, v)| (k.clone(), GeyserSubscribeRequestFilterTransactions::from(v)))
-                .collect(),
-            accounts: self
-                .config
-                .accounts
-                .iter()
-                .map(|(k, v)| (k.clone(), GeyserSubscribeRequestFilterAccounts::from(v)))
-                .collect(),
+            transactions,
+            accounts,
             slots: self
                 .config
                 .slots
@@ -70,12 +264,7 @@ impl GeyserClient {
                 .iter()
                 .map(|(k, v)| (k.clone(), GeyserSubscribeRequestFilterBlocks::from(v)))
                 .collect(),
-            blocks_meta: self
-                .config
-                .blocks_meta
-                .iter()
-                .map(|(k, v)| (k.clone(), GeyserSubscribeRequestFilterBlocksMeta::from(v)))
-                .collect(),
+            blocks_meta,
             entry: self
                 .config
                 .entry
@@ -86,95 +275,167 @@ impl GeyserClient {
             accounts_data_slice: vec![],
             from_slot: None,
             ping: None,
-        }
+        };
+        self.reductions.apply(&mut request);
+        request
     }
 
-    /// Processes Geyser message and adds relevant transactions to queue
+    /// Processes Geyser message and adds relevant transactions to queue.
+    /// Transactions come from transaction updates or, with block subscriptions, from blocks.
+    /// Filtering runs on raw key bytes; base58 encoding only happens for queued transactions.
     async fn process_message(&self, msg: &GeyserSubscribeUpdate) {
-        match &msg.update_oneof {
-            Some(GeyserUpdateOneof::Transaction(tx_info)) => {
-                let received_time = Utc::now();
-                let slot = tx_info.slot;
-
-                if let Some(tx) = &tx_info.transaction {
-                    if let Some(inner_tx) = &tx.transaction {
-                        // Get transaction signature
-                        if let Some(sig) = inner_tx.signatures.first() {
-                            let signature = bs58::encode(sig).into_string();
-
-                            // Collect all accounts from transaction
-                            let mut accounts = Vec::new();
-                            let mut instructions = Vec::new();
-
-                            // Add accounts from account_keys and extract instructions
-                            if let Some(message) = &inner_tx.message {
-                                for account_key in &message.account_keys {
-                                    accounts.push(bs58::encode(account_key).into_string());
-                                }
-
-                                // Extract instruction data
-                                for instruction in &message.instructions {
-                                    let program_id_index = instruction.program_id_index as usize;
-                                    if program_id_index < accounts.len() {
-                                        let tx_instruction = TransactionInstruction {
-                                            program_id: accounts[program_id_index].clone(),
-                                            accounts: instruction.accounts.clone(),
-                                            data: instruction.data.clone(),
-                                        };
-                                        instructions.push(tx_instruction);
-                                    }
-                                }
-                            }
-
-                            // Check if transaction contains accounts of interest
-                            let should_queue = self.should_queue_transaction(&accounts);
-
-                            if should_queue {
-                                let queued_tx = QueuedTransaction {
-                                    signature: signature.clone(),
-                                    slot,
-                                    received_time,
-                                    accounts,
-                                    instructions,
-                                };
-
-                                self.transaction_queue.push(queued_tx).await;
-                                // Reduced logging frequency for performance
-                            }
-                        }
-                    }
+        let transactions: Vec<_> = match &msg.update_oneof {
+            Some(GeyserUpdateOneof::Transaction(update)) => {
+                metrics::global().inc_counter("geyser_transactions_total", &[]);
+                update
+                    .transaction
+                    .iter()
+                    .map(|info| (update.slot, info))
+                    .collect()
+            }
+            Some(GeyserUpdateOneof::Block(block)) => {
+                if let Some(block_time) = &block.block_time {
+                    self.slot_clock.record(block.slot, block_time.timestamp);
+                }
+                if let Some(gaps) = &self.gaps {
+                    gaps.observe(block.slot, block.parent_slot);
                 }
+                block
+                    .transactions
+                    .iter()
+                    .map(|info| (block.slot, info))
+                    .collect()
             }
-            _ => {}
-        }
-    }
-
-    /// Checks if transaction should be queued based on contained accounts
-    fn should_queue_transaction(&self, transaction_accounts: &[String]) -> bool {
-        // Check transaction filters from configuration
-        for (_filter_name, tx_filter) in &self.config.transactions {
-            if let Some(account_include) = &tx_filter.account_include {
-                // Check if transaction contains any accounts of interest
-                for target_account in account_include {
-                    if transaction_accounts.contains(target_account) {
-                        return true;
-                    }
+            Some(GeyserUpdateOneof::BlockMeta(meta)) => {
+                if let Some(block_time) = &meta.block_time {
+                    self.slot_clock.record(meta.slot, block_time.timestamp);
+                }
+                if let Some(gaps) = &self.gaps {
+                    gaps.observe(meta.slot, meta.parent_slot);
+                }
+                return;
+            }
+            Some(GeyserUpdateOneof::Account(update)) => {
+                if let (Some(subscriptions), Some(account)) =
+                    (&self.account_subscriptions, &update.account)
+                {
+                    subscriptions.handle_update(
+                        &encode_pubkey(&account.pubkey),
+                        &account.data,
+                        update.slot,
+                    );
                 }
+                return;
             }
+            _ => return,
+        };
 
-            if let Some(account_required) = &tx_filter.account_required {
-                // Check if transaction contains all required accounts
-                let has_all_required = account_required
-                    .iter()
-                    .all(|required_account| transaction_accounts.contains(required_account));
+        let received_time = self.clock.now();
+
+        for (slot, tx_info) in transactions {
+            let Some(inner_tx) = tx_info.transaction.as_ref() else {
+                continue;
+            };
+            // Get transaction signature
+            let Some(sig) = inner_tx.signatures.first() else {
+                continue;
+            };
+
+            // Block subscriptions deliver votes regardless of the transaction filters
+            if tx_info.is_vote && !self.keep_votes {
+                metrics::global().inc_counter("vote_transactions_dropped_total", &[]);
+                drops::record_with(DropReason::Vote, || {
+                    DroppedTransaction::new(DropReason::Vote, &encode_signature(sig), slot)
+                });
+                continue;
+            }
+
+            // Pre-filters run on raw bytes, before anything is decoded
+            let meta = tx_info.meta.as_ref();
+            let candidate = candidate(slot, inner_tx, meta);
+            // The pre-filters only know the configured transaction filters
+            let creator_transfer = msg
+                .filters
+                .iter()
+                .any(|filter| filter == CREATOR_ACCOUNTS_FILTER);
+            if !creator_transfer && let Some(filter) = self.pre_filters.rejected_by(&candidate) {
+                let reason = DropReason::from_pre_filter(filter);
+                drops::record_with(reason, || {
+                    DroppedTransaction::new(reason, &encode_signature(sig), slot)
+                        .with_detail(filter)
+                });
+                continue;
+            }
+
+            let signature = encode_signature(sig);
+
+            if let Some(passthrough) = &self.raw_passthrough {
+                passthrough.forward(RawTransaction {
+                    signature: signature.clone(),
+                    slot,
+                    transaction_index: Some(tx_info.index),
+                    filters: msg.filters.clone(),
+                    received_time,
+                    data: tx_info.encode_to_vec(),
+                });
+            }
 
-                if has_all_required {
-                    return true;
+            let DecodedTransaction {
+                accounts,
+                signers,
+                instructions,
+                inner_instructions,
+            } = decode_transaction(inner_tx, meta);
+
+            // Matched on accounts alone, there is nothing a parser could read
+            if instructions.is_empty() && inner_instructions.is_empty() {
+                metrics::global().inc_counter("transactions_without_instructions_total", &[]);
+                #[cfg(feature = "enrichment")]
+                if let Some(refetcher) = &self.refetcher {
+                    refetcher.request(signature, slot, received_time);
+                    continue;
                 }
+                debug!("Dropping {} without instructions", signature);
+                drops::record(DroppedTransaction::new(
+                    DropReason::NoInstructions,
+                    &signature,
+                    slot,
+                ));
+                continue;
             }
-        }
 
-        false
+            // Only this transaction, a block update would be counted and recorded once
+            // for each of its transactions
+            let raw = self.capture_raw.then(|| Arc::new(tx_info.encode_to_vec()));
+
+            let queued_tx = QueuedTransaction {
+                signature,
+                slot,
+                transaction_index: Some(tx_info.index),
+                received_time,
+                block_time: self.slot_clock.block_time(slot),
+                backfilled: false,
+                is_vote: tx_info.is_vote,
+                version: if inner_tx
+                    .message
+                    .as_ref()
+                    .is_some_and(|message| message.versioned)
+                {
+                    TransactionVersion::V0
+                } else {
+                    TransactionVersion::Legacy
+                },
+                fee_payer: signers.first().cloned(),
+                signers,
+                accounts,
+                instructions,
+                inner_instructions,
+                raw,
+            };
+
+            self.transaction_queue.push(queued_tx).await;
+        }
+        // Reduced logging frequency for performance
     }
 
     /// Starts Geyser client in separate task
@@ -184,29 +445,154 @@ impl GeyserClient {
         tokio::spawn(async move {
             info!("Starting Geyser client...");
 
+            let (endpoint, tls_domain) = client
+                .resolve_endpoint()
+                .await
+                .inspect_err(|e| error!("Invalid Geyser connection settings: {:#}", e))?;
+
+            let mut rejection_backoff = Duration::from_secs(5);
             loop {
                 // Build a fresh request each reconnect to avoid ownership issues
                 let request = client.build_subscribe_request();
 
-                if let Err(e) = client.run_stream_loop(request).await {
-                    error!("Error in Geyser stream: {:?}", e);
-                    tokio::time::sleep(Duration::from_secs(5)).await;
+                let result = client
+                    .run_stream_loop(request, &endpoint, tls_domain.as_deref())
+                    .await;
+                *client.active_request.write().await = None;
+
+                let reason = match &result {
+                    Ok(()) => "stream ended".to_string(),
+                    Err(e) => format!("{:#}", e),
+                };
+                if !client.health.failed(&reason) {
+                    anyhow::bail!("Geyser reconnect budget exhausted: {}", reason);
+                }
+
+                let Err(e) = result else {
+                    continue;
+                };
+                match e.downcast_ref::<FilterRejection>() {
+                    Some(rejection) => {
+                        let wait = client.handle_rejection(rejection, rejection_backoff);
+                        rejection_backoff = (wait * 2).min(MAX_REJECTION_BACKOFF);
+                        tokio::time::sleep(wait).await;
+                    }
+                    None => {
+                        error!("Error in Geyser stream: {:?}", e);
+                        ops::report(OpsError::new(OpsErrorKind::StreamReconnect, "geyser", &e));
+                        tokio::time::sleep(Duration::from_secs(5)).await;
+                    }
                 }
             }
         })
     }
 
+    /// Reduces the subscription after the provider rejected it.
+    /// Returns how long to wait before reconnecting: briefly when something was dropped,
+    /// `backoff` when nothing is left to drop and the same request will be sent again.
+    fn handle_rejection(&self, rejection: &FilterRejection, backoff: Duration) -> Duration {
+        let filter = rejection
+            .filter
+            .as_ref()
+            .map_or("unknown".to_string(), ToString::to_string);
+        metrics::global().inc_counter(
+            "geyser_subscription_rejections_total",
+            &[("filter", &filter)],
+        );
+        ops::report(OpsError::new(
+            OpsErrorKind::SubscriptionRejected,
+            "geyser",
+            rejection,
+        ));
+
+        // Rebuilt rather than the request sent on connect, tracked accounts may have changed since
+        match self
+            .reductions
+            .reduce(rejection, &self.build_subscribe_request())
+        {
+            Some(dropped) => {
+                warn!("⛔ {}, retrying without {}", rejection, dropped);
+                Duration::from_secs(1)
+            }
+            None => {
+                error!(
+                    "⛔ {}, nothing left to drop, retrying in {}s",
+                    rejection,
+                    backoff.as_secs()
+                );
+                backoff
+            }
+        }
+    }
+
+    /// Returns the endpoint to dial and the TLS domain to verify.
+    /// With a proxy configured this is a local tunnel and the real host name.
+    async fn resolve_endpoint(&self) -> Result<(String, Option<String>)> {
+        let endpoint = &self.geyser_config.grpc_endpoint;
+        let Some(proxy_url) = &self.geyser_config.proxy else {
+            return Ok((endpoint.clone(), None));
+        };
+
+        let uri: Uri = endpoint.parse().context("Invalid GRPC_ENDPOINT")?;
+        let host = uri.host().context("GRPC_ENDPOINT has no host")?;
+        let https = uri.scheme_str() == Some("https");
+        let port = uri.port_u16().unwrap_or(if https { 443 } else { 80 });
+
+        let local = proxy::start_tunnel(proxy_url, host, port).await?;
+        let scheme = if https { "https" } else { "http" };
+
+        Ok((format!("{}://{}", scheme, local), Some(host.to_string())))
+    }
+
+    /// TLS settings: native roots plus the optional custom CA and client identity
+    fn tls_config(&self, domain: Option<&str>) -> Result<ClientTlsConfig> {
+        let mut tls = ClientTlsConfig::new().with_native_roots();
+
+        if let Some(path) = &self.geyser_config.ca_cert_path {
+            let pem = std::fs::read(path)
+                .with_context(|| format!("Failed to read CA bundle {}", path))?;
+            tls = tls.ca_certificate(Certificate::from_pem(pem));
+        }
+
+        match (
+            &self.geyser_config.client_cert_path,
+            &self.geyser_config.client_key_path,
+        ) {
+            (Some(cert_path), Some(key_path)) => {
+                let cert = std::fs::read(cert_path)
+                    .with_context(|| format!("Failed to read client certificate {}", cert_path))?;
+                let key = std::fs::read(key_path)
+                    .with_context(|| format!("Failed to read client key {}", key_path))?;
+                tls = tls.identity(Identity::from_pem(cert, key));
+            }
+            (None, None) => {}
+            _ => {
+                anyhow::bail!("GRPC_CLIENT_CERT_PATH and GRPC_CLIENT_KEY_PATH must be set together")
+            }
+        }
+
+        if let Some(domain) = domain {
+            tls = tls.domain_name(domain);
+        }
+
+        Ok(tls)
+    }
+
     /// Main loop for handling Geyser stream
-    async fn run_stream_loop(&self, request: GeyserSubscribeRequest) -> Result<()> {
+    async fn run_stream_loop(
+        &self,
+        request: GeyserSubscribeRequest,
+        endpoint: &str,
+        tls_domain: Option<&str>,
+    ) -> Result<()> {
         // Connect to Geyser GRPC
         info!(
             "Connecting to Geyser GRPC: {}",
             self.geyser_config.grpc_endpoint
         );
 
-        let mut builder =
-            GeyserGrpcClient::build_from_shared(self.geyser_config.grpc_endpoint.clone())
-                .context("Failed to build GRPC client")?;
+        let mut builder = GeyserGrpcClient::build_from_shared(endpoint.to_string())
+            .context("Failed to build GRPC client")?;
 
         builder = builder
             .x_token(Some(self.geyser_config.x_token.clone()))
@@ -214,7 +600,7 @@ impl GeyserClient {
 
         if self.geyser_config.grpc_endpoint.starts_with("https://") {
             builder = builder
-                .tls_config(ClientTlsConfig::new().with_native_roots())
+                .tls_config(self.tls_config(tls_domain)?)
                 .context("Failed to configure TLS")?;
         }
 
@@ -231,23 +617,396 @@ impl GeyserClient {
         // Send subscription request
         sink.send(request.clone()).await?;
         info!("Sent Geyser subscription request");
+        *self.active_request.write().await = Some(request);
+        // Connected once the provider streams something, a rejected request fails right away
+        let mut streaming = false;
+
+        // Tracked account changes are batched and sent as a new request on the same stream
+        let mut resubscribe = interval(
+            self.account_subscriptions
+                .as_ref()
+                .map_or(Duration::from_secs(1), |subscriptions| {
+                    subscriptions.update_interval()
+                }),
+        );
+        resubscribe.set_missed_tick_behavior(MissedTickBehavior::Delay);
 
         // Main message receiving loop
-        while let Some(message) = stream.next().await {
-            match message {
-                Ok(msg) => {
-                    self.process_message(&msg).await;
+        loop {
+            tokio::select! {
+                message = stream.next() => {
+                    let Some(message) = message else {
+                        break;
+                    };
+                    match message {
+                        Ok(msg) => {
+                            if !streaming {
+                                streaming = true;
+                                self.health.connected();
+                            }
+                            metrics::global().inc_counter("geyser_messages_total", &[]);
+                            record_bandwidth(&msg);
+                            self.process_message(&msg).await;
+                        }
+                        Err(e) => {
+                            let rejection = self
+                                .active_request
+                                .read()
+                                .await
+                                .as_ref()
+                                .and_then(|request| FilterRejection::classify(&e, request));
+                            if let Some(rejection) = rejection {
+                                return Err(rejection.into());
+                            }
+                            error!("Stream error: {:?}, reconnecting...", e);
+                            return Err(e.into());
+                        }
+                    }
                 }
-                Err(e) => {
-                    error!("Stream error: {:?}, reconnecting...", e);
-                    return Err(e.into());
+                _ = resubscribe.tick() => {
+                    if self
+                        .account_subscriptions
+                        .as_ref()
+                        .is_some_and(|subscriptions| subscriptions.take_changed())
+                    {
+                        let request = self.build_subscribe_request();
+                        sink.send(request.clone()).await?;
+                        metrics::global().inc_counter("geyser_resubscriptions_total", &[]);
+                        debug!(
+                            "🔁 Updated Geyser subscription, {} tracked accounts",
+                            request
+                                .accounts
+                                .get(TRACKED_ACCOUNTS_FILTER)
+                                .map_or(0, |filter| filter.account.len())
+                        );
+                        *self.active_request.write().await = Some(request);
+                    }
                 }
             }
         }
 
         warn!("Stream ended, reconnecting...");
+        ops::report(OpsError::new(
+            OpsErrorKind::StreamReconnect,
+            "geyser",
+            "stream ended",
+        ));
         tokio::time::sleep(Duration::from_secs(1)).await;
 
         Ok(())
     }
 }
+
+/// Accounts, signers and resolved instructions of a stream transaction
+struct DecodedTransaction {
+    accounts: Vec<String>,
+    signers: Vec<String>,
+    instructions: Vec<TransactionInstruction>,
+    inner_instructions: Vec<TransactionInstruction>,
+}
+
+/// Raw view of a stream transaction for the pre-filters. A v0 message may reference
+/// accounts only through lookup tables, so the addresses loaded in the meta count too.
+fn candidate<'a>(
+    slot: u64,
+    transaction: &'a Transaction,
+    meta: Option<&'a TransactionStatusMeta>,
+) -> Candidate<'a> {
+    Candidate {
+        slot,
+        account_keys: transaction
+            .message
+            .iter()
+            .flat_map(|message| &message.account_keys)
+            .chain(meta.into_iter().flat_map(|meta| {
+                meta.loaded_writable_addresses
+                    .iter()
+                    .chain(&meta.loaded_readonly_addresses)
+            }))
+            .map(Vec::as_slice)
+            .collect(),
+        instruction_data: transaction
+            .message
+            .iter()
+            .flat_map(|message| message.instructions.iter().map(|ix| ix.data.as_slice()))
+            .chain(
+                meta.into_iter()
+                    .flat_map(|meta| &meta.inner_instructions)
+                    .flat_map(|inner| inner.instructions.iter().map(|ix| ix.data.as_slice())),
+            )
+            .collect(),
+        failed: meta.is_some_and(|meta| meta.err.is_some()),
+    }
+}
+
+/// Resolves the accounts, signers and outer and inner instructions of a stream transaction
+fn decode_transaction(
+    transaction: &Transaction,
+    meta: Option<&TransactionStatusMeta>,
+) -> DecodedTransaction {
+    // Collect all accounts from transaction, addresses loaded from lookup tables follow the static keys
+    let account_keys = transaction
+        .message
+        .as_ref()
+        .map(|message| message.account_keys.as_slice())
+        .unwrap_or_default();
+    let loaded_addresses = meta
+        .map(|meta| {
+            meta.loaded_writable_addresses
+                .iter()
+                .chain(&meta.loaded_readonly_addresses)
+        })
+        .into_iter()
+        .flatten();
+    let accounts: Vec<String> = account_keys
+        .iter()
+        .chain(loaded_addresses)
+        .map(|key| encode_pubkey(key))
+        .collect();
+    // The first `num_required_signatures` static keys signed, the first of them pays
+    let signer_count = transaction
+        .message
+        .as_ref()
+        .and_then(|message| message.header.as_ref())
+        .map_or(1, |header| header.num_required_signatures as usize);
+    let signers: Vec<String> = accounts.iter().take(signer_count).cloned().collect();
+
+    // Program ids are indexes into the static keys followed by the loaded addresses
+    let instructions = transaction
+        .message
+        .iter()
+        .flat_map(|message| &message.instructions)
+        .filter_map(|ix| {
+            resolve_instruction(&accounts, ix.program_id_index, &ix.accounts, &ix.data)
+        })
+        .collect();
+    let inner_instructions = meta
+        .into_iter()
+        .flat_map(|meta| &meta.inner_instructions)
+        .flat_map(|inner| &inner.instructions)
+        .filter_map(|ix| {
+            resolve_instruction(&accounts, ix.program_id_index, &ix.accounts, &ix.data)
+        })
+        .collect();
+
+    DecodedTransaction {
+        accounts,
+        signers,
+        instructions,
+        inner_instructions,
+    }
+}
+
+/// Resolves a compiled instruction's program id against the transaction's accounts.
+/// Indexes out of range are counted and the instruction skipped.
+fn resolve_instruction(
+    accounts: &[String],
+    program_id_index: u32,
+    instruction_accounts: &[u8],
+    data: &[u8],
+) -> Option<TransactionInstruction> {
+    let Some(program_id) = accounts.get(program_id_index as usize) else {
+        metrics::global().inc_counter("instructions_unresolved_total", &[]);
+        return None;
+    };
+    Some(TransactionInstruction {
+        program_id: program_id.clone(),
+        accounts: instruction_accounts.to_vec(),
+        data: data.to_vec(),
+        truncated: false,
+    })
+}
+
+/// Accounts received bytes per update type and per matched filter.
+/// An update matching several filters counts towards each of them.
+fn record_bandwidth(msg: &GeyserSubscribeUpdate) {
+    let bytes = msg.encoded_len() as u64;
+    let update_type = match &msg.update_oneof {
+        Some(GeyserUpdateOneof::Account(_)) => "account",
+        Some(GeyserUpdateOneof::Slot(_)) => "slot",
+        Some(GeyserUpdateOneof::Transaction(_)) => "transaction",
+        Some(GeyserUpdateOneof::TransactionStatus(_)) => "transaction_status",
+        Some(GeyserUpdateOneof::Block(_)) => "block",
+        Some(GeyserUpdateOneof::BlockMeta(_)) => "block_meta",
+        Some(GeyserUpdateOneof::Entry(_)) => "entry",
+        Some(GeyserUpdateOneof::Ping(_)) | Some(GeyserUpdateOneof::Pong(_)) => "ping",
+        None => "unknown",
+    };
+
+    let metrics = metrics::global();
+    metrics.add_counter("geyser_bytes_total", &[("update_type", update_type)], bytes);
+    for filter in &msg.filters {
+        metrics.add_counter("geyser_filter_bytes_total", &[("filter", filter)], bytes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::grpc::TransactionFilter;
+    use crate::geyser::filters::{PreFilter, Verdict, WatchlistFilter};
+    use solana_stream_sdk::yellowstone_grpc_proto::prelude::{
+        CompiledInstruction, InnerInstruction, InnerInstructions, Message, MessageHeader,
+    };
+
+    fn key(byte: u8) -> Vec<u8> {
+        vec![byte; 32]
+    }
+
+    fn address(byte: u8) -> String {
+        encode_pubkey(&key(byte))
+    }
+
+    fn transaction(
+        account_keys: Vec<Vec<u8>>,
+        instructions: Vec<CompiledInstruction>,
+    ) -> Transaction {
+        Transaction {
+            signatures: vec![vec![9; 64]],
+            message: Some(Message {
+                header: Some(MessageHeader {
+                    num_required_signatures: 1,
+                    ..Default::default()
+                }),
+                account_keys,
+                instructions,
+                ..Default::default()
+            }),
+        }
+    }
+
+    fn compiled(program_id_index: u32, accounts: Vec<u8>, data: Vec<u8>) -> CompiledInstruction {
+        CompiledInstruction {
+            program_id_index,
+            accounts,
+            data,
+        }
+    }
+
+    fn inner(program_id_index: u32, data: Vec<u8>) -> InnerInstructions {
+        InnerInstructions {
+            index: 0,
+            instructions: vec![InnerInstruction {
+                program_id_index,
+                accounts: vec![0],
+                data,
+                stack_height: Some(2),
+            }],
+        }
+    }
+
+    #[test]
+    fn resolve_instruction_maps_program_id_index() {
+        let accounts = vec![address(1), address(2), address(3)];
+        let ix = resolve_instruction(&accounts, 2, &[0, 1], &[7, 8]).unwrap();
+        assert_eq!(ix.program_id, address(3));
+        assert_eq!(ix.accounts, vec![0, 1]);
+        assert_eq!(ix.data, vec![7, 8]);
+        assert!(!ix.truncated);
+    }
+
+    #[test]
+    fn resolve_instruction_skips_index_out_of_range() {
+        let accounts = vec![address(1), address(2)];
+        assert!(resolve_instruction(&accounts, 2, &[0], &[]).is_none());
+    }
+
+    #[test]
+    fn decode_transaction_resolves_outer_and_inner_instructions() {
+        let tx = transaction(
+            vec![key(1), key(2), key(3)],
+            vec![compiled(2, vec![0, 1], vec![1])],
+        );
+        let meta = TransactionStatusMeta {
+            inner_instructions: vec![inner(1, vec![2])],
+            ..Default::default()
+        };
+
+        let decoded = decode_transaction(&tx, Some(&meta));
+        assert_eq!(decoded.accounts, vec![address(1), address(2), address(3)]);
+        assert_eq!(decoded.signers, vec![address(1)]);
+        assert_eq!(decoded.instructions.len(), 1);
+        assert_eq!(decoded.instructions[0].program_id, address(3));
+        assert_eq!(decoded.instructions[0].data, vec![1]);
+        assert_eq!(decoded.inner_instructions.len(), 1);
+        assert_eq!(decoded.inner_instructions[0].program_id, address(2));
+        assert_eq!(decoded.inner_instructions[0].data, vec![2]);
+    }
+
+    #[test]
+    fn decode_transaction_resolves_lookup_table_indices() {
+        // Static keys 0-1, loaded writable 2, loaded readonly 3
+        let tx = transaction(
+            vec![key(1), key(2)],
+            vec![
+                compiled(3, vec![0, 2], vec![1]),
+                compiled(4, vec![], vec![]),
+            ],
+        );
+        let meta = TransactionStatusMeta {
+            loaded_writable_addresses: vec![key(3)],
+            loaded_readonly_addresses: vec![key(4)],
+            inner_instructions: vec![inner(2, vec![2])],
+            ..Default::default()
+        };
+
+        let decoded = decode_transaction(&tx, Some(&meta));
+        assert_eq!(
+            decoded.accounts,
+            vec![address(1), address(2), address(3), address(4)]
+        );
+        // Index 4 points past the loaded addresses and is skipped
+        assert_eq!(decoded.instructions.len(), 1);
+        assert_eq!(decoded.instructions[0].program_id, address(4));
+        assert_eq!(decoded.inner_instructions.len(), 1);
+        assert_eq!(decoded.inner_instructions[0].program_id, address(3));
+    }
+
+    #[test]
+    fn decode_transaction_without_meta_uses_static_keys() {
+        let tx = transaction(vec![key(1), key(2)], vec![compiled(2, vec![], vec![])]);
+
+        let decoded = decode_transaction(&tx, None);
+        assert_eq!(decoded.accounts, vec![address(1), address(2)]);
+        assert!(decoded.instructions.is_empty());
+        assert!(decoded.inner_instructions.is_empty());
+    }
+
+    #[test]
+    fn candidate_matches_accounts_loaded_from_lookup_tables() {
+        // v0 message whose program and watched account are only in the lookup tables
+        let tx = transaction(vec![key(1)], vec![compiled(1, vec![2], vec![1])]);
+        let meta = TransactionStatusMeta {
+            loaded_writable_addresses: vec![key(3)],
+            loaded_readonly_addresses: vec![key(2)],
+            ..Default::default()
+        };
+        let v0 = candidate(7, &tx, Some(&meta));
+        assert!(v0.references(&key(2)));
+        assert!(v0.references(&key(3)));
+
+        let transactions = HashMap::from([(
+            "program".to_string(),
+            TransactionFilter {
+                account_include: Some(vec![address(2)]),
+                account_exclude: None,
+                account_required: None,
+                vote: None,
+                failed: None,
+                signature: None,
+            },
+        )]);
+        assert_eq!(AccountFilter::new(&transactions).check(&v0), Verdict::Pass);
+        assert_eq!(
+            WatchlistFilter::new(&[address(3)]).check(&v0),
+            Verdict::Keep
+        );
+
+        // Without the meta the loaded addresses are unknown
+        let legacy = candidate(7, &tx, None);
+        assert_eq!(
+            AccountFilter::new(&transactions).check(&legacy),
+            Verdict::Drop
+        );
+    }
+}
@@ -0,0 +1,49 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+/// Target slot duration, used to extrapolate from the nearest known block time
+const SLOT_DURATION_MS: i64 = 400;
+/// Slots kept in the map, about an hour
+const MAX_SLOTS: usize = 9000;
+
+/// Block times by slot, from block meta and block updates
+#[derive(Clone, Default)]
+pub struct SlotClock {
+    slots: Arc<Mutex<BTreeMap<u64, i64>>>,
+}
+
+impl SlotClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the block time of a slot, in unix seconds
+    pub fn record(&self, slot: u64, block_time: i64) {
+        let mut slots = self.slots.lock().unwrap();
+        slots.insert(slot, block_time);
+        while slots.len() > MAX_SLOTS {
+            slots.pop_first();
+        }
+    }
+
+    /// Block time of the slot. Transactions usually arrive before their block meta,
+    /// so until it does, the time is extrapolated from the closest known slot.
+    /// `None` until any block time is known.
+    pub fn block_time(&self, slot: u64) -> Option<DateTime<Utc>> {
+        let slots = self.slots.lock().unwrap();
+        if let Some(&time) = slots.get(&slot) {
+            return DateTime::from_timestamp(time, 0);
+        }
+
+        let before = slots.range(..slot).next_back();
+        let after = slots.range(slot..).next();
+        let (&known, &time) = match (before, after) {
+            (Some(before), Some(after)) if after.0 - slot < slot - before.0 => after,
+            (Some(before), _) => before,
+            (None, after) => after?,
+        };
+        let offset = (slot as i64 - known as i64) * SLOT_DURATION_MS;
+        DateTime::from_timestamp(time, 0).map(|time| time + TimeDelta::milliseconds(offset))
+    }
+}
@@ -0,0 +1,28 @@
+use five8::{BASE58_ENCODED_32_MAX_LEN, BASE58_ENCODED_64_MAX_LEN};
+
+/// Encodes a public key as base58, using the fixed-size fast path for 32-byte keys
+pub fn encode_pubkey(bytes: &[u8]) -> String {
+    let Ok(key) = <&[u8; 32]>::try_from(bytes) else {
+        return bs58::encode(bytes).into_string();
+    };
+
+    let mut out = [0u8; BASE58_ENCODED_32_MAX_LEN];
+    let len = five8::encode_32(key, &mut out) as usize;
+    to_string(&out[..len])
+}
+
+/// Encodes a transaction signature as base58, using the fixed-size fast path for 64-byte signatures
+pub fn encode_signature(bytes: &[u8]) -> String {
+    let Ok(sig) = <&[u8; 64]>::try_from(bytes) else {
+        return bs58::encode(bytes).into_string();
+    };
+
+    let mut out = [0u8; BASE58_ENCODED_64_MAX_LEN];
+    let len = five8::encode_64(sig, &mut out) as usize;
+    to_string(&out[..len])
+}
+
+fn to_string(encoded: &[u8]) -> String {
+    // base58 alphabet is pure ASCII
+    String::from_utf8(encoded.to_vec()).expect("base58 output is ASCII")
+}
@@ -0,0 +1,446 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tracing::{info, warn};
+
+use crate::config::filters::{PreFilterConfig, PreFilterKind};
+use crate::config::grpc::TransactionFilter;
+use crate::metrics;
+
+/// Incoming transaction as seen by pre-filters, still undecoded
+pub struct Candidate<'a> {
+    pub slot: u64,
+    /// Static account keys of the message followed by the addresses it loads from
+    /// lookup tables, writable then readonly
+    pub account_keys: Vec<&'a [u8]>,
+    /// Data of outer and inner instructions
+    pub instruction_data: Vec<&'a [u8]>,
+    pub failed: bool,
+}
+
+impl Candidate<'_> {
+    /// Whether the transaction references `account`, statically or through a lookup table
+    pub fn references(&self, account: &[u8]) -> bool {
+        self.account_keys.contains(&account)
+    }
+}
+
+/// Outcome of a pre-filter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    /// No objection, the next filter decides
+    Pass,
+    /// Queue the transaction without consulting later filters
+    Keep,
+    /// Skip the transaction
+    Drop,
+}
+
+/// Check applied to incoming transactions before they are decoded and queued
+pub trait PreFilter: Send + Sync {
+    /// Name used in logs and the `pre_filter_dropped_total` metric
+    fn name(&self) -> &'static str;
+
+    fn check(&self, candidate: &Candidate) -> Verdict;
+}
+
+/// Pre-filters applied in order; a transaction no filter dropped is queued
+#[derive(Default)]
+pub struct PreFilterChain {
+    filters: Vec<Box<dyn PreFilter>>,
+}
+
+impl PreFilterChain {
+    /// Builds the configured filters, the account filter matching `transactions`
+    pub fn from_config(
+        config: &PreFilterConfig,
+        transactions: &HashMap<String, TransactionFilter>,
+    ) -> Self {
+        let mut chain = Self::default();
+        for kind in &config.filters {
+            chain = match kind {
+                PreFilterKind::Accounts => chain.with_filter(AccountFilter::new(transactions)),
+                PreFilterKind::Discriminators => {
+                    chain.with_filter(DiscriminatorFilter::new(config.discriminators.clone()))
+                }
+                PreFilterKind::Failed => chain.with_filter(FailedFilter),
+                PreFilterKind::Age => chain.with_filter(AgeFilter::new(config.max_slot_lag)),
+                PreFilterKind::Watchlist => {
+                    chain.with_filter(WatchlistFilter::new(&config.watchlist))
+                }
+            };
+        }
+
+        info!(
+            "🧹 Pre-filters: {}",
+            chain
+                .filters
+                .iter()
+                .map(|filter| filter.name())
+                .collect::<Vec<_>>()
+                .join(" → ")
+        );
+        chain
+    }
+
+    /// Appends a filter to the end of the chain
+    pub fn with_filter(mut self, filter: impl PreFilter + 'static) -> Self {
+        self.filters.push(Box::new(filter));
+        self
+    }
+
+    /// Appends the filters of `other` to the end of the chain
+    pub fn append(mut self, other: PreFilterChain) -> Self {
+        self.filters.extend(other.filters);
+        self
+    }
+
+    /// Whether the transaction should be queued
+    pub fn accepts(&self, candidate: &Candidate) -> bool {
+        self.rejected_by(candidate).is_none()
+    }
+
+    /// Name of the filter dropping the transaction, `None` when it should be queued
+    pub fn rejected_by(&self, candidate: &Candidate) -> Option<&'static str> {
+        for filter in &self.filters {
+            match filter.check(candidate) {
+                Verdict::Pass => {}
+                Verdict::Keep => return None,
+                Verdict::Drop => {
+                    metrics::global()
+                        .inc_counter("pre_filter_dropped_total", &[("filter", filter.name())]);
+                    return Some(filter.name());
+                }
+            }
+        }
+        None
+    }
+}
+
+fn decode_accounts(keys: &[String], context: &str) -> Vec<Vec<u8>> {
+    keys.iter()
+        .map(|key| {
+            bs58::decode(key).into_vec().unwrap_or_else(|e| {
+                // An empty key never matches, same as the undecodable string would
+                warn!("⚠️ Invalid account {} in {}: {}", key, context, e);
+                Vec::new()
+            })
+        })
+        .collect()
+}
+
+/// Transaction filter with account keys decoded to raw bytes,
+/// so incoming transactions can be matched before anything is base58-encoded
+struct DecodedTransactionFilter {
+    account_include: Option<Vec<Vec<u8>>>,
+    account_required: Option<Vec<Vec<u8>>>,
+}
+
+/// Drops transactions matching none of the subscription's transaction filters
+pub struct AccountFilter {
+    filters: Vec<DecodedTransactionFilter>,
+}
+
+impl AccountFilter {
+    pub fn new(transactions: &HashMap<String, TransactionFilter>) -> Self {
+        let filters = transactions
+            .iter()
+            .map(|(name, filter)| {
+                let context = format!("filter {}", name);
+                DecodedTransactionFilter {
+                    account_include: filter
+                        .account_include
+                        .as_ref()
+                        .map(|keys| decode_accounts(keys, &context)),
+                    account_required: filter
+                        .account_required
+                        .as_ref()
+                        .map(|keys| decode_accounts(keys, &context)),
+                }
+            })
+            .collect();
+
+        Self { filters }
+    }
+}
+
+impl PreFilter for AccountFilter {
+    fn name(&self) -> &'static str {
+        "accounts"
+    }
+
+    fn check(&self, candidate: &Candidate) -> Verdict {
+        let contains = |account: &Vec<u8>| candidate.references(account);
+        let matches = self.filters.iter().any(|filter| {
+            filter
+                .account_include
+                .as_ref()
+                .is_some_and(|include| include.iter().any(contains))
+                || filter
+                    .account_required
+                    .as_ref()
+                    .is_some_and(|required| required.iter().all(contains))
+        });
+
+        if matches {
+            Verdict::Pass
+        } else {
+            Verdict::Drop
+        }
+    }
+}
+
+/// Drops transactions without an instruction starting with one of the discriminators
+pub struct DiscriminatorFilter {
+    discriminators: Vec<Vec<u8>>,
+}
+
+impl DiscriminatorFilter {
+    pub fn new(discriminators: Vec<Vec<u8>>) -> Self {
+        Self { discriminators }
+    }
+}
+
+impl PreFilter for DiscriminatorFilter {
+    fn name(&self) -> &'static str {
+        "discriminators"
+    }
+
+    fn check(&self, candidate: &Candidate) -> Verdict {
+        let matches = candidate.instruction_data.iter().any(|data| {
+            self.discriminators
+                .iter()
+                .any(|discriminator| data.starts_with(discriminator))
+        });
+
+        if matches {
+            Verdict::Pass
+        } else {
+            Verdict::Drop
+        }
+    }
+}
+
+/// Drops failed transactions, block subscriptions deliver them regardless of the filter
+pub struct FailedFilter;
+
+impl PreFilter for FailedFilter {
+    fn name(&self) -> &'static str {
+        "failed"
+    }
+
+    fn check(&self, candidate: &Candidate) -> Verdict {
+        if candidate.failed {
+            Verdict::Drop
+        } else {
+            Verdict::Pass
+        }
+    }
+}
+
+/// Drops transactions more than `max_slot_lag` slots behind the newest one seen,
+/// e.g. replayed after a reconnect
+pub struct AgeFilter {
+    max_slot_lag: u64,
+    newest_slot: AtomicU64,
+}
+
+impl AgeFilter {
+    pub fn new(max_slot_lag: u64) -> Self {
+        Self {
+            max_slot_lag,
+            newest_slot: AtomicU64::new(0),
+        }
+    }
+}
+
+impl PreFilter for AgeFilter {
+    fn name(&self) -> &'static str {
+        "age"
+    }
+
+    fn check(&self, candidate: &Candidate) -> Verdict {
+        let newest = self
+            .newest_slot
+            .fetch_max(candidate.slot, Ordering::Relaxed)
+            .max(candidate.slot);
+
+        if newest - candidate.slot > self.max_slot_lag {
+            Verdict::Drop
+        } else {
+            Verdict::Pass
+        }
+    }
+}
+
+/// Drops transactions touching a program an earlier stream of the filter plan subscribes
+/// to, so a transaction matching filters on several streams is queued once
+pub struct EarlierStreamFilter {
+    accounts: Vec<Vec<u8>>,
+}
+
+impl EarlierStreamFilter {
+    pub fn new(accounts: &[String]) -> Self {
+        Self {
+            accounts: decode_accounts(accounts, "filter plan"),
+        }
+    }
+}
+
+impl PreFilter for EarlierStreamFilter {
+    fn name(&self) -> &'static str {
+        "earlier_stream"
+    }
+
+    fn check(&self, candidate: &Candidate) -> Verdict {
+        if self
+            .accounts
+            .iter()
+            .any(|account| candidate.references(account))
+        {
+            Verdict::Drop
+        } else {
+            Verdict::Pass
+        }
+    }
+}
+
+/// Queues transactions touching a watchlisted account, whatever later filters say
+pub struct WatchlistFilter {
+    accounts: Vec<Vec<u8>>,
+}
+
+impl WatchlistFilter {
+    pub fn new(accounts: &[String]) -> Self {
+        Self {
+            accounts: decode_accounts(accounts, "watchlist"),
+        }
+    }
+}
+
+impl PreFilter for WatchlistFilter {
+    fn name(&self) -> &'static str {
+        "watchlist"
+    }
+
+    fn check(&self, candidate: &Candidate) -> Verdict {
+        if self
+            .accounts
+            .iter()
+            .any(|account| candidate.references(account))
+        {
+            Verdict::Keep
+        } else {
+            Verdict::Pass
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geyser::encoding::encode_pubkey;
+
+    const PROGRAM: [u8; 32] = [1; 32];
+    const MINT: [u8; 32] = [2; 32];
+    const OTHER: [u8; 32] = [3; 32];
+
+    /// Successful transaction at slot 100 referencing `accounts`, without instructions
+    fn candidate<'a>(accounts: &[&'a [u8]]) -> Candidate<'a> {
+        Candidate {
+            slot: 100,
+            account_keys: accounts.to_vec(),
+            instruction_data: Vec::new(),
+            failed: false,
+        }
+    }
+
+    fn transaction_filter(
+        include: Option<&[[u8; 32]]>,
+        required: Option<&[[u8; 32]]>,
+    ) -> TransactionFilter {
+        let encode = |keys: &[[u8; 32]]| keys.iter().map(|key| encode_pubkey(key)).collect();
+        TransactionFilter {
+            account_include: include.map(encode),
+            account_exclude: None,
+            account_required: required.map(encode),
+            vote: None,
+            failed: None,
+            signature: None,
+        }
+    }
+
+    #[test]
+    fn account_filter_matches_included_or_all_required_accounts() {
+        let filter = AccountFilter::new(&HashMap::from([
+            (
+                "launchpad".to_string(),
+                transaction_filter(Some(&[PROGRAM]), None),
+            ),
+            (
+                "mint".to_string(),
+                transaction_filter(None, Some(&[MINT, OTHER])),
+            ),
+        ]));
+
+        assert_eq!(filter.check(&candidate(&[&OTHER, &PROGRAM])), Verdict::Pass);
+        assert_eq!(filter.check(&candidate(&[&MINT, &OTHER])), Verdict::Pass);
+        assert_eq!(filter.check(&candidate(&[&MINT])), Verdict::Drop);
+        assert_eq!(filter.check(&candidate(&[])), Verdict::Drop);
+    }
+
+    #[test]
+    fn discriminator_filter_matches_any_instruction_prefix() {
+        let filter = DiscriminatorFilter::new(vec![vec![24, 30, 200, 40], vec![9]]);
+        let mut transaction = candidate(&[]);
+
+        transaction.instruction_data = vec![&[2, 0, 0, 0], &[24, 30, 200, 40, 5, 28]];
+        assert_eq!(filter.check(&transaction), Verdict::Pass);
+        transaction.instruction_data = vec![&[24, 30, 200], &[8, 9]];
+        assert_eq!(filter.check(&transaction), Verdict::Drop);
+    }
+
+    #[test]
+    fn failed_filter_drops_failed_transactions() {
+        let mut transaction = candidate(&[]);
+        assert_eq!(FailedFilter.check(&transaction), Verdict::Pass);
+        transaction.failed = true;
+        assert_eq!(FailedFilter.check(&transaction), Verdict::Drop);
+    }
+
+    #[test]
+    fn age_filter_measures_lag_from_the_newest_slot_seen() {
+        let filter = AgeFilter::new(10);
+        let at = |slot| Candidate {
+            slot,
+            ..candidate(&[])
+        };
+
+        assert_eq!(filter.check(&at(100)), Verdict::Pass);
+        assert_eq!(filter.check(&at(90)), Verdict::Pass);
+        assert_eq!(filter.check(&at(89)), Verdict::Drop);
+        // Older slots do not move the newest back
+        assert_eq!(filter.check(&at(95)), Verdict::Pass);
+        assert_eq!(filter.check(&at(120)), Verdict::Pass);
+        assert_eq!(filter.check(&at(100)), Verdict::Drop);
+    }
+
+    #[test]
+    fn earlier_stream_filter_drops_programs_of_earlier_streams() {
+        let filter = EarlierStreamFilter::new(&[encode_pubkey(&PROGRAM)]);
+        assert_eq!(filter.check(&candidate(&[&MINT, &PROGRAM])), Verdict::Drop);
+        assert_eq!(filter.check(&candidate(&[&MINT])), Verdict::Pass);
+    }
+
+    #[test]
+    fn watchlist_keeps_transactions_later_filters_would_drop() {
+        let chain = PreFilterChain::default()
+            .with_filter(WatchlistFilter::new(&[encode_pubkey(&MINT)]))
+            .with_filter(FailedFilter);
+        let mut transaction = candidate(&[&OTHER, &MINT]);
+        transaction.failed = true;
+
+        assert!(chain.accepts(&transaction));
+        transaction.account_keys = vec![&OTHER];
+        assert_eq!(chain.rejected_by(&transaction), Some("failed"));
+    }
+}
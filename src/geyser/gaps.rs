@@ -0,0 +1,119 @@
+use std::collections::BTreeSet;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tokio::sync::mpsc;
+use tracing::warn;
+
+use crate::clock::{self, SharedClock};
+use crate::metrics;
+
+/// Recently received blocks remembered, about an hour of slots
+const MAX_SEEN_SLOTS: usize = 9000;
+
+/// Slots missed by the stream, first and last inclusive
+#[derive(Debug, Clone, Copy)]
+pub struct SlotGap {
+    pub first: u64,
+    pub last: u64,
+    pub detected_at: Instant,
+}
+
+impl SlotGap {
+    /// Number of slots in the gap, skipped slots without a block included
+    pub fn slots(&self) -> u64 {
+        self.last - self.first + 1
+    }
+}
+
+#[derive(Default)]
+struct Continuity {
+    highest: Option<u64>,
+    seen: BTreeSet<u64>,
+}
+
+/// Detects blocks the stream never delivered, from the parent slot of each received block.
+/// A block whose parent is newer than the highest block seen so far means the blocks in
+/// between were missed, typically during a reconnect.
+#[derive(Clone)]
+pub struct GapDetector {
+    inner: Arc<Mutex<Continuity>>,
+    sender: mpsc::UnboundedSender<SlotGap>,
+    clock: SharedClock,
+}
+
+impl GapDetector {
+    /// Returns the detector and the receiver of the gaps it finds
+    pub fn new() -> (Self, mpsc::UnboundedReceiver<SlotGap>) {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let detector = Self {
+            inner: Arc::new(Mutex::new(Continuity::default())),
+            sender,
+            clock: clock::system(),
+        };
+        (detector, receiver)
+    }
+
+    /// Replaces the clock gaps are stamped with
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Records a received block
+    pub fn observe(&self, slot: u64, parent_slot: u64) {
+        let mut inner = self.inner.lock().unwrap();
+
+        inner.seen.insert(slot);
+        while inner.seen.len() > MAX_SEEN_SLOTS {
+            inner.seen.pop_first();
+        }
+
+        if let Some(highest) = inner.highest
+            && parent_slot > highest
+        {
+            let gap = SlotGap {
+                first: highest + 1,
+                last: parent_slot,
+                detected_at: self.clock.instant(),
+            };
+            warn!(
+                "🕳️ Stream skipped slots {}..={} ({} slots)",
+                gap.first,
+                gap.last,
+                gap.slots()
+            );
+            metrics::global().inc_counter("geyser_slot_gaps_total", &[]);
+            metrics::global().add_counter("geyser_missed_slots_total", &[], gap.slots());
+            let _ = self.sender.send(gap);
+        }
+        inner.highest = inner.highest.max(Some(slot));
+    }
+
+    /// Whether the block of `slot` was received, possibly after its gap was detected
+    pub fn seen(&self, slot: u64) -> bool {
+        self.inner.lock().unwrap().seen.contains(&slot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::{Clock, MockClock};
+    use crate::testing::start_time;
+    use std::time::Duration;
+
+    #[test]
+    fn gaps_are_stamped_with_the_clock() {
+        let clock = MockClock::new(start_time());
+        let (gaps, mut receiver) = GapDetector::new();
+        let gaps = gaps.with_clock(clock.shared());
+        gaps.observe(100, 99);
+        clock.advance(Duration::from_secs(5));
+        gaps.observe(105, 103);
+
+        let gap = receiver.try_recv().unwrap();
+        assert_eq!((gap.first, gap.last), (101, 103));
+        assert_eq!(gap.detected_at, clock.instant());
+        assert!(gaps.seen(100) && !gaps.seen(101));
+    }
+}
@@ -0,0 +1,111 @@
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tracing::{error, info, warn};
+
+use crate::metrics;
+use crate::monitor::{OpsError, OpsErrorKind, OpsSeverity, ops};
+
+#[derive(Default)]
+struct HealthState {
+    /// Start of the current outage, `None` while connected
+    down_since: Option<Instant>,
+    /// Failed attempts since the last successful connect
+    failed_attempts: u32,
+    alerted: bool,
+}
+
+/// Tracks Geyser connects and disconnects, publishing them as ops events and
+/// escalating when reconnecting keeps failing
+#[derive(Clone)]
+pub struct ConnectionHealth {
+    alert_after: u32,
+    max_attempts: Option<u32>,
+    state: Arc<Mutex<HealthState>>,
+}
+
+impl ConnectionHealth {
+    /// `alert_after` failed attempts raise a critical alert (0 never does),
+    /// after `max_attempts` the client gives up
+    pub fn new(alert_after: u32, max_attempts: Option<u32>) -> Self {
+        Self {
+            alert_after,
+            max_attempts,
+            state: Arc::new(Mutex::new(HealthState::default())),
+        }
+    }
+
+    /// Stream subscribed, ends the outage if there was one
+    pub fn connected(&self) {
+        let mut state = self.state.lock().unwrap();
+        metrics::global().set_gauge("geyser_connected", &[], 1.0);
+
+        let downtime = state.down_since.take().map(|since| since.elapsed());
+        state.failed_attempts = 0;
+        state.alerted = false;
+
+        let mut event = OpsError::new(OpsErrorKind::StreamConnected, "geyser", "connected")
+            .with_severity(OpsSeverity::Info);
+        if let Some(downtime) = downtime {
+            info!(
+                "🟢 Geyser stream back after {:.1}s down",
+                downtime.as_secs_f64()
+            );
+            metrics::global().add_counter("geyser_downtime_seconds_total", &[], downtime.as_secs());
+            event = event.with_downtime(downtime);
+        }
+        ops::report(event);
+    }
+
+    /// A connection attempt failed or the stream ended. Returns `false` once the
+    /// reconnect budget is spent and the client should stop.
+    pub fn failed(&self, reason: &str) -> bool {
+        let mut state = self.state.lock().unwrap();
+        metrics::global().set_gauge("geyser_connected", &[], 0.0);
+
+        let down_since = *state.down_since.get_or_insert_with(Instant::now);
+        state.failed_attempts += 1;
+        let downtime = down_since.elapsed();
+
+        if state.failed_attempts == 1 {
+            ops::report(
+                OpsError::new(OpsErrorKind::StreamDisconnected, "geyser", reason)
+                    .with_downtime(downtime),
+            );
+        }
+
+        let exhausted = self
+            .max_attempts
+            .is_some_and(|max| state.failed_attempts >= max);
+        let alert = self.alert_after > 0 && state.failed_attempts >= self.alert_after;
+        let message = format!(
+            "{} failed reconnect attempts over {:.0}s: {}",
+            state.failed_attempts,
+            downtime.as_secs_f64(),
+            reason
+        );
+
+        if exhausted {
+            error!("🚨 Geyser reconnect budget exhausted, {}", message);
+            ops::report(
+                OpsError::new(OpsErrorKind::ReconnectBudgetExhausted, "geyser", message)
+                    .with_severity(OpsSeverity::Critical)
+                    .with_downtime(downtime),
+            );
+        } else if alert && !state.alerted {
+            error!("🚨 Geyser stream down, {}", message);
+            ops::report(
+                OpsError::new(OpsErrorKind::StreamDown, "geyser", message)
+                    .with_severity(OpsSeverity::Critical)
+                    .with_downtime(downtime),
+            );
+            state.alerted = true;
+        } else {
+            warn!(
+                "Geyser reconnect attempt {} failed: {}",
+                state.failed_attempts, reason
+            );
+        }
+
+        !exhausted
+    }
+}
@@ -1,5 +1,20 @@
 pub mod client;
+pub mod clock;
+pub mod encoding;
+pub mod filters;
+pub mod gaps;
+pub mod health;
+pub mod plan;
+pub mod proxy;
 pub mod queue;
+pub mod rejection;
+pub mod snapshot;
+pub mod subscriptions;
 
 pub use client::GeyserClient;
-pub use queue::{QueuedTransaction, TransactionInstruction, TransactionQueue};
+pub use clock::SlotClock;
+pub use filters::{Candidate, EarlierStreamFilter, PreFilter, PreFilterChain, Verdict};
+pub use gaps::{GapDetector, SlotGap};
+pub use plan::FilterPlan;
+pub use queue::{QueuedTransaction, TransactionInstruction, TransactionQueue, TransactionVersion};
+pub use subscriptions::{AccountSubscriptions, CreatorAccount};
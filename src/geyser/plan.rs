@@ -0,0 +1,144 @@
+use serde::Serialize;
+use std::collections::HashMap;
+
+use crate::config::error::{ErrorConfig, Result};
+use crate::config::filter_plan::FilterPlanConfig;
+use crate::config::grpc::{Config, TransactionFilter};
+
+/// Prefix of the compiled transaction filter names, numbered from 1
+const FILTER_PREFIX: &str = "launchpads";
+
+/// Transaction filters compiled from the registered parsers' program IDs, spread over as
+/// few filters and streams as the provider limits allow
+#[derive(Debug, Clone, Serialize)]
+pub struct FilterPlan {
+    /// Distinct program IDs subscribed to
+    pub accounts: usize,
+    pub streams: Vec<StreamPlan>,
+}
+
+/// Filters sent on one subscription
+#[derive(Debug, Clone, Serialize)]
+pub struct StreamPlan {
+    pub filters: Vec<FilterEntry>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FilterEntry {
+    pub name: String,
+    /// Launchpads with a program ID in this filter
+    pub launchpads: Vec<String>,
+    pub account_include: Vec<String>,
+}
+
+impl FilterPlan {
+    /// Packs the program IDs of each launchpad into transaction filters of at most
+    /// `max_accounts` accounts, and the filters into streams of at most `max_filters`.
+    /// Program IDs listed by several launchpads are subscribed once. The number of filters
+    /// and streams is the least the limits allow; a launchpad's program IDs stay together
+    /// unless a filter boundary falls between them. Fails when more streams than
+    /// `max_streams` would be needed.
+    pub fn compile(programs: &[(String, Vec<String>)], limits: &FilterPlanConfig) -> Result<Self> {
+        let mut owners: Vec<(String, String)> = Vec::new();
+        for (launchpad, program_ids) in programs {
+            for program_id in program_ids {
+                if !owners.iter().any(|(known, _)| known == program_id) {
+                    owners.push((program_id.clone(), launchpad.clone()));
+                }
+            }
+        }
+
+        let chunk = |limit: usize, len: usize| if limit == 0 { len.max(1) } else { limit };
+        let filters: Vec<FilterEntry> = owners
+            .chunks(chunk(limits.max_accounts, owners.len()))
+            .enumerate()
+            .map(|(position, chunk)| {
+                let mut launchpads: Vec<String> = Vec::new();
+                for (_, launchpad) in chunk {
+                    if !launchpads.contains(launchpad) {
+                        launchpads.push(launchpad.clone());
+                    }
+                }
+                FilterEntry {
+                    name: format!("{}_{}", FILTER_PREFIX, position + 1),
+                    launchpads,
+                    account_include: chunk.iter().map(|(id, _)| id.clone()).collect(),
+                }
+            })
+            .collect();
+
+        let streams: Vec<StreamPlan> = filters
+            .chunks(chunk(limits.max_filters, filters.len()))
+            .map(|filters| StreamPlan {
+                filters: filters.to_vec(),
+            })
+            .collect();
+
+        if limits.max_streams > 0 && streams.len() > limits.max_streams {
+            return Err(ErrorConfig::FilterPlanExceedsLimits(format!(
+                "{} program IDs need {} filters over {} streams, FILTER_MAX_STREAMS is {}",
+                owners.len(),
+                filters.len(),
+                streams.len(),
+                limits.max_streams
+            )));
+        }
+
+        Ok(Self {
+            accounts: owners.len(),
+            streams,
+        })
+    }
+
+    /// Subscription configs, one per stream. The first keeps every other filter of `base`,
+    /// later streams only carry their transaction filters.
+    pub fn configs(&self, base: &Config) -> Vec<Config> {
+        self.streams
+            .iter()
+            .enumerate()
+            .map(|(index, stream)| {
+                let mut config = base.clone();
+                if index > 0 {
+                    config.accounts.clear();
+                    config.slots.clear();
+                    config.blocks.clear();
+                    config.blocks_meta.clear();
+                    config.entry.clear();
+                }
+                config.transactions = stream.transactions();
+                config
+            })
+            .collect()
+    }
+
+    /// Program IDs subscribed by the streams before `index`
+    pub fn earlier_accounts(&self, index: usize) -> Vec<String> {
+        self.streams
+            .iter()
+            .take(index)
+            .flat_map(|stream| &stream.filters)
+            .flat_map(|filter| filter.account_include.iter().cloned())
+            .collect()
+    }
+}
+
+impl StreamPlan {
+    fn transactions(&self) -> HashMap<String, TransactionFilter> {
+        self.filters
+            .iter()
+            .map(|filter| {
+                (
+                    filter.name.clone(),
+                    TransactionFilter {
+                        account_include: Some(filter.account_include.clone()),
+                        account_exclude: None,
+                        account_required: None,
+                        vote: Some(false),
+                        failed: Some(false),
+                        signature: None,
+                    },
+                )
+            })
+            .collect()
+    }
+}
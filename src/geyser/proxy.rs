@@ -0,0 +1,198 @@
+use anyhow::{Context, Result, bail};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use std::net::SocketAddr;
+use tokio::io::{AsyncReadExt, AsyncWriteExt, copy_bidirectional};
+use tokio::net::{TcpListener, TcpStream};
+use tracing::{debug, info, warn};
+
+/// Largest HTTP CONNECT response header accepted from the proxy
+const MAX_CONNECT_RESPONSE: usize = 8 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ProxyKind {
+    Http,
+    Socks5,
+}
+
+/// Parsed `GRPC_PROXY` value
+#[derive(Debug, Clone)]
+struct Proxy {
+    kind: ProxyKind,
+    addr: String,
+    credentials: Option<(String, String)>,
+}
+
+impl Proxy {
+    fn parse(url: &str) -> Result<Self> {
+        let (scheme, rest) = url
+            .split_once("://")
+            .context("Proxy URL must include a scheme")?;
+        let kind = match scheme {
+            "http" => ProxyKind::Http,
+            "socks5" | "socks5h" => ProxyKind::Socks5,
+            other => bail!("Unsupported proxy scheme: {}", other),
+        };
+
+        let rest = rest.trim_end_matches('/');
+        let (credentials, addr) = match rest.rsplit_once('@') {
+            Some((userinfo, addr)) => {
+                let (user, pass) = userinfo.split_once(':').unwrap_or((userinfo, ""));
+                (Some((user.to_string(), pass.to_string())), addr)
+            }
+            None => (None, rest),
+        };
+
+        Ok(Self {
+            kind,
+            addr: addr.to_string(),
+            credentials,
+        })
+    }
+}
+
+/// Starts a local listener that tunnels every accepted connection to `target_host:target_port`
+/// through the proxy. The gRPC client connects to the returned address while TLS still
+/// verifies the real host name.
+pub async fn start_tunnel(
+    proxy_url: &str,
+    target_host: &str,
+    target_port: u16,
+) -> Result<SocketAddr> {
+    let proxy = Proxy::parse(proxy_url)?;
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .context("Failed to bind local proxy tunnel")?;
+    let local_addr = listener.local_addr()?;
+
+    info!(
+        "🔀 Tunneling Geyser gRPC to {}:{} via {:?} proxy {}",
+        target_host, target_port, proxy.kind, proxy.addr
+    );
+
+    let target_host = target_host.to_string();
+    tokio::spawn(async move {
+        loop {
+            let (mut inbound, _) = match listener.accept().await {
+                Ok(conn) => conn,
+                Err(e) => {
+                    warn!("Proxy tunnel accept failed: {}", e);
+                    continue;
+                }
+            };
+
+            let proxy = proxy.clone();
+            let target_host = target_host.clone();
+            tokio::spawn(async move {
+                match open(&proxy, &target_host, target_port).await {
+                    Ok(mut outbound) => {
+                        if let Err(e) = copy_bidirectional(&mut inbound, &mut outbound).await {
+                            debug!("Proxy tunnel closed: {}", e);
+                        }
+                    }
+                    Err(e) => warn!("Failed to open proxy tunnel: {:#}", e),
+                }
+            });
+        }
+    });
+
+    Ok(local_addr)
+}
+
+/// Connects to the proxy and asks it for a tunnel to the target
+async fn open(proxy: &Proxy, host: &str, port: u16) -> Result<TcpStream> {
+    let mut stream = TcpStream::connect(&proxy.addr)
+        .await
+        .with_context(|| format!("Cannot connect to proxy {}", proxy.addr))?;
+
+    match proxy.kind {
+        ProxyKind::Http => http_connect(&mut stream, proxy, host, port).await?,
+        ProxyKind::Socks5 => socks5_connect(&mut stream, proxy, host, port).await?,
+    }
+
+    Ok(stream)
+}
+
+async fn http_connect(stream: &mut TcpStream, proxy: &Proxy, host: &str, port: u16) -> Result<()> {
+    let mut request = format!("CONNECT {host}:{port} HTTP/1.1\r\nHost: {host}:{port}\r\n");
+    if let Some((user, pass)) = &proxy.credentials {
+        let token = BASE64.encode(format!("{user}:{pass}"));
+        request.push_str(&format!("Proxy-Authorization: Basic {token}\r\n"));
+    }
+    request.push_str("\r\n");
+    stream.write_all(request.as_bytes()).await?;
+
+    // Read byte by byte so nothing past the header is consumed from the tunnel
+    let mut response = Vec::new();
+    while !response.ends_with(b"\r\n\r\n") {
+        if response.len() >= MAX_CONNECT_RESPONSE {
+            bail!("Proxy CONNECT response too large");
+        }
+        response.push(stream.read_u8().await?);
+    }
+
+    let status_line = String::from_utf8_lossy(&response);
+    let status_line = status_line.lines().next().unwrap_or_default();
+    match status_line.split_whitespace().nth(1) {
+        Some("200") => Ok(()),
+        _ => bail!("Proxy refused CONNECT: {}", status_line),
+    }
+}
+
+async fn socks5_connect(
+    stream: &mut TcpStream,
+    proxy: &Proxy,
+    host: &str,
+    port: u16,
+) -> Result<()> {
+    // Greeting: offer username/password only when credentials are configured
+    let method = if proxy.credentials.is_some() {
+        0x02
+    } else {
+        0x00
+    };
+    stream.write_all(&[0x05, 0x01, method]).await?;
+
+    let mut reply = [0u8; 2];
+    stream.read_exact(&mut reply).await?;
+    if reply != [0x05, method] {
+        bail!("SOCKS5 proxy rejected authentication method");
+    }
+
+    if let Some((user, pass)) = &proxy.credentials {
+        let mut auth = vec![0x01, user.len() as u8];
+        auth.extend_from_slice(user.as_bytes());
+        auth.push(pass.len() as u8);
+        auth.extend_from_slice(pass.as_bytes());
+        stream.write_all(&auth).await?;
+
+        stream.read_exact(&mut reply).await?;
+        if reply[1] != 0x00 {
+            bail!("SOCKS5 proxy authentication failed");
+        }
+    }
+
+    // CONNECT by domain name so the proxy resolves the host
+    let mut request = vec![0x05, 0x01, 0x00, 0x03, host.len() as u8];
+    request.extend_from_slice(host.as_bytes());
+    request.extend_from_slice(&port.to_be_bytes());
+    stream.write_all(&request).await?;
+
+    let mut header = [0u8; 4];
+    stream.read_exact(&mut header).await?;
+    if header[1] != 0x00 {
+        bail!("SOCKS5 CONNECT failed with code {}", header[1]);
+    }
+
+    // Skip the bound address
+    let addr_len = match header[3] {
+        0x01 => 4,
+        0x04 => 16,
+        0x03 => stream.read_u8().await? as usize,
+        other => bail!("SOCKS5 reply with unknown address type {}", other),
+    };
+    let mut bound = vec![0u8; addr_len + 2];
+    stream.read_exact(&mut bound).await?;
+
+    Ok(())
+}
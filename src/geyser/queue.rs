@@ -1,17 +1,85 @@
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::Mutex;
 use tracing::warn;
 
+use crate::clock::{self, SharedClock};
+use crate::config::queue::QueueConfig;
+use crate::metrics;
+use crate::monitor::{DropReason, DroppedTransaction, drops};
+
 /// Structure representing a transaction in the queue
 #[derive(Clone, Debug)]
 pub struct QueuedTransaction {
     pub signature: String,
     pub slot: u64,
+    /// Position of the transaction within its block
+    pub transaction_index: Option<u64>,
     pub received_time: DateTime<Utc>,
+    /// Time of the transaction's block, extrapolated while its block meta is pending
+    pub block_time: Option<DateTime<Utc>>,
+    /// Fetched from RPC to fill a gap in the stream
+    pub backfilled: bool,
+    /// Simple vote transaction, only queued with `QUEUE_KEEP_VOTE_TRANSACTIONS`
+    pub is_vote: bool,
+    /// Message format, v0 messages can load accounts from lookup tables
+    pub version: TransactionVersion,
     pub accounts: Vec<String>,
+    /// Pays the transaction fee, always the first signer
+    pub fee_payer: Option<String>,
+    /// All signers in message order, the fee payer included
+    pub signers: Vec<String>,
     pub instructions: Vec<TransactionInstruction>,
+    /// Inner (CPI) instructions from the transaction meta, in execution order
+    pub inner_instructions: Vec<TransactionInstruction>,
+    /// Raw `SubscribeUpdateTransactionInfo` protobuf of the transaction, captured only
+    /// when recording is enabled
+    pub raw: Option<Arc<Vec<u8>>>,
+}
+
+impl QueuedTransaction {
+    /// Approximate heap footprint, used for queue memory accounting
+    pub fn size_bytes(&self) -> usize {
+        self.signature.len()
+            + self.accounts.iter().map(String::len).sum::<usize>()
+            + self
+                .instructions
+                .iter()
+                .chain(&self.inner_instructions)
+                .map(|i| i.program_id.len() + i.accounts.len() + i.data.len())
+                .sum::<usize>()
+            + self.raw.as_ref().map_or(0, |raw| raw.len())
+    }
+
+    /// Whether `account` signed the transaction
+    pub fn is_signer(&self, account: &str) -> bool {
+        self.signers.iter().any(|signer| signer == account)
+    }
+
+    /// First signer among the accounts of `instruction` other than the fee payer,
+    /// otherwise the fee payer. Attributes launches sent through a relayer or
+    /// co-signed by a multisig to the wallet acting in the instruction.
+    pub fn instruction_signer(&self, instruction: &TransactionInstruction) -> Option<String> {
+        instruction
+            .accounts
+            .iter()
+            .filter_map(|idx| self.accounts.get(*idx as usize))
+            .find(|account| self.is_signer(account) && Some(*account) != self.fee_payer.as_ref())
+            .or(self.fee_payer.as_ref())
+            .cloned()
+    }
+}
+
+/// Transaction message format
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransactionVersion {
+    #[default]
+    Legacy,
+    V0,
 }
 
 /// Instruction data from transaction
@@ -20,69 +88,190 @@ pub struct TransactionInstruction {
     pub program_id: String,
     pub accounts: Vec<u8>, // Account indices
     pub data: Vec<u8>,     // Instruction data
+    pub truncated: bool,   // Data was cut to the configured size limit
+}
+
+struct QueueState {
+    /// Transactions with the time they were queued, oldest first
+    items: VecDeque<(QueuedTransaction, Instant)>,
+    bytes: usize,
 }
 
 /// Thread-safe queue for transactions
 #[derive(Clone)]
 pub struct TransactionQueue {
-    queue: Arc<Mutex<VecDeque<QueuedTransaction>>>,
+    state: Arc<Mutex<QueueState>>,
     max_size: usize,
+    max_bytes: usize,
+    max_instruction_data_bytes: usize,
+    max_transaction_data_bytes: usize,
+    clock: SharedClock,
 }
 
 impl TransactionQueue {
-    /// Creates a new queue with specified maximum size
+    /// Creates a new queue with specified maximum size and no byte limits
     pub fn new(max_size: usize) -> Self {
+        Self::from_config(&QueueConfig {
+            max_items: max_size,
+            max_bytes: usize::MAX,
+            max_instruction_data_bytes: usize::MAX,
+            max_transaction_data_bytes: usize::MAX,
+            keep_votes: false,
+        })
+    }
+
+    /// Creates a new queue enforcing item count and memory limits
+    pub fn from_config(config: &QueueConfig) -> Self {
         Self {
-            queue: Arc::new(Mutex::new(VecDeque::new())),
-            max_size,
+            state: Arc::new(Mutex::new(QueueState {
+                items: VecDeque::new(),
+                bytes: 0,
+            })),
+            max_size: config.max_items,
+            max_bytes: config.max_bytes,
+            max_instruction_data_bytes: config.max_instruction_data_bytes,
+            max_transaction_data_bytes: config.max_transaction_data_bytes,
+            clock: clock::system(),
         }
     }
 
+    /// Replaces the clock measuring how long transactions wait in the queue
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Adds transaction to queue
-    pub async fn push(&self, transaction: QueuedTransaction) {
-        let mut queue = self.queue.lock().await;
+    pub async fn push(&self, mut transaction: QueuedTransaction) {
+        // Truncate oversized instruction data
+        let mut truncated = 0;
+        for instruction in transaction
+            .instructions
+            .iter_mut()
+            .chain(&mut transaction.inner_instructions)
+        {
+            if instruction.data.len() > self.max_instruction_data_bytes {
+                instruction.data.truncate(self.max_instruction_data_bytes);
+                instruction.truncated = true;
+                truncated += 1;
+            }
+        }
+        if truncated > 0 {
+            metrics::global().add_counter("instruction_data_truncated_total", &[], truncated);
+        }
+
+        // Drop pathological transactions outright
+        let data_bytes: usize = transaction
+            .instructions
+            .iter()
+            .chain(&transaction.inner_instructions)
+            .map(|i| i.data.len())
+            .sum();
+        let size = transaction.size_bytes();
+        let limit = if data_bytes > self.max_transaction_data_bytes {
+            Some("transaction_data_limit")
+        } else if size > self.max_bytes {
+            Some("byte_limit")
+        } else {
+            None
+        };
+        if let Some(limit) = limit {
+            warn!(
+                "Dropped oversized transaction {} ({} bytes of instruction data)",
+                transaction.signature, data_bytes
+            );
+            metrics::global().inc_counter("queue_dropped_total", &[("reason", "oversized")]);
+            drops::record(
+                DroppedTransaction::new(
+                    DropReason::Oversized,
+                    &transaction.signature,
+                    transaction.slot,
+                )
+                .with_detail(limit),
+            );
+            return;
+        }
+
+        let mut state = self.state.lock().await;
 
         // Remove oldest transactions if exceeding limit
-        while queue.len() >= self.max_size {
-            if let Some(_removed) = queue.pop_front() {
-                warn!("Removed oldest transaction from queue");
-            }
+        while state.items.len() >= self.max_size || state.bytes + size > self.max_bytes {
+            let reason = if state.items.len() >= self.max_size {
+                "count_limit"
+            } else {
+                "byte_limit"
+            };
+
+            let Some((removed, _)) = state.items.pop_front() else {
+                break;
+            };
+            state.bytes -= removed.size_bytes();
+            warn!("Removed oldest transaction from queue");
+            metrics::global().inc_counter("queue_dropped_total", &[("reason", reason)]);
+            drops::record(
+                DroppedTransaction::new(DropReason::QueueFull, &removed.signature, removed.slot)
+                    .with_detail(reason),
+            );
         }
 
-        queue.push_back(transaction);
+        state.bytes += size;
+        state.items.push_back((transaction, self.clock.instant()));
+        metrics::global().set_gauge("queue_bytes", &[], state.bytes as f64);
+        metrics::global().inc_counter("queue_pushed_total", &[]);
     }
 
     /// Gets transaction from queue (FIFO)
     pub async fn pop(&self) -> Option<QueuedTransaction> {
-        let mut queue = self.queue.lock().await;
-        queue.pop_front()
+        let mut state = self.state.lock().await;
+        let (transaction, _) = state.items.pop_front()?;
+        state.bytes -= transaction.size_bytes();
+        Some(transaction)
     }
 
     /// Returns current queue size
     pub async fn len(&self) -> usize {
-        let queue = self.queue.lock().await;
-        queue.len()
+        let state = self.state.lock().await;
+        state.items.len()
+    }
+
+    /// Returns approximate memory held by queued transactions
+    pub async fn bytes(&self) -> usize {
+        let state = self.state.lock().await;
+        state.bytes
+    }
+
+    /// How long the oldest queued transaction has been waiting
+    pub async fn oldest_age(&self) -> Option<Duration> {
+        let state = self.state.lock().await;
+        let (_, queued_at) = state.items.front()?;
+        Some(self.clock.instant().saturating_duration_since(*queued_at))
     }
 
     /// Checks if queue is empty
     pub async fn is_empty(&self) -> bool {
-        let queue = self.queue.lock().await;
-        queue.is_empty()
+        let state = self.state.lock().await;
+        state.items.is_empty()
     }
 
     /// Gets all transactions from queue (clears queue)
     pub async fn drain_all(&self) -> Vec<QueuedTransaction> {
-        let mut queue = self.queue.lock().await;
-        queue.drain(..).collect()
+        let mut state = self.state.lock().await;
+        state.bytes = 0;
+        state
+            .items
+            .drain(..)
+            .map(|(transaction, _)| transaction)
+            .collect()
     }
 
     /// Gets a batch of transactions from queue (up to max_count)
     pub async fn pop_batch(&self, max_count: usize) -> Vec<QueuedTransaction> {
-        let mut queue = self.queue.lock().await;
+        let mut state = self.state.lock().await;
         let mut batch = Vec::new();
 
         for _ in 0..max_count {
-            if let Some(transaction) = queue.pop_front() {
+            if let Some((transaction, _)) = state.items.pop_front() {
+                state.bytes -= transaction.size_bytes();
                 batch.push(transaction);
             } else {
                 break;
@@ -92,3 +281,157 @@ impl TransactionQueue {
         batch
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::MockClock;
+    use crate::testing::start_time;
+
+    fn transaction(signature: &str) -> QueuedTransaction {
+        QueuedTransaction {
+            signature: signature.to_string(),
+            slot: 1,
+            transaction_index: None,
+            received_time: start_time(),
+            block_time: None,
+            backfilled: false,
+            is_vote: false,
+            version: TransactionVersion::Legacy,
+            accounts: Vec::new(),
+            fee_payer: None,
+            signers: Vec::new(),
+            instructions: Vec::new(),
+            inner_instructions: Vec::new(),
+            raw: None,
+        }
+    }
+
+    /// Transaction with one instruction of `data_bytes`, `signature.len() + 1 + data_bytes`
+    /// bytes in total
+    fn transaction_with_data(signature: &str, data_bytes: usize) -> QueuedTransaction {
+        let mut transaction = transaction(signature);
+        transaction.instructions.push(TransactionInstruction {
+            program_id: "p".to_string(),
+            accounts: Vec::new(),
+            data: vec![1; data_bytes],
+            truncated: false,
+        });
+        transaction
+    }
+
+    fn queue(
+        max_items: usize,
+        max_bytes: usize,
+        max_transaction_data_bytes: usize,
+    ) -> TransactionQueue {
+        TransactionQueue::from_config(&QueueConfig {
+            max_items,
+            max_bytes,
+            max_instruction_data_bytes: 16,
+            max_transaction_data_bytes,
+            keep_votes: false,
+        })
+    }
+
+    fn dropped(signature: &str) -> DroppedTransaction {
+        drops::recent(Some(signature), None, 1)
+            .pop()
+            .expect("drop recorded")
+    }
+
+    #[tokio::test]
+    async fn instruction_data_over_the_limit_is_truncated() {
+        let queue = queue(10, usize::MAX, usize::MAX);
+        let mut transaction = transaction_with_data("truncated", 20);
+        transaction.inner_instructions = transaction_with_data("inner", 16).instructions;
+        queue.push(transaction).await;
+
+        let transaction = queue.pop().await.unwrap();
+        assert_eq!(transaction.instructions[0].data.len(), 16);
+        assert!(transaction.instructions[0].truncated);
+        assert_eq!(transaction.inner_instructions[0].data.len(), 16);
+        assert!(!transaction.inner_instructions[0].truncated);
+    }
+
+    #[tokio::test]
+    async fn oversized_transactions_are_dropped() {
+        drops::set_capacity(1000);
+        let queue = queue(10, 40, 24);
+
+        // Two truncated instructions still carry 32 bytes of data
+        let mut transaction = transaction_with_data("over-data", 20);
+        transaction.inner_instructions = transaction_with_data("inner", 20).instructions;
+        queue.push(transaction).await;
+        // 24 bytes of data within the limit, 45 bytes in total over it
+        let mut transaction = transaction_with_data("over-size", 16);
+        transaction.inner_instructions = transaction_with_data("inner", 8).instructions;
+        transaction.accounts.push("a".repeat(10));
+        queue.push(transaction).await;
+
+        assert!(queue.is_empty().await);
+        let dropped_data = dropped("over-data");
+        assert_eq!(dropped_data.reason, DropReason::Oversized);
+        assert_eq!(
+            dropped_data.detail.as_deref(),
+            Some("transaction_data_limit")
+        );
+        let dropped_size = dropped("over-size");
+        assert_eq!(dropped_size.reason, DropReason::Oversized);
+        assert_eq!(dropped_size.detail.as_deref(), Some("byte_limit"));
+    }
+
+    #[tokio::test]
+    async fn oldest_transactions_are_evicted_at_the_count_limit() {
+        drops::set_capacity(1000);
+        let queue = queue(2, usize::MAX, usize::MAX);
+        for signature in ["count-a", "count-b", "count-c"] {
+            queue.push(transaction(signature)).await;
+        }
+
+        let signatures: Vec<_> = queue
+            .drain_all()
+            .await
+            .into_iter()
+            .map(|transaction| transaction.signature)
+            .collect();
+        assert_eq!(signatures, ["count-b", "count-c"]);
+        let evicted = dropped("count-a");
+        assert_eq!(evicted.reason, DropReason::QueueFull);
+        assert_eq!(evicted.detail.as_deref(), Some("count_limit"));
+    }
+
+    #[tokio::test]
+    async fn oldest_transactions_are_evicted_at_the_byte_limit() {
+        drops::set_capacity(1000);
+        // 7 + 1 + 10 = 18 bytes each, two fit
+        let queue = queue(10, 40, usize::MAX);
+        for signature in ["bytes-a", "bytes-b", "bytes-c"] {
+            queue.push(transaction_with_data(signature, 10)).await;
+        }
+
+        assert_eq!(queue.len().await, 2);
+        assert_eq!(queue.bytes().await, 36);
+        assert_eq!(queue.pop().await.unwrap().signature, "bytes-b");
+        assert_eq!(queue.bytes().await, 18);
+        let evicted = dropped("bytes-a");
+        assert_eq!(evicted.reason, DropReason::QueueFull);
+        assert_eq!(evicted.detail.as_deref(), Some("byte_limit"));
+    }
+
+    #[tokio::test]
+    async fn oldest_age_follows_the_clock() {
+        let clock = MockClock::new(start_time());
+        let queue = TransactionQueue::new(10).with_clock(clock.shared());
+        assert_eq!(queue.oldest_age().await, None);
+
+        queue.push(transaction("a")).await;
+        clock.advance(Duration::from_secs(2));
+        queue.push(transaction("b")).await;
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(queue.oldest_age().await, Some(Duration::from_secs(3)));
+
+        queue.pop().await;
+        assert_eq!(queue.oldest_age().await, Some(Duration::from_secs(1)));
+    }
+}
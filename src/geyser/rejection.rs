@@ -0,0 +1,238 @@
+use serde_json::{Value, json};
+use solana_stream_sdk::GeyserSubscribeRequest;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use tonic::{Code, Status};
+
+/// Optional filters dropped, in this order, when a rejection names no filter or account.
+/// Transaction filters are never dropped.
+const OPTIONAL_FILTERS: &[&str] = &["accounts", "entry", "slots", "blocks_meta"];
+
+/// Filter of a subscription request, as `<kind>.<name>`
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FilterId {
+    pub kind: &'static str,
+    pub name: String,
+}
+
+impl fmt::Display for FilterId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.kind, self.name)
+    }
+}
+
+/// Subscribe request refused by the Geyser provider
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("subscription rejected{}: {message}", describe(.filter, .account))]
+pub struct FilterRejection {
+    /// Filter the provider or a local check blamed, if any
+    pub filter: Option<FilterId>,
+    /// Account the provider or a local check blamed, if any
+    pub account: Option<String>,
+    pub message: String,
+}
+
+fn describe(filter: &Option<FilterId>, account: &Option<String>) -> String {
+    match (filter, account) {
+        (Some(filter), Some(account)) => format!(" ({}, account {})", filter, account),
+        (Some(filter), None) => format!(" ({})", filter),
+        (None, Some(account)) => format!(" (account {})", account),
+        (None, None) => String::new(),
+    }
+}
+
+impl FilterRejection {
+    /// Identifies what `request` was rejected for. `None` unless the status is a rejection.
+    /// The offending filter or account is taken from the provider's message when it names
+    /// one, otherwise an account that is not a valid public key is looked for.
+    pub fn classify(status: &Status, request: &GeyserSubscribeRequest) -> Option<Self> {
+        if status.code() != Code::InvalidArgument {
+            return None;
+        }
+
+        let message = status.message().to_string();
+        let words: BTreeSet<&str> = message
+            .split(|c: char| !c.is_alphanumeric() && c != '_' && c != '-')
+            .collect();
+        let filters = request_filters(request);
+
+        let named = filters.iter().find_map(|(filter, accounts)| {
+            accounts
+                .iter()
+                .find(|account| words.contains(account.as_str()))
+                .map(|account| (filter.clone(), Some(account.clone())))
+                .or_else(|| {
+                    words
+                        .contains(filter.name.as_str())
+                        .then(|| (filter.clone(), None))
+                })
+        });
+        let invalid = || {
+            filters.iter().find_map(|(filter, accounts)| {
+                accounts
+                    .iter()
+                    .find(|account| !is_pubkey(account))
+                    .map(|account| (filter.clone(), Some(account.clone())))
+            })
+        };
+
+        let (filter, account) = match named.or_else(invalid) {
+            Some((filter, account)) => (Some(filter), account),
+            None => (None, None),
+        };
+        Some(Self {
+            filter,
+            account,
+            message,
+        })
+    }
+}
+
+fn is_pubkey(account: &str) -> bool {
+    bs58::decode(account)
+        .into_vec()
+        .is_ok_and(|key| key.len() == 32)
+}
+
+/// Filters of the request with the accounts they list
+fn request_filters(request: &GeyserSubscribeRequest) -> Vec<(FilterId, Vec<String>)> {
+    let id = |kind, name: &String| FilterId {
+        kind,
+        name: name.clone(),
+    };
+    let mut filters = Vec::new();
+    for (name, filter) in &request.transactions {
+        let accounts = filter
+            .account_include
+            .iter()
+            .chain(&filter.account_exclude)
+            .chain(&filter.account_required)
+            .cloned()
+            .collect();
+        filters.push((id("transactions", name), accounts));
+    }
+    for (name, filter) in &request.accounts {
+        let accounts = filter
+            .account
+            .iter()
+            .chain(&filter.owner)
+            .cloned()
+            .collect();
+        filters.push((id("accounts", name), accounts));
+    }
+    for (name, filter) in &request.blocks {
+        filters.push((id("blocks", name), filter.account_include.clone()));
+    }
+    for name in request.slots.keys() {
+        filters.push((id("slots", name), Vec::new()));
+    }
+    for name in request.blocks_meta.keys() {
+        filters.push((id("blocks_meta", name), Vec::new()));
+    }
+    for name in request.entry.keys() {
+        filters.push((id("entry", name), Vec::new()));
+    }
+    filters
+}
+
+#[derive(Default)]
+struct Reductions {
+    filters: BTreeSet<FilterId>,
+    accounts: BTreeSet<String>,
+}
+
+/// Filters and accounts removed from the subscription after rejections, kept until restart
+#[derive(Clone, Default)]
+pub struct SubscriptionReductions {
+    inner: Arc<Mutex<Reductions>>,
+}
+
+impl SubscriptionReductions {
+    /// Reduces the subscription so the rejected request is not sent again:
+    /// drops the blamed account, else the blamed filter, else the next optional filter.
+    /// Returns what was dropped, `None` when nothing is left to drop.
+    pub fn reduce(
+        &self,
+        rejection: &FilterRejection,
+        request: &GeyserSubscribeRequest,
+    ) -> Option<String> {
+        let mut inner = self.inner.lock().unwrap();
+
+        if let Some(account) = &rejection.account {
+            inner.accounts.insert(account.clone());
+            return Some(format!("account {}", account));
+        }
+
+        let filters = request_filters(request);
+        let transaction_filters = filters
+            .iter()
+            .filter(|(filter, _)| filter.kind == "transactions")
+            .count();
+        if let Some(filter) = &rejection.filter
+            && (filter.kind != "transactions" || transaction_filters > 1)
+        {
+            inner.filters.insert(filter.clone());
+            return Some(format!("filter {}", filter));
+        }
+
+        let optional = OPTIONAL_FILTERS.iter().find_map(|kind| {
+            filters
+                .iter()
+                .map(|(filter, _)| filter)
+                .find(|filter| filter.kind == *kind)
+        })?;
+        inner.filters.insert(optional.clone());
+        Some(format!("filter {}", optional))
+    }
+
+    /// Removes dropped filters and accounts from a request. Filters left without any
+    /// included account are dropped too, an empty include list would match everything.
+    pub fn apply(&self, request: &mut GeyserSubscribeRequest) {
+        let inner = self.inner.lock().unwrap();
+        if inner.filters.is_empty() && inner.accounts.is_empty() {
+            return;
+        }
+
+        let kept = |kind: &'static str, name: &String| {
+            !inner.filters.contains(&FilterId {
+                kind,
+                name: name.clone(),
+            })
+        };
+        let valid = |account: &String| !inner.accounts.contains(account);
+        // Whether a non-empty list still has accounts once dropped ones are removed
+        let restrict = |accounts: &mut Vec<String>| {
+            let listed = !accounts.is_empty();
+            accounts.retain(valid);
+            !listed || !accounts.is_empty()
+        };
+
+        request.transactions.retain(|name, filter| {
+            filter.account_exclude.retain(valid);
+            kept("transactions", name)
+                && restrict(&mut filter.account_include)
+                && restrict(&mut filter.account_required)
+        });
+        request.accounts.retain(|name, filter| {
+            kept("accounts", name) && restrict(&mut filter.account) && restrict(&mut filter.owner)
+        });
+        request
+            .blocks
+            .retain(|name, filter| kept("blocks", name) && restrict(&mut filter.account_include));
+        request.slots.retain(|name, _| kept("slots", name));
+        request
+            .blocks_meta
+            .retain(|name, _| kept("blocks_meta", name));
+        request.entry.retain(|name, _| kept("entry", name));
+    }
+
+    /// Dropped filters and accounts as JSON
+    pub fn to_json(&self) -> Value {
+        let inner = self.inner.lock().unwrap();
+        json!({
+            "filters": inner.filters.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            "accounts": inner.accounts,
+        })
+    }
+}
@@ -0,0 +1,126 @@
+use serde_json::{Value, json};
+use solana_stream_sdk::{
+    GeyserAccountsFilterEnum, GeyserLamportsCmp, GeyserMemcmpData, GeyserSubscribeRequest,
+    GeyserSubscribeRequestFilterAccounts, GeyserSubscribeRequestFilterTransactions,
+};
+use std::collections::HashMap;
+
+/// Converts a subscription request into JSON so operators can inspect what is actually subscribed
+pub fn subscribe_request_to_json(request: &GeyserSubscribeRequest) -> Value {
+    json!({
+        "commitment": request.commitment.map(commitment_name),
+        "transactions": transactions_to_json(&request.transactions),
+        "transactions_status": transactions_to_json(&request.transactions_status),
+        "accounts": request
+            .accounts
+            .iter()
+            .map(|(name, filter)| (name.clone(), accounts_filter_to_json(filter)))
+            .collect::<serde_json::Map<_, _>>(),
+        "slots": request
+            .slots
+            .iter()
+            .map(|(name, filter)| {
+                (
+                    name.clone(),
+                    json!({
+                        "filter_by_commitment": filter.filter_by_commitment,
+                        "interslot_updates": filter.interslot_updates,
+                    }),
+                )
+            })
+            .collect::<serde_json::Map<_, _>>(),
+        "blocks": request
+            .blocks
+            .iter()
+            .map(|(name, filter)| {
+                (
+                    name.clone(),
+                    json!({
+                        "account_include": filter.account_include,
+                        "include_transactions": filter.include_transactions,
+                        "include_accounts": filter.include_accounts,
+                        "include_entries": filter.include_entries,
+                    }),
+                )
+            })
+            .collect::<serde_json::Map<_, _>>(),
+        "blocks_meta": request.blocks_meta.keys().collect::<Vec<_>>(),
+        "entry": request.entry.keys().collect::<Vec<_>>(),
+        "accounts_data_slice": request
+            .accounts_data_slice
+            .iter()
+            .map(|slice| json!({ "offset": slice.offset, "length": slice.length }))
+            .collect::<Vec<_>>(),
+        "from_slot": request.from_slot,
+    })
+}
+
+fn commitment_name(commitment: i32) -> String {
+    match commitment {
+        0 => "Processed".to_string(),
+        1 => "Confirmed".to_string(),
+        2 => "Finalized".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn transactions_to_json(
+    filters: &HashMap<String, GeyserSubscribeRequestFilterTransactions>,
+) -> Value {
+    filters
+        .iter()
+        .map(|(name, filter)| {
+            (
+                name.clone(),
+                json!({
+                    "vote": filter.vote,
+                    "failed": filter.failed,
+                    "signature": filter.signature,
+                    "account_include": filter.account_include,
+                    "account_exclude": filter.account_exclude,
+                    "account_required": filter.account_required,
+                }),
+            )
+        })
+        .collect::<serde_json::Map<_, _>>()
+        .into()
+}
+
+fn accounts_filter_to_json(filter: &GeyserSubscribeRequestFilterAccounts) -> Value {
+    let filters: Vec<Value> = filter
+        .filters
+        .iter()
+        .filter_map(|f| f.filter.as_ref())
+        .map(|f| match f {
+            GeyserAccountsFilterEnum::Memcmp(memcmp) => {
+                let data = match &memcmp.data {
+                    Some(GeyserMemcmpData::Base58(data)) => json!({ "base58": data }),
+                    Some(other) => json!(format!("{:?}", other)),
+                    None => Value::Null,
+                };
+                json!({ "memcmp": { "offset": memcmp.offset, "data": data } })
+            }
+            GeyserAccountsFilterEnum::Datasize(size) => json!({ "datasize": size }),
+            GeyserAccountsFilterEnum::TokenAccountState(state) => {
+                json!({ "token_account_state": state })
+            }
+            GeyserAccountsFilterEnum::Lamports(lamports) => {
+                let (cmp, value) = match &lamports.cmp {
+                    Some(GeyserLamportsCmp::Eq(v)) => ("eq", *v),
+                    Some(GeyserLamportsCmp::Ne(v)) => ("ne", *v),
+                    Some(GeyserLamportsCmp::Lt(v)) => ("lt", *v),
+                    Some(GeyserLamportsCmp::Gt(v)) => ("gt", *v),
+                    None => ("none", 0),
+                };
+                json!({ "lamports": { "cmp": cmp, "value": value } })
+            }
+        })
+        .collect();
+
+    json!({
+        "account": filter.account,
+        "owner": filter.owner,
+        "filters": filters,
+        "nonempty_txn_signature": filter.nonempty_txn_signature,
+    })
+}
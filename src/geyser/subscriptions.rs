@@ -0,0 +1,322 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+use tokio::time::{MissedTickBehavior, interval};
+use tracing::{debug, info, warn};
+
+use crate::clock::{self, SharedClock};
+use crate::config::accounts::AccountTrackingConfig;
+use crate::metrics;
+use crate::monitor::{OpsError, OpsErrorKind, ops};
+use crate::parser::{CurveProgress, LaunchpadType, TokenLaunch, accounts, pricing};
+use crate::sink::EventSink;
+
+/// LaunchLab PoolState account discriminator (raydiumIDL.json)
+const LAUNCHLAB_POOL_STATE: [u8; 8] = [247, 237, 227, 245, 215, 195, 222, 70];
+/// Offset of `status`, after the discriminator, `epoch` and `auth_bump`; 0 while funding
+const LAUNCHLAB_STATUS_OFFSET: usize = 8 + 8 + 1;
+
+/// Account of a launch followed through the Geyser accounts filter
+struct TrackedAccount {
+    token_address: String,
+    launchpad: LaunchpadType,
+    added: Instant,
+    /// Set for the creator's token account rather than the pool
+    creator: Option<CreatorAccount>,
+}
+
+/// Token account of a launch's creator, followed for transfers out of it
+#[derive(Debug, Clone)]
+pub struct CreatorAccount {
+    pub launchpad: LaunchpadType,
+    pub token_address: String,
+    pub creator: String,
+    /// Supply the share of a transfer is measured against
+    pub supply: u64,
+}
+
+#[derive(Default)]
+struct SubscriptionState {
+    accounts: HashMap<String, TrackedAccount>,
+    /// Set when the account set differs from the one last sent to Geyser
+    changed: bool,
+    /// Latest progress of each curve updated since the last round was published
+    progress: HashMap<String, CurveProgress>,
+}
+
+/// Keeps the bonding curve / pool accounts of fresh launches subscribed.
+/// Accounts are added on launch and dropped on graduation or after the TTL;
+/// the Geyser client picks up changes and updates the live subscription.
+#[derive(Clone)]
+pub struct AccountSubscriptions {
+    config: AccountTrackingConfig,
+    state: Arc<Mutex<SubscriptionState>>,
+    clock: SharedClock,
+}
+
+impl AccountSubscriptions {
+    pub fn new(config: AccountTrackingConfig) -> Self {
+        Self {
+            config,
+            state: Arc::new(Mutex::new(SubscriptionState::default())),
+            clock: clock::system(),
+        }
+    }
+
+    /// Replaces the clock the TTL is measured with
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Shortest interval between two subscription updates
+    pub fn update_interval(&self) -> Duration {
+        Duration::from_millis(self.config.update_interval_ms)
+    }
+
+    /// Subscribes to the pool account of a launch
+    pub fn track(&self, launch: &TokenLaunch) {
+        if let Some(pool) = &launch.pool {
+            self.insert(pool, launch, None);
+        }
+    }
+
+    /// Subscribes to the creator's token account of a launch, when creator transfers
+    /// are followed
+    pub fn track_creator_account(&self, launch: &TokenLaunch, token_account: &str) {
+        let Some(creator) = &launch.creator else {
+            return;
+        };
+        if !self.config.creator_transfers {
+            return;
+        }
+        let creator = CreatorAccount {
+            launchpad: launch.launchpad.clone(),
+            token_address: launch.token_address.clone(),
+            creator: creator.clone(),
+            supply: launch
+                .metadata
+                .initial_supply
+                .unwrap_or(pricing::DEFAULT_SUPPLY),
+        };
+        self.insert(token_account, launch, Some(creator));
+    }
+
+    fn insert(&self, account: &str, launch: &TokenLaunch, creator: Option<CreatorAccount>) {
+        let mut state = self.state.lock().unwrap();
+        if state.accounts.contains_key(account) {
+            return;
+        }
+        if state.accounts.len() >= self.config.max_accounts {
+            warn!(
+                "⚠️ Account tracking full ({} accounts), not subscribing to {}",
+                self.config.max_accounts, account
+            );
+            metrics::global().inc_counter("tracked_accounts_skipped_total", &[]);
+            return;
+        }
+
+        debug!("➕ Subscribing to {} of {}", account, launch.token_address);
+        state.accounts.insert(
+            account.to_string(),
+            TrackedAccount {
+                token_address: launch.token_address.clone(),
+                launchpad: launch.launchpad.clone(),
+                added: self.clock.instant(),
+                creator,
+            },
+        );
+        state.changed = true;
+        metrics::global().set_gauge("tracked_accounts", &[], state.accounts.len() as f64);
+    }
+
+    /// Creator of the launch owning a followed token account
+    pub fn creator_account(&self, account: &str) -> Option<CreatorAccount> {
+        let state = self.state.lock().unwrap();
+        state.accounts.get(account)?.creator.clone()
+    }
+
+    /// Followed creator token accounts, whose transactions are subscribed too
+    pub fn creator_accounts(&self) -> Vec<String> {
+        let state = self.state.lock().unwrap();
+        let mut accounts: Vec<String> = state
+            .accounts
+            .iter()
+            .filter(|(_, account)| account.creator.is_some())
+            .map(|(account, _)| account.clone())
+            .collect();
+        accounts.sort();
+        accounts
+    }
+
+    /// Share of the supply in percent a creator transfer must move to be published,
+    /// `None` when creator transfers are not followed
+    pub fn creator_transfer_min_percent(&self) -> Option<f64> {
+        self.config
+            .creator_transfers
+            .then_some(self.config.creator_transfer_min_percent)
+    }
+
+    /// Unsubscribes from an account
+    pub fn untrack(&self, account: &str) {
+        let mut state = self.state.lock().unwrap();
+        if state.accounts.remove(account).is_some() {
+            state.changed = true;
+            metrics::global().set_gauge("tracked_accounts", &[], state.accounts.len() as f64);
+        }
+    }
+
+    /// Accounts currently subscribed
+    pub fn accounts(&self) -> Vec<String> {
+        let state = self.state.lock().unwrap();
+        let mut accounts: Vec<String> = state.accounts.keys().cloned().collect();
+        accounts.sort();
+        accounts
+    }
+
+    /// Drops expired accounts and returns whether the subscription needs an update
+    pub fn take_changed(&self) -> bool {
+        let ttl = Duration::from_secs(self.config.ttl_secs);
+        let now = self.clock.instant();
+        let mut state = self.state.lock().unwrap();
+
+        let before = state.accounts.len();
+        state
+            .accounts
+            .retain(|_, account| now.duration_since(account.added) < ttl);
+        let expired = before - state.accounts.len();
+        if expired > 0 {
+            debug!("⌛ {} tracked accounts expired", expired);
+            metrics::global().add_counter("tracked_accounts_expired_total", &[], expired as u64);
+            metrics::global().set_gauge("tracked_accounts", &[], state.accounts.len() as f64);
+            state.changed = true;
+        }
+
+        std::mem::take(&mut state.changed)
+    }
+
+    /// Handles an account update, unsubscribing once the launch graduated
+    pub fn handle_update(&self, account: &str, data: &[u8], slot: u64) {
+        let graduated = {
+            let mut state = self.state.lock().unwrap();
+            let Some(tracked) = state.accounts.get(account) else {
+                return;
+            };
+            metrics::global().inc_counter(
+                "tracked_account_updates_total",
+                &[("launchpad", &tracked.launchpad.to_string())],
+            );
+
+            // A closed creator token account has nothing left to transfer
+            if tracked.creator.is_some() {
+                if data.is_empty() {
+                    debug!("🗑️ Creator token account {} closed", account);
+                    drop(state);
+                    self.untrack(account);
+                }
+                return;
+            }
+
+            let mut progress = None;
+            let graduated = match tracked.launchpad {
+                LaunchpadType::Pumpfun => {
+                    let curve = accounts::decode_bonding_curve(data);
+                    if self.config.curve_progress
+                        && let Some(curve) = &curve
+                    {
+                        progress = Some(CurveProgress {
+                            launchpad: tracked.launchpad.clone(),
+                            token_address: tracked.token_address.clone(),
+                            bonding_curve: account.to_string(),
+                            percent_complete: curve.progress(),
+                            virtual_sol_reserves: curve.virtual_sol_reserves,
+                            virtual_token_reserves: curve.virtual_token_reserves,
+                            real_sol_reserves: curve.real_sol_reserves,
+                            real_token_reserves: curve.real_token_reserves,
+                            complete: curve.complete,
+                            slot,
+                            timestamp: self.clock.now(),
+                        });
+                    }
+                    curve.is_some_and(|curve| curve.complete)
+                }
+                LaunchpadType::Meteora | LaunchpadType::JupiterStudio => {
+                    accounts::decode_virtual_pool(data).is_some_and(|pool| pool.is_migrated)
+                }
+                LaunchpadType::LaunchLab | LaunchpadType::LetsBonk => {
+                    data.starts_with(&LAUNCHLAB_POOL_STATE)
+                        && data
+                            .get(LAUNCHLAB_STATUS_OFFSET)
+                            .is_some_and(|status| *status != 0)
+                }
+                // Curve layout not decoded, these accounts only expire
+                LaunchpadType::Moonshot
+                | LaunchpadType::Believe
+                | LaunchpadType::DaosFun
+                | LaunchpadType::Bags
+                | LaunchpadType::Vertigo
+                | LaunchpadType::Fluxbeam
+                | LaunchpadType::Virtuals
+                | LaunchpadType::TimeFun
+                | LaunchpadType::PumpSwap
+                | LaunchpadType::RaydiumCpmm
+                | LaunchpadType::RaydiumClmm
+                | LaunchpadType::MeteoraDlmm
+                | LaunchpadType::Unknown
+                | LaunchpadType::Custom(_) => false,
+            };
+            let graduated = graduated.then(|| tracked.token_address.clone());
+            if let Some(progress) = progress {
+                state.progress.insert(account.to_string(), progress);
+            }
+            graduated
+        };
+
+        if let Some(token_address) = graduated {
+            info!(
+                "🎓 {} graduated, unsubscribing from {}",
+                token_address, account
+            );
+            metrics::global().inc_counter("tracked_account_graduations_total", &[]);
+            self.untrack(account);
+        }
+    }
+
+    /// Latest progress of each curve updated since the last call
+    pub fn take_curve_progress(&self) -> Vec<CurveProgress> {
+        let mut state = self.state.lock().unwrap();
+        std::mem::take(&mut state.progress).into_values().collect()
+    }
+
+    /// Publishes the progress of the curves updated in each interval
+    pub fn start_curve_progress(&self, sink: Arc<dyn EventSink>) -> JoinHandle<()> {
+        let subscriptions = self.clone();
+        tokio::spawn(async move {
+            info!(
+                "📈 Publishing bonding curve progress every {}ms",
+                subscriptions.config.curve_progress_interval_ms
+            );
+
+            let mut ticker = interval(Duration::from_millis(
+                subscriptions.config.curve_progress_interval_ms,
+            ));
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+            loop {
+                ticker.tick().await;
+
+                for progress in subscriptions.take_curve_progress() {
+                    metrics::global().inc_counter(
+                        "curve_progress_total",
+                        &[("launchpad", &progress.launchpad.to_string())],
+                    );
+                    if let Err(e) = sink.publish_curve_progress(&progress).await {
+                        warn!("Failed to publish curve progress: {}", e);
+                        ops::report(OpsError::new(OpsErrorKind::SinkFailure, "accounts", &e));
+                    }
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,160 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::fs;
+
+/// Anchor discriminator namespaces
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Namespace {
+    Instruction,
+    Event,
+    Account,
+}
+
+impl Namespace {
+    fn prefix(self) -> &'static str {
+        match self {
+            Namespace::Instruction => "global",
+            Namespace::Event => "event",
+            Namespace::Account => "account",
+        }
+    }
+}
+
+/// Computes the 8-byte Anchor discriminator, `sha256("<namespace>:<name>")[..8]`.
+/// Instruction names are converted to snake_case like Anchor does; events and accounts keep their name.
+pub fn discriminator(namespace: Namespace, name: &str) -> [u8; 8] {
+    let name = match namespace {
+        Namespace::Instruction => to_snake_case(name),
+        Namespace::Event | Namespace::Account => name.to_string(),
+    };
+    let hash = Sha256::digest(format!("{}:{}", namespace.prefix(), name));
+
+    let mut out = [0u8; 8];
+    out.copy_from_slice(&hash[..8]);
+    out
+}
+
+/// Converts a camelCase or PascalCase name to snake_case
+pub fn to_snake_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len() + 4);
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Subset of an Anchor IDL (legacy and 0.30+ formats) needed to write parsers
+#[derive(Debug, Clone, Deserialize)]
+pub struct Idl {
+    pub address: Option<String>,
+    pub name: Option<String>,
+    pub metadata: Option<IdlMetadata>,
+    #[serde(default)]
+    pub instructions: Vec<IdlInstruction>,
+    #[serde(default)]
+    pub events: Vec<IdlItem>,
+    #[serde(default)]
+    pub accounts: Vec<IdlItem>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IdlMetadata {
+    pub name: Option<String>,
+    pub address: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IdlInstruction {
+    pub name: String,
+    pub discriminator: Option<Vec<u8>>,
+    /// Account items, possibly nested in groups
+    #[serde(default)]
+    pub accounts: Vec<Value>,
+    #[serde(default)]
+    pub args: Vec<IdlField>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IdlField {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: Value,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IdlItem {
+    pub name: String,
+    pub discriminator: Option<Vec<u8>>,
+}
+
+impl Idl {
+    /// Reads and parses an IDL JSON file
+    pub fn load(path: &str) -> Result<Self> {
+        let content =
+            fs::read_to_string(path).with_context(|| format!("Failed to read IDL {}", path))?;
+        serde_json::from_str(&content).with_context(|| format!("Failed to parse IDL {}", path))
+    }
+
+    /// Program name from the IDL metadata (0.30+) or top level (legacy)
+    pub fn program_name(&self) -> Option<&str> {
+        self.metadata
+            .as_ref()
+            .and_then(|m| m.name.as_deref())
+            .or(self.name.as_deref())
+    }
+
+    /// Program address from the top level (0.30+) or metadata (legacy)
+    pub fn program_address(&self) -> Option<&str> {
+        self.address
+            .as_deref()
+            .or_else(|| self.metadata.as_ref().and_then(|m| m.address.as_deref()))
+    }
+}
+
+impl IdlInstruction {
+    /// Discriminator listed in the IDL, computed from the name when absent
+    pub fn discriminator(&self) -> [u8; 8] {
+        declared(&self.discriminator)
+            .unwrap_or_else(|| discriminator(Namespace::Instruction, &self.name))
+    }
+
+    /// Flattened account names in instruction order
+    pub fn account_names(&self) -> Vec<String> {
+        fn collect(items: &[Value], out: &mut Vec<String>) {
+            for item in items {
+                match item.get("accounts").and_then(Value::as_array) {
+                    Some(nested) => collect(nested, out),
+                    None => {
+                        if let Some(name) = item.get("name").and_then(Value::as_str) {
+                            out.push(name.to_string());
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut names = Vec::new();
+        collect(&self.accounts, &mut names);
+        names
+    }
+}
+
+impl IdlItem {
+    /// Discriminator listed in the IDL, computed from the name when absent
+    pub fn discriminator(&self, namespace: Namespace) -> [u8; 8] {
+        declared(&self.discriminator).unwrap_or_else(|| discriminator(namespace, &self.name))
+    }
+}
+
+fn declared(discriminator: &Option<Vec<u8>>) -> Option<[u8; 8]> {
+    discriminator.as_deref().and_then(|d| d.try_into().ok())
+}
@@ -1,5 +1,52 @@
+//! Detects token launches on Solana launchpads from a Geyser gRPC stream.
+//!
+//! Transactions flow from a [`GeyserClient`] through its [`TransactionQueue`] into the
+//! [`ParserManager`], which runs the matching [`LaunchpadParser`]s and hands the resulting
+//! events to an [`EventSink`]. `Pipeline` wires them up the way the service binary runs
+//! them. The items re-exported at the crate root are the public API and follow semver. The
+//! modules stay public for the binaries and for advanced use, but their layout may change
+//! in minor releases.
+
+#[cfg(feature = "admin")]
+pub mod admin;
+#[cfg(feature = "enrichment")]
+pub mod cache;
+pub mod clock;
 pub mod config;
+#[cfg(feature = "enrichment")]
+pub mod enrichment;
 pub mod error;
 pub mod geyser;
+pub mod idl;
+pub mod metrics;
+pub mod monitor;
 pub mod parser;
+#[cfg(all(feature = "rabbitmq", feature = "admin", feature = "enrichment"))]
+pub mod pipeline;
+#[cfg(feature = "rabbitmq")]
 pub mod rabbitmq;
+#[cfg(feature = "enrichment")]
+pub mod rpc;
+pub mod sink;
+#[cfg(test)]
+mod testing;
+
+pub use geyser::{
+    GeyserClient, PreFilter, PreFilterChain, QueuedTransaction, TransactionInstruction,
+    TransactionQueue, TransactionVersion,
+};
+pub use parser::accounts::{
+    BondingCurve, CurvePoint, PoolConfig, VirtualPool, decode_bonding_curve, decode_pool_config,
+    decode_virtual_pool,
+};
+pub use parser::{
+    AuthorityRevoked, AuthorityType, CreatorTransfer, CurveProgress, DevSell, EnrichmentError,
+    EventKind, FeeClaim, FeeClaimKind, FirstBuyer, FirstBuyers, Graduation, GraduationTrigger,
+    LaunchpadCapabilities, LaunchpadParser, LaunchpadType, Links, Liquidity, LiquidityAction,
+    LpBurned, MetadataField, Migration, ParseResult, ParserCapabilities, ParserManager,
+    PartialParse, ProgramEvent, TokenExtensions, TokenLaunch, Trade, TradeFees,
+    launchpad_parser::LaunchMetadata,
+};
+#[cfg(all(feature = "rabbitmq", feature = "admin", feature = "enrichment"))]
+pub use pipeline::{Dump, Pipeline, RunningPipeline};
+pub use sink::{EventSink, RawSink};
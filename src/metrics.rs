@@ -0,0 +1,123 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::{Mutex, OnceLock};
+
+type Labels = Vec<(&'static str, String)>;
+
+/// Process-wide counters and gauges, rendered in Prometheus text format
+#[derive(Default)]
+pub struct Metrics {
+    counters: Mutex<BTreeMap<(&'static str, Labels), u64>>,
+    gauges: Mutex<BTreeMap<(&'static str, Labels), f64>>,
+}
+
+/// Returns the global metrics registry
+pub fn global() -> &'static Metrics {
+    static METRICS: OnceLock<Metrics> = OnceLock::new();
+    METRICS.get_or_init(Metrics::default)
+}
+
+fn to_labels(labels: &[(&'static str, &str)]) -> Labels {
+    labels.iter().map(|(k, v)| (*k, v.to_string())).collect()
+}
+
+impl Metrics {
+    /// Increments a counter by one
+    pub fn inc_counter(&self, name: &'static str, labels: &[(&'static str, &str)]) {
+        self.add_counter(name, labels, 1);
+    }
+
+    /// Increments a counter by `value`
+    pub fn add_counter(&self, name: &'static str, labels: &[(&'static str, &str)], value: u64) {
+        let mut counters = self.counters.lock().unwrap();
+        *counters.entry((name, to_labels(labels))).or_insert(0) += value;
+    }
+
+    /// Returns current counter value (0 if never incremented)
+    pub fn counter_value(&self, name: &'static str, labels: &[(&'static str, &str)]) -> u64 {
+        let counters = self.counters.lock().unwrap();
+        counters
+            .get(&(name, to_labels(labels)))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Sets a gauge to `value`
+    pub fn set_gauge(&self, name: &'static str, labels: &[(&'static str, &str)], value: f64) {
+        let mut gauges = self.gauges.lock().unwrap();
+        gauges.insert((name, to_labels(labels)), value);
+    }
+
+    /// Renders all metrics in Prometheus text exposition format
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+
+        let counters = self.counters.lock().unwrap();
+        render_family(
+            &mut out,
+            "counter",
+            counters.iter().map(|(k, v)| (k, *v as f64)),
+        );
+        drop(counters);
+
+        let gauges = self.gauges.lock().unwrap();
+        render_family(&mut out, "gauge", gauges.iter().map(|(k, v)| (k, *v)));
+
+        out
+    }
+}
+
+/// Escapes a label value for the text format, where backslash, double quote and line
+/// feed are the only characters needing it
+fn escape_label(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn render_family<'a>(
+    out: &mut String,
+    kind: &str,
+    samples: impl Iterator<Item = (&'a (&'static str, Labels), f64)>,
+) {
+    let mut last_name = "";
+    for ((name, labels), value) in samples {
+        if *name != last_name {
+            let _ = writeln!(out, "# TYPE {name} {kind}");
+            last_name = name;
+        }
+
+        if labels.is_empty() {
+            let _ = writeln!(out, "{name} {value}");
+        } else {
+            let rendered: Vec<String> = labels
+                .iter()
+                .map(|(k, v)| format!("{k}=\"{}\"", escape_label(v)))
+                .collect();
+            let _ = writeln!(out, "{name}{{{}}} {value}", rendered.join(","));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn label_values_are_escaped() {
+        let metrics = Metrics::default();
+        metrics.inc_counter("launches_total", &[("launchpad", "a\\b \"c\"\nd")]);
+
+        assert_eq!(
+            metrics.render(),
+            "# TYPE launches_total counter\nlaunches_total{launchpad=\"a\\\\b \\\"c\\\"\\nd\"} 1\n"
+        );
+    }
+}
@@ -0,0 +1,254 @@
+use chrono::Utc;
+use std::time::Instant;
+use tokio::task::JoinHandle;
+use tokio::time::{Duration, MissedTickBehavior, interval};
+use tracing::{info, warn};
+
+use crate::config::bench::BenchConfig;
+use crate::geyser::{QueuedTransaction, TransactionInstruction, TransactionVersion};
+use crate::metrics;
+use crate::parser::anchor::EVENT_IX_TAG;
+use crate::parser::meteora::MeteoraParser;
+use crate::parser::pumpfun::PumpfunParser;
+use crate::parser::{LaunchpadParser, ParseResult};
+
+const PUMPFUN_PROGRAM_ID: &str = "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P";
+const METEORA_DBC_PROGRAM_ID: &str = "dbcij3LWUppWqq96dh6gJWwBifmcGfLSB5D4DuSMaqN";
+/// Observed load above this share of the measured capacity is reported as undersized
+const HEADROOM_WARN_RATIO: f64 = 0.5;
+
+/// Result of one benchmark run
+#[derive(Debug, Clone, Copy)]
+pub struct BenchReport {
+    pub transactions_per_sec: f64,
+    pub events_per_sec: f64,
+}
+
+/// Measures the parse path against embedded fixtures and exports a capacity estimate
+pub struct ParseBenchmark {
+    config: BenchConfig,
+    parsers: Vec<Box<dyn LaunchpadParser + Send + Sync>>,
+    fixtures: Vec<QueuedTransaction>,
+}
+
+impl ParseBenchmark {
+    pub fn new(config: BenchConfig) -> Self {
+        Self {
+            config,
+            parsers: vec![
+                Box::new(PumpfunParser::default()),
+                Box::new(MeteoraParser::default()),
+            ],
+            fixtures: fixtures(),
+        }
+    }
+
+    /// Parses fixtures in a loop for the configured duration. Logging is disabled
+    /// meanwhile, the parsers log every launch they find.
+    pub fn run(&self) -> BenchReport {
+        let duration = Duration::from_millis(self.config.duration_ms);
+        let (mut transactions, mut events) = (0u64, 0u64);
+
+        let start = Instant::now();
+        tracing::subscriber::with_default(tracing::subscriber::NoSubscriber::default(), || {
+            while start.elapsed() < duration {
+                for fixture in &self.fixtures {
+                    for parser in &self.parsers {
+                        let program_ids = parser.get_program_ids();
+                        if !fixture
+                            .instructions
+                            .iter()
+                            .chain(&fixture.inner_instructions)
+                            .any(|instr| program_ids.contains(&instr.program_id))
+                        {
+                            continue;
+                        }
+                        if let Ok(results) = parser.parse_transaction(fixture) {
+                            events += results
+                                .iter()
+                                .filter(|result| !matches!(result, ParseResult::NotRelevant))
+                                .count() as u64;
+                        }
+                    }
+                    transactions += 1;
+                }
+            }
+        });
+        let elapsed = start.elapsed().as_secs_f64();
+
+        BenchReport {
+            transactions_per_sec: transactions as f64 / elapsed,
+            events_per_sec: events as f64 / elapsed,
+        }
+    }
+
+    /// Runs the benchmark on startup and every interval in a separate task, comparing the
+    /// capacity with the transaction rate observed since the previous run
+    pub fn start(self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = interval(Duration::from_secs(self.config.interval_secs));
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            let mut previous: Option<(Instant, u64)> = None;
+            let benchmark = std::sync::Arc::new(self);
+
+            loop {
+                ticker.tick().await;
+
+                let bench = benchmark.clone();
+                let report = match tokio::task::spawn_blocking(move || bench.run()).await {
+                    Ok(report) => report,
+                    Err(e) => {
+                        warn!("Parse benchmark failed: {}", e);
+                        continue;
+                    }
+                };
+                metrics::global().set_gauge(
+                    "bench_transactions_per_second",
+                    &[],
+                    report.transactions_per_sec,
+                );
+                metrics::global().set_gauge("bench_events_per_second", &[], report.events_per_sec);
+
+                let queued = metrics::global().counter_value("queue_pushed_total", &[]);
+                let observed = previous.map(|(at, count)| {
+                    queued.saturating_sub(count) as f64 / at.elapsed().as_secs_f64()
+                });
+                previous = Some((Instant::now(), queued));
+
+                match observed {
+                    Some(observed)
+                        if observed > report.transactions_per_sec * HEADROOM_WARN_RATIO =>
+                    {
+                        warn!(
+                            "⚠️  Parse capacity {:.0} tx/s ({:.0} events/s), observed {:.0} tx/s, host may be undersized",
+                            report.transactions_per_sec, report.events_per_sec, observed
+                        );
+                    }
+                    Some(observed) => info!(
+                        "⏱️  Parse capacity {:.0} tx/s ({:.0} events/s), observed {:.0} tx/s",
+                        report.transactions_per_sec, report.events_per_sec, observed
+                    ),
+                    None => info!(
+                        "⏱️  Parse capacity {:.0} tx/s ({:.0} events/s)",
+                        report.transactions_per_sec, report.events_per_sec
+                    ),
+                }
+            }
+        })
+    }
+}
+
+fn borsh_string(data: &mut Vec<u8>, value: &str) {
+    data.extend((value.len() as u32).to_le_bytes());
+    data.extend(value.as_bytes());
+}
+
+fn pump_trade_event(is_buy: bool) -> Vec<u8> {
+    let mut data = EVENT_IX_TAG.to_vec();
+    data.extend([189, 219, 127, 211, 78, 230, 97, 238]);
+    data.extend([1; 32]); // mint
+    data.extend(500_000_000u64.to_le_bytes());
+    data.extend(17_000_000_000_000u64.to_le_bytes());
+    data.push(is_buy as u8);
+    data.extend([2; 32]); // user
+    data.extend(Utc::now().timestamp().to_le_bytes());
+    for reserves in [
+        30_000_000_000u64,
+        1_000_000_000_000_000,
+        500_000_000,
+        700_000_000_000_000,
+    ] {
+        data.extend(reserves.to_le_bytes());
+    }
+    data.extend([3; 32]); // fee recipient
+    data.extend(95u64.to_le_bytes());
+    data.extend(4_750_000u64.to_le_bytes());
+    data.extend([4; 32]); // creator
+    data.extend(5u64.to_le_bytes());
+    data.extend(250_000u64.to_le_bytes());
+    data
+}
+
+fn transaction(
+    accounts: usize,
+    instructions: Vec<TransactionInstruction>,
+    inner_instructions: Vec<TransactionInstruction>,
+) -> QueuedTransaction {
+    QueuedTransaction {
+        signature: "5".repeat(88),
+        slot: 1,
+        transaction_index: Some(0),
+        received_time: Utc::now(),
+        block_time: None,
+        backfilled: false,
+        is_vote: false,
+        version: TransactionVersion::Legacy,
+        accounts: (0..accounts).map(|i| format!("{:0>44}", i)).collect(),
+        fee_payer: Some(format!("{:0>44}", 0)),
+        signers: vec![format!("{:0>44}", 0)],
+        instructions,
+        inner_instructions,
+        raw: None,
+    }
+}
+
+fn instruction(program_id: &str, accounts: u8, data: Vec<u8>) -> TransactionInstruction {
+    TransactionInstruction {
+        program_id: program_id.to_string(),
+        accounts: (0..accounts).collect(),
+        data,
+        truncated: false,
+    }
+}
+
+/// Mix resembling mainnet traffic: mostly pump.fun trades, some launches
+fn fixtures() -> Vec<QueuedTransaction> {
+    let buy = transaction(
+        16,
+        vec![instruction(
+            PUMPFUN_PROGRAM_ID,
+            12,
+            vec![102, 6, 61, 18, 1, 218, 235, 234],
+        )],
+        vec![instruction(PUMPFUN_PROGRAM_ID, 1, pump_trade_event(true))],
+    );
+    let sell = transaction(
+        16,
+        vec![instruction(
+            PUMPFUN_PROGRAM_ID,
+            12,
+            vec![51, 230, 133, 164, 1, 127, 131, 173],
+        )],
+        vec![instruction(PUMPFUN_PROGRAM_ID, 1, pump_trade_event(false))],
+    );
+
+    let mut create = vec![24, 30, 200, 40, 5, 28, 7, 119];
+    borsh_string(&mut create, "Benchmark");
+    borsh_string(&mut create, "BENCH");
+    borsh_string(&mut create, "https://example.com/bench.json");
+    create.extend([4; 32]);
+    let pump_launch = transaction(
+        20,
+        vec![instruction(PUMPFUN_PROGRAM_ID, 14, create)],
+        vec![instruction(PUMPFUN_PROGRAM_ID, 1, pump_trade_event(true))],
+    );
+
+    let mut initialize = vec![140, 85, 215, 176, 102, 54, 104, 79];
+    borsh_string(&mut initialize, "Benchmark");
+    borsh_string(&mut initialize, "BENCH");
+    borsh_string(&mut initialize, "https://example.com/bench.json");
+    let meteora_launch = transaction(
+        18,
+        vec![instruction(METEORA_DBC_PROGRAM_ID, 16, initialize)],
+        Vec::new(),
+    );
+
+    let mut fixtures = Vec::new();
+    for _ in 0..8 {
+        fixtures.push(buy.clone());
+        fixtures.push(sell.clone());
+    }
+    fixtures.push(pump_launch);
+    fixtures.push(meteora_launch);
+    fixtures
+}
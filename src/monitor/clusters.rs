@@ -0,0 +1,247 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::debug;
+
+use crate::clock::{self, SharedClock};
+use crate::config::clusters::ClusterConfig;
+use crate::geyser::QueuedTransaction;
+use crate::metrics;
+use crate::parser::TokenLaunch;
+
+const SYSTEM_PROGRAM_ID: &str = "11111111111111111111111111111111";
+/// System program `Transfer` instruction index
+const SYSTEM_TRANSFER: [u8; 4] = [2, 0, 0, 0];
+/// Launches between sweeps of expired keys
+const PRUNE_EVERY: u64 = 256;
+
+/// Wallet, name, symbol or uri linking launches to a cluster
+struct ClusterKey {
+    cluster: String,
+    last_seen: Instant,
+}
+
+struct Cluster {
+    created: Instant,
+    last_seen: Instant,
+    launches: u64,
+}
+
+#[derive(Default)]
+struct ClusterState {
+    keys: HashMap<String, ClusterKey>,
+    clusters: HashMap<String, Cluster>,
+    /// Clusters merged into another one, by the id they were merged into
+    merged: HashMap<String, String>,
+    launches: u64,
+}
+
+impl ClusterState {
+    /// Id of the cluster `id` ended up in after merges
+    fn resolve(&self, id: &str) -> String {
+        let mut id = id;
+        while let Some(next) = self.merged.get(id) {
+            id = next;
+        }
+        id.to_string()
+    }
+
+    fn prune(&mut self, now: Instant, window: Duration, max_keys: usize) {
+        self.keys
+            .retain(|_, key| now.duration_since(key.last_seen) < window);
+        if self.keys.len() > max_keys {
+            let mut seen: Vec<Instant> = self.keys.values().map(|key| key.last_seen).collect();
+            seen.sort_unstable();
+            let cutoff = seen[self.keys.len() - max_keys];
+            self.keys.retain(|_, key| key.last_seen >= cutoff);
+        }
+
+        self.clusters
+            .retain(|_, cluster| now.duration_since(cluster.last_seen) < window);
+        let clusters = &self.clusters;
+        self.merged.retain(|_, into| clusters.contains_key(into));
+
+        metrics::global().set_gauge("launch_cluster_keys", &[], self.keys.len() as f64);
+    }
+}
+
+/// Groups launches sharing creator lineage or metadata into clusters, so consumers can
+/// mute a whole spam operation. Launches are linked through their creator, or a creator
+/// funded by an earlier one in its launch transaction, and optionally a repeated name,
+/// symbol or uri seen within the window. A cluster is identified by the mint of its first launch.
+#[derive(Clone)]
+pub struct LaunchClusterer {
+    config: ClusterConfig,
+    state: Arc<Mutex<ClusterState>>,
+    clock: SharedClock,
+}
+
+impl LaunchClusterer {
+    pub fn new(config: ClusterConfig) -> Self {
+        Self {
+            config,
+            state: Arc::new(Mutex::new(ClusterState::default())),
+            clock: clock::system(),
+        }
+    }
+
+    /// Replaces the clock the window is measured with
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Sets `cluster_id` on the launch, merging the clusters it links
+    pub fn assign(&self, launch: &mut TokenLaunch, transaction: &QueuedTransaction) {
+        let (keys, funded) = self.keys(launch, transaction);
+        let window = Duration::from_secs(self.config.window_secs);
+        let now = self.clock.instant();
+        let mut state = self.state.lock().unwrap();
+
+        let mut linked: Vec<String> = Vec::new();
+        for key in &keys {
+            if let Some(entry) = state.keys.get(key)
+                && now.duration_since(entry.last_seen) < window
+            {
+                let cluster = state.resolve(&entry.cluster);
+                if state.clusters.contains_key(&cluster) && !linked.contains(&cluster) {
+                    linked.push(cluster);
+                }
+            }
+        }
+
+        // The oldest cluster absorbs the others, so ids stay stable
+        linked.sort_by_key(|id| state.clusters[id].created);
+        let id = match linked.split_first() {
+            Some((oldest, others)) => {
+                for other in others {
+                    if let Some(absorbed) = state.clusters.remove(other) {
+                        let cluster = state.clusters.get_mut(oldest).unwrap();
+                        cluster.launches += absorbed.launches;
+                        cluster.created = cluster.created.min(absorbed.created);
+                    }
+                    state.merged.insert(other.clone(), oldest.clone());
+                    debug!("🧬 Merged launch cluster {} into {}", other, oldest);
+                    metrics::global().inc_counter("launch_clusters_merged_total", &[]);
+                }
+                metrics::global().inc_counter("launch_clusters_joined_total", &[]);
+                oldest.clone()
+            }
+            None => launch.token_address.clone(),
+        };
+
+        let cluster = state.clusters.entry(id.clone()).or_insert(Cluster {
+            created: now,
+            last_seen: now,
+            launches: 0,
+        });
+        cluster.launches += 1;
+        cluster.last_seen = now;
+        if cluster.launches > 1 {
+            debug!(
+                "🧬 {} joins cluster {} ({} launches)",
+                launch.token_address, id, cluster.launches
+            );
+        }
+
+        for key in keys.into_iter().chain(funded) {
+            state.keys.insert(
+                key,
+                ClusterKey {
+                    cluster: id.clone(),
+                    last_seen: now,
+                },
+            );
+        }
+
+        state.launches += 1;
+        if state.launches.is_multiple_of(PRUNE_EVERY) || state.keys.len() > self.config.max_keys {
+            state.prune(now, window, self.config.max_keys);
+        }
+
+        launch.cluster_id = Some(id);
+    }
+
+    /// Keys linking the launch to earlier ones, and wallets it funds, which only link
+    /// later launches: fee and tip accounts are paid by everyone and would join unrelated
+    /// launches
+    fn keys(
+        &self,
+        launch: &TokenLaunch,
+        transaction: &QueuedTransaction,
+    ) -> (Vec<String>, Vec<String>) {
+        // The creator rather than every signer, a relayer paying for many launches would join them all
+        let Some(creator) = launch.creator.as_ref().or(transaction.fee_payer.as_ref()) else {
+            return (self.metadata_keys(launch), Vec::new());
+        };
+
+        let mut funded = Vec::new();
+        for instr in transaction
+            .instructions
+            .iter()
+            .chain(&transaction.inner_instructions)
+        {
+            if instr.program_id != SYSTEM_PROGRAM_ID || !instr.data.starts_with(&SYSTEM_TRANSFER) {
+                continue;
+            }
+            let account = |index: usize| {
+                instr
+                    .accounts
+                    .get(index)
+                    .and_then(|idx| transaction.accounts.get(*idx as usize))
+            };
+            if let (Some(from), Some(to)) = (account(0), account(1))
+                && from == creator
+                && to != creator
+            {
+                funded.push(format!("wallet:{}", to));
+            }
+        }
+        funded.sort();
+        funded.dedup();
+
+        let mut keys = self.metadata_keys(launch);
+        keys.push(format!("wallet:{}", creator));
+        (keys, funded)
+    }
+
+    fn metadata_keys(&self, launch: &TokenLaunch) -> Vec<String> {
+        let mut keys = Vec::new();
+        if !self.config.match_metadata {
+            return keys;
+        }
+
+        let metadata = &launch.metadata;
+        let normalized = |value: &String| -> Option<String> {
+            let value: String = value
+                .chars()
+                .filter(|c| c.is_alphanumeric())
+                .flat_map(char::to_lowercase)
+                .collect();
+            (value.chars().count() >= 2).then_some(value)
+        };
+        keys.extend(
+            metadata
+                .name
+                .as_ref()
+                .and_then(normalized)
+                .map(|name| format!("name:{}", name)),
+        );
+        keys.extend(
+            metadata
+                .symbol
+                .as_ref()
+                .and_then(normalized)
+                .map(|symbol| format!("symbol:{}", symbol)),
+        );
+        keys.extend(
+            metadata
+                .uri
+                .as_ref()
+                .map(|uri| uri.trim())
+                .filter(|uri| !uri.is_empty())
+                .map(|uri| format!("uri:{}", uri)),
+        );
+        keys
+    }
+}
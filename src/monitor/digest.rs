@@ -0,0 +1,200 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use tokio::task::JoinHandle;
+use tokio::time::{Duration, MissedTickBehavior, interval};
+use tracing::{info, warn};
+
+use crate::clock::{self, SharedClock};
+use crate::config::digest::DigestConfig;
+use crate::monitor::{OpsError, OpsErrorKind, ops};
+use crate::parser::{LaunchpadType, TokenLaunch};
+use crate::sink::EventSink;
+
+/// Periodic summary of launch activity for consumers that don't need every event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Digest {
+    pub interval_start: DateTime<Utc>,
+    pub interval_end: DateTime<Utc>,
+    pub total_launches: u64,
+    pub launches_per_launchpad: BTreeMap<String, u64>,
+    /// Tokens with the highest traded volume in the interval
+    pub top_launches: Vec<DigestEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DigestEntry {
+    pub launchpad: LaunchpadType,
+    pub token_address: String,
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    /// Launch signature, when the launch itself happened in the interval
+    pub signature: Option<String>,
+    pub trades: u64,
+    pub volume: u64,
+}
+
+/// Activity collected since the last digest
+struct DigestWindow {
+    start: DateTime<Utc>,
+    launches_per_launchpad: BTreeMap<String, u64>,
+    tokens: HashMap<String, DigestEntry>,
+}
+
+impl DigestWindow {
+    fn new(start: DateTime<Utc>) -> Self {
+        Self {
+            start,
+            launches_per_launchpad: BTreeMap::new(),
+            tokens: HashMap::new(),
+        }
+    }
+
+    fn entry(&mut self, launchpad: &LaunchpadType, token_address: &str) -> &mut DigestEntry {
+        self.tokens
+            .entry(token_address.to_string())
+            .or_insert_with(|| DigestEntry {
+                launchpad: launchpad.clone(),
+                token_address: token_address.to_string(),
+                name: None,
+                symbol: None,
+                signature: None,
+                trades: 0,
+                volume: 0,
+            })
+    }
+
+    fn into_digest(self, top_n: usize, end: DateTime<Utc>) -> Digest {
+        let mut top_launches: Vec<DigestEntry> = self.tokens.into_values().collect();
+        top_launches.sort_by(|a, b| {
+            b.volume
+                .cmp(&a.volume)
+                .then(b.trades.cmp(&a.trades))
+                .then_with(|| a.token_address.cmp(&b.token_address))
+        });
+        top_launches.truncate(top_n);
+
+        Digest {
+            interval_start: self.start,
+            interval_end: end,
+            total_launches: self.launches_per_launchpad.values().sum(),
+            launches_per_launchpad: self.launches_per_launchpad,
+            top_launches,
+        }
+    }
+}
+
+/// Aggregates launches and trades into a `Digest` published every interval
+#[derive(Clone)]
+pub struct Digester {
+    config: DigestConfig,
+    window: Arc<Mutex<DigestWindow>>,
+    clock: SharedClock,
+}
+
+impl Digester {
+    pub fn new(config: DigestConfig) -> Self {
+        let clock = clock::system();
+        Self {
+            config,
+            window: Arc::new(Mutex::new(DigestWindow::new(clock.now()))),
+            clock,
+        }
+    }
+
+    /// Replaces the clock the intervals are stamped with, restarting the current interval
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.window = Arc::new(Mutex::new(DigestWindow::new(clock.now())));
+        self.clock = clock;
+        self
+    }
+
+    /// Ends the current interval and returns its digest
+    fn take_digest(&self) -> Digest {
+        let now = self.clock.now();
+        let window = std::mem::replace(&mut *self.window.lock().unwrap(), DigestWindow::new(now));
+        window.into_digest(self.config.top_n, now)
+    }
+
+    pub fn record_launch(&self, launch: &TokenLaunch) {
+        let mut window = self.window.lock().unwrap();
+        *window
+            .launches_per_launchpad
+            .entry(launch.launchpad.to_string())
+            .or_default() += 1;
+
+        let entry = window.entry(&launch.launchpad, &launch.token_address);
+        entry.name = launch.metadata.name.clone();
+        entry.symbol = launch.metadata.symbol.clone();
+        entry.signature = Some(launch.signature.clone());
+    }
+
+    pub fn record_trade(&self, launchpad: &LaunchpadType, token_address: &str, amount: u64) {
+        let mut window = self.window.lock().unwrap();
+        let entry = window.entry(launchpad, token_address);
+        entry.trades += 1;
+        entry.volume = entry.volume.saturating_add(amount);
+    }
+
+    /// Publishes a digest through `sink` every interval in a separate task
+    pub fn start(self, sink: Arc<dyn EventSink>) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            info!(
+                "🧾 Publishing launch digests every {}s (top {})",
+                self.config.interval_secs, self.config.top_n
+            );
+
+            let mut ticker = interval(Duration::from_secs(self.config.interval_secs));
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            // First tick completes immediately
+            ticker.tick().await;
+
+            loop {
+                ticker.tick().await;
+
+                let digest = self.take_digest();
+
+                if let Err(e) = sink.publish_digest(&digest).await {
+                    warn!("Failed to publish digest: {}", e);
+                    ops::report(OpsError::new(OpsErrorKind::SinkFailure, "digest", &e));
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::{Clock, MockClock};
+    use crate::testing::{launch, start_time};
+
+    #[test]
+    fn intervals_follow_the_clock() {
+        let clock = MockClock::new(start_time());
+        let digester = Digester::new(DigestConfig {
+            enabled: true,
+            interval_secs: 60,
+            top_n: 5,
+        })
+        .with_clock(clock.shared());
+        digester.record_launch(&launch("a", 1, clock.now()));
+        digester.record_trade(&LaunchpadType::Pumpfun, "mint-a", 1_000);
+
+        clock.advance(Duration::from_secs(60));
+        let digest = digester.take_digest();
+        assert_eq!(digest.interval_start, start_time());
+        assert_eq!(digest.interval_end, clock.now());
+        assert_eq!(digest.total_launches, 1);
+        assert_eq!(digest.top_launches[0].volume, 1_000);
+
+        clock.advance(Duration::from_secs(60));
+        let digest = digester.take_digest();
+        assert_eq!(
+            digest.interval_start,
+            start_time() + chrono::Duration::seconds(60)
+        );
+        assert_eq!(digest.total_launches, 0);
+    }
+}
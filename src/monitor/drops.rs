@@ -0,0 +1,184 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::{Mutex, OnceLock};
+
+use crate::clock::SharedClock;
+use crate::metrics;
+
+/// Why a transaction was not turned into an event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DropReason {
+    /// Simple vote transaction
+    Vote,
+    /// Failed on chain, dropped by the `failed` pre-filter
+    FailedTransaction,
+    /// Dropped by another pre-filter, named in the detail
+    PreFilter,
+    /// Already queued by an earlier stream of the filter plan
+    Duplicate,
+    /// Delivered without instructions and not refetched
+    NoInstructions,
+    /// Over the queue's instruction data or memory limit on its own, the limit in the detail
+    Oversized,
+    /// Evicted from a full queue to make room, the limit reached in the detail
+    QueueFull,
+    /// None of its programs has a registered parser
+    NoMatchingParser,
+    /// Parsers ran but found no instruction they know
+    DiscriminatorMismatch,
+    /// Parsers only found instructions that are not published, e.g. sells on a curve
+    IgnoredInstruction,
+    /// A parser failed
+    ExtractionFailure,
+    /// Left out by configuration, e.g. a skipped partner or a dev buy below the minimum
+    Policy,
+}
+
+impl DropReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Vote => "vote",
+            Self::FailedTransaction => "failed_transaction",
+            Self::PreFilter => "pre_filter",
+            Self::Duplicate => "duplicate",
+            Self::NoInstructions => "no_instructions",
+            Self::Oversized => "oversized",
+            Self::QueueFull => "queue_full",
+            Self::NoMatchingParser => "no_matching_parser",
+            Self::DiscriminatorMismatch => "discriminator_mismatch",
+            Self::IgnoredInstruction => "ignored_instruction",
+            Self::ExtractionFailure => "extraction_failure",
+            Self::Policy => "policy",
+        }
+    }
+
+    /// Reason for a transaction the named pre-filter dropped
+    pub fn from_pre_filter(filter: &str) -> Self {
+        match filter {
+            "failed" => Self::FailedTransaction,
+            "earlier_stream" => Self::Duplicate,
+            "discriminators" => Self::DiscriminatorMismatch,
+            _ => Self::PreFilter,
+        }
+    }
+}
+
+/// Transaction that produced no event, kept for `/debug/drops`
+#[derive(Debug, Clone, Serialize)]
+pub struct DroppedTransaction {
+    pub signature: String,
+    pub slot: u64,
+    pub reason: DropReason,
+    pub launchpad: Option<String>,
+    pub detail: Option<String>,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl DroppedTransaction {
+    pub fn new(reason: DropReason, signature: &str, slot: u64) -> Self {
+        Self {
+            signature: signature.to_string(),
+            slot,
+            reason,
+            launchpad: None,
+            detail: None,
+            timestamp: Utc::now(),
+        }
+    }
+
+    pub fn with_launchpad(mut self, launchpad: impl ToString) -> Self {
+        self.launchpad = Some(launchpad.to_string());
+        self
+    }
+
+    pub fn with_detail(mut self, detail: impl ToString) -> Self {
+        self.detail = Some(detail.to_string());
+        self
+    }
+}
+
+/// Counts of drop reasons since startup and the most recent drops
+#[derive(Default)]
+struct DropLog {
+    capacity: usize,
+    counts: BTreeMap<DropReason, u64>,
+    recent: VecDeque<DroppedTransaction>,
+    /// Stamps kept drops, the system clock when unset
+    clock: Option<SharedClock>,
+}
+
+fn log() -> &'static Mutex<DropLog> {
+    static LOG: OnceLock<Mutex<DropLog>> = OnceLock::new();
+    LOG.get_or_init(Mutex::default)
+}
+
+/// Keeps the last `capacity` drops for lookup, 0 only counts them
+pub fn set_capacity(capacity: usize) {
+    let mut log = log().lock().unwrap();
+    log.capacity = capacity;
+    while log.recent.len() > capacity {
+        log.recent.pop_front();
+    }
+}
+
+/// Stamps kept drops with `clock` instead of the system clock
+pub fn set_clock(clock: SharedClock) {
+    log().lock().unwrap().clock = Some(clock);
+}
+
+/// Whether drops are kept, so callers can skip building them otherwise
+pub fn enabled() -> bool {
+    log().lock().unwrap().capacity > 0
+}
+
+/// Counts a drop (`transactions_dropped_total{reason}`) and keeps it when enabled
+pub fn record(dropped: DroppedTransaction) {
+    let reason = dropped.reason;
+    record_with(reason, || dropped);
+}
+
+/// Like `record`, building the entry only when drops are kept. For hot paths where the
+/// signature is not encoded yet.
+pub fn record_with(reason: DropReason, dropped: impl FnOnce() -> DroppedTransaction) {
+    metrics::global().inc_counter("transactions_dropped_total", &[("reason", reason.as_str())]);
+
+    let mut log = log().lock().unwrap();
+    *log.counts.entry(reason).or_default() += 1;
+    if log.capacity == 0 {
+        return;
+    }
+    if log.recent.len() >= log.capacity {
+        log.recent.pop_front();
+    }
+    let mut dropped = dropped();
+    if let Some(clock) = &log.clock {
+        dropped.timestamp = clock.now();
+    }
+    log.recent.push_back(dropped);
+}
+
+/// Drop counts by reason since startup
+pub fn counts() -> BTreeMap<DropReason, u64> {
+    log().lock().unwrap().counts.clone()
+}
+
+/// Kept drops, newest first, optionally of one signature or reason
+pub fn recent(
+    signature: Option<&str>,
+    reason: Option<&str>,
+    limit: usize,
+) -> Vec<DroppedTransaction> {
+    log()
+        .lock()
+        .unwrap()
+        .recent
+        .iter()
+        .rev()
+        .filter(|dropped| signature.is_none_or(|signature| dropped.signature == signature))
+        .filter(|dropped| reason.is_none_or(|reason| dropped.reason.as_str() == reason))
+        .take(limit)
+        .cloned()
+        .collect()
+}
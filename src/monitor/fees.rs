@@ -0,0 +1,138 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::clock::{self, SharedClock};
+use crate::metrics;
+use crate::parser::{LaunchpadType, TradeFees};
+
+/// Tokens without trades for this long are forgotten
+const IDLE_RETENTION_HOURS: i64 = 24;
+/// Idle tokens are pruned every this many recorded trades
+const PRUNE_EVERY: u64 = 10_000;
+
+/// Cumulative fees paid on a token's trades since it was first seen
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenFees {
+    pub launchpad: LaunchpadType,
+    pub token_address: String,
+    pub creator: Option<String>,
+    pub trades: u64,
+    pub protocol_fees: u64,
+    pub creator_fees: u64,
+    pub last_trade: DateTime<Utc>,
+}
+
+#[derive(Default)]
+struct FeeState {
+    tokens: HashMap<String, TokenFees>,
+    recorded: u64,
+}
+
+/// Tracks protocol and creator fee earnings per token
+#[derive(Clone)]
+pub struct FeeTracker {
+    state: Arc<Mutex<FeeState>>,
+    clock: SharedClock,
+}
+
+impl Default for FeeTracker {
+    fn default() -> Self {
+        Self {
+            state: Arc::default(),
+            clock: clock::system(),
+        }
+    }
+}
+
+impl FeeTracker {
+    /// Replaces the clock idle tokens are pruned with
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub fn record(
+        &self,
+        launchpad: &LaunchpadType,
+        token_address: &str,
+        fees: &TradeFees,
+        timestamp: DateTime<Utc>,
+    ) {
+        let launchpad_label = launchpad.to_string();
+        metrics::global().add_counter(
+            "protocol_fees_lamports_total",
+            &[("launchpad", &launchpad_label)],
+            fees.protocol_fee,
+        );
+        metrics::global().add_counter(
+            "creator_fees_lamports_total",
+            &[("launchpad", &launchpad_label)],
+            fees.creator_fee,
+        );
+
+        let mut state = self.state.lock().unwrap();
+        state.recorded += 1;
+        if state.recorded.is_multiple_of(PRUNE_EVERY) {
+            let cutoff = self.clock.now() - Duration::hours(IDLE_RETENTION_HOURS);
+            state.tokens.retain(|_, token| token.last_trade >= cutoff);
+        }
+
+        let token = state
+            .tokens
+            .entry(token_address.to_string())
+            .or_insert_with(|| TokenFees {
+                launchpad: launchpad.clone(),
+                token_address: token_address.to_string(),
+                creator: None,
+                trades: 0,
+                protocol_fees: 0,
+                creator_fees: 0,
+                last_trade: timestamp,
+            });
+        token.trades += 1;
+        token.protocol_fees = token.protocol_fees.saturating_add(fees.protocol_fee);
+        token.creator_fees = token.creator_fees.saturating_add(fees.creator_fee);
+        token.last_trade = timestamp;
+        if fees.creator.is_some() {
+            token.creator = fees.creator.clone();
+        }
+    }
+
+    pub fn get(&self, token_address: &str) -> Option<TokenFees> {
+        self.state
+            .lock()
+            .unwrap()
+            .tokens
+            .get(token_address)
+            .cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::{Clock, MockClock};
+    use crate::testing::start_time;
+
+    #[test]
+    fn idle_tokens_are_pruned() {
+        let clock = MockClock::new(start_time());
+        let tracker = FeeTracker::default().with_clock(clock.shared());
+        let fees = TradeFees {
+            fee_recipient: "recipient".to_string(),
+            protocol_fee: 10,
+            creator: None,
+            creator_fee: 5,
+        };
+        tracker.record(&LaunchpadType::Pumpfun, "idle", &fees, clock.now());
+
+        clock.advance(std::time::Duration::from_secs(25 * 3600));
+        for _ in 1..PRUNE_EVERY {
+            tracker.record(&LaunchpadType::Pumpfun, "busy", &fees, clock.now());
+        }
+        assert!(tracker.get("idle").is_none());
+        assert_eq!(tracker.get("busy").unwrap().trades, PRUNE_EVERY - 1);
+    }
+}
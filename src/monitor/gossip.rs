@@ -0,0 +1,307 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+use tokio::time::{MissedTickBehavior, interval};
+use tracing::debug;
+
+use crate::clock::{self, SharedClock};
+use crate::config::gossip::GossipConfig;
+use crate::metrics;
+use crate::monitor::latency::percentile;
+use crate::parser::TokenLaunch;
+
+/// How often the relative latency gauges are refreshed
+const CHECK_INTERVAL: Duration = Duration::from_secs(10);
+/// Comparisons kept per peer, the oldest are dropped first
+const MAX_SAMPLES: usize = 10_000;
+
+/// "I saw signature X at T", as exchanged between instances
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Sighting {
+    pub signature: String,
+    pub seen_at: DateTime<Utc>,
+}
+
+/// Sightings of one instance since its last flush
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GossipBatch {
+    /// `region/provider` of the instance
+    pub source: String,
+    pub sightings: Vec<Sighting>,
+}
+
+/// Detection latency of this instance relative to a peer over the rolling window.
+/// Positive leads mean this instance saw the launch first.
+#[derive(Debug, Clone, Serialize)]
+pub struct PeerReport {
+    /// Launches both instances saw
+    pub samples: usize,
+    /// Launches this instance saw first
+    pub local_first: usize,
+    pub peer_first: usize,
+    pub p50_lead_ms: f64,
+    pub p95_lead_ms: f64,
+    pub mean_lead_ms: f64,
+}
+
+/// Times a signature was seen, until it leaves the window
+struct SignatureSightings {
+    added: Instant,
+    local: Option<DateTime<Utc>>,
+    peers: HashMap<String, DateTime<Utc>>,
+}
+
+#[derive(Default)]
+struct GossipState {
+    sightings: HashMap<String, SignatureSightings>,
+    /// Local sightings not published yet
+    pending: Vec<Sighting>,
+    /// Comparison time and lead in milliseconds per peer
+    leads: HashMap<String, VecDeque<(Instant, f64)>>,
+}
+
+/// Compares when this instance and others running elsewhere, or on another Geyser
+/// vendor, saw the same launches. Each instance publishes its sightings and reads the
+/// others'; a launch seen by both yields the difference of their receive times. Receive
+/// times come from each host's clock, so hosts need synchronized clocks.
+#[derive(Clone)]
+pub struct LatencyGossip {
+    config: GossipConfig,
+    source: String,
+    state: Arc<Mutex<GossipState>>,
+    clock: SharedClock,
+}
+
+impl LatencyGossip {
+    pub fn new(config: GossipConfig) -> Self {
+        Self {
+            source: config.source(),
+            config,
+            state: Arc::new(Mutex::new(GossipState::default())),
+            clock: clock::system(),
+        }
+    }
+
+    /// Replaces the clock the rolling window is measured with
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub fn config(&self) -> &GossipConfig {
+        &self.config
+    }
+
+    /// `region/provider` of this instance
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Records a launch this instance detected, to be published on the next flush.
+    /// Backfilled launches are skipped.
+    pub fn observe(&self, launch: &TokenLaunch) {
+        if launch.backfilled {
+            return;
+        }
+        let now = self.clock.instant();
+        let mut state = self.state.lock().unwrap();
+        state.pending.push(Sighting {
+            signature: launch.signature.clone(),
+            seen_at: launch.timestamp,
+        });
+
+        let entry = state
+            .sightings
+            .entry(launch.signature.clone())
+            .or_insert_with(|| SignatureSightings::new(now));
+        if entry.local.is_some() {
+            return;
+        }
+        entry.local = Some(launch.timestamp);
+        let leads: Vec<(String, f64)> = entry
+            .peers
+            .iter()
+            .map(|(peer, seen_at)| (peer.clone(), lead_ms(launch.timestamp, *seen_at)))
+            .collect();
+        for (peer, lead) in leads {
+            state.record_lead(peer, lead, now);
+        }
+    }
+
+    /// Local sightings since the last call, `None` when there are none
+    pub fn take_pending(&self) -> Option<GossipBatch> {
+        let sightings = std::mem::take(&mut self.state.lock().unwrap().pending);
+        (!sightings.is_empty()).then(|| GossipBatch {
+            source: self.source.clone(),
+            sightings,
+        })
+    }
+
+    /// Merges the sightings of another instance, this instance's own are ignored
+    pub fn receive(&self, batch: GossipBatch) {
+        if batch.source == self.source {
+            return;
+        }
+        debug!(
+            "Received {} sightings from {}",
+            batch.sightings.len(),
+            batch.source
+        );
+        let now = self.clock.instant();
+        let mut state = self.state.lock().unwrap();
+        for sighting in batch.sightings {
+            let entry = state
+                .sightings
+                .entry(sighting.signature)
+                .or_insert_with(|| SignatureSightings::new(now));
+            if entry.peers.contains_key(&batch.source) {
+                continue;
+            }
+            entry.peers.insert(batch.source.clone(), sighting.seen_at);
+            if let Some(local) = entry.local {
+                state.record_lead(batch.source.clone(), lead_ms(local, sighting.seen_at), now);
+            }
+        }
+    }
+
+    /// Relative latency per peer over the window
+    pub fn report(&self) -> BTreeMap<String, PeerReport> {
+        let window = Duration::from_secs(self.config.window_secs);
+        let now = self.clock.instant();
+        let mut state = self.state.lock().unwrap();
+        state
+            .sightings
+            .retain(|_, sightings| now.duration_since(sightings.added) <= window);
+
+        state
+            .leads
+            .iter_mut()
+            .filter_map(|(peer, leads)| {
+                while leads
+                    .front()
+                    .is_some_and(|(at, _)| now.duration_since(*at) > window)
+                {
+                    leads.pop_front();
+                }
+                if leads.is_empty() {
+                    return None;
+                }
+
+                let mut sorted: Vec<f64> = leads.iter().map(|(_, lead)| *lead).collect();
+                sorted.sort_by(f64::total_cmp);
+                let report = PeerReport {
+                    samples: sorted.len(),
+                    local_first: sorted.iter().filter(|lead| **lead > 0.0).count(),
+                    peer_first: sorted.iter().filter(|lead| **lead < 0.0).count(),
+                    p50_lead_ms: percentile(&sorted, 50.0),
+                    p95_lead_ms: percentile(&sorted, 95.0),
+                    mean_lead_ms: sorted.iter().sum::<f64>() / sorted.len() as f64,
+                };
+                Some((peer.clone(), report))
+            })
+            .collect()
+    }
+
+    /// Refreshes the relative latency gauges and prunes old sightings in a separate task
+    pub fn start(self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = interval(CHECK_INTERVAL);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                for (peer, report) in self.report() {
+                    let labels = [("peer", peer.as_str())];
+                    metrics::global().set_gauge(
+                        "gossip_relative_latency_p50_ms",
+                        &labels,
+                        report.p50_lead_ms,
+                    );
+                    metrics::global().set_gauge(
+                        "gossip_local_first_ratio",
+                        &labels,
+                        report.local_first as f64 / report.samples as f64,
+                    );
+                }
+            }
+        })
+    }
+}
+
+impl SignatureSightings {
+    fn new(added: Instant) -> Self {
+        Self {
+            added,
+            local: None,
+            peers: HashMap::new(),
+        }
+    }
+}
+
+impl GossipState {
+    fn record_lead(&mut self, peer: String, lead: f64, now: Instant) {
+        metrics::global().inc_counter("gossip_comparisons_total", &[("peer", &peer)]);
+        let leads = self.leads.entry(peer).or_default();
+        leads.push_back((now, lead));
+        if leads.len() > MAX_SAMPLES {
+            leads.pop_front();
+        }
+    }
+}
+
+/// How long before the peer this instance saw a launch, negative when the peer was first
+fn lead_ms(local: DateTime<Utc>, peer: DateTime<Utc>) -> f64 {
+    (peer - local).num_microseconds().unwrap_or(0) as f64 / 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::{Clock, MockClock};
+    use crate::testing::{launch, start_time};
+
+    fn gossip(clock: &MockClock) -> LatencyGossip {
+        LatencyGossip::new(GossipConfig {
+            enabled: true,
+            region: "fra".to_string(),
+            provider: "a".to_string(),
+            routing_key: "gossip".to_string(),
+            flush_interval_ms: 1000,
+            window_secs: 60,
+        })
+        .with_clock(clock.shared())
+    }
+
+    fn peer_batch(signature: &str, seen_at: DateTime<Utc>) -> GossipBatch {
+        GossipBatch {
+            source: "ams/b".to_string(),
+            sightings: vec![Sighting {
+                signature: signature.to_string(),
+                seen_at,
+            }],
+        }
+    }
+
+    #[test]
+    fn sightings_expire_after_window() {
+        let clock = MockClock::new(start_time());
+        let gossip = gossip(&clock);
+        gossip.observe(&launch("a", 1, clock.now()));
+        gossip.receive(peer_batch(
+            "a",
+            clock.now() + chrono::Duration::milliseconds(40),
+        ));
+
+        let report = gossip.report();
+        assert_eq!(report["ams/b"].samples, 1);
+        assert_eq!(report["ams/b"].local_first, 1);
+
+        // The local sighting is forgotten, a late peer sighting is not compared with it
+        clock.advance(Duration::from_secs(61));
+        assert!(gossip.report().is_empty());
+        gossip.receive(peer_batch("a", clock.now()));
+        assert!(gossip.report().is_empty());
+    }
+}
@@ -0,0 +1,225 @@
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+use tokio::time::{MissedTickBehavior, interval};
+use tracing::{info, warn};
+
+use crate::clock::{self, SharedClock};
+use crate::config::latency::LatencyConfig;
+use crate::metrics;
+use crate::monitor::{OpsError, OpsErrorKind, OpsSeverity, ops};
+use crate::parser::TokenLaunch;
+
+/// How often percentiles are refreshed and SLOs evaluated
+const CHECK_INTERVAL: Duration = Duration::from_secs(10);
+/// Latencies kept per launchpad, the oldest are dropped first
+const MAX_SAMPLES: usize = 10_000;
+
+/// Detection latency percentiles of a launchpad over the rolling window
+#[derive(Debug, Clone, Serialize)]
+pub struct LatencyReport {
+    pub samples: usize,
+    pub p50_ms: f64,
+    pub p95_ms: f64,
+    pub p99_ms: f64,
+    /// Latency of the SLO percentile, compared against `slo_ms`
+    pub slo_value_ms: f64,
+    pub violated: bool,
+}
+
+#[derive(Default)]
+struct LatencyState {
+    /// Publish time and latency in milliseconds per launchpad
+    samples: HashMap<String, VecDeque<(Instant, f64)>>,
+    violated: HashMap<String, bool>,
+}
+
+/// Tracks the time from a launch's block to its publishing, per launchpad,
+/// and alerts when the configured percentile exceeds the SLO
+#[derive(Clone)]
+pub struct LatencyTracker {
+    config: LatencyConfig,
+    state: Arc<Mutex<LatencyState>>,
+    clock: SharedClock,
+}
+
+impl LatencyTracker {
+    pub fn new(config: LatencyConfig) -> Self {
+        Self {
+            config,
+            state: Arc::new(Mutex::new(LatencyState::default())),
+            clock: clock::system(),
+        }
+    }
+
+    /// Replaces the clock latencies and the rolling window are measured with
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub fn config(&self) -> &LatencyConfig {
+        &self.config
+    }
+
+    /// Records a launch being published. Backfilled launches and launches without a
+    /// block time are skipped.
+    pub fn record(&self, launch: &TokenLaunch) {
+        let Some(block_time) = launch.block_time else {
+            return;
+        };
+        if launch.backfilled {
+            return;
+        }
+
+        let latency_ms = (self.clock.now() - block_time).num_milliseconds().max(0) as f64;
+        let mut state = self.state.lock().unwrap();
+        let samples = state
+            .samples
+            .entry(launch.launchpad.to_string())
+            .or_default();
+        samples.push_back((self.clock.instant(), latency_ms));
+        if samples.len() > MAX_SAMPLES {
+            samples.pop_front();
+        }
+    }
+
+    /// Percentiles per launchpad over the window
+    pub fn report(&self) -> BTreeMap<String, LatencyReport> {
+        let window = Duration::from_secs(self.config.window_secs);
+        let now = self.clock.instant();
+        let mut state = self.state.lock().unwrap();
+        let LatencyState { samples, violated } = &mut *state;
+
+        samples
+            .iter_mut()
+            .filter_map(|(launchpad, samples)| {
+                while samples
+                    .front()
+                    .is_some_and(|(at, _)| now.duration_since(*at) > window)
+                {
+                    samples.pop_front();
+                }
+                if samples.is_empty() {
+                    return None;
+                }
+
+                let mut sorted: Vec<f64> = samples.iter().map(|(_, latency)| *latency).collect();
+                sorted.sort_by(f64::total_cmp);
+                let report = LatencyReport {
+                    samples: sorted.len(),
+                    p50_ms: percentile(&sorted, 50.0),
+                    p95_ms: percentile(&sorted, 95.0),
+                    p99_ms: percentile(&sorted, 99.0),
+                    slo_value_ms: percentile(&sorted, self.config.slo_percentile as f64),
+                    violated: violated.get(launchpad).copied().unwrap_or(false),
+                };
+                Some((launchpad.clone(), report))
+            })
+            .collect()
+    }
+
+    /// Refreshes the latency gauges and evaluates the SLO in a separate task
+    pub fn start(self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = interval(CHECK_INTERVAL);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                self.check();
+            }
+        })
+    }
+
+    fn check(&self) {
+        for (launchpad, report) in self.report() {
+            let labels = |quantile| [("launchpad", launchpad.as_str()), ("quantile", quantile)];
+            metrics::global().set_gauge("detection_latency_ms", &labels("0.5"), report.p50_ms);
+            metrics::global().set_gauge("detection_latency_ms", &labels("0.95"), report.p95_ms);
+            metrics::global().set_gauge("detection_latency_ms", &labels("0.99"), report.p99_ms);
+
+            if self.config.slo_ms == 0 || report.samples < self.config.min_samples {
+                continue;
+            }
+            let violated = report.slo_value_ms > self.config.slo_ms as f64;
+            metrics::global().set_gauge(
+                "latency_slo_violated",
+                &[("launchpad", &launchpad)],
+                if violated { 1.0 } else { 0.0 },
+            );
+
+            let was_violated = self
+                .state
+                .lock()
+                .unwrap()
+                .violated
+                .insert(launchpad.clone(), violated)
+                .unwrap_or(false);
+            if violated == was_violated {
+                continue;
+            }
+
+            let message = format!(
+                "{} p{} detection latency {:.0} ms over {} launches, SLO {} ms",
+                launchpad,
+                self.config.slo_percentile,
+                report.slo_value_ms,
+                report.samples,
+                self.config.slo_ms
+            );
+            if violated {
+                warn!("🐢 {}", message);
+                metrics::global()
+                    .inc_counter("latency_slo_violations_total", &[("launchpad", &launchpad)]);
+                ops::report(OpsError::new(
+                    OpsErrorKind::LatencySloViolated,
+                    "latency",
+                    message,
+                ));
+            } else {
+                info!("Latency back within SLO: {}", message);
+                ops::report(
+                    OpsError::new(OpsErrorKind::LatencySloRecovered, "latency", message)
+                        .with_severity(OpsSeverity::Info),
+                );
+            }
+        }
+    }
+}
+
+/// Nearest-rank percentile of sorted values
+pub(crate) fn percentile(sorted: &[f64], percentile: f64) -> f64 {
+    let rank = (percentile / 100.0 * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::{Clock, MockClock};
+    use crate::testing::{launch, start_time};
+
+    #[test]
+    fn samples_expire_after_window() {
+        let clock = MockClock::new(start_time());
+        let tracker = LatencyTracker::new(LatencyConfig {
+            window_secs: 60,
+            slo_ms: 0,
+            slo_percentile: 95,
+            min_samples: 1,
+        })
+        .with_clock(clock.shared());
+        let mut detected = launch("a", 1, clock.now());
+        detected.block_time = Some(clock.now() - chrono::Duration::milliseconds(500));
+        tracker.record(&detected);
+
+        let report = tracker.report();
+        assert_eq!(report["Pumpfun"].samples, 1);
+        assert_eq!(report["Pumpfun"].p50_ms, 500.0);
+
+        clock.advance(Duration::from_secs(61));
+        assert!(tracker.report().is_empty());
+    }
+}
@@ -0,0 +1,28 @@
+pub mod bench;
+pub mod clusters;
+pub mod digest;
+pub mod drops;
+pub mod fees;
+pub mod gossip;
+pub mod latency;
+pub mod ops;
+pub mod recorder;
+pub mod stats;
+pub mod tracker;
+pub mod watchdog;
+
+pub use bench::ParseBenchmark;
+pub use clusters::LaunchClusterer;
+pub use digest::{Digest, Digester};
+pub use drops::{DropReason, DroppedTransaction};
+pub use fees::{FeeTracker, TokenFees};
+pub use gossip::{GossipBatch, LatencyGossip, PeerReport, Sighting};
+pub use latency::{LatencyReport, LatencyTracker};
+pub use ops::{OpsError, OpsErrorKind, OpsSeverity};
+pub use recorder::ErrorRecorder;
+pub use stats::ParserStats;
+pub use tracker::{
+    CreatorSell, GraduationEntry, LaunchTracker, SellPressure, TokenAlert, TokenStatus,
+    WalletTrades,
+};
+pub use watchdog::LaunchWatchdog;
@@ -0,0 +1,147 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tracing::debug;
+
+use crate::clock::{self, SharedClock};
+use crate::metrics;
+use crate::sink::EventSink;
+
+/// Errors waiting to be published, newer ones are dropped when full
+const REPORT_QUEUE_SIZE: usize = 1024;
+
+static REPORTER: OnceLock<mpsc::Sender<OpsError>> = OnceLock::new();
+
+fn clock() -> &'static Mutex<SharedClock> {
+    static CLOCK: OnceLock<Mutex<SharedClock>> = OnceLock::new();
+    CLOCK.get_or_init(|| Mutex::new(clock::system()))
+}
+
+/// Stamps ops errors with `clock` instead of the system clock
+pub fn set_clock(clock: SharedClock) {
+    *self::clock().lock().unwrap() = clock;
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OpsErrorKind {
+    ParserFailure,
+    SinkFailure,
+    StreamReconnect,
+    SubscriptionRejected,
+    StreamConnected,
+    StreamDisconnected,
+    StreamDown,
+    ReconnectBudgetExhausted,
+    LatencySloViolated,
+    LatencySloRecovered,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OpsSeverity {
+    Info,
+    #[default]
+    Warning,
+    Critical,
+}
+
+/// Operational error published for alerting, alongside the log line
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpsError {
+    pub kind: OpsErrorKind,
+    #[serde(default)]
+    pub severity: OpsSeverity,
+    pub component: String,
+    pub message: String,
+    pub signature: Option<String>,
+    pub slot: Option<u64>,
+    /// Time the Geyser stream was down, on connection events
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub downtime_secs: Option<f64>,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl OpsError {
+    pub fn new(kind: OpsErrorKind, component: &str, message: impl ToString) -> Self {
+        Self {
+            kind,
+            severity: OpsSeverity::default(),
+            component: component.to_string(),
+            message: message.to_string(),
+            signature: None,
+            slot: None,
+            downtime_secs: None,
+            timestamp: clock().lock().unwrap().now(),
+        }
+    }
+
+    /// Attaches the transaction that triggered the error
+    pub fn with_transaction(mut self, signature: &str, slot: u64) -> Self {
+        self.signature = Some(signature.to_string());
+        self.slot = Some(slot);
+        self
+    }
+
+    pub fn with_severity(mut self, severity: OpsSeverity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    pub fn with_downtime(mut self, downtime: Duration) -> Self {
+        self.downtime_secs = Some(downtime.as_secs_f64());
+        self
+    }
+}
+
+/// Queues an error for publishing, no-op until `start_reporter` is called
+pub fn report(error: OpsError) {
+    metrics::global().inc_counter(
+        "ops_errors_total",
+        &[("kind", &format!("{:?}", error.kind))],
+    );
+
+    if let Some(sender) = REPORTER.get()
+        && sender.try_send(error).is_err()
+    {
+        metrics::global().inc_counter("ops_errors_dropped_total", &[]);
+    }
+}
+
+/// Publishes reported errors through `sink` in a separate task
+pub fn start_reporter(sink: Arc<dyn EventSink>) -> JoinHandle<()> {
+    let (sender, mut receiver) = mpsc::channel(REPORT_QUEUE_SIZE);
+    if REPORTER.set(sender).is_err() {
+        debug!("Ops error reporter already started");
+    }
+
+    tokio::spawn(async move {
+        while let Some(error) = receiver.recv().await {
+            // Not reported again, a broken sink would feed itself
+            if let Err(e) = sink.publish_ops_error(&error).await {
+                debug!("Failed to publish ops error: {}", e);
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::{Clock, MockClock};
+    use crate::testing::start_time;
+
+    #[test]
+    fn errors_are_stamped_with_the_injected_clock() {
+        let clock = MockClock::new(start_time());
+        set_clock(clock.shared());
+        clock.advance(Duration::from_secs(5));
+
+        let error = OpsError::new(OpsErrorKind::SinkFailure, "test", "failed");
+        assert_eq!(error.timestamp, clock.now());
+        set_clock(clock::system());
+    }
+}
@@ -0,0 +1,121 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tracing::{debug, warn};
+
+use crate::clock::{self, SharedClock};
+use crate::config::recorder::RecorderConfig;
+use crate::geyser::QueuedTransaction;
+
+const REDACTED: &str = "[REDACTED]";
+
+/// Metadata stored next to each raw sample
+#[derive(Debug, Serialize)]
+struct SampleMetadata {
+    signature: String,
+    slot: u64,
+    received_time: DateTime<Utc>,
+    recorded_at: DateTime<Utc>,
+    parser: String,
+    reason: String,
+    endpoint: &'static str,
+}
+
+/// Captures raw protobuf of transactions that broke a parser into a bounded on-disk ring,
+/// redacting credentials so samples can be attached to bug reports
+#[derive(Clone)]
+pub struct ErrorRecorder {
+    dir: PathBuf,
+    max_samples: usize,
+    secrets: Arc<Vec<String>>,
+    next_slot: Arc<AtomicUsize>,
+    clock: SharedClock,
+}
+
+impl ErrorRecorder {
+    /// Creates a recorder; `secrets` are scrubbed from any metadata written to disk
+    pub fn new(config: &RecorderConfig, secrets: Vec<String>) -> Self {
+        Self {
+            dir: PathBuf::from(&config.dir),
+            max_samples: config.max_samples.max(1),
+            secrets: Arc::new(secrets.into_iter().filter(|s| !s.is_empty()).collect()),
+            next_slot: Arc::new(AtomicUsize::new(0)),
+            clock: clock::system(),
+        }
+    }
+
+    /// Replaces the clock samples are stamped with
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Records a transaction sample, overwriting the oldest one when the ring is full
+    pub async fn record(&self, transaction: &QueuedTransaction, parser: &str, reason: &str) {
+        if let Err(e) = self.write_sample(transaction, parser, reason).await {
+            warn!(
+                "Failed to record sample for {}: {}",
+                transaction.signature, e
+            );
+        }
+    }
+
+    async fn write_sample(
+        &self,
+        transaction: &QueuedTransaction,
+        parser: &str,
+        reason: &str,
+    ) -> Result<()> {
+        let Some(raw) = &transaction.raw else {
+            debug!(
+                "No raw payload captured for {}, skipping sample",
+                transaction.signature
+            );
+            return Ok(());
+        };
+
+        tokio::fs::create_dir_all(&self.dir)
+            .await
+            .context("Failed to create recorder directory")?;
+
+        let index = self.next_slot.fetch_add(1, Ordering::Relaxed) % self.max_samples;
+        let base = self.dir.join(format!("sample-{index:04}"));
+
+        let metadata = SampleMetadata {
+            signature: transaction.signature.clone(),
+            slot: transaction.slot,
+            received_time: transaction.received_time,
+            recorded_at: self.clock.now(),
+            parser: parser.to_string(),
+            reason: self.redact(reason),
+            endpoint: REDACTED,
+        };
+
+        tokio::fs::write(base.with_extension("bin"), raw.as_slice())
+            .await
+            .context("Failed to write raw sample")?;
+        tokio::fs::write(
+            base.with_extension("json"),
+            serde_json::to_vec_pretty(&metadata)?,
+        )
+        .await
+        .context("Failed to write sample metadata")?;
+
+        debug!(
+            "📼 Recorded sample {} for {}",
+            base.display(),
+            transaction.signature
+        );
+        Ok(())
+    }
+
+    /// Replaces every configured secret in `text`
+    fn redact(&self, text: &str) -> String {
+        self.secrets.iter().fold(text.to_string(), |acc, secret| {
+            acc.replace(secret, REDACTED)
+        })
+    }
+}
@@ -0,0 +1,308 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tokio::fs;
+use tokio::task::JoinHandle;
+use tokio::time::{Duration, MissedTickBehavior, interval};
+use tracing::{info, warn};
+
+use crate::clock::SharedClock;
+use crate::config::stats::StatsConfig;
+use crate::metrics;
+use crate::parser::LaunchpadType;
+
+/// Counters of one parser
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ParserCounters {
+    /// Transactions routed to the parser
+    pub matches: u64,
+    pub launches: u64,
+    pub partial_parses: u64,
+    pub errors: u64,
+    pub last_match: Option<DateTime<Utc>>,
+}
+
+/// Parser counters of one build, accumulated over its restarts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildStats {
+    pub build: String,
+    /// Running time covered by the counters
+    pub uptime_secs: u64,
+    pub updated_at: DateTime<Utc>,
+    pub parsers: BTreeMap<String, ParserCounters>,
+}
+
+impl BuildStats {
+    fn new(build: &str, now: DateTime<Utc>) -> Self {
+        Self {
+            build: build.to_string(),
+            uptime_secs: 0,
+            updated_at: now,
+            parsers: BTreeMap::new(),
+        }
+    }
+
+    fn matches_per_hour(&self, parser: &str) -> Option<f64> {
+        let counters = self.parsers.get(parser)?;
+        (self.uptime_secs > 0).then(|| counters.matches as f64 * 3600.0 / self.uptime_secs as f64)
+    }
+}
+
+/// Match rate of a parser in this build against the previous one
+#[derive(Debug, Clone, Serialize)]
+pub struct RateComparison {
+    pub matches_per_hour: Option<f64>,
+    pub previous_matches_per_hour: Option<f64>,
+    /// Relative change against the previous build, in percent
+    pub change_percent: Option<f64>,
+}
+
+/// Current and previous build counters with their match rates
+#[derive(Debug, Clone, Serialize)]
+pub struct StatsReport {
+    pub current: BuildStats,
+    pub previous: Option<BuildStats>,
+    pub rates: BTreeMap<String, RateComparison>,
+}
+
+/// On-disk layout
+#[derive(Serialize, Deserialize)]
+struct StatsFile {
+    current: BuildStats,
+    previous: Option<BuildStats>,
+}
+
+struct StatsState {
+    current: BuildStats,
+    previous: Option<BuildStats>,
+    /// Uptime already covered by the counters when this process started
+    base_uptime_secs: u64,
+    started: Instant,
+}
+
+impl StatsState {
+    fn snapshot(&mut self, clock: &SharedClock) -> StatsFile {
+        self.current.uptime_secs =
+            self.base_uptime_secs + clock.instant().duration_since(self.started).as_secs();
+        self.current.updated_at = clock.now();
+        StatsFile {
+            current: self.current.clone(),
+            previous: self.previous.clone(),
+        }
+    }
+}
+
+/// Per-parser counters persisted across restarts. Counters of the same build keep
+/// accumulating; after a deploy the last build's counters are kept as `previous`,
+/// so match rates can be compared right away.
+#[derive(Clone)]
+pub struct ParserStats {
+    config: StatsConfig,
+    state: Arc<Mutex<StatsState>>,
+    clock: SharedClock,
+}
+
+impl ParserStats {
+    /// Loads persisted counters, starting fresh ones when the build changed. Uptime and
+    /// match times are measured with `clock`.
+    pub async fn open(config: StatsConfig, clock: SharedClock) -> Result<Self> {
+        let stored = match fs::read_to_string(&config.path).await {
+            Ok(content) => match serde_json::from_str::<StatsFile>(&content) {
+                Ok(stored) => Some(stored),
+                Err(e) => {
+                    warn!("Ignoring unreadable parser statistics: {}", e);
+                    None
+                }
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+            Err(e) => return Err(e).context("Failed to read parser statistics"),
+        };
+
+        let (current, previous) = match stored {
+            Some(stored) if stored.current.build == config.build => {
+                info!(
+                    "📊 Continuing parser statistics of build {} ({}s recorded)",
+                    config.build, stored.current.uptime_secs
+                );
+                (stored.current, stored.previous)
+            }
+            Some(stored) => {
+                info!(
+                    "📊 New build {}, keeping statistics of build {} for comparison",
+                    config.build, stored.current.build
+                );
+                (
+                    BuildStats::new(&config.build, clock.now()),
+                    Some(stored.current),
+                )
+            }
+            None => (BuildStats::new(&config.build, clock.now()), None),
+        };
+
+        if let Some(dir) = PathBuf::from(&config.path).parent() {
+            fs::create_dir_all(dir)
+                .await
+                .context("Failed to create parser statistics directory")?;
+        }
+
+        for (parser, counters) in &current.parsers {
+            set_gauges(parser, counters);
+        }
+        if let Some(previous) = &previous {
+            for parser in previous.parsers.keys() {
+                if let Some(rate) = previous.matches_per_hour(parser) {
+                    metrics::global().set_gauge(
+                        "parser_stats_previous_matches_per_hour",
+                        &[("launchpad", parser)],
+                        rate,
+                    );
+                }
+            }
+        }
+
+        Ok(Self {
+            state: Arc::new(Mutex::new(StatsState {
+                base_uptime_secs: current.uptime_secs,
+                current,
+                previous,
+                started: clock.instant(),
+            })),
+            config,
+            clock,
+        })
+    }
+
+    /// Counts a transaction routed to the parser of `launchpad`
+    pub fn record_match(&self, launchpad: &LaunchpadType) {
+        let now = self.clock.now();
+        self.update(launchpad, |counters| {
+            counters.matches += 1;
+            counters.last_match = Some(now);
+        });
+    }
+
+    pub fn record_launch(&self, launchpad: &LaunchpadType) {
+        self.update(launchpad, |counters| counters.launches += 1);
+    }
+
+    pub fn record_partial_parse(&self, launchpad: &LaunchpadType) {
+        self.update(launchpad, |counters| counters.partial_parses += 1);
+    }
+
+    pub fn record_error(&self, launchpad: &LaunchpadType) {
+        self.update(launchpad, |counters| counters.errors += 1);
+    }
+
+    fn update(&self, launchpad: &LaunchpadType, apply: impl FnOnce(&mut ParserCounters)) {
+        let parser = launchpad.to_string();
+        let mut state = self.state.lock().unwrap();
+        let counters = state.current.parsers.entry(parser.clone()).or_default();
+        apply(counters);
+        set_gauges(&parser, counters);
+    }
+
+    /// Counters of this and the previous build with per-parser match rates
+    pub fn report(&self) -> StatsReport {
+        let file = self.state.lock().unwrap().snapshot(&self.clock);
+
+        let parsers = file.current.parsers.keys().chain(
+            file.previous
+                .iter()
+                .flat_map(|previous| previous.parsers.keys()),
+        );
+        let mut rates = BTreeMap::new();
+        for parser in parsers {
+            let current = file.current.matches_per_hour(parser);
+            let previous = file
+                .previous
+                .as_ref()
+                .and_then(|previous| previous.matches_per_hour(parser));
+            let change_percent = match (current, previous) {
+                (Some(current), Some(previous)) if previous > 0.0 => {
+                    Some((current - previous) / previous * 100.0)
+                }
+                _ => None,
+            };
+            rates.insert(
+                parser.clone(),
+                RateComparison {
+                    matches_per_hour: current,
+                    previous_matches_per_hour: previous,
+                    change_percent,
+                },
+            );
+        }
+
+        StatsReport {
+            current: file.current,
+            previous: file.previous,
+            rates,
+        }
+    }
+
+    /// Writes the counters to disk, through a temporary file so a crash never leaves a partial one
+    pub async fn flush(&self) -> Result<()> {
+        let file = self.state.lock().unwrap().snapshot(&self.clock);
+        for parser in file.current.parsers.keys() {
+            if let Some(rate) = file.current.matches_per_hour(parser) {
+                metrics::global().set_gauge(
+                    "parser_stats_matches_per_hour",
+                    &[("launchpad", parser)],
+                    rate,
+                );
+            }
+        }
+
+        let content = serde_json::to_vec_pretty(&file)?;
+        let tmp = format!("{}.tmp", self.config.path);
+        fs::write(&tmp, content)
+            .await
+            .context("Failed to write parser statistics")?;
+        fs::rename(&tmp, &self.config.path)
+            .await
+            .context("Failed to replace parser statistics")?;
+
+        Ok(())
+    }
+
+    /// Flushes the counters every `flush_secs`
+    pub fn start(self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = interval(Duration::from_secs(self.config.flush_secs));
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            // The first tick completes immediately
+            ticker.tick().await;
+
+            loop {
+                ticker.tick().await;
+                if let Err(e) = self.flush().await {
+                    warn!("Failed to persist parser statistics: {:#}", e);
+                }
+            }
+        })
+    }
+}
+
+fn set_gauges(parser: &str, counters: &ParserCounters) {
+    let metrics = metrics::global();
+    let labels = [("launchpad", parser)];
+    metrics.set_gauge("parser_stats_matches", &labels, counters.matches as f64);
+    metrics.set_gauge("parser_stats_launches", &labels, counters.launches as f64);
+    metrics.set_gauge(
+        "parser_stats_partial_parses",
+        &labels,
+        counters.partial_parses as f64,
+    );
+    metrics.set_gauge("parser_stats_errors", &labels, counters.errors as f64);
+    if let Some(last_match) = counters.last_match {
+        metrics.set_gauge(
+            "parser_stats_last_match_timestamp_seconds",
+            &labels,
+            last_match.timestamp() as f64,
+        );
+    }
+}
@@ -0,0 +1,820 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tokio::task::JoinHandle;
+use tokio::time::{Duration, MissedTickBehavior, interval};
+use tracing::{debug, info, warn};
+
+use crate::clock::{self, SharedClock};
+use crate::config::tracker::TrackerConfig;
+use crate::geyser::QueuedTransaction;
+use crate::metrics;
+use crate::monitor::{FeeTracker, OpsError, OpsErrorKind, TokenFees, ops};
+use crate::parser::{LaunchpadType, TokenLaunch, Trade};
+use crate::sink::EventSink;
+
+const SYSTEM_PROGRAM_ID: &str = "11111111111111111111111111111111";
+/// System program `Transfer` instruction index
+const SYSTEM_TRANSFER: [u8; 4] = [2, 0, 0, 0];
+/// Bonding curves without trades for this long leave the graduation leaderboard
+const CURVE_IDLE_SECS: u64 = 3600;
+/// Span over which the graduation rate is measured
+const CURVE_RATE_WINDOW_SECS: u64 = 300;
+/// Minimum spacing of the progress samples kept for the rate
+const CURVE_SAMPLE_SECS: u64 = 10;
+
+/// Trading of one wallet on a tracked token
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletTrades {
+    pub wallet: String,
+    pub first_buy_slot: Option<u64>,
+    pub first_buy_transaction_index: Option<u64>,
+    pub buys: u64,
+    pub sells: u64,
+    /// SOL spent on buys in lamports
+    pub sol_bought: u64,
+    /// SOL received from sells in lamports
+    pub sol_sold: u64,
+    pub tokens_bought: u64,
+    pub tokens_sold: u64,
+}
+
+/// Trading activity of a freshly launched token, published once its window elapsed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenStatus {
+    pub launchpad: LaunchpadType,
+    pub token_address: String,
+    pub creator: Option<String>,
+    pub launch_signature: String,
+    pub launch_slot: u64,
+    pub launched_at: DateTime<Utc>,
+    pub window_secs: u64,
+    pub buys: u64,
+    pub sells: u64,
+    pub unique_wallets: usize,
+    pub sol_bought: u64,
+    pub sol_sold: u64,
+    /// Earliest buyers ordered by slot and position in the slot
+    pub early_buyers: Vec<WalletTrades>,
+    pub fees: Option<TokenFees>,
+}
+
+/// Alert raised from the trading on a tracked token
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TokenAlert {
+    SellPressure(SellPressure),
+    CreatorSell(CreatorSell),
+}
+
+/// SOL sold exceeded SOL bought by the configured threshold within the window
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SellPressure {
+    pub launchpad: LaunchpadType,
+    pub token_address: String,
+    pub window_secs: u64,
+    pub buys: u64,
+    pub sells: u64,
+    pub sol_bought: u64,
+    pub sol_sold: u64,
+    /// Trade that pushed the window over the threshold
+    pub signature: String,
+    pub slot: u64,
+    pub timestamp: DateTime<Utc>,
+    #[serde(default)]
+    pub block_time: Option<DateTime<Utc>>,
+}
+
+/// The creator, or a wallet the creator funded, sold the token
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatorSell {
+    pub launchpad: LaunchpadType,
+    pub token_address: String,
+    pub creator: String,
+    pub seller: String,
+    /// Seller was funded by the creator rather than being the creator
+    pub funded_wallet: bool,
+    pub token_amount: u64,
+    pub sol_amount: u64,
+    /// Tokens bought by the creator and its funded wallets, the launch's dev buy included
+    pub initial_allocation: u64,
+    /// Tokens sold by the creator and its funded wallets so far
+    pub total_sold: u64,
+    /// Share of the initial allocation sold so far, absent when no buy was seen. Capped at
+    /// 100, tokens received other than by a seen buy can be sold too.
+    pub percent_sold: Option<f64>,
+    pub signature: String,
+    pub slot: u64,
+    pub timestamp: DateTime<Utc>,
+    #[serde(default)]
+    pub block_time: Option<DateTime<Utc>>,
+}
+
+/// Bonding curve close to graduation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraduationEntry {
+    pub launchpad: LaunchpadType,
+    pub token_address: String,
+    /// Progress towards graduation in percent
+    pub progress: f64,
+    /// Progress gained per minute over the last few minutes, absent until two samples exist
+    pub rate_per_minute: Option<f64>,
+    pub last_trade: DateTime<Utc>,
+}
+
+struct CurveProgress {
+    launchpad: LaunchpadType,
+    progress: f64,
+    last_trade: DateTime<Utc>,
+    last_seen: Instant,
+    /// Progress samples within the rate window, oldest first
+    samples: VecDeque<(Instant, f64)>,
+}
+
+impl CurveProgress {
+    fn update(&mut self, progress: f64, timestamp: DateTime<Utc>, now: Instant) {
+        self.progress = progress;
+        self.last_trade = timestamp;
+        self.last_seen = now;
+
+        if self
+            .samples
+            .back()
+            .is_none_or(|(at, _)| now.duration_since(*at) >= Duration::from_secs(CURVE_SAMPLE_SECS))
+        {
+            self.samples.push_back((now, progress));
+        }
+        while self.samples.front().is_some_and(|(at, _)| {
+            now.duration_since(*at) > Duration::from_secs(CURVE_RATE_WINDOW_SECS)
+        }) {
+            self.samples.pop_front();
+        }
+    }
+
+    fn rate_per_minute(&self) -> Option<f64> {
+        let (first_at, first) = self.samples.front()?;
+        let minutes = self.last_seen.duration_since(*first_at).as_secs_f64() / 60.0;
+        (minutes > 0.0).then(|| (self.progress - first) / minutes)
+    }
+}
+
+struct RecentTrade {
+    at: Instant,
+    is_buy: bool,
+    amount: u64,
+}
+
+struct TrackedToken {
+    launch: TokenLaunch,
+    started: Instant,
+    status_published: bool,
+    wallets: HashMap<String, WalletTrades>,
+    /// Trades inside the sell pressure window
+    recent: VecDeque<RecentTrade>,
+    last_alert: Option<Instant>,
+    /// Creator and the wallets it funded
+    associated: HashSet<String>,
+    /// Tokens of the creator's buys in the launch transaction seen as trades, already part
+    /// of the launch's `initial_buy_tokens`
+    launch_buy_trades: u64,
+}
+
+impl TrackedToken {
+    /// Adds the trade to the sliding window and returns an alert when sells outweigh buys.
+    /// At most one alert is raised per window.
+    fn check_sell_pressure(
+        &mut self,
+        trade: &Trade,
+        config: &TrackerConfig,
+        now: Instant,
+    ) -> Option<SellPressure> {
+        if config.sell_pressure_lamports == 0 {
+            return None;
+        }
+
+        let window = Duration::from_secs(config.sell_pressure_window_secs);
+        while self
+            .recent
+            .front()
+            .is_some_and(|recent| now.duration_since(recent.at) > window)
+        {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(RecentTrade {
+            at: now,
+            is_buy: trade.is_buy,
+            amount: trade.amount,
+        });
+
+        if trade.is_buy
+            || self
+                .last_alert
+                .is_some_and(|last| now.duration_since(last) < window)
+        {
+            return None;
+        }
+
+        let (mut buys, mut sells, mut sol_bought, mut sol_sold) = (0, 0, 0u64, 0u64);
+        for recent in &self.recent {
+            if recent.is_buy {
+                buys += 1;
+                sol_bought = sol_bought.saturating_add(recent.amount);
+            } else {
+                sells += 1;
+                sol_sold = sol_sold.saturating_add(recent.amount);
+            }
+        }
+        if sol_sold.saturating_sub(sol_bought) < config.sell_pressure_lamports {
+            return None;
+        }
+
+        self.last_alert = Some(now);
+        Some(SellPressure {
+            launchpad: trade.launchpad.clone(),
+            token_address: trade.token_address.clone(),
+            window_secs: config.sell_pressure_window_secs,
+            buys,
+            sells,
+            sol_bought,
+            sol_sold,
+            signature: trade.signature.clone(),
+            slot: trade.slot,
+            timestamp: trade.timestamp,
+            block_time: trade.block_time,
+        })
+    }
+
+    /// Returns an alert when the trade is a sell by the creator or a wallet it funded.
+    /// Expects the trade already added to the wallet aggregates.
+    fn check_creator_sell(&self, trade: &Trade) -> Option<CreatorSell> {
+        let creator = self.launch.creator.as_ref()?;
+        if trade.is_buy || !self.associated.contains(&trade.trader) {
+            return None;
+        }
+
+        let (bought, total_sold) = self
+            .associated
+            .iter()
+            .filter_map(|wallet| self.wallets.get(wallet))
+            .fold((0u64, 0u64), |(bought, sold), w| {
+                (
+                    bought.saturating_add(w.tokens_bought),
+                    sold.saturating_add(w.tokens_sold),
+                )
+            });
+        // The dev buy is known from the launch even when no trade of it is seen
+        let initial_allocation = match self.launch.initial_buy_tokens {
+            Some(dev_buy) => bought
+                .saturating_sub(self.launch_buy_trades)
+                .saturating_add(dev_buy),
+            None => bought,
+        };
+        let percent_sold = (initial_allocation > 0)
+            .then(|| (total_sold as f64 / initial_allocation as f64 * 100.0).min(100.0));
+
+        Some(CreatorSell {
+            launchpad: trade.launchpad.clone(),
+            token_address: trade.token_address.clone(),
+            creator: creator.clone(),
+            seller: trade.trader.clone(),
+            funded_wallet: &trade.trader != creator,
+            token_amount: trade.token_amount,
+            sol_amount: trade.amount,
+            initial_allocation,
+            total_sold,
+            percent_sold,
+            signature: trade.signature.clone(),
+            slot: trade.slot,
+            timestamp: trade.timestamp,
+            block_time: trade.block_time,
+        })
+    }
+
+    fn status(&self, config: &TrackerConfig, fees: Option<TokenFees>) -> TokenStatus {
+        let unique_wallets = self.wallets.len();
+        let mut wallets: Vec<WalletTrades> = self.wallets.values().cloned().collect();
+        let (buys, sells, sol_bought, sol_sold) =
+            wallets.iter().fold((0, 0, 0u64, 0u64), |acc, w| {
+                (
+                    acc.0 + w.buys,
+                    acc.1 + w.sells,
+                    acc.2.saturating_add(w.sol_bought),
+                    acc.3.saturating_add(w.sol_sold),
+                )
+            });
+
+        wallets.retain(|w| w.first_buy_slot.is_some());
+        wallets.sort_by_key(|w| {
+            (
+                w.first_buy_slot,
+                w.first_buy_transaction_index.unwrap_or(u64::MAX),
+            )
+        });
+        wallets.truncate(config.early_buyers);
+
+        TokenStatus {
+            launchpad: self.launch.launchpad.clone(),
+            token_address: self.launch.token_address.clone(),
+            creator: self.launch.creator.clone(),
+            launch_signature: self.launch.signature.clone(),
+            launch_slot: self.launch.slot,
+            launched_at: self.launch.timestamp,
+            window_secs: config.status_window_secs,
+            buys,
+            sells,
+            unique_wallets,
+            sol_bought,
+            sol_sold,
+            early_buyers: wallets,
+            fees,
+        }
+    }
+}
+
+/// Follows trading on freshly launched tokens per wallet, publishes a `TokenStatus`
+/// with the earliest buyers once the configured window after launch elapsed and
+/// raises alerts on suspicious trading while the token is followed
+#[derive(Clone)]
+pub struct LaunchTracker {
+    config: TrackerConfig,
+    state: Arc<Mutex<TrackerState>>,
+    fees: Option<FeeTracker>,
+    clock: SharedClock,
+}
+
+#[derive(Default)]
+struct TrackerState {
+    tokens: HashMap<String, TrackedToken>,
+    /// Creator to the mints it launched, for attributing funding transfers
+    creators: HashMap<String, Vec<String>>,
+    /// Bonding curve progress of every traded token that has not graduated, tracked launch or not
+    curves: HashMap<String, CurveProgress>,
+}
+
+impl LaunchTracker {
+    pub fn new(config: TrackerConfig) -> Self {
+        Self {
+            config,
+            state: Arc::new(Mutex::new(TrackerState::default())),
+            fees: None,
+            clock: clock::system(),
+        }
+    }
+
+    /// Replaces the clock the status window, tracking period and alert windows are measured with
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Includes the token's fee earnings on published statuses
+    pub fn with_fee_tracker(mut self, fees: FeeTracker) -> Self {
+        self.fees = Some(fees);
+        self
+    }
+
+    pub fn record_launch(&self, launch: &TokenLaunch) {
+        let mut state = self.state.lock().unwrap();
+        if state.tokens.contains_key(&launch.token_address) {
+            return;
+        }
+        if state.tokens.len() >= self.config.max_tokens {
+            metrics::global().inc_counter("tracker_skipped_total", &[]);
+            debug!("Tracker full, not tracking {}", launch.token_address);
+            return;
+        }
+
+        if let Some(creator) = &launch.creator {
+            state
+                .creators
+                .entry(creator.clone())
+                .or_default()
+                .push(launch.token_address.clone());
+        }
+        state.tokens.insert(
+            launch.token_address.clone(),
+            TrackedToken {
+                launch: launch.clone(),
+                started: self.clock.instant(),
+                status_published: false,
+                wallets: HashMap::new(),
+                recent: VecDeque::new(),
+                last_alert: None,
+                associated: launch.creator.iter().cloned().collect(),
+                launch_buy_trades: 0,
+            },
+        );
+        metrics::global().set_gauge("tracker_tokens", &[], state.tokens.len() as f64);
+    }
+
+    /// Associates wallets receiving SOL from a tracked creator in `transaction` with the
+    /// creator's launches, so their sells are reported as creator sells. Only one hop is
+    /// followed and only transfers in transactions the parsers see.
+    pub fn record_funding(&self, transaction: &QueuedTransaction) {
+        if !self.config.creator_funded_wallets {
+            return;
+        }
+
+        let mut state = self.state.lock().unwrap();
+        let TrackerState {
+            tokens, creators, ..
+        } = &mut *state;
+        for instr in transaction
+            .instructions
+            .iter()
+            .chain(&transaction.inner_instructions)
+        {
+            if instr.program_id != SYSTEM_PROGRAM_ID
+                || instr.data.len() < 12
+                || !instr.data.starts_with(&SYSTEM_TRANSFER)
+            {
+                continue;
+            }
+            let account = |index: usize| {
+                instr
+                    .accounts
+                    .get(index)
+                    .and_then(|idx| transaction.accounts.get(*idx as usize))
+            };
+            let (Some(from), Some(to)) = (account(0), account(1)) else {
+                continue;
+            };
+            let Some(mints) = creators.get(from) else {
+                continue;
+            };
+
+            for mint in mints {
+                if let Some(token) = tokens.get_mut(mint)
+                    && token.associated.insert(to.clone())
+                {
+                    debug!("Creator {} funded {} ({})", from, to, mint);
+                }
+            }
+        }
+    }
+
+    /// Adds a trade to the trader's aggregate and returns the alerts it raised,
+    /// trades on untracked tokens are ignored
+    pub fn record_trade(&self, trade: &Trade) -> Vec<TokenAlert> {
+        let now = self.clock.instant();
+        let mut state = self.state.lock().unwrap();
+        if let Some(progress) = trade.curve_progress {
+            Self::record_curve(&mut state.curves, trade, progress, now);
+        }
+
+        let Some(token) = state.tokens.get_mut(&trade.token_address) else {
+            return Vec::new();
+        };
+
+        let mut alerts = Vec::new();
+        if let Some(pressure) = token.check_sell_pressure(trade, &self.config, now) {
+            alerts.push(TokenAlert::SellPressure(pressure));
+        }
+
+        let wallet = token
+            .wallets
+            .entry(trade.trader.clone())
+            .or_insert_with(|| WalletTrades {
+                wallet: trade.trader.clone(),
+                first_buy_slot: None,
+                first_buy_transaction_index: None,
+                buys: 0,
+                sells: 0,
+                sol_bought: 0,
+                sol_sold: 0,
+                tokens_bought: 0,
+                tokens_sold: 0,
+            });
+
+        if trade.is_buy {
+            // Trades can arrive out of order across slots, keep the earliest buy
+            let position = (trade.slot, trade.transaction_index.unwrap_or(u64::MAX));
+            let first = wallet
+                .first_buy_slot
+                .map(|slot| (slot, wallet.first_buy_transaction_index.unwrap_or(u64::MAX)));
+            if first.is_none_or(|first| position < first) {
+                wallet.first_buy_slot = Some(trade.slot);
+                wallet.first_buy_transaction_index = trade.transaction_index;
+            }
+            wallet.buys += 1;
+            wallet.sol_bought = wallet.sol_bought.saturating_add(trade.amount);
+            wallet.tokens_bought = wallet.tokens_bought.saturating_add(trade.token_amount);
+            if trade.signature == token.launch.signature
+                && token.launch.creator.as_ref() == Some(&trade.trader)
+            {
+                token.launch_buy_trades =
+                    token.launch_buy_trades.saturating_add(trade.token_amount);
+            }
+        } else {
+            wallet.sells += 1;
+            wallet.sol_sold = wallet.sol_sold.saturating_add(trade.amount);
+            wallet.tokens_sold = wallet.tokens_sold.saturating_add(trade.token_amount);
+        }
+
+        if let Some(sell) = token.check_creator_sell(trade) {
+            alerts.push(TokenAlert::CreatorSell(sell));
+        }
+
+        alerts
+    }
+
+    fn record_curve(
+        curves: &mut HashMap<String, CurveProgress>,
+        trade: &Trade,
+        progress: f64,
+        now: Instant,
+    ) {
+        if progress >= 100.0 {
+            curves.remove(&trade.token_address);
+            return;
+        }
+
+        curves
+            .entry(trade.token_address.clone())
+            .or_insert_with(|| CurveProgress {
+                launchpad: trade.launchpad.clone(),
+                progress,
+                last_trade: trade.timestamp,
+                last_seen: now,
+                samples: VecDeque::new(),
+            })
+            .update(progress, trade.timestamp, now);
+    }
+
+    /// Tokens closest to graduation per launchpad, at most `limit` each
+    pub fn graduation_leaderboard(&self, limit: usize) -> BTreeMap<String, Vec<GraduationEntry>> {
+        let state = self.state.lock().unwrap();
+        let mut leaderboard: BTreeMap<String, Vec<GraduationEntry>> = BTreeMap::new();
+        for (mint, curve) in &state.curves {
+            leaderboard
+                .entry(curve.launchpad.to_string())
+                .or_default()
+                .push(GraduationEntry {
+                    launchpad: curve.launchpad.clone(),
+                    token_address: mint.clone(),
+                    progress: curve.progress,
+                    rate_per_minute: curve.rate_per_minute(),
+                    last_trade: curve.last_trade,
+                });
+        }
+
+        for entries in leaderboard.values_mut() {
+            entries.sort_by(|a, b| {
+                b.progress
+                    .total_cmp(&a.progress)
+                    .then_with(|| a.token_address.cmp(&b.token_address))
+            });
+            entries.truncate(limit);
+        }
+        leaderboard
+    }
+
+    /// Returns the statuses of tokens whose status window elapsed and stops following
+    /// tokens past the tracking period
+    fn take_due(&self) -> Vec<TokenStatus> {
+        let status_window = Duration::from_secs(self.config.status_window_secs);
+        let track = Duration::from_secs(self.config.track_secs);
+        let now = self.clock.instant();
+        let mut state = self.state.lock().unwrap();
+
+        let mut statuses = Vec::new();
+        for token in state.tokens.values_mut() {
+            if !token.status_published && now.duration_since(token.started) >= status_window {
+                token.status_published = true;
+                let fees = self
+                    .fees
+                    .as_ref()
+                    .and_then(|fees| fees.get(&token.launch.token_address));
+                statuses.push(token.status(&self.config, fees));
+            }
+        }
+        let TrackerState {
+            tokens,
+            creators,
+            curves,
+        } = &mut *state;
+        curves.retain(|_, curve| {
+            now.duration_since(curve.last_seen) < Duration::from_secs(CURVE_IDLE_SECS)
+        });
+        metrics::global().set_gauge("tracker_curves", &[], curves.len() as f64);
+        tokens.retain(|_, token| now.duration_since(token.started) < track);
+        creators.retain(|_, mints| {
+            mints.retain(|mint| tokens.contains_key(mint));
+            !mints.is_empty()
+        });
+        metrics::global().set_gauge("tracker_tokens", &[], tokens.len() as f64);
+
+        statuses
+    }
+
+    /// Publishes token statuses through `sink` as their windows elapse in a separate task
+    pub fn start(self, sink: Arc<dyn EventSink>) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            info!(
+                "👀 Tracking launches for {}s (status after {}s, top {} early buyers)",
+                self.config.track_secs, self.config.status_window_secs, self.config.early_buyers
+            );
+
+            let mut ticker = interval(Duration::from_secs(1));
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+            loop {
+                ticker.tick().await;
+
+                for status in self.take_due() {
+                    if let Err(e) = sink.publish_token_status(&status).await {
+                        warn!("Failed to publish token status: {}", e);
+                        ops::report(OpsError::new(OpsErrorKind::SinkFailure, "tracker", &e));
+                    }
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::{Clock, MockClock};
+    use crate::testing::{instruction, launch, start_time, trade, transaction};
+
+    const SOL: u64 = 1_000_000_000;
+
+    fn config() -> TrackerConfig {
+        TrackerConfig {
+            enabled: true,
+            status_window_secs: 60,
+            early_buyers: 20,
+            track_secs: 600,
+            sell_pressure_window_secs: 30,
+            sell_pressure_lamports: 5 * SOL,
+            creator_funded_wallets: true,
+            max_tokens: 100,
+        }
+    }
+
+    fn tracker(clock: &MockClock) -> LaunchTracker {
+        LaunchTracker::new(config()).with_clock(clock.shared())
+    }
+
+    fn sell_pressure(alerts: &[TokenAlert]) -> usize {
+        alerts
+            .iter()
+            .filter(|alert| matches!(alert, TokenAlert::SellPressure(_)))
+            .count()
+    }
+
+    fn creator_sell(alerts: &[TokenAlert]) -> &CreatorSell {
+        let [TokenAlert::CreatorSell(sell)] = alerts else {
+            panic!("expected one creator sell, got {} alerts", alerts.len());
+        };
+        sell
+    }
+
+    /// Launch `a` by `creator`, who bought 1000 tokens in the launch transaction
+    fn creator_launch(clock: &MockClock) -> TokenLaunch {
+        let mut launch = launch("a", 1, clock.now());
+        launch.creator = Some("creator".to_string());
+        launch.initial_buy_tokens = Some(1000);
+        launch
+    }
+
+    #[test]
+    fn status_is_published_once_after_window() {
+        let clock = MockClock::new(start_time());
+        let tracker = tracker(&clock);
+        tracker.record_launch(&launch("a", 1, clock.now()));
+        tracker.record_trade(&trade("a", "buyer", true, SOL, 1000, clock.now()));
+
+        clock.advance(Duration::from_secs(59));
+        assert!(tracker.take_due().is_empty());
+
+        clock.advance(Duration::from_secs(1));
+        let statuses = tracker.take_due();
+        assert_eq!(statuses.len(), 1);
+        assert_eq!(statuses[0].buys, 1);
+        assert_eq!(statuses[0].early_buyers[0].wallet, "buyer");
+        assert!(tracker.take_due().is_empty());
+    }
+
+    #[test]
+    fn token_is_evicted_after_tracking_period() {
+        let clock = MockClock::new(start_time());
+        let tracker = tracker(&clock);
+        tracker.record_launch(&launch("a", 1, clock.now()));
+
+        clock.advance(Duration::from_secs(599));
+        tracker.take_due();
+        assert!(tracker.state.lock().unwrap().tokens.contains_key("mint-a"));
+
+        clock.advance(Duration::from_secs(1));
+        tracker.take_due();
+        let state = tracker.state.lock().unwrap();
+        assert!(state.tokens.is_empty());
+        assert!(state.creators.is_empty());
+        drop(state);
+
+        // Sells on an evicted token raise nothing
+        let alerts =
+            tracker.record_trade(&trade("a", "seller", false, 10 * SOL, 1000, clock.now()));
+        assert!(alerts.is_empty());
+    }
+
+    #[test]
+    fn sell_pressure_counts_trades_within_window() {
+        let clock = MockClock::new(start_time());
+        let tracker = tracker(&clock);
+        tracker.record_launch(&launch("a", 1, clock.now()));
+
+        tracker.record_trade(&trade("a", "seller", false, 3 * SOL, 1000, clock.now()));
+        // The first sell left the window, so the second alone stays below the threshold
+        clock.advance(Duration::from_secs(31));
+        let alerts = tracker.record_trade(&trade("a", "seller", false, 3 * SOL, 1000, clock.now()));
+        assert_eq!(sell_pressure(&alerts), 0);
+
+        clock.advance(Duration::from_secs(1));
+        let alerts = tracker.record_trade(&trade("a", "seller", false, 3 * SOL, 1000, clock.now()));
+        assert_eq!(sell_pressure(&alerts), 1);
+
+        // One alert per window
+        clock.advance(Duration::from_secs(1));
+        let alerts = tracker.record_trade(&trade("a", "seller", false, 3 * SOL, 1000, clock.now()));
+        assert_eq!(sell_pressure(&alerts), 0);
+        clock.advance(Duration::from_secs(30));
+        let alerts = tracker.record_trade(&trade("a", "seller", false, 6 * SOL, 1000, clock.now()));
+        assert_eq!(sell_pressure(&alerts), 1);
+    }
+
+    #[test]
+    fn creator_sell_counts_the_dev_buy_once() {
+        let clock = MockClock::new(start_time());
+        let tracker = tracker(&clock);
+        tracker.record_launch(&creator_launch(&clock));
+        // The dev buy seen as a trade of the launch transaction
+        let mut dev_buy = trade("a", "creator", true, SOL, 1000, clock.now());
+        dev_buy.signature = "a".to_string();
+        tracker.record_trade(&dev_buy);
+
+        let alerts = tracker.record_trade(&trade("a", "creator", false, SOL, 250, clock.now()));
+        let sell = creator_sell(&alerts);
+        assert!(!sell.funded_wallet);
+        assert_eq!(sell.initial_allocation, 1000);
+        assert_eq!(sell.total_sold, 250);
+        assert_eq!(sell.percent_sold, Some(25.0));
+    }
+
+    #[test]
+    fn sell_by_a_funded_wallet_is_a_creator_sell() {
+        let clock = MockClock::new(start_time());
+        let tracker = tracker(&clock);
+        tracker.record_launch(&creator_launch(&clock));
+        let mut funding = SYSTEM_TRANSFER.to_vec();
+        funding.extend(SOL.to_le_bytes());
+        tracker.record_funding(&transaction(
+            &["creator", "sniper"],
+            vec![instruction(SYSTEM_PROGRAM_ID, &[0, 1], funding)],
+            Vec::new(),
+        ));
+
+        tracker.record_trade(&trade("a", "sniper", true, SOL, 500, clock.now()));
+        // Unrelated wallets selling raise nothing
+        let alerts = tracker.record_trade(&trade("a", "other", false, SOL, 500, clock.now()));
+        assert!(alerts.is_empty());
+
+        // Selling tokens it did not buy here, the share sold stays at 100
+        let alerts = tracker.record_trade(&trade("a", "sniper", false, SOL, 2000, clock.now()));
+        let sell = creator_sell(&alerts);
+        assert!(sell.funded_wallet);
+        assert_eq!(sell.seller, "sniper");
+        assert_eq!(sell.initial_allocation, 1500);
+        assert_eq!(sell.total_sold, 2000);
+        assert_eq!(sell.percent_sold, Some(100.0));
+    }
+
+    #[test]
+    fn idle_curve_leaves_leaderboard() {
+        let clock = MockClock::new(start_time());
+        let tracker = tracker(&clock);
+        let mut buy = trade("a", "buyer", true, SOL, 1000, clock.now());
+        buy.curve_progress = Some(40.0);
+        tracker.record_trade(&buy);
+
+        clock.advance(Duration::from_secs(60));
+        buy.curve_progress = Some(46.0);
+        tracker.record_trade(&buy);
+        let leaderboard = tracker.graduation_leaderboard(10);
+        let entry = &leaderboard["Pumpfun"][0];
+        assert_eq!(entry.progress, 46.0);
+        assert_eq!(entry.rate_per_minute, Some(6.0));
+
+        clock.advance(Duration::from_secs(CURVE_IDLE_SECS - 1));
+        tracker.take_due();
+        assert_eq!(tracker.graduation_leaderboard(10).len(), 1);
+
+        clock.advance(Duration::from_secs(1));
+        tracker.take_due();
+        assert!(tracker.graduation_leaderboard(10).is_empty());
+    }
+}
@@ -0,0 +1,247 @@
+use std::collections::HashMap;
+use std::time::Instant;
+use tokio::task::JoinHandle;
+use tokio::time::{Duration, interval};
+#[cfg(feature = "webhook")]
+use tracing::warn;
+use tracing::{error, info};
+
+use crate::clock::{self, SharedClock};
+use crate::config::watchdog::WatchdogConfig;
+use crate::metrics;
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Payload posted to the watchdog webhook
+#[cfg(feature = "webhook")]
+#[derive(Debug, serde::Serialize)]
+struct WatchdogAlert<'a> {
+    alert: &'a str,
+    launchpad: &'a str,
+    silent_for_secs: u64,
+    timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+/// Per-launchpad silence tracking
+struct LaunchpadState {
+    launches: u64,
+    last_change: Instant,
+    alerting: bool,
+}
+
+/// Silence of the watched launchpads, measured between checks
+struct Silence {
+    window: Duration,
+    last_transactions: u64,
+    states: HashMap<String, LaunchpadState>,
+}
+
+impl Silence {
+    fn new(launchpads: &[String], window: Duration, now: Instant, transactions: u64) -> Self {
+        let states = launchpads
+            .iter()
+            .map(|launchpad| {
+                let state = LaunchpadState {
+                    launches: launches_total(launchpad),
+                    last_change: now,
+                    alerting: false,
+                };
+                (launchpad.clone(), state)
+            })
+            .collect();
+        Self {
+            window,
+            last_transactions: transactions,
+            states,
+        }
+    }
+
+    /// Launchpads that went silent for the window, each returned once per silence.
+    /// `transactions` is the count of transactions received from the stream so far.
+    fn check(&mut self, now: Instant, transactions: u64) -> Vec<(String, Duration)> {
+        // Silence only counts while the stream is delivering transactions; pings and
+        // slot or block meta updates keep flowing when the transaction filters match nothing
+        let stream_alive = transactions > self.last_transactions;
+        self.last_transactions = transactions;
+
+        let mut silent = Vec::new();
+        for (launchpad, state) in self.states.iter_mut() {
+            let launches = launches_total(launchpad);
+            let launched = launches != state.launches;
+
+            if launched || !stream_alive {
+                state.launches = launches;
+                state.last_change = now;
+
+                if launched && state.alerting {
+                    info!("🐕 Launches from {} resumed", launchpad);
+                    state.alerting = false;
+                    metrics::global().set_gauge(
+                        "watchdog_silent",
+                        &[("launchpad", launchpad)],
+                        0.0,
+                    );
+                }
+                continue;
+            }
+
+            let silent_for = now.duration_since(state.last_change);
+            if !state.alerting && silent_for >= self.window {
+                state.alerting = true;
+                silent.push((launchpad.clone(), silent_for));
+            }
+        }
+        silent
+    }
+}
+
+/// Alerts when a normally busy launchpad stops producing launches while the stream is alive
+pub struct LaunchWatchdog {
+    config: WatchdogConfig,
+    clock: SharedClock,
+    #[cfg(feature = "webhook")]
+    http: reqwest::Client,
+}
+
+impl LaunchWatchdog {
+    pub fn new(config: WatchdogConfig) -> Self {
+        Self {
+            config,
+            clock: clock::system(),
+            #[cfg(feature = "webhook")]
+            http: reqwest::Client::new(),
+        }
+    }
+
+    /// Replaces the clock silence is measured with
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Starts the watchdog in a separate task
+    pub fn start(self) -> JoinHandle<()> {
+        tokio::spawn(async move { self.run().await })
+    }
+
+    async fn run(self) {
+        let window = Duration::from_secs(self.config.window_minutes * 60);
+        info!(
+            "🐕 Launch watchdog watching {:?} with a {} minute window",
+            self.config.launchpads, self.config.window_minutes
+        );
+
+        let mut silence = Silence::new(
+            &self.config.launchpads,
+            window,
+            self.clock.instant(),
+            transactions_total(),
+        );
+        let mut ticker = interval(CHECK_INTERVAL);
+
+        loop {
+            ticker.tick().await;
+            let silent = silence.check(self.clock.instant(), transactions_total());
+            for (launchpad, silent_for) in silent {
+                self.raise_alert(&launchpad, silent_for).await;
+            }
+        }
+    }
+
+    async fn raise_alert(&self, launchpad: &str, silent_for: Duration) {
+        error!(
+            "🚨 No {} launches detected for {}s while the Geyser stream is alive - \
+             possible discriminator or account layout change",
+            launchpad,
+            silent_for.as_secs()
+        );
+
+        metrics::global().inc_counter("watchdog_alerts_total", &[("launchpad", launchpad)]);
+        metrics::global().set_gauge("watchdog_silent", &[("launchpad", launchpad)], 1.0);
+
+        #[cfg(feature = "webhook")]
+        if let Some(url) = &self.config.webhook_url {
+            let alert = WatchdogAlert {
+                alert: "no_launches",
+                launchpad,
+                silent_for_secs: silent_for.as_secs(),
+                timestamp: self.clock.now(),
+            };
+
+            let result = self
+                .http
+                .post(url)
+                .json(&alert)
+                .send()
+                .await
+                .and_then(|response| response.error_for_status());
+
+            if let Err(e) = result {
+                warn!("Failed to deliver watchdog webhook: {}", e);
+            }
+        }
+    }
+}
+
+fn launches_total(launchpad: &str) -> u64 {
+    metrics::global().counter_value("launches_total", &[("launchpad", launchpad)])
+}
+
+fn transactions_total() -> u64 {
+    metrics::global().counter_value("geyser_transactions_total", &[])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::{Clock, MockClock};
+    use crate::testing::start_time;
+
+    const WINDOW: Duration = Duration::from_secs(600);
+
+    /// Checks after one interval, with a transaction received in it when `stream_alive`
+    fn tick(clock: &MockClock, silence: &mut Silence, stream_alive: bool) -> Vec<String> {
+        clock.advance(CHECK_INTERVAL);
+        let transactions = silence.last_transactions + u64::from(stream_alive);
+        silence
+            .check(clock.instant(), transactions)
+            .into_iter()
+            .map(|(launchpad, _)| launchpad)
+            .collect()
+    }
+
+    /// Alerts over `ticks` checks of a live stream
+    fn alerts(clock: &MockClock, silence: &mut Silence, ticks: u32) -> Vec<String> {
+        (0..ticks)
+            .flat_map(|_| tick(clock, silence, true))
+            .collect()
+    }
+
+    #[test]
+    fn silence_alerts_once_until_launches_resume() {
+        let clock = MockClock::new(start_time());
+        let mut silence = Silence::new(&["Silent".to_string()], WINDOW, clock.instant(), 0);
+
+        assert!(alerts(&clock, &mut silence, 19).is_empty());
+        assert_eq!(tick(&clock, &mut silence, true), vec!["Silent".to_string()]);
+        assert!(alerts(&clock, &mut silence, 40).is_empty());
+
+        metrics::global().inc_counter("launches_total", &[("launchpad", "Silent")]);
+        assert!(alerts(&clock, &mut silence, 19).is_empty());
+        assert_eq!(alerts(&clock, &mut silence, 40), vec!["Silent".to_string()]);
+    }
+
+    #[test]
+    fn stalled_stream_restarts_the_silence() {
+        let clock = MockClock::new(start_time());
+        let mut silence = Silence::new(&["Stalled".to_string()], WINDOW, clock.instant(), 0);
+
+        assert!(alerts(&clock, &mut silence, 19).is_empty());
+        assert!(tick(&clock, &mut silence, false).is_empty());
+        assert!(alerts(&clock, &mut silence, 19).is_empty());
+        assert_eq!(
+            tick(&clock, &mut silence, true),
+            vec!["Stalled".to_string()]
+        );
+    }
+}
@@ -0,0 +1,259 @@
+//! Decoders for launchpad account layouts, shared with the account tracker. Each takes the
+//! raw account data, discriminator included, and returns `None` when the data is not an
+//! account of that type or is too short.
+
+use serde::Serialize;
+
+use crate::parser::anchor::BorshReader;
+
+/// Pump BondingCurve account discriminator (pump.json)
+pub const PUMP_BONDING_CURVE: [u8; 8] = [23, 183, 248, 55, 96, 216, 172, 96];
+/// DBC VirtualPool account discriminator (meteoraDBC.json)
+pub const DBC_VIRTUAL_POOL: [u8; 8] = [213, 224, 5, 209, 98, 69, 119, 92];
+/// DBC PoolConfig account discriminator (meteoraDBC.json)
+pub const DBC_POOL_CONFIG: [u8; 8] = [26, 108, 14, 123, 116, 230, 129, 43];
+
+/// Real token reserves of a new pump.fun bonding curve, the curve graduates once they are sold
+pub const PUMP_INITIAL_REAL_TOKEN_RESERVES: u64 = 793_100_000_000_000;
+
+/// Points of a DBC curve, unused ones are zeroed
+const DBC_CURVE_POINTS: usize = 20;
+
+/// pump.fun bonding curve of a token
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BondingCurve {
+    pub virtual_token_reserves: u64,
+    pub virtual_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    pub real_sol_reserves: u64,
+    pub token_total_supply: u64,
+    /// Set once the curve is sold out and the token migrates
+    pub complete: bool,
+    /// Missing on curves created before creator fees were introduced
+    pub creator: Option<String>,
+}
+
+impl BondingCurve {
+    /// Share of the curve's tokens sold, in percent
+    pub fn progress(&self) -> f64 {
+        progress(self.real_token_reserves)
+    }
+}
+
+/// Share of a pump.fun curve's tokens sold given its real token reserves, in percent
+pub fn progress(real_token_reserves: u64) -> f64 {
+    100.0
+        * (1.0 - real_token_reserves as f64 / PUMP_INITIAL_REAL_TOKEN_RESERVES as f64)
+            .clamp(0.0, 1.0)
+}
+
+/// Meteora DBC pool of a token, zero-copy layout
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct VirtualPool {
+    /// Pool config account, see `PoolConfig`
+    pub config: String,
+    pub creator: String,
+    pub base_mint: String,
+    pub base_vault: String,
+    pub quote_vault: String,
+    pub base_reserve: u64,
+    pub quote_reserve: u64,
+    pub protocol_base_fee: u64,
+    pub protocol_quote_fee: u64,
+    pub partner_base_fee: u64,
+    pub partner_quote_fee: u64,
+    pub sqrt_price: u128,
+    pub activation_point: u64,
+    /// 0 for SPL Token, 1 for Token-2022
+    pub pool_type: u8,
+    pub is_migrated: bool,
+    pub migration_progress: u8,
+    pub finish_curve_timestamp: u64,
+    pub creator_base_fee: u64,
+    pub creator_quote_fee: u64,
+}
+
+/// Meteora DBC pool config, shared by the pools of a partner
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PoolConfig {
+    pub quote_mint: String,
+    pub fee_claimer: String,
+    pub leftover_receiver: String,
+    /// Base trading fee before any fee schedule, over 1e9
+    pub cliff_fee_numerator: u64,
+    pub protocol_fee_percent: u8,
+    pub referral_fee_percent: u8,
+    pub collect_fee_mode: u8,
+    pub migration_option: u8,
+    pub activation_type: u8,
+    pub token_decimal: u8,
+    pub token_type: u8,
+    pub partner_locked_lp_percentage: u8,
+    pub partner_lp_percentage: u8,
+    pub creator_locked_lp_percentage: u8,
+    pub creator_lp_percentage: u8,
+    pub fixed_token_supply: bool,
+    pub creator_trading_fee_percentage: u8,
+    pub swap_base_amount: u64,
+    pub migration_quote_threshold: u64,
+    pub migration_base_threshold: u64,
+    pub migration_sqrt_price: u128,
+    pub pre_migration_token_supply: u64,
+    pub post_migration_token_supply: u64,
+    pub sqrt_start_price: u128,
+    /// Used curve points, in order
+    pub curve: Vec<CurvePoint>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CurvePoint {
+    pub sqrt_price: u128,
+    pub liquidity: u128,
+}
+
+/// Decodes a pump.fun `BondingCurve` account
+pub fn decode_bonding_curve(data: &[u8]) -> Option<BondingCurve> {
+    let mut reader = BorshReader::new(data.strip_prefix(&PUMP_BONDING_CURVE)?);
+    Some(BondingCurve {
+        virtual_token_reserves: reader.u64()?,
+        virtual_sol_reserves: reader.u64()?,
+        real_token_reserves: reader.u64()?,
+        real_sol_reserves: reader.u64()?,
+        token_total_supply: reader.u64()?,
+        complete: reader.bool()?,
+        creator: reader.pubkey(),
+    })
+}
+
+/// Decodes a Meteora DBC `VirtualPool` account
+pub fn decode_virtual_pool(data: &[u8]) -> Option<VirtualPool> {
+    let mut reader = BorshReader::new(data.strip_prefix(&DBC_VIRTUAL_POOL)?);
+    // volatility_tracker
+    reader.skip(64)?;
+    let config = reader.pubkey()?;
+    let creator = reader.pubkey()?;
+    let base_mint = reader.pubkey()?;
+    let base_vault = reader.pubkey()?;
+    let quote_vault = reader.pubkey()?;
+    let base_reserve = reader.u64()?;
+    let quote_reserve = reader.u64()?;
+    let protocol_base_fee = reader.u64()?;
+    let protocol_quote_fee = reader.u64()?;
+    let partner_base_fee = reader.u64()?;
+    let partner_quote_fee = reader.u64()?;
+    let sqrt_price = reader.u128()?;
+    let activation_point = reader.u64()?;
+    let pool_type = reader.u8()?;
+    let is_migrated = reader.bool()?;
+    // is_partner_withdraw_surplus, is_protocol_withdraw_surplus
+    reader.skip(2)?;
+    let migration_progress = reader.u8()?;
+    // is_withdraw_leftover, is_creator_withdraw_surplus, migration_fee_withdraw_status, metrics
+    reader.skip(3 + 4 * 8)?;
+    Some(VirtualPool {
+        config,
+        creator,
+        base_mint,
+        base_vault,
+        quote_vault,
+        base_reserve,
+        quote_reserve,
+        protocol_base_fee,
+        protocol_quote_fee,
+        partner_base_fee,
+        partner_quote_fee,
+        sqrt_price,
+        activation_point,
+        pool_type,
+        is_migrated,
+        migration_progress,
+        finish_curve_timestamp: reader.u64()?,
+        creator_base_fee: reader.u64()?,
+        creator_quote_fee: reader.u64()?,
+    })
+}
+
+/// Decodes a Meteora DBC `PoolConfig` account
+pub fn decode_pool_config(data: &[u8]) -> Option<PoolConfig> {
+    let mut reader = BorshReader::new(data.strip_prefix(&DBC_POOL_CONFIG)?);
+    let quote_mint = reader.pubkey()?;
+    let fee_claimer = reader.pubkey()?;
+    let leftover_receiver = reader.pubkey()?;
+
+    // pool_fees: base fee (cliff_fee_numerator first, 32 bytes), dynamic fee (48),
+    // padding (46), protocol_fee_percent, referral_fee_percent
+    let cliff_fee_numerator = reader.u64()?;
+    reader.skip(24 + 48 + 46)?;
+    let protocol_fee_percent = reader.u8()?;
+    let referral_fee_percent = reader.u8()?;
+
+    let collect_fee_mode = reader.u8()?;
+    let migration_option = reader.u8()?;
+    let activation_type = reader.u8()?;
+    let token_decimal = reader.u8()?;
+    // version
+    reader.skip(1)?;
+    let token_type = reader.u8()?;
+    // quote_token_flag
+    reader.skip(1)?;
+    let partner_locked_lp_percentage = reader.u8()?;
+    let partner_lp_percentage = reader.u8()?;
+    let creator_locked_lp_percentage = reader.u8()?;
+    let creator_lp_percentage = reader.u8()?;
+    // migration_fee_option
+    reader.skip(1)?;
+    let fixed_token_supply = reader.bool()?;
+    let creator_trading_fee_percentage = reader.u8()?;
+    // token_update_authority, migration fee percentages, padding
+    reader.skip(3 + 7)?;
+
+    let swap_base_amount = reader.u64()?;
+    let migration_quote_threshold = reader.u64()?;
+    let migration_base_threshold = reader.u64()?;
+    let migration_sqrt_price = reader.u128()?;
+    // locked_vesting_config
+    reader.skip(6 * 8)?;
+    let pre_migration_token_supply = reader.u64()?;
+    let post_migration_token_supply = reader.u64()?;
+    reader.skip(2 * 16)?;
+    let sqrt_start_price = reader.u128()?;
+
+    let mut curve = Vec::new();
+    for _ in 0..DBC_CURVE_POINTS {
+        let point = CurvePoint {
+            sqrt_price: reader.u128()?,
+            liquidity: reader.u128()?,
+        };
+        if point.sqrt_price != 0 {
+            curve.push(point);
+        }
+    }
+
+    Some(PoolConfig {
+        quote_mint,
+        fee_claimer,
+        leftover_receiver,
+        cliff_fee_numerator,
+        protocol_fee_percent,
+        referral_fee_percent,
+        collect_fee_mode,
+        migration_option,
+        activation_type,
+        token_decimal,
+        token_type,
+        partner_locked_lp_percentage,
+        partner_lp_percentage,
+        creator_locked_lp_percentage,
+        creator_lp_percentage,
+        fixed_token_supply,
+        creator_trading_fee_percentage,
+        swap_base_amount,
+        migration_quote_threshold,
+        migration_base_threshold,
+        migration_sqrt_price,
+        pre_migration_token_supply,
+        post_migration_token_supply,
+        sqrt_start_price,
+        curve,
+    })
+}
@@ -0,0 +1,75 @@
+use crate::geyser::encoding::encode_pubkey;
+
+/// Prefix of Anchor `emit_cpi!` event instructions, `EVENT_IX_TAG` in little endian
+pub const EVENT_IX_TAG: [u8; 8] = [228, 69, 165, 46, 81, 203, 154, 29];
+
+/// Returns the borsh body of a self-CPI event instruction with the given event discriminator
+pub fn cpi_event<'a>(data: &'a [u8], discriminator: &[u8; 8]) -> Option<&'a [u8]> {
+    let rest = data.strip_prefix(EVENT_IX_TAG.as_slice())?;
+    rest.strip_prefix(discriminator.as_slice())
+}
+
+/// Minimal borsh reader for instruction arguments and event bodies
+pub struct BorshReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> BorshReader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        if self.data.len() < len {
+            return None;
+        }
+        let (head, rest) = self.data.split_at(len);
+        self.data = rest;
+        Some(head)
+    }
+
+    pub fn skip(&mut self, len: usize) -> Option<()> {
+        self.take(len).map(|_| ())
+    }
+
+    pub fn u8(&mut self) -> Option<u8> {
+        Some(self.take(1)?[0])
+    }
+
+    pub fn u16(&mut self) -> Option<u16> {
+        Some(u16::from_le_bytes(self.take(2)?.try_into().ok()?))
+    }
+
+    pub fn u64(&mut self) -> Option<u64> {
+        Some(u64::from_le_bytes(self.take(8)?.try_into().ok()?))
+    }
+
+    pub fn u128(&mut self) -> Option<u128> {
+        Some(u128::from_le_bytes(self.take(16)?.try_into().ok()?))
+    }
+
+    pub fn i64(&mut self) -> Option<i64> {
+        Some(i64::from_le_bytes(self.take(8)?.try_into().ok()?))
+    }
+
+    pub fn bool(&mut self) -> Option<bool> {
+        Some(self.take(1)?[0] != 0)
+    }
+
+    /// Reads u32 length prefixed bytes
+    pub fn bytes(&mut self) -> Option<&'a [u8]> {
+        let len = u32::from_le_bytes(self.take(4)?.try_into().ok()?) as usize;
+        self.take(len)
+    }
+
+    /// Reads a u32 length prefixed UTF-8 string
+    pub fn string(&mut self) -> Option<String> {
+        let len = u32::from_le_bytes(self.take(4)?.try_into().ok()?) as usize;
+        String::from_utf8(self.take(len)?.to_vec()).ok()
+    }
+
+    /// Reads a 32 byte public key as base58
+    pub fn pubkey(&mut self) -> Option<String> {
+        Some(encode_pubkey(self.take(32)?))
+    }
+}
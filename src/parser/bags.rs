@@ -0,0 +1,213 @@
+use crate::config::parsers::BagsConfig;
+use crate::geyser::{QueuedTransaction, TransactionInstruction};
+use crate::parser::spl;
+use crate::parser::{
+    EventKind, LaunchpadParser, LaunchpadType, MetadataField, ParseResult, ParserCapabilities,
+    PartialParse, TokenLaunch,
+};
+use tracing::{debug, info};
+
+/// Bags.fm launches. Token creation is a Bags instruction in a transaction that
+/// initializes a new mint, unless a create discriminator is configured.
+pub struct BagsParser {
+    config: BagsConfig,
+}
+
+impl BagsParser {
+    pub fn new(config: BagsConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl Default for BagsParser {
+    fn default() -> Self {
+        Self::new(BagsConfig::default())
+    }
+}
+
+impl LaunchpadParser for BagsParser {
+    fn get_program_ids(&self) -> Vec<String> {
+        self.config.program_ids.clone()
+    }
+
+    fn get_launchpad_type(&self) -> LaunchpadType {
+        LaunchpadType::Bags
+    }
+
+    fn capabilities(&self) -> ParserCapabilities {
+        ParserCapabilities {
+            events: vec![EventKind::TokenLaunch, EventKind::PartialParse],
+            metadata: vec![
+                MetadataField::Name,
+                MetadataField::Symbol,
+                MetadataField::Uri,
+                MetadataField::FeeRecipient,
+            ],
+        }
+    }
+
+    fn parse_transaction(
+        &self,
+        transaction: &QueuedTransaction,
+    ) -> Result<Vec<ParseResult>, Box<dyn std::error::Error + Send + Sync>> {
+        debug!("🔍 Parsing Bags transaction: {}", transaction.signature);
+
+        let create = transaction
+            .instructions
+            .iter()
+            .chain(&transaction.inner_instructions)
+            .find(|instr| {
+                self.config.program_ids.contains(&instr.program_id)
+                    && self
+                        .config
+                        .create_discriminator
+                        .is_none_or(|discriminator| instr.data.starts_with(&discriminator))
+            });
+        let Some(create) = create else {
+            return Ok(vec![ParseResult::NotRelevant]);
+        };
+
+        // Without a discriminator, trades and fee claims are told apart by not creating a mint
+        if self.config.create_discriminator.is_none()
+            && self.config.mint_account_index.is_none()
+            && spl::initialized_mint(transaction).is_none()
+        {
+            return Ok(vec![ParseResult::NotRelevant]);
+        }
+
+        info!(
+            "🎯 Found Bags create instruction in: {}",
+            transaction.signature
+        );
+        Ok(vec![self.extract_token_launch(transaction, create)])
+    }
+}
+
+impl BagsParser {
+    /// Extract token launch information from the create instruction
+    fn extract_token_launch(
+        &self,
+        transaction: &QueuedTransaction,
+        instruction: &TransactionInstruction,
+    ) -> ParseResult {
+        let account = |index: usize| {
+            instruction
+                .accounts
+                .get(index)
+                .and_then(|idx| transaction.accounts.get(*idx as usize))
+                .cloned()
+        };
+
+        let creator = match self.config.creator_account_index {
+            Some(index) => account(index),
+            None => transaction.instruction_signer(instruction),
+        };
+        let mint = match self.config.mint_account_index {
+            Some(index) => account(index),
+            None => spl::initialized_mint(transaction),
+        };
+        // Royalties often go to someone other than the launcher, e.g. a claimed social account
+        let fee_recipient = self
+            .config
+            .fee_recipient_account_index
+            .and_then(account)
+            .filter(|recipient| Some(recipient) != creator.as_ref());
+
+        let mut metadata = spl::created_metadata(transaction);
+        metadata.fee_recipient = fee_recipient;
+
+        let Some(mint_address) = mint else {
+            debug!("❌ Could not extract mint from Bags create instruction");
+            return ParseResult::PartialParse(PartialParse::new(
+                LaunchpadType::Bags,
+                "create_token",
+                transaction,
+                None,
+                creator,
+                metadata,
+            ));
+        };
+
+        let token_launch = TokenLaunch::new(
+            LaunchpadType::Bags,
+            transaction,
+            mint_address.clone(),
+            creator,
+            metadata,
+        );
+
+        debug!("✅ Extracted Bags token launch: {}", mint_address);
+        ParseResult::TokenLaunch(token_launch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{create_metadata, initialize_mint, instruction, transaction};
+
+    const PROGRAM_ID: &str = "BAGSB9TpGrZxQbEsrEznv5jXXdwyP6AXerN8aVRiAmcv";
+
+    fn parser(fee_recipient_account_index: Option<usize>) -> BagsParser {
+        BagsParser::new(BagsConfig {
+            program_ids: vec![PROGRAM_ID.to_string()],
+            create_discriminator: None,
+            mint_account_index: None,
+            creator_account_index: None,
+            fee_recipient_account_index,
+        })
+    }
+
+    /// Create by `launcher` paying royalties to `royalty_wallet`, initializing the mint
+    /// and its metadata
+    fn create_transaction() -> QueuedTransaction {
+        transaction(
+            &["launcher", "royalty_wallet", "mint", "metadata"],
+            vec![
+                instruction(PROGRAM_ID, &[0, 2, 1], vec![6; 8]),
+                initialize_mint(2, 9),
+                create_metadata(3, 2, "Bag", "BAG", "https://example.com/bag.json"),
+            ],
+            Vec::new(),
+        )
+    }
+
+    #[test]
+    fn create_reports_the_royalty_wallet() {
+        let results = parser(Some(2))
+            .parse_transaction(&create_transaction())
+            .unwrap();
+        let [ParseResult::TokenLaunch(launch)] = results.as_slice() else {
+            panic!("expected one launch, got {} results", results.len());
+        };
+        assert_eq!(launch.token_address, "mint");
+        assert_eq!(launch.creator.as_deref(), Some("launcher"));
+        assert_eq!(
+            launch.metadata.fee_recipient.as_deref(),
+            Some("royalty_wallet")
+        );
+        assert_eq!(launch.metadata.symbol.as_deref(), Some("BAG"));
+    }
+
+    #[test]
+    fn royalty_wallet_of_the_creator_is_left_out() {
+        let results = parser(Some(0))
+            .parse_transaction(&create_transaction())
+            .unwrap();
+        let [ParseResult::TokenLaunch(launch)] = results.as_slice() else {
+            panic!("expected one launch, got {} results", results.len());
+        };
+        assert_eq!(launch.metadata.fee_recipient, None);
+    }
+
+    #[test]
+    fn instructions_without_a_new_mint_are_not_relevant() {
+        let transaction = transaction(
+            &["trader"],
+            vec![instruction(PROGRAM_ID, &[0], vec![6; 8])],
+            Vec::new(),
+        );
+        let results = parser(None).parse_transaction(&transaction).unwrap();
+        assert!(matches!(results.as_slice(), [ParseResult::NotRelevant]));
+    }
+}
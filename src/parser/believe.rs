@@ -0,0 +1,193 @@
+use crate::config::parsers::BelieveConfig;
+use crate::geyser::{QueuedTransaction, TransactionInstruction};
+use crate::parser::spl;
+use crate::parser::{
+    EventKind, LaunchpadParser, LaunchpadType, MetadataField, ParseResult, ParserCapabilities,
+    PartialParse, TokenLaunch,
+};
+use tracing::{debug, info};
+
+pub struct BelieveParser {
+    config: BelieveConfig,
+}
+
+impl BelieveParser {
+    pub fn new(config: BelieveConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl Default for BelieveParser {
+    fn default() -> Self {
+        Self::new(BelieveConfig::default())
+    }
+}
+
+impl LaunchpadParser for BelieveParser {
+    fn get_program_ids(&self) -> Vec<String> {
+        self.config.program_ids.clone()
+    }
+
+    fn get_launchpad_type(&self) -> LaunchpadType {
+        LaunchpadType::Believe
+    }
+
+    fn capabilities(&self) -> ParserCapabilities {
+        ParserCapabilities {
+            events: vec![EventKind::TokenLaunch, EventKind::PartialParse],
+            metadata: MetadataField::METAPLEX.to_vec(),
+        }
+    }
+
+    fn parse_transaction(
+        &self,
+        transaction: &QueuedTransaction,
+    ) -> Result<Vec<ParseResult>, Box<dyn std::error::Error + Send + Sync>> {
+        debug!("🔍 Parsing Believe transaction: {}", transaction.signature);
+
+        let create = transaction
+            .instructions
+            .iter()
+            .chain(&transaction.inner_instructions)
+            .find(|instr| {
+                self.config.program_ids.contains(&instr.program_id)
+                    && instr.data.starts_with(&self.config.create_discriminator)
+            });
+
+        let Some(create) = create else {
+            return Ok(vec![ParseResult::NotRelevant]);
+        };
+
+        info!(
+            "🎯 Found Believe create instruction in: {}",
+            transaction.signature
+        );
+        Ok(vec![self.extract_token_launch(transaction, create)])
+    }
+}
+
+impl BelieveParser {
+    /// Extract token launch information from the create instruction
+    fn extract_token_launch(
+        &self,
+        transaction: &QueuedTransaction,
+        instruction: &TransactionInstruction,
+    ) -> ParseResult {
+        let account = |index: usize| {
+            instruction
+                .accounts
+                .get(index)
+                .and_then(|idx| transaction.accounts.get(*idx as usize))
+                .cloned()
+        };
+
+        let creator = match self.config.creator_account_index {
+            Some(index) => account(index),
+            None => transaction.instruction_signer(instruction),
+        };
+        let mint = match self.config.mint_account_index {
+            Some(index) => account(index),
+            None => spl::initialized_mint(transaction),
+        };
+        // Create arguments are not decoded, metadata comes from the Metaplex account
+        let metadata = spl::created_metadata(transaction);
+
+        let Some(mint_address) = mint else {
+            debug!("❌ Could not extract mint from Believe create instruction");
+            return ParseResult::PartialParse(PartialParse::new(
+                LaunchpadType::Believe,
+                "create",
+                transaction,
+                None,
+                creator,
+                metadata,
+            ));
+        };
+
+        let token_launch = TokenLaunch::new(
+            LaunchpadType::Believe,
+            transaction,
+            mint_address.clone(),
+            creator,
+            metadata,
+        );
+
+        debug!("✅ Extracted Believe token launch: {}", mint_address);
+        ParseResult::TokenLaunch(token_launch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{create_metadata, initialize_mint, instruction, transaction};
+
+    const PROGRAM_ID: &str = "Believe111111111111111111111111111111111111";
+    const CREATE: [u8; 8] = [1, 1, 2, 3, 5, 8, 13, 21];
+
+    fn parser(mint_account_index: Option<usize>) -> BelieveParser {
+        BelieveParser::new(BelieveConfig {
+            program_ids: vec![PROGRAM_ID.to_string()],
+            create_discriminator: CREATE,
+            mint_account_index,
+            creator_account_index: None,
+        })
+    }
+
+    /// Create co-signed by the creator and paid by a relayer, which initializes the mint
+    /// and its Metaplex metadata
+    fn create_transaction() -> QueuedTransaction {
+        let mut transaction = transaction(
+            &["relayer", "creator", "mint", "metadata"],
+            vec![
+                instruction(PROGRAM_ID, &[0, 1, 2, 3], CREATE.to_vec()),
+                initialize_mint(2, 6),
+                create_metadata(3, 2, "Believe Token", "BLV", "https://example.com/blv.json"),
+            ],
+            Vec::new(),
+        );
+        transaction.signers.push("creator".to_string());
+        transaction
+    }
+
+    #[test]
+    fn create_takes_the_initialized_mint_and_its_metadata() {
+        let results = parser(None)
+            .parse_transaction(&create_transaction())
+            .unwrap();
+        let [ParseResult::TokenLaunch(launch)] = results.as_slice() else {
+            panic!("expected one launch, got {} results", results.len());
+        };
+        assert_eq!(launch.token_address, "mint");
+        assert_eq!(launch.creator.as_deref(), Some("creator"));
+        assert_eq!(launch.metadata.name.as_deref(), Some("Believe Token"));
+        assert_eq!(launch.metadata.symbol.as_deref(), Some("BLV"));
+        assert_eq!(
+            launch.metadata.uri.as_deref(),
+            Some("https://example.com/blv.json")
+        );
+    }
+
+    #[test]
+    fn configured_mint_index_outside_the_accounts_is_a_partial_parse() {
+        let results = parser(Some(7))
+            .parse_transaction(&create_transaction())
+            .unwrap();
+        let [ParseResult::PartialParse(partial)] = results.as_slice() else {
+            panic!("expected one partial parse, got {} results", results.len());
+        };
+        assert_eq!(partial.token_address, None);
+        assert_eq!(partial.creator.as_deref(), Some("creator"));
+    }
+
+    #[test]
+    fn other_instructions_are_not_relevant() {
+        let transaction = transaction(
+            &["creator"],
+            vec![instruction(PROGRAM_ID, &[0], vec![9; 8])],
+            Vec::new(),
+        );
+        let results = parser(None).parse_transaction(&transaction).unwrap();
+        assert!(matches!(results.as_slice(), [ParseResult::NotRelevant]));
+    }
+}
@@ -0,0 +1,147 @@
+use crate::config::parsers::DaosFunConfig;
+use crate::geyser::{QueuedTransaction, TransactionInstruction};
+use crate::parser::spl;
+use crate::parser::{
+    EventKind, LaunchpadParser, LaunchpadType, MetadataField, ParseResult, ParserCapabilities,
+    TokenLaunch,
+};
+use tracing::{debug, info};
+
+/// Daos.fun fund launches. A fund creation is recognized as a Daos.fun instruction in a
+/// transaction that initializes a new mint, the DAO token, so the instruction layout
+/// need not be decoded.
+pub struct DaosFunParser {
+    config: DaosFunConfig,
+}
+
+impl DaosFunParser {
+    pub fn new(config: DaosFunConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl Default for DaosFunParser {
+    fn default() -> Self {
+        Self::new(DaosFunConfig::default())
+    }
+}
+
+impl LaunchpadParser for DaosFunParser {
+    fn get_program_ids(&self) -> Vec<String> {
+        self.config.program_ids.clone()
+    }
+
+    fn get_launchpad_type(&self) -> LaunchpadType {
+        LaunchpadType::DaosFun
+    }
+
+    fn capabilities(&self) -> ParserCapabilities {
+        ParserCapabilities {
+            events: vec![EventKind::TokenLaunch],
+            metadata: MetadataField::METAPLEX.to_vec(),
+        }
+    }
+
+    fn parse_transaction(
+        &self,
+        transaction: &QueuedTransaction,
+    ) -> Result<Vec<ParseResult>, Box<dyn std::error::Error + Send + Sync>> {
+        debug!("🔍 Parsing Daos.fun transaction: {}", transaction.signature);
+
+        let create = transaction
+            .instructions
+            .iter()
+            .chain(&transaction.inner_instructions)
+            .find(|instr| {
+                self.config.program_ids.contains(&instr.program_id)
+                    && self
+                        .config
+                        .create_discriminator
+                        .is_none_or(|discriminator| instr.data.starts_with(&discriminator))
+            });
+        let Some(create) = create else {
+            return Ok(vec![ParseResult::NotRelevant]);
+        };
+
+        // Trades and fund management don't create mints
+        let Some(mint) = spl::initialized_mint(transaction) else {
+            return Ok(vec![ParseResult::NotRelevant]);
+        };
+
+        info!(
+            "🎯 Found Daos.fun fund creation in: {}",
+            transaction.signature
+        );
+        Ok(vec![self.extract_token_launch(transaction, create, mint)])
+    }
+}
+
+impl DaosFunParser {
+    /// Extract token launch information from the fund creation
+    fn extract_token_launch(
+        &self,
+        transaction: &QueuedTransaction,
+        instruction: &TransactionInstruction,
+        mint_address: String,
+    ) -> ParseResult {
+        let creator = transaction.instruction_signer(instruction);
+        let metadata = spl::created_metadata(transaction);
+
+        let token_launch = TokenLaunch::new(
+            LaunchpadType::DaosFun,
+            transaction,
+            mint_address.clone(),
+            creator,
+            metadata,
+        );
+
+        debug!("✅ Extracted Daos.fun token launch: {}", mint_address);
+        ParseResult::TokenLaunch(token_launch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{create_metadata, initialize_mint, instruction, transaction};
+
+    const PROGRAM_ID: &str = "5jnapfrAN47UYkLkEf7HnprPPBCQLvkYWGZDeKkaP5hv";
+
+    #[test]
+    fn fund_creation_reports_the_initialized_dao_token() {
+        let transaction = transaction(
+            &["manager", "dao_mint", "metadata"],
+            vec![
+                instruction(PROGRAM_ID, &[0, 1], vec![4; 8]),
+                initialize_mint(1, 6),
+                create_metadata(2, 1, "Fund", "FUND", "https://example.com/fund.json"),
+            ],
+            Vec::new(),
+        );
+
+        let results = DaosFunParser::default()
+            .parse_transaction(&transaction)
+            .unwrap();
+        let [ParseResult::TokenLaunch(launch)] = results.as_slice() else {
+            panic!("expected one launch, got {} results", results.len());
+        };
+        assert!(matches!(launch.launchpad, LaunchpadType::DaosFun));
+        assert_eq!(launch.token_address, "dao_mint");
+        assert_eq!(launch.creator.as_deref(), Some("manager"));
+        assert_eq!(launch.metadata.symbol.as_deref(), Some("FUND"));
+    }
+
+    #[test]
+    fn instructions_without_a_new_mint_are_not_relevant() {
+        let transaction = transaction(
+            &["trader"],
+            vec![instruction(PROGRAM_ID, &[0], vec![4; 8])],
+            Vec::new(),
+        );
+
+        let results = DaosFunParser::default()
+            .parse_transaction(&transaction)
+            .unwrap();
+        assert!(matches!(results.as_slice(), [ParseResult::NotRelevant]));
+    }
+}
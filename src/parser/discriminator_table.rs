@@ -0,0 +1,224 @@
+use crate::config::parsers::DiscriminatorEntry;
+use crate::geyser::{QueuedTransaction, TransactionInstruction};
+use crate::parser::spl;
+use crate::parser::{
+    EventKind, LaunchpadParser, LaunchpadType, MetadataField, ParseResult, ParserCapabilities,
+    PartialParse, TokenLaunch,
+};
+use tracing::{debug, error, info};
+
+/// Launches of one program recognized from a table of discriminators and account positions
+/// in the config file. Lets operators cover niche launchpads whose layout is not decoded,
+/// without an IDL or a release.
+pub struct DiscriminatorTableParser {
+    program_id: String,
+    entries: Vec<DiscriminatorEntry>,
+}
+
+impl DiscriminatorTableParser {
+    /// One parser per program in the table, in order of first appearance. Entries with an
+    /// empty discriminator would match every instruction and are skipped.
+    pub fn from_table(table: &[DiscriminatorEntry]) -> Vec<Self> {
+        let mut parsers: Vec<Self> = Vec::new();
+        for entry in table {
+            if entry.discriminator.is_empty() {
+                error!(
+                    "❌ Skipping {} discriminator table entry without discriminator",
+                    entry.launchpad_label
+                );
+                continue;
+            }
+            match parsers
+                .iter_mut()
+                .find(|parser| parser.program_id == entry.program_id)
+            {
+                Some(parser) => parser.entries.push(entry.clone()),
+                None => parsers.push(Self {
+                    program_id: entry.program_id.clone(),
+                    entries: vec![entry.clone()],
+                }),
+            }
+        }
+        parsers
+    }
+}
+
+impl LaunchpadParser for DiscriminatorTableParser {
+    fn get_program_ids(&self) -> Vec<String> {
+        vec![self.program_id.clone()]
+    }
+
+    /// The label of the program's first entry, events carry their own entry's label
+    fn get_launchpad_type(&self) -> LaunchpadType {
+        LaunchpadType::Custom(self.entries[0].launchpad_label.clone())
+    }
+
+    fn capabilities(&self) -> ParserCapabilities {
+        ParserCapabilities {
+            events: vec![EventKind::TokenLaunch, EventKind::PartialParse],
+            metadata: MetadataField::METAPLEX.to_vec(),
+        }
+    }
+
+    fn parse_transaction(
+        &self,
+        transaction: &QueuedTransaction,
+    ) -> Result<Vec<ParseResult>, Box<dyn std::error::Error + Send + Sync>> {
+        debug!(
+            "🔍 Parsing {} transaction: {}",
+            self.program_id, transaction.signature
+        );
+
+        let mut results = Vec::new();
+        for instr in transaction
+            .instructions
+            .iter()
+            .chain(&transaction.inner_instructions)
+        {
+            if instr.program_id != self.program_id {
+                continue;
+            }
+            let Some(entry) = self
+                .entries
+                .iter()
+                .find(|entry| instr.data.starts_with(&entry.discriminator))
+            else {
+                continue;
+            };
+            info!(
+                "🎯 Found {} launch instruction in: {}",
+                entry.launchpad_label, transaction.signature
+            );
+            results.push(self.extract_token_launch(transaction, instr, entry));
+        }
+
+        if results.is_empty() {
+            results.push(ParseResult::NotRelevant);
+        }
+        Ok(results)
+    }
+}
+
+impl DiscriminatorTableParser {
+    /// Extract token launch information from the instruction matched by `entry`
+    fn extract_token_launch(
+        &self,
+        transaction: &QueuedTransaction,
+        instruction: &TransactionInstruction,
+        entry: &DiscriminatorEntry,
+    ) -> ParseResult {
+        let launchpad = LaunchpadType::Custom(entry.launchpad_label.clone());
+        let account = |index: usize| {
+            instruction
+                .accounts
+                .get(index)
+                .and_then(|idx| transaction.accounts.get(*idx as usize))
+                .cloned()
+        };
+        let creator = match entry.creator_account_index {
+            Some(index) => account(index),
+            None => transaction.instruction_signer(instruction),
+        };
+        let metadata = spl::created_metadata(transaction);
+
+        let Some(mint_address) = account(entry.mint_account_index) else {
+            debug!(
+                "❌ Could not extract mint from {} instruction",
+                entry.launchpad_label
+            );
+            return ParseResult::PartialParse(PartialParse::new(
+                launchpad,
+                "launch",
+                transaction,
+                None,
+                creator,
+                metadata,
+            ));
+        };
+
+        let token_launch = TokenLaunch::new(
+            launchpad,
+            transaction,
+            mint_address.clone(),
+            creator,
+            metadata,
+        );
+
+        debug!(
+            "✅ Extracted {} token launch: {}",
+            entry.launchpad_label, mint_address
+        );
+        ParseResult::TokenLaunch(token_launch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{instruction, transaction};
+
+    const PROGRAM_ID: &str = "Niche111111111111111111111111111111111111111";
+
+    fn entry(discriminator: Vec<u8>, mint_account_index: usize, label: &str) -> DiscriminatorEntry {
+        DiscriminatorEntry {
+            program_id: PROGRAM_ID.to_string(),
+            discriminator,
+            mint_account_index,
+            creator_account_index: Some(0),
+            launchpad_label: label.to_string(),
+        }
+    }
+
+    #[test]
+    fn table_is_grouped_by_program_without_empty_discriminators() {
+        let parsers = DiscriminatorTableParser::from_table(&[
+            entry(vec![1; 8], 1, "Niche"),
+            entry(Vec::new(), 1, "Everything"),
+            entry(vec![2; 8], 2, "Niche v2"),
+        ]);
+
+        let [parser] = parsers.as_slice() else {
+            panic!("expected one parser, got {}", parsers.len());
+        };
+        assert_eq!(parser.get_program_ids(), vec![PROGRAM_ID.to_string()]);
+        assert_eq!(parser.entries.len(), 2);
+        assert_eq!(parser.get_launchpad_type().to_string(), "Niche");
+    }
+
+    #[test]
+    fn launch_carries_its_entry_label_and_accounts() {
+        let parsers = DiscriminatorTableParser::from_table(&[
+            entry(vec![1; 8], 1, "Niche"),
+            entry(vec![2; 8], 2, "Niche v2"),
+        ]);
+        let transaction = transaction(
+            &["creator", "other", "mint"],
+            vec![instruction(PROGRAM_ID, &[0, 1, 2], vec![2; 8])],
+            Vec::new(),
+        );
+
+        let results = parsers[0].parse_transaction(&transaction).unwrap();
+        let [ParseResult::TokenLaunch(launch)] = results.as_slice() else {
+            panic!("expected one launch, got {} results", results.len());
+        };
+        assert_eq!(launch.launchpad.to_string(), "Niche v2");
+        assert_eq!(launch.token_address, "mint");
+        assert_eq!(launch.creator.as_deref(), Some("creator"));
+    }
+
+    #[test]
+    fn missing_mint_account_is_a_partial_parse() {
+        let parsers = DiscriminatorTableParser::from_table(&[entry(vec![1; 8], 5, "Niche")]);
+        let transaction = transaction(
+            &["creator", "mint"],
+            vec![instruction(PROGRAM_ID, &[0, 1], vec![1; 8])],
+            Vec::new(),
+        );
+
+        let results = parsers[0].parse_transaction(&transaction).unwrap();
+        let [ParseResult::PartialParse(partial)] = results.as_slice() else {
+            panic!("expected one partial parse, got {} results", results.len());
+        };
+        assert_eq!(partial.creator.as_deref(), Some("creator"));
+    }
+}
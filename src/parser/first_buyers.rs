@@ -0,0 +1,144 @@
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::config::first_buyers::FirstBuyersConfig;
+use crate::parser::{FirstBuyer, FirstBuyers, LaunchpadType, TokenLaunch, Trade};
+
+/// Launch whose first buyers are being collected
+struct PendingLaunch {
+    launchpad: LaunchpadType,
+    creator: Option<String>,
+    signature: String,
+    slot: u64,
+    timestamp: DateTime<Utc>,
+    block_time: Option<DateTime<Utc>>,
+    buyers: Vec<FirstBuyer>,
+}
+
+/// Collects the buys of each launch within its first slots, so snipers show up in one
+/// summary rather than scattered trades
+pub struct FirstBuyersCollector {
+    config: FirstBuyersConfig,
+    pending: Mutex<HashMap<String, PendingLaunch>>,
+    /// Newest slot the windows were checked against, so each slot is checked once
+    checked_slot: Mutex<u64>,
+}
+
+impl FirstBuyersCollector {
+    pub fn new(config: FirstBuyersConfig) -> Self {
+        Self {
+            config,
+            pending: Mutex::default(),
+            checked_slot: Mutex::default(),
+        }
+    }
+
+    /// Opens the window of a launch, whether there was room for it
+    pub fn record_launch(&self, launch: &TokenLaunch) -> bool {
+        let mut pending = self.pending.lock().unwrap();
+        if pending.len() >= self.config.max_pending && !pending.contains_key(&launch.token_address)
+        {
+            return false;
+        }
+        pending
+            .entry(launch.token_address.clone())
+            .or_insert_with(|| PendingLaunch {
+                launchpad: launch.launchpad.clone(),
+                creator: launch.creator.clone(),
+                signature: launch.signature.clone(),
+                slot: launch.slot,
+                timestamp: launch.timestamp,
+                block_time: launch.block_time,
+                buyers: Vec::new(),
+            });
+        true
+    }
+
+    /// Adds a buy of a launch within its window
+    pub fn record_trade(&self, trade: &Trade) {
+        if !trade.is_buy {
+            return;
+        }
+        let mut pending = self.pending.lock().unwrap();
+        let Some(launch) = pending.get_mut(&trade.token_address) else {
+            return;
+        };
+        if trade.slot > launch.slot.saturating_add(self.config.window_slots) {
+            return;
+        }
+
+        if let Some(buyer) = launch
+            .buyers
+            .iter_mut()
+            .find(|buyer| buyer.wallet == trade.trader)
+        {
+            buyer.buys += 1;
+            buyer.sol_amount = buyer.sol_amount.saturating_add(trade.amount);
+            buyer.token_amount = buyer.token_amount.saturating_add(trade.token_amount);
+            buyer.in_launch_transaction |= trade.signature == launch.signature;
+            return;
+        }
+        if launch.buyers.len() >= self.config.max_buyers {
+            return;
+        }
+        launch.buyers.push(FirstBuyer {
+            wallet: trade.trader.clone(),
+            buys: 1,
+            sol_amount: trade.amount,
+            token_amount: trade.token_amount,
+            first_slot: trade.slot,
+            first_transaction_index: trade.transaction_index,
+            in_launch_transaction: trade.signature == launch.signature,
+            is_creator: launch.creator.as_ref() == Some(&trade.trader),
+        });
+    }
+
+    /// Summaries of the launches whose window closed before `slot`. Launches nobody
+    /// bought are dropped.
+    pub fn take_closed(&self, slot: u64) -> Vec<FirstBuyers> {
+        {
+            let mut checked_slot = self.checked_slot.lock().unwrap();
+            if slot <= *checked_slot {
+                return Vec::new();
+            }
+            *checked_slot = slot;
+        }
+
+        let mut pending = self.pending.lock().unwrap();
+        let closed: Vec<String> = pending
+            .iter()
+            .filter(|(_, launch)| launch.slot.saturating_add(self.config.window_slots) < slot)
+            .map(|(mint, _)| mint.clone())
+            .collect();
+
+        closed
+            .into_iter()
+            .filter_map(|mint| {
+                let mut launch = pending.remove(&mint)?;
+                if launch.buyers.is_empty() {
+                    return None;
+                }
+                launch.buyers.sort_by_key(|buyer| {
+                    (
+                        buyer.first_slot,
+                        buyer.first_transaction_index.unwrap_or(u64::MAX),
+                    )
+                });
+                Some(FirstBuyers {
+                    launchpad: launch.launchpad,
+                    token_address: mint,
+                    creator: launch.creator,
+                    launch_signature: launch.signature,
+                    launch_slot: launch.slot,
+                    window_slots: self.config.window_slots,
+                    total_sol_amount: launch.buyers.iter().map(|buyer| buyer.sol_amount).sum(),
+                    buyers: launch.buyers,
+                    timestamp: launch.timestamp,
+                    block_time: launch.block_time,
+                    links: None,
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,259 @@
+use crate::geyser::{QueuedTransaction, TransactionInstruction};
+use crate::parser::{
+    EventKind, LaunchpadParser, LaunchpadType, MetadataField, ParseResult, ParserCapabilities,
+    PartialParse, TokenLaunch, spl,
+};
+use tracing::{debug, info};
+
+/// Token swap `Initialize` instruction tag, creating a pool
+const INITIALIZE_TAG: u8 = 0;
+
+/// Other Fluxbeam instruction tags
+const INSTRUCTIONS: &[(&str, u8)] = &[
+    ("swap", 1),
+    ("deposit_all_token_types", 2),
+    ("withdraw_all_token_types", 3),
+    ("deposit_single_token_type_exact_amount_in", 4),
+    ("withdraw_single_token_type_exact_amount_out", 5),
+];
+
+/// Account positions in the `Initialize` instruction
+const POOL_ACCOUNT_INDEX: usize = 0; // `swap`
+const TOKEN_A_ACCOUNT_INDEX: usize = 2; // `token_a` vault
+const TOKEN_B_ACCOUNT_INDEX: usize = 3; // `token_b` vault
+const POOL_MINT_ACCOUNT_INDEX: usize = 4; // LP mint
+
+/// Fluxbeam pool launches. Fluxbeam's token creator mints Token-2022 tokens without calling
+/// its program, so a launch is recognized by the pool creation, a fork of the SPL token
+/// swap `Initialize`. The instruction takes the pool's vaults, whose mints are found in
+/// the transaction's token account initializations, or else the mint it creates.
+pub struct FluxbeamParser {
+    program_id: String,
+}
+
+impl FluxbeamParser {
+    pub fn new() -> Self {
+        Self {
+            program_id: "FLUXubRmkEi2q6K3Y9kBPg9248ggaZVsoSFhtJHSrm1X".to_string(),
+        }
+    }
+}
+
+impl Default for FluxbeamParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LaunchpadParser for FluxbeamParser {
+    fn get_program_ids(&self) -> Vec<String> {
+        vec![self.program_id.clone()]
+    }
+
+    fn get_launchpad_type(&self) -> LaunchpadType {
+        LaunchpadType::Fluxbeam
+    }
+
+    fn capabilities(&self) -> ParserCapabilities {
+        ParserCapabilities {
+            events: vec![EventKind::TokenLaunch, EventKind::PartialParse],
+            metadata: MetadataField::METAPLEX.to_vec(),
+        }
+    }
+
+    fn parse_transaction(
+        &self,
+        transaction: &QueuedTransaction,
+    ) -> Result<Vec<ParseResult>, Box<dyn std::error::Error + Send + Sync>> {
+        debug!("🔍 Parsing Fluxbeam transaction: {}", transaction.signature);
+
+        let mut results = Vec::new();
+        for instr in transaction
+            .instructions
+            .iter()
+            .chain(&transaction.inner_instructions)
+        {
+            if instr.program_id != self.program_id {
+                continue;
+            }
+            let Some(tag) = instr.data.first() else {
+                continue;
+            };
+
+            if *tag == INITIALIZE_TAG {
+                info!(
+                    "🎯 Found Fluxbeam pool initialization in: {}",
+                    transaction.signature
+                );
+                results.push(self.extract_token_launch(transaction, instr));
+            } else if let Some((name, _)) = INSTRUCTIONS.iter().find(|(_, t)| t == tag) {
+                results.push(ParseResult::Other {
+                    launchpad: LaunchpadType::Fluxbeam,
+                    event_type: name.to_string(),
+                    detail: None,
+                    signature: transaction.signature.clone(),
+                });
+            }
+        }
+
+        if results.is_empty() {
+            results.push(ParseResult::NotRelevant);
+        }
+        Ok(results)
+    }
+}
+
+impl FluxbeamParser {
+    /// Extract token launch information from the pool initialization
+    fn extract_token_launch(
+        &self,
+        transaction: &QueuedTransaction,
+        instruction: &TransactionInstruction,
+    ) -> ParseResult {
+        let account = |index: usize| {
+            instruction
+                .accounts
+                .get(index)
+                .and_then(|idx| transaction.accounts.get(*idx as usize))
+                .cloned()
+        };
+        let vault_mint = |index: usize| {
+            account(index).and_then(|vault| spl::initialized_account_mint(transaction, &vault))
+        };
+        // The swap account signs as a fresh keypair, the fee payer is the one creating the pool
+        let creator = transaction.fee_payer.clone();
+        let metadata = spl::created_metadata(transaction);
+
+        let pool_mint = account(POOL_MINT_ACCOUNT_INDEX);
+        let token = match (
+            vault_mint(TOKEN_A_ACCOUNT_INDEX),
+            vault_mint(TOKEN_B_ACCOUNT_INDEX),
+        ) {
+            (Some(mint_a), Some(mint_b)) => Some(spl::split_pair(mint_a, mint_b).0),
+            _ => spl::initialized_mints(transaction)
+                .into_iter()
+                .find(|mint| Some(mint) != pool_mint.as_ref()),
+        };
+
+        let Some(mint_address) = token else {
+            debug!("❌ Could not extract mint from Fluxbeam pool initialization");
+            return ParseResult::PartialParse(PartialParse::new(
+                LaunchpadType::Fluxbeam,
+                "initialize",
+                transaction,
+                None,
+                creator,
+                metadata,
+            ));
+        };
+
+        let token_launch = TokenLaunch::new(
+            LaunchpadType::Fluxbeam,
+            transaction,
+            mint_address.clone(),
+            creator,
+            metadata,
+        )
+        .with_pool(account(POOL_ACCOUNT_INDEX));
+
+        debug!("✅ Extracted Fluxbeam token launch: {}", mint_address);
+        ParseResult::TokenLaunch(token_launch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{initialize_mint, instruction, transaction};
+
+    const PROGRAM_ID: &str = "FLUXubRmkEi2q6K3Y9kBPg9248ggaZVsoSFhtJHSrm1X";
+    const TOKEN_2022_PROGRAM_ID: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PMa9U1zx5Vf2wZ";
+    const WSOL_MINT: &str = "So11111111111111111111111111111111111111112";
+
+    /// Token-2022 `InitializeAccount3` of the vault at `account` for the mint at `mint`
+    fn initialize_vault(account: u8, mint: u8) -> TransactionInstruction {
+        let mut data = vec![18];
+        data.extend([5; 32]);
+        instruction(TOKEN_2022_PROGRAM_ID, &[account, mint], data)
+    }
+
+    #[test]
+    fn pool_initialization_takes_the_token_from_the_vaults() {
+        let transaction = transaction(
+            &[
+                "payer",
+                "swap",
+                "authority",
+                "vault_a",
+                "vault_b",
+                "lp_mint",
+                WSOL_MINT,
+                "mint",
+            ],
+            vec![
+                initialize_vault(3, 6),
+                initialize_vault(4, 7),
+                instruction(PROGRAM_ID, &[1, 2, 3, 4, 5], vec![INITIALIZE_TAG]),
+            ],
+            Vec::new(),
+        );
+
+        let results = FluxbeamParser::new()
+            .parse_transaction(&transaction)
+            .unwrap();
+        let [ParseResult::TokenLaunch(launch)] = results.as_slice() else {
+            panic!("expected one launch, got {} results", results.len());
+        };
+        assert!(matches!(launch.launchpad, LaunchpadType::Fluxbeam));
+        assert_eq!(launch.token_address, "mint");
+        assert_eq!(launch.creator.as_deref(), Some("payer"));
+        assert_eq!(launch.pool.as_deref(), Some("swap"));
+    }
+
+    #[test]
+    fn pool_initialization_falls_back_to_the_created_mint() {
+        // Vaults created beforehand, only the token and LP mints are initialized here
+        let transaction = transaction(
+            &[
+                "payer",
+                "swap",
+                "authority",
+                "vault_a",
+                "vault_b",
+                "lp_mint",
+                "mint",
+            ],
+            vec![
+                initialize_mint(5, 9),
+                initialize_mint(6, 9),
+                instruction(PROGRAM_ID, &[1, 2, 3, 4, 5], vec![INITIALIZE_TAG]),
+            ],
+            Vec::new(),
+        );
+
+        let results = FluxbeamParser::new()
+            .parse_transaction(&transaction)
+            .unwrap();
+        let [ParseResult::TokenLaunch(launch)] = results.as_slice() else {
+            panic!("expected one launch, got {} results", results.len());
+        };
+        assert_eq!(launch.token_address, "mint");
+    }
+
+    #[test]
+    fn swap_is_reported_by_name() {
+        let transaction = transaction(
+            &["trader"],
+            vec![instruction(PROGRAM_ID, &[0], vec![1])],
+            Vec::new(),
+        );
+
+        let results = FluxbeamParser::new()
+            .parse_transaction(&transaction)
+            .unwrap();
+        let [ParseResult::Other { event_type, .. }] = results.as_slice() else {
+            panic!("expected one instruction, got {} results", results.len());
+        };
+        assert_eq!(event_type, "swap");
+    }
+}
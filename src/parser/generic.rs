@@ -0,0 +1,338 @@
+use anyhow::{Context, Result, bail};
+use serde_json::Value;
+use std::collections::HashMap;
+use tracing::{debug, info};
+
+use crate::config::parsers::{AccountRef, GenericAnchorConfig};
+use crate::geyser::{QueuedTransaction, TransactionInstruction};
+use crate::idl::{self, Idl, IdlField, Namespace};
+use crate::parser::anchor::BorshReader;
+use crate::parser::launchpad_parser::LaunchMetadata;
+use crate::parser::{
+    EventKind, LaunchpadParser, LaunchpadType, MetadataField, ParseResult, ParserCapabilities,
+    PartialParse, TokenLaunch,
+};
+
+/// Anchor launchpad parsed from configuration alone: a create instruction matched by
+/// discriminator, accounts picked by position and string arguments decoded from their
+/// layout. Lets operators add launchpads without a release.
+pub struct GenericAnchorParser {
+    launchpad: LaunchpadType,
+    program_id: String,
+    instruction: String,
+    discriminator: [u8; 8],
+    mint_account: usize,
+    creator_account: Option<usize>,
+    pool_account: Option<usize>,
+    args: Vec<IdlField>,
+    metadata_args: [String; 3],
+}
+
+impl GenericAnchorParser {
+    /// Resolves the configuration against its IDL, if any
+    pub fn new(config: &GenericAnchorConfig) -> Result<Self> {
+        let idl = config.idl.as_deref().map(Idl::load).transpose()?;
+        let idl_instruction = idl
+            .as_ref()
+            .map(|idl| {
+                idl.instructions
+                    .iter()
+                    .find(|instruction| {
+                        idl::to_snake_case(&instruction.name)
+                            == idl::to_snake_case(&config.instruction)
+                    })
+                    .with_context(|| format!("IDL has no instruction {}", config.instruction))
+            })
+            .transpose()?;
+
+        let program_id = config
+            .program_id
+            .clone()
+            .or_else(|| idl.as_ref()?.program_address().map(str::to_string))
+            .with_context(|| format!("No program_id for {}", config.name))?;
+        let discriminator = config
+            .discriminator
+            .or_else(|| idl_instruction.map(|instruction| instruction.discriminator()))
+            .unwrap_or_else(|| idl::discriminator(Namespace::Instruction, &config.instruction));
+
+        let account_names = idl_instruction
+            .map(|instruction| instruction.account_names())
+            .unwrap_or_default();
+        let resolve = |account: &AccountRef| match account {
+            AccountRef::Index(index) => Ok(*index),
+            AccountRef::Name(name) => account_names
+                .iter()
+                .position(|account| account == name)
+                .with_context(|| format!("No account {} in {}", name, config.instruction)),
+        };
+
+        let args = match (&config.args, idl_instruction) {
+            (Some(args), _) => args.clone(),
+            (None, Some(instruction)) => instruction.args.clone(),
+            (None, None) => bail!("No args layout for {}", config.name),
+        };
+
+        Ok(Self {
+            launchpad: LaunchpadType::Custom(config.name.clone()),
+            program_id,
+            instruction: config.instruction.clone(),
+            discriminator,
+            mint_account: resolve(&config.mint_account)?,
+            creator_account: config.creator_account.as_ref().map(resolve).transpose()?,
+            pool_account: config.pool_account.as_ref().map(resolve).transpose()?,
+            args,
+            metadata_args: [
+                config.metadata_args.name.clone(),
+                config.metadata_args.symbol.clone(),
+                config.metadata_args.uri.clone(),
+            ],
+        })
+    }
+}
+
+impl LaunchpadParser for GenericAnchorParser {
+    fn get_program_ids(&self) -> Vec<String> {
+        vec![self.program_id.clone()]
+    }
+
+    fn get_launchpad_type(&self) -> LaunchpadType {
+        self.launchpad.clone()
+    }
+
+    fn capabilities(&self) -> ParserCapabilities {
+        // Fields whose configured argument is a string of the instruction layout
+        let configured = self.metadata_args.each_ref().map(|name| {
+            self.args
+                .iter()
+                .any(|arg| arg.name == *name && arg.ty.as_str() == Some("string"))
+        });
+        ParserCapabilities {
+            events: vec![EventKind::TokenLaunch, EventKind::PartialParse],
+            metadata: MetadataField::METAPLEX
+                .into_iter()
+                .zip(configured)
+                .filter_map(|(field, configured)| configured.then_some(field))
+                .collect(),
+        }
+    }
+
+    fn parse_transaction(
+        &self,
+        transaction: &QueuedTransaction,
+    ) -> Result<Vec<ParseResult>, Box<dyn std::error::Error + Send + Sync>> {
+        debug!(
+            "🔍 Parsing {} transaction: {}",
+            self.launchpad, transaction.signature
+        );
+
+        let mut results = Vec::new();
+        for instr in transaction
+            .instructions
+            .iter()
+            .chain(&transaction.inner_instructions)
+        {
+            if instr.program_id != self.program_id || !instr.data.starts_with(&self.discriminator) {
+                continue;
+            }
+            info!(
+                "🎯 Found {} {} instruction in: {}",
+                self.launchpad, self.instruction, transaction.signature
+            );
+            results.push(self.extract_token_launch(transaction, instr));
+        }
+
+        if results.is_empty() {
+            results.push(ParseResult::NotRelevant);
+        }
+        Ok(results)
+    }
+}
+
+impl GenericAnchorParser {
+    /// Extract token launch information from the create instruction
+    fn extract_token_launch(
+        &self,
+        transaction: &QueuedTransaction,
+        instruction: &TransactionInstruction,
+    ) -> ParseResult {
+        let account = |index: usize| {
+            instruction
+                .accounts
+                .get(index)
+                .and_then(|idx| transaction.accounts.get(*idx as usize))
+                .cloned()
+        };
+        let creator = match self.creator_account {
+            Some(index) => account(index),
+            None => transaction.instruction_signer(instruction),
+        };
+
+        let strings = decode_strings(&self.args, &instruction.data[8..]);
+        let [name, symbol, uri] = self
+            .metadata_args
+            .clone()
+            .map(|arg| strings.get(&arg).cloned());
+        let metadata = LaunchMetadata {
+            name,
+            symbol,
+            uri,
+            initial_supply: None,
+            mint_authority: None,
+            fee_recipient: None,
+            token_extensions: None,
+            normalized: None,
+        };
+
+        let Some(mint_address) = account(self.mint_account) else {
+            debug!("❌ Could not extract mint from {} create", self.launchpad);
+            return ParseResult::PartialParse(PartialParse::new(
+                self.launchpad.clone(),
+                &self.instruction,
+                transaction,
+                None,
+                creator,
+                metadata,
+            ));
+        };
+
+        let token_launch = TokenLaunch::new(
+            self.launchpad.clone(),
+            transaction,
+            mint_address.clone(),
+            creator,
+            metadata,
+        )
+        .with_pool(self.pool_account.and_then(account));
+
+        debug!(
+            "✅ Extracted {} token launch: {}",
+            self.launchpad, mint_address
+        );
+        ParseResult::TokenLaunch(token_launch)
+    }
+}
+
+/// String arguments by name, decoded in order until an argument of a type without a
+/// fixed layout here, e.g. a defined struct
+fn decode_strings(args: &[IdlField], data: &[u8]) -> HashMap<String, String> {
+    let mut reader = BorshReader::new(data);
+    let mut strings = HashMap::new();
+    for arg in args {
+        match arg.ty.as_str() {
+            Some("string") => match reader.string() {
+                Some(value) => {
+                    strings.insert(arg.name.clone(), value);
+                }
+                None => break,
+            },
+            _ => {
+                if skip(&mut reader, &arg.ty).is_none() {
+                    break;
+                }
+            }
+        }
+    }
+    strings
+}
+
+/// Skips a value of the IDL type, `None` when the type is unsupported or the data ends
+fn skip(reader: &mut BorshReader, ty: &Value) -> Option<()> {
+    if let Some(option) = ty.get("option") {
+        return if reader.bool()? {
+            skip(reader, option)
+        } else {
+            Some(())
+        };
+    }
+    if let Some([inner, len]) = ty.get("array").and_then(Value::as_array).map(Vec::as_slice) {
+        for _ in 0..len.as_u64()? {
+            skip(reader, inner)?;
+        }
+        return Some(());
+    }
+    let size = match ty.as_str()? {
+        "bool" | "u8" | "i8" => 1,
+        "u16" | "i16" => 2,
+        "u32" | "i32" | "f32" => 4,
+        "u64" | "i64" | "f64" => 8,
+        "u128" | "i128" => 16,
+        "pubkey" | "publicKey" => 32,
+        "string" | "bytes" => return reader.bytes().map(|_| ()),
+        _ => return None,
+    };
+    reader.skip(size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{borsh_string, instruction, transaction};
+    use serde_json::json;
+
+    const PROGRAM_ID: &str = "CustomPad11111111111111111111111111111111111";
+
+    fn parser() -> GenericAnchorParser {
+        let config: GenericAnchorConfig = serde_json::from_value(json!({
+            "name": "MyPad",
+            "program_id": PROGRAM_ID,
+            "instruction": "create_token",
+            "discriminator": [1, 2, 3, 4, 5, 6, 7, 8],
+            "mint_account": 1,
+            "pool_account": 2,
+            "args": [
+                {"name": "decimals", "type": "u8"},
+                {"name": "name", "type": "string"},
+                {"name": "symbol", "type": "string"},
+                {"name": "uri", "type": "string"},
+            ],
+        }))
+        .unwrap();
+        GenericAnchorParser::new(&config).unwrap()
+    }
+
+    #[test]
+    fn custom_launchpad_launch_is_decoded_and_labelled_by_name() {
+        let mut data = vec![1, 2, 3, 4, 5, 6, 7, 8, 6];
+        data.extend(borsh_string("My Token"));
+        data.extend(borsh_string("MINE"));
+        data.extend(borsh_string("https://example.com/mine.json"));
+        let transaction = transaction(
+            &["creator", "mint", "pool"],
+            vec![instruction(PROGRAM_ID, &[0, 1, 2], data)],
+            Vec::new(),
+        );
+
+        let results = parser().parse_transaction(&transaction).unwrap();
+        let [ParseResult::TokenLaunch(launch)] = results.as_slice() else {
+            panic!("expected a launch, got {} results", results.len());
+        };
+        assert!(matches!(&launch.launchpad, LaunchpadType::Custom(name) if name == "MyPad"));
+        assert_eq!(launch.launchpad.to_string(), "MyPad");
+        assert_eq!(launch.token_address, "mint");
+        assert_eq!(launch.creator.as_deref(), Some("creator"));
+        assert_eq!(launch.pool.as_deref(), Some("pool"));
+        assert_eq!(launch.metadata.name.as_deref(), Some("My Token"));
+        assert_eq!(launch.metadata.symbol.as_deref(), Some("MINE"));
+        assert_eq!(
+            launch.metadata.uri.as_deref(),
+            Some("https://example.com/mine.json")
+        );
+        assert_eq!(serde_json::to_value(launch).unwrap()["launchpad"], "MyPad");
+    }
+
+    #[test]
+    fn other_discriminators_are_not_relevant() {
+        let transaction = transaction(
+            &["creator", "mint"],
+            vec![instruction(
+                PROGRAM_ID,
+                &[0, 1],
+                vec![8, 7, 6, 5, 4, 3, 2, 1],
+            )],
+            Vec::new(),
+        );
+
+        let results = parser().parse_transaction(&transaction).unwrap();
+        assert!(matches!(results.as_slice(), [ParseResult::NotRelevant]));
+    }
+}
@@ -0,0 +1,71 @@
+use std::collections::{BTreeMap, HashSet};
+use std::sync::Mutex;
+
+use crate::geyser::QueuedTransaction;
+
+/// Jito tip payment accounts, a transfer to one of them pays for a bundle
+pub const TIP_ACCOUNTS: [&str; 8] = [
+    "96gYZGLnJYVFmbjzopPSU6QiEV5fGqZNyN9nmNhvrZU5",
+    "HFqU5x63VTqvQss8hp11i4wVV8bD44PvwucfZ2bU7gRe",
+    "Cw8CFyM9FkoMi7K7Crf6HNQqf4uEMzpKw6QNghXLvLkY",
+    "ADaUMid9yfUytqMBgopwjb2DTLSokTSzL1zt6iGPaS49",
+    "DfXygSm4jCyNCybVYYK6DwvWqjKee8pbDmJGcLWNDXjh",
+    "ADuUkR4vqLUMWXxW9gh6D6L8pMSawimctcNZ5pGwDcEt",
+    "DttWaMuVvTiduZRnguLF7jNxTgiMBZ1hyAumKUiL2KRL",
+    "3AVi9Tg9Uo68tJfuvoKvqKNWKkC5wPdSSdeBnizKZ6jT",
+];
+
+/// Most transactions in a bundle, which land next to each other in the block
+const MAX_BUNDLE_TRANSACTIONS: u64 = 5;
+/// Slots whose tipping transactions are remembered
+const TIP_SLOTS: usize = 32;
+
+/// Whether the transaction references a Jito tip account
+pub fn tips(transaction: &QueuedTransaction) -> bool {
+    transaction
+        .accounts
+        .iter()
+        .any(|account| TIP_ACCOUNTS.contains(&account.as_str()))
+}
+
+/// Positions of the tipping transactions received in recent slots, so a transaction
+/// can be matched to a tip paid elsewhere in its bundle
+#[derive(Default)]
+pub struct TipTracker {
+    slots: Mutex<BTreeMap<u64, HashSet<u64>>>,
+}
+
+impl TipTracker {
+    /// Remembers the position of a tipping transaction
+    pub fn record(&self, transaction: &QueuedTransaction) {
+        let Some(index) = transaction.transaction_index else {
+            return;
+        };
+        if !tips(transaction) {
+            return;
+        }
+        let mut slots = self.slots.lock().unwrap();
+        slots.entry(transaction.slot).or_default().insert(index);
+        while slots.len() > TIP_SLOTS {
+            slots.pop_first();
+        }
+    }
+
+    /// Whether the transaction tipped, or a transaction close enough in the same slot to
+    /// share its bundle did
+    pub fn bundled(&self, transaction: &QueuedTransaction) -> bool {
+        if tips(transaction) {
+            return true;
+        }
+        let Some(index) = transaction.transaction_index else {
+            return false;
+        };
+        let slots = self.slots.lock().unwrap();
+        let Some(tipped) = slots.get(&transaction.slot) else {
+            return false;
+        };
+        let reach = MAX_BUNDLE_TRANSACTIONS - 1;
+        (index.saturating_sub(reach)..=index.saturating_add(reach))
+            .any(|neighbour| tipped.contains(&neighbour))
+    }
+}
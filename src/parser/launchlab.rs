@@ -0,0 +1,531 @@
+use crate::config::parsers::LaunchLabConfig;
+use crate::geyser::{QueuedTransaction, TransactionInstruction};
+use crate::parser::anchor::{self, BorshReader};
+use crate::parser::pricing::{self, Price};
+use crate::parser::{
+    EventKind, LaunchpadParser, LaunchpadType, MetadataField, ParseResult, ParserCapabilities,
+    PartialParse, TokenLaunch, Trade,
+    launchpad_parser::{LaunchMetadata, fill_initial_buys},
+};
+use tracing::{debug, info};
+
+/// `initialize` instruction discriminator (raydiumIDL.json)
+const INITIALIZE_DISCRIMINATOR: [u8; 8] = [175, 175, 109, 31, 13, 152, 155, 237];
+
+/// Trade instruction discriminators, with whether they buy and whether the input amount
+/// is exact
+const TRADE_INSTRUCTIONS: &[(&str, [u8; 8], bool, bool)] = &[
+    (
+        "buy_exact_in",
+        [250, 234, 13, 123, 213, 156, 19, 236],
+        true,
+        true,
+    ),
+    (
+        "buy_exact_out",
+        [24, 211, 116, 40, 105, 3, 153, 56],
+        true,
+        false,
+    ),
+    (
+        "sell_exact_in",
+        [149, 39, 222, 155, 211, 124, 152, 26],
+        false,
+        true,
+    ),
+    (
+        "sell_exact_out",
+        [95, 200, 71, 34, 8, 9, 11, 166],
+        false,
+        false,
+    ),
+];
+
+/// Other LaunchLab instruction discriminators
+const INSTRUCTIONS: &[(&str, [u8; 8])] = &[
+    ("migrate_to_amm", [207, 82, 192, 145, 254, 207, 145, 223]),
+    ("migrate_to_cpswap", [136, 92, 200, 103, 28, 218, 144, 140]),
+];
+
+/// `TradeEvent` discriminator, emitted through self-CPI by every buy and sell
+const TRADE_EVENT_DISCRIMINATOR: [u8; 8] = [189, 219, 127, 211, 78, 230, 97, 238];
+/// Trades are reported for pools quoted in SOL only, so amounts are lamports
+const WSOL_MINT: &str = "So11111111111111111111111111111111111111112";
+
+/// Account positions in the `initialize` instruction
+const CREATOR_ACCOUNT_INDEX: usize = 1; // `creator`
+const PLATFORM_CONFIG_ACCOUNT_INDEX: usize = 3; // `platform_config`
+const POOL_ACCOUNT_INDEX: usize = 5; // `pool_state`
+const MINT_ACCOUNT_INDEX: usize = 6; // `base_mint`
+
+/// Account positions in the buy and sell instructions
+const TRADE_PAYER_ACCOUNT_INDEX: usize = 0; // `payer`
+const TRADE_PLATFORM_CONFIG_ACCOUNT_INDEX: usize = 3; // `platform_config`
+const TRADE_POOL_ACCOUNT_INDEX: usize = 4; // `pool_state`
+const TRADE_BASE_MINT_ACCOUNT_INDEX: usize = 9; // `base_token_mint`
+const TRADE_QUOTE_MINT_ACCOUNT_INDEX: usize = 10; // `quote_token_mint`
+
+/// Amounts of a `TradeEvent`
+struct TradeAmounts {
+    pool: String,
+    amount_in: u64,
+    amount_out: u64,
+    /// Base tokens sold by the curve after the trade, and in total until it migrates
+    real_base_after: u64,
+    total_base_sell: u64,
+    /// Spot price after the trade
+    price: Option<Price>,
+}
+
+/// Raydium LaunchLab launches. Launches on a LetsBonk.fun platform config are
+/// reported as `LetsBonk`, all others as `LaunchLab`.
+pub struct LaunchLabParser {
+    program_id: String,
+    config: LaunchLabConfig,
+}
+
+impl LaunchLabParser {
+    pub fn new(config: LaunchLabConfig) -> Self {
+        Self {
+            program_id: "LanMV9sAd7wArD4vJFi2qDdfnVhFxYSUg6eADduJ3uj".to_string(),
+            config,
+        }
+    }
+}
+
+impl Default for LaunchLabParser {
+    fn default() -> Self {
+        Self::new(LaunchLabConfig::default())
+    }
+}
+
+impl LaunchpadParser for LaunchLabParser {
+    fn get_program_ids(&self) -> Vec<String> {
+        vec![self.program_id.clone()]
+    }
+
+    fn get_launchpad_type(&self) -> LaunchpadType {
+        LaunchpadType::LaunchLab
+    }
+
+    fn capabilities(&self) -> ParserCapabilities {
+        ParserCapabilities {
+            events: vec![
+                EventKind::TokenLaunch,
+                EventKind::PartialParse,
+                EventKind::Trade,
+            ],
+            metadata: vec![
+                MetadataField::Name,
+                MetadataField::Symbol,
+                MetadataField::Uri,
+                MetadataField::InitialSupply,
+            ],
+        }
+    }
+
+    fn parse_transaction(
+        &self,
+        transaction: &QueuedTransaction,
+    ) -> Result<Vec<ParseResult>, Box<dyn std::error::Error + Send + Sync>> {
+        debug!(
+            "🔍 Parsing LaunchLab transaction: {}",
+            transaction.signature
+        );
+
+        let mut results = Vec::new();
+        for instr in transaction
+            .instructions
+            .iter()
+            .chain(&transaction.inner_instructions)
+        {
+            if instr.program_id != self.program_id || instr.data.len() < 8 {
+                continue;
+            }
+            let discriminator = &instr.data[0..8];
+
+            if discriminator == INITIALIZE_DISCRIMINATOR {
+                info!(
+                    "🎯 Found LaunchLab initialize instruction in: {}",
+                    transaction.signature
+                );
+                results.push(self.extract_token_launch(transaction, instr));
+            } else if let Some((name, _)) = INSTRUCTIONS.iter().find(|(_, d)| d == discriminator) {
+                results.push(ParseResult::Other {
+                    launchpad: LaunchpadType::LaunchLab,
+                    event_type: name.to_string(),
+                    detail: None,
+                    signature: transaction.signature.clone(),
+                });
+            }
+        }
+
+        // Buys and sells, also through aggregators calling LaunchLab by CPI. Each takes
+        // its amounts from the next TradeEvent of its pool.
+        let mut events: Vec<TradeAmounts> = transaction
+            .inner_instructions
+            .iter()
+            .filter(|instr| instr.program_id == self.program_id && !instr.truncated)
+            .filter_map(|instr| Self::extract_trade_event(&instr.data))
+            .collect();
+        for instr in transaction
+            .instructions
+            .iter()
+            .chain(&transaction.inner_instructions)
+        {
+            if instr.program_id != self.program_id {
+                continue;
+            }
+            let Some(&(name, _, is_buy, exact_in)) = TRADE_INSTRUCTIONS
+                .iter()
+                .find(|(_, d, _, _)| instr.data.starts_with(d))
+            else {
+                continue;
+            };
+            if !self.config.emit_trades {
+                results.push(ParseResult::Other {
+                    launchpad: LaunchpadType::LaunchLab,
+                    event_type: name.to_string(),
+                    detail: None,
+                    signature: transaction.signature.clone(),
+                });
+                continue;
+            }
+            if let Some(trade) =
+                self.extract_trade(transaction, instr, is_buy, exact_in, &mut events)
+            {
+                results.push(ParseResult::Trade(trade));
+            }
+        }
+        if self.config.emit_trades {
+            fill_initial_buys(&mut results);
+        }
+
+        if results.is_empty() {
+            results.push(ParseResult::NotRelevant);
+        }
+        Ok(results)
+    }
+}
+
+impl LaunchLabParser {
+    /// Extract token launch information from the initialize instruction
+    fn extract_token_launch(
+        &self,
+        transaction: &QueuedTransaction,
+        instruction: &TransactionInstruction,
+    ) -> ParseResult {
+        let account = |index: usize| {
+            instruction
+                .accounts
+                .get(index)
+                .and_then(|idx| transaction.accounts.get(*idx as usize))
+                .cloned()
+        };
+        let creator = account(CREATOR_ACCOUNT_INDEX);
+        let metadata = self.extract_metadata_from_instruction(&instruction.data);
+
+        let launchpad = match account(PLATFORM_CONFIG_ACCOUNT_INDEX) {
+            Some(platform) if self.config.letsbonk_platform_configs.contains(&platform) => {
+                LaunchpadType::LetsBonk
+            }
+            _ => LaunchpadType::LaunchLab,
+        };
+
+        let Some(mint_address) = account(MINT_ACCOUNT_INDEX) else {
+            debug!("❌ Could not extract mint from LaunchLab initialize instruction");
+            return ParseResult::PartialParse(PartialParse::new(
+                launchpad,
+                "initialize",
+                transaction,
+                None,
+                creator,
+                metadata,
+            ));
+        };
+
+        let token_launch = TokenLaunch::new(
+            launchpad.clone(),
+            transaction,
+            mint_address.clone(),
+            creator,
+            metadata,
+        )
+        .with_pool(account(POOL_ACCOUNT_INDEX));
+
+        debug!("✅ Extracted {} token launch: {}", launchpad, mint_address);
+        ParseResult::TokenLaunch(token_launch)
+    }
+
+    /// Extract a buy or sell, with the amounts of its pool's TradeEvent, or the
+    /// instruction's own amounts when the event is missing
+    fn extract_trade(
+        &self,
+        transaction: &QueuedTransaction,
+        instruction: &TransactionInstruction,
+        is_buy: bool,
+        exact_in: bool,
+        events: &mut Vec<TradeAmounts>,
+    ) -> Option<Trade> {
+        let account = |index: usize| {
+            instruction
+                .accounts
+                .get(index)
+                .and_then(|idx| transaction.accounts.get(*idx as usize))
+                .cloned()
+        };
+        if account(TRADE_QUOTE_MINT_ACCOUNT_INDEX)? != WSOL_MINT {
+            debug!(
+                "Skipping LaunchLab trade in a pool not quoted in SOL: {}",
+                transaction.signature
+            );
+            return None;
+        }
+        let pool = account(TRADE_POOL_ACCOUNT_INDEX)?;
+
+        let (amount_in, amount_out, curve_progress, price, estimated) =
+            match events.iter().position(|event| event.pool == pool) {
+                Some(position) => {
+                    let event = events.remove(position);
+                    let progress = (event.total_base_sell > 0).then(|| {
+                        100.0
+                            * (event.real_base_after as f64 / event.total_base_sell as f64)
+                                .clamp(0.0, 1.0)
+                    });
+                    (
+                        event.amount_in,
+                        event.amount_out,
+                        progress,
+                        event.price,
+                        false,
+                    )
+                }
+                // Arguments: the exact amount, then the slippage bound on the other side
+                None => {
+                    let mut reader = BorshReader::new(&instruction.data[8..]);
+                    let exact = reader.u64()?;
+                    let bound = reader.u64()?;
+                    if exact_in {
+                        (exact, bound, None, None, true)
+                    } else {
+                        (bound, exact, None, None, true)
+                    }
+                }
+            };
+        let (amount, token_amount) = if is_buy {
+            (amount_in, amount_out)
+        } else {
+            (amount_out, amount_in)
+        };
+
+        let launchpad = match account(TRADE_PLATFORM_CONFIG_ACCOUNT_INDEX) {
+            Some(platform) if self.config.letsbonk_platform_configs.contains(&platform) => {
+                LaunchpadType::LetsBonk
+            }
+            _ => LaunchpadType::LaunchLab,
+        };
+
+        Some(Trade {
+            launchpad,
+            token_address: account(TRADE_BASE_MINT_ACCOUNT_INDEX)?,
+            trader: account(TRADE_PAYER_ACCOUNT_INDEX)?,
+            amount,
+            token_amount,
+            is_buy,
+            fees: None,
+            curve_progress,
+            price_sol: price.map(|price| price.price_sol),
+            market_cap_sol: price.map(|price| price.market_cap_sol),
+            estimated,
+            signature: transaction.signature.clone(),
+            slot: transaction.slot,
+            transaction_index: transaction.transaction_index,
+            timestamp: transaction.received_time,
+            block_time: transaction.block_time,
+        })
+    }
+
+    /// Decode the amounts of a TradeEvent self-CPI instruction
+    fn extract_trade_event(data: &[u8]) -> Option<TradeAmounts> {
+        let body = anchor::cpi_event(data, &TRADE_EVENT_DISCRIMINATOR)?;
+        let mut reader = BorshReader::new(body);
+
+        // pool_state, total_base_sell, virtual_base, virtual_quote, real_base_before,
+        // real_quote_before, real_base_after, real_quote_after, amount_in, amount_out
+        let pool = reader.pubkey()?;
+        let total_base_sell = reader.u64()?;
+        let virtual_base = reader.u64()?;
+        let virtual_quote = reader.u64()?;
+        reader.skip(2 * 8)?;
+        let real_base_after = reader.u64()?;
+        let real_quote_after = reader.u64()?;
+        let amount_in = reader.u64()?;
+        let amount_out = reader.u64()?;
+        // Constant product over the virtual reserves shifted by what the curve sold
+        let price = Price::from_reserves(
+            virtual_quote.saturating_add(real_quote_after),
+            virtual_base.saturating_sub(real_base_after),
+            pricing::DEFAULT_SUPPLY,
+        );
+
+        Some(TradeAmounts {
+            pool,
+            amount_in,
+            amount_out,
+            real_base_after,
+            total_base_sell,
+            price,
+        })
+    }
+
+    /// Extract metadata from instruction data
+    fn extract_metadata_from_instruction(&self, data: &[u8]) -> LaunchMetadata {
+        // initialize args (after discriminator): base_mint_param: MintParams { decimals: u8,
+        // name: string, symbol: string, uri: string }, curve_param: CurveParams, whose
+        // variants all start with `supply: u64`
+        let mut reader = BorshReader::new(data.get(8..).unwrap_or_default());
+        let name = reader.skip(1).and_then(|_| reader.string());
+        let symbol = name.as_ref().and_then(|_| reader.string());
+        let uri = symbol.as_ref().and_then(|_| reader.string());
+        let initial_supply = uri
+            .as_ref()
+            .and_then(|_| reader.skip(1))
+            .and_then(|_| reader.u64());
+
+        LaunchMetadata {
+            name,
+            symbol,
+            uri,
+            initial_supply,
+            mint_authority: None,
+            fee_recipient: None,
+            token_extensions: None,
+            normalized: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geyser::encoding::encode_pubkey;
+    use crate::testing::{borsh_string, instruction, transaction};
+
+    const PROGRAM_ID: &str = "LanMV9sAd7wArD4vJFi2qDdfnVhFxYSUg6eADduJ3uj";
+    const LETSBONK_PLATFORM: &str = "FfYek5vEz23cMkWsdJwG2oa6EphsvXSHrGpdALN4g6W1";
+
+    /// LetsBonk.fun launch by `creator` followed by their buy of 1 SOL, with the buy's
+    /// `TradeEvent` when `with_event`
+    fn launch_transaction(with_event: bool) -> QueuedTransaction {
+        let pool = encode_pubkey(&[5; 32]);
+        let mut initialize = INITIALIZE_DISCRIMINATOR.to_vec();
+        initialize.push(6);
+        initialize.extend(borsh_string("Bonk Token"));
+        initialize.extend(borsh_string("BNKT"));
+        initialize.extend(borsh_string("https://example.com/bnkt.json"));
+        initialize.push(0); // CurveParams::Constant
+        initialize.extend(1_000_000_000_000_000u64.to_le_bytes());
+
+        let mut buy = TRADE_INSTRUCTIONS[0].1.to_vec();
+        buy.extend(1_000_000_000u64.to_le_bytes());
+        buy.extend(30_000_000_000_000u64.to_le_bytes());
+        buy.extend(0u64.to_le_bytes());
+
+        let mut event = anchor::EVENT_IX_TAG.to_vec();
+        event.extend(TRADE_EVENT_DISCRIMINATOR);
+        event.extend([5; 32]);
+        for amount in [
+            793_100_000_000_000u64,
+            1_073_025_605_596_382,
+            30_000_852_951,
+            0,
+            0,
+            35_000_000_000_000,
+            1_000_000_000,
+            1_000_000_000,
+            35_000_000_000_000,
+        ] {
+            event.extend(amount.to_le_bytes());
+        }
+
+        transaction(
+            &[
+                "creator",
+                "global_config",
+                LETSBONK_PLATFORM,
+                "authority",
+                &pool,
+                "mint",
+                WSOL_MINT,
+                "vault",
+                PROGRAM_ID,
+            ],
+            vec![
+                instruction(PROGRAM_ID, &[0, 0, 1, 2, 3, 4, 5, 6], initialize),
+                instruction(PROGRAM_ID, &[0, 3, 1, 2, 4, 7, 7, 7, 7, 5, 6], buy),
+            ],
+            if with_event {
+                vec![instruction(PROGRAM_ID, &[8], event)]
+            } else {
+                Vec::new()
+            },
+        )
+    }
+
+    #[test]
+    fn initialize_and_dev_buy_are_decoded() {
+        let results = LaunchLabParser::default()
+            .parse_transaction(&launch_transaction(true))
+            .unwrap();
+        let [ParseResult::TokenLaunch(launch), ParseResult::Trade(trade)] = results.as_slice()
+        else {
+            panic!(
+                "expected a launch and a trade, got {} results",
+                results.len()
+            );
+        };
+
+        assert!(matches!(launch.launchpad, LaunchpadType::LetsBonk));
+        assert_eq!(launch.token_address, "mint");
+        assert_eq!(launch.creator.as_deref(), Some("creator"));
+        assert_eq!(launch.pool, Some(encode_pubkey(&[5; 32])));
+        assert_eq!(launch.metadata.name.as_deref(), Some("Bonk Token"));
+        assert_eq!(launch.metadata.symbol.as_deref(), Some("BNKT"));
+        assert_eq!(launch.metadata.initial_supply, Some(1_000_000_000_000_000));
+
+        assert!(matches!(trade.launchpad, LaunchpadType::LetsBonk));
+        assert_eq!(trade.token_address, "mint");
+        assert_eq!(trade.trader, "creator");
+        assert!(trade.is_buy);
+        assert!(!trade.estimated);
+        assert_eq!(trade.amount, 1_000_000_000);
+        assert_eq!(trade.token_amount, 35_000_000_000_000);
+        let progress = trade.curve_progress.expect("curve progress");
+        assert!((progress - 100.0 * 35.0 / 793.1).abs() < 1e-9);
+
+        assert_eq!(launch.initial_buy_sol, Some(1_000_000_000));
+        assert_eq!(launch.initial_buy_tokens, Some(35_000_000_000_000));
+    }
+
+    #[test]
+    fn buy_without_its_event_is_estimated_from_the_arguments() {
+        let results = LaunchLabParser::default()
+            .parse_transaction(&launch_transaction(false))
+            .unwrap();
+        let [ParseResult::TokenLaunch(launch), ParseResult::Trade(trade)] = results.as_slice()
+        else {
+            panic!(
+                "expected a launch and a trade, got {} results",
+                results.len()
+            );
+        };
+
+        assert!(trade.estimated);
+        assert_eq!(trade.amount, 1_000_000_000);
+        assert_eq!(trade.token_amount, 30_000_000_000_000);
+        assert_eq!(trade.curve_progress, None);
+        // Slippage bounds are no initial buy
+        assert_eq!(launch.initial_buy_sol, None);
+        assert_eq!(launch.initial_buy_tokens, None);
+    }
+}
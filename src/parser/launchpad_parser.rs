@@ -1,11 +1,45 @@
-use crate::geyser::QueuedTransaction;
+use crate::config::explorer::Explorer;
+use crate::geyser::{QueuedTransaction, TransactionVersion};
+use crate::parser::pricing::Price;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum LaunchpadType {
     Pumpfun,
     Meteora,
+    Moonshot,
+    Believe,
+    LaunchLab,
+    LetsBonk,
+    DaosFun,
+    JupiterStudio,
+    Bags,
+    Vertigo,
+    Fluxbeam,
+    Virtuals,
+    TimeFun,
+    PumpSwap,
+    RaydiumCpmm,
+    RaydiumClmm,
+    MeteoraDlmm,
+    /// Mint initialized outside the known launchpads
+    Unknown,
+    /// Launchpad configured in `parsers.generic`, by its configured name
+    #[serde(untagged)]
+    Custom(String),
+}
+
+/// Name used in metric labels, partitions, routing keys and stats, the configured name for
+/// custom launchpads
+impl fmt::Display for LaunchpadType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Custom(name) => f.write_str(name),
+            launchpad => fmt::Debug::fmt(launchpad, f),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -13,10 +47,110 @@ pub struct TokenLaunch {
     pub launchpad: LaunchpadType,
     pub token_address: String,
     pub creator: Option<String>,
+    /// Bonding curve or pool account holding the launch's liquidity
+    #[serde(default)]
+    pub pool: Option<String>,
     pub signature: String,
     pub slot: u64,
+    /// Position of the transaction within its block
+    #[serde(default)]
+    pub transaction_index: Option<u64>,
+    /// When the transaction was received
     pub timestamp: DateTime<Utc>,
+    /// Chain time of the transaction's block, extrapolated from nearby slots while its
+    /// block meta is pending
+    #[serde(default)]
+    pub block_time: Option<DateTime<Utc>>,
+    /// Recovered from RPC after a gap in the stream
+    #[serde(default)]
+    pub backfilled: bool,
     pub metadata: LaunchMetadata,
+    /// Explorer pages of the event, filled in before publishing
+    #[serde(default)]
+    pub links: Option<Links>,
+    /// Cluster of related launches, the mint of its first launch
+    #[serde(default)]
+    pub cluster_id: Option<String>,
+    /// Fields RPC enrichment could not fill, retried in the background
+    #[serde(default)]
+    pub enrichment_errors: Vec<EnrichmentError>,
+    /// Republished after a retried enrichment filled the fields in `enrichment_errors`
+    /// of the first publish
+    #[serde(default)]
+    pub enrichment_update: bool,
+    /// Sent in a Jito bundle, a tip account was in the transaction or in a neighbouring
+    /// one of the same slot
+    #[serde(default)]
+    pub bundled: bool,
+    /// Price of one whole token in SOL at launch, from the curve's initial reserves
+    #[serde(default)]
+    pub price_sol: Option<f64>,
+    /// Price times the minted supply, in SOL
+    #[serde(default)]
+    pub market_cap_sol: Option<f64>,
+    /// SOL the creator spent buying the token in the launch transaction, in lamports.
+    /// Only set by parsers decoding trades, empty when the buy is unknown and 0 only when
+    /// the transaction rules a buy out.
+    #[serde(default)]
+    pub initial_buy_sol: Option<u64>,
+    /// Raw tokens the creator bought in the launch transaction
+    #[serde(default)]
+    pub initial_buy_tokens: Option<u64>,
+}
+
+impl TokenLaunch {
+    /// Builds a launch found in `transaction`, leaving the fields filled in later by
+    /// enrichment, tracking and publishing empty
+    pub fn new(
+        launchpad: LaunchpadType,
+        transaction: &QueuedTransaction,
+        token_address: String,
+        creator: Option<String>,
+        metadata: LaunchMetadata,
+    ) -> Self {
+        Self {
+            launchpad,
+            token_address,
+            creator,
+            pool: None,
+            signature: transaction.signature.clone(),
+            slot: transaction.slot,
+            transaction_index: transaction.transaction_index,
+            timestamp: transaction.received_time,
+            block_time: transaction.block_time,
+            backfilled: transaction.backfilled,
+            metadata,
+            links: None,
+            cluster_id: None,
+            enrichment_errors: Vec::new(),
+            enrichment_update: false,
+            bundled: false,
+            price_sol: None,
+            market_cap_sol: None,
+            initial_buy_sol: None,
+            initial_buy_tokens: None,
+        }
+    }
+
+    /// Sets the bonding curve or pool account
+    pub fn with_pool(mut self, pool: Option<String>) -> Self {
+        self.pool = pool;
+        self
+    }
+
+    /// Sets the launch price and market cap
+    pub fn with_price(mut self, price: Option<Price>) -> Self {
+        self.price_sol = price.map(|price| price.price_sol);
+        self.market_cap_sol = price.map(|price| price.market_cap_sol);
+        self
+    }
+}
+
+/// Launch field left empty by a failed enrichment step
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnrichmentError {
+    pub field: String,
+    pub error: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -26,27 +160,702 @@ pub struct LaunchMetadata {
     pub uri: Option<String>,
     pub initial_supply: Option<u64>,
     pub mint_authority: Option<String>,
+    /// Wallet receiving the launch's creator fees or royalties, when not the creator
+    #[serde(default)]
+    pub fee_recipient: Option<String>,
+    /// Extensions of a Token-2022 mint, `None` for SPL Token mints
+    #[serde(default)]
+    pub token_extensions: Option<TokenExtensions>,
+    /// Name and symbol cleaned up for display, set before publishing
+    #[serde(default)]
+    pub normalized: Option<NormalizedMetadata>,
+}
+
+/// Name and symbol NFC normalized, without control, bidi and zero width characters
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NormalizedMetadata {
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    /// The raw values contained bidi overrides, which can make a name or ticker display
+    /// as something else
+    pub bidi_override: bool,
+    /// The raw values contained control characters other than NUL padding
+    pub control_characters: bool,
+}
+
+/// Token-2022 mint extensions relevant to holders, each `None` when not initialized
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TokenExtensions {
+    /// Fee withheld on every transfer
+    pub transfer_fee_basis_points: Option<u16>,
+    /// Cap on the transfer fee, in base units
+    pub maximum_transfer_fee: Option<u64>,
+    /// Can transfer or burn tokens from any account
+    pub permanent_delegate: Option<String>,
+    /// Program invoked on every transfer
+    pub transfer_hook_program: Option<String>,
+}
+
+/// Explorer pages of an event's transaction, mint and creator
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Links {
+    pub transaction: String,
+    pub mint: Option<String>,
+    pub creator: Option<String>,
+}
+
+impl Links {
+    pub fn new(
+        explorer: Explorer,
+        signature: &str,
+        mint: Option<&str>,
+        creator: Option<&str>,
+    ) -> Self {
+        Self {
+            transaction: explorer.transaction_url(signature),
+            mint: mint.map(|mint| explorer.token_url(mint)),
+            creator: creator.map(|creator| explorer.account_url(creator)),
+        }
+    }
+}
+
+/// A launch instruction that matched but could not be fully extracted
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartialParse {
+    pub launchpad: LaunchpadType,
+    pub instruction: String,
+    pub token_address: Option<String>,
+    pub creator: Option<String>,
+    pub signature: String,
+    pub slot: u64,
+    #[serde(default)]
+    pub transaction_index: Option<u64>,
+    /// When the transaction was received
+    pub timestamp: DateTime<Utc>,
+    /// Chain time of the transaction's block, extrapolated from nearby slots while its
+    /// block meta is pending
+    #[serde(default)]
+    pub block_time: Option<DateTime<Utc>>,
+    /// Recovered from RPC after a gap in the stream
+    #[serde(default)]
+    pub backfilled: bool,
+    /// Message format of the transaction, for tracing account resolution issues
+    #[serde(default)]
+    pub version: TransactionVersion,
+    #[serde(default)]
+    pub is_vote: bool,
+    pub metadata: LaunchMetadata,
+    /// Share of expected fields that were recovered (0.0 - 1.0)
+    pub confidence: f32,
+    pub missing_fields: Vec<String>,
+    /// Explorer pages of the event, filled in before publishing
+    #[serde(default)]
+    pub links: Option<Links>,
+}
+
+impl PartialParse {
+    /// Builds a partial parse from whatever was recovered, deriving confidence from missing fields
+    pub fn new(
+        launchpad: LaunchpadType,
+        instruction: &str,
+        transaction: &QueuedTransaction,
+        token_address: Option<String>,
+        creator: Option<String>,
+        metadata: LaunchMetadata,
+    ) -> Self {
+        let expected = [
+            ("token_address", token_address.is_some()),
+            ("creator", creator.is_some()),
+            ("name", metadata.name.is_some()),
+            ("symbol", metadata.symbol.is_some()),
+        ];
+        let missing_fields: Vec<String> = expected
+            .iter()
+            .filter(|(_, present)| !present)
+            .map(|(field, _)| field.to_string())
+            .collect();
+        let confidence = 1.0 - missing_fields.len() as f32 / expected.len() as f32;
+
+        Self {
+            launchpad,
+            instruction: instruction.to_string(),
+            token_address,
+            creator,
+            signature: transaction.signature.clone(),
+            slot: transaction.slot,
+            transaction_index: transaction.transaction_index,
+            timestamp: transaction.received_time,
+            block_time: transaction.block_time,
+            backfilled: transaction.backfilled,
+            version: transaction.version,
+            is_vote: transaction.is_vote,
+            metadata,
+            confidence,
+            missing_fields,
+            links: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeeClaimKind {
+    PartnerTradingFee,
+    CreatorTradingFee,
+    PartnerSurplus,
+    CreatorSurplus,
+}
+
+/// Fees or surplus withdrawn from a launch pool by its partner or creator
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeeClaim {
+    pub launchpad: LaunchpadType,
+    pub kind: FeeClaimKind,
+    pub pool: String,
+    pub claimer: Option<String>,
+    pub base_mint: Option<String>,
+    /// Raw base token amount claimed
+    pub base_amount: u64,
+    /// Raw quote token amount claimed
+    pub quote_amount: u64,
+    pub signature: String,
+    pub slot: u64,
+    #[serde(default)]
+    pub transaction_index: Option<u64>,
+    /// When the transaction was received
+    pub timestamp: DateTime<Utc>,
+    /// Chain time of the transaction's block, extrapolated from nearby slots while its
+    /// block meta is pending
+    #[serde(default)]
+    pub block_time: Option<DateTime<Utc>>,
+    /// Recovered from RPC after a gap in the stream
+    #[serde(default)]
+    pub backfilled: bool,
+    /// Explorer pages of the event, filled in before publishing
+    #[serde(default)]
+    pub links: Option<Links>,
+}
+
+/// Token moved from its bonding curve to an AMM pool, or a pool created on the AMM directly
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Migration {
+    /// Launchpad the token graduated from, the AMM itself for pools created directly
+    pub launchpad: LaunchpadType,
+    pub token_address: String,
+    /// New AMM pool
+    pub pool: String,
+    pub quote_mint: Option<String>,
+    /// Pool creator, the launchpad's migration authority for graduations
+    pub creator: Option<String>,
+    /// Price step between bins in basis points, Meteora DLMM pools only
+    #[serde(default)]
+    pub bin_step: Option<u16>,
+    /// Bonding curve the liquidity was moved from, pump.fun graduations only
+    #[serde(default)]
+    pub bonding_curve: Option<String>,
+    /// Token and quote amounts the pool was seeded with, in raw units, PumpSwap pools only
+    #[serde(default)]
+    pub base_reserve: Option<u64>,
+    #[serde(default)]
+    pub quote_reserve: Option<u64>,
+    /// LP token of the pool, PumpSwap and Raydium CPMM pools only
+    #[serde(default)]
+    pub lp_mint: Option<String>,
+    pub signature: String,
+    pub slot: u64,
+    #[serde(default)]
+    pub transaction_index: Option<u64>,
+    /// When the transaction was received
+    pub timestamp: DateTime<Utc>,
+    /// Chain time of the transaction's block, extrapolated from nearby slots while its
+    /// block meta is pending
+    #[serde(default)]
+    pub block_time: Option<DateTime<Utc>>,
+    /// Recovered from RPC after a gap in the stream
+    #[serde(default)]
+    pub backfilled: bool,
+    /// Explorer pages of the event, filled in before publishing
+    #[serde(default)]
+    pub links: Option<Links>,
+}
+
+/// Token whose bonding curve completed, reported by the first transaction showing it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Graduation {
+    pub launchpad: LaunchpadType,
+    pub token_address: String,
+    pub bonding_curve: Option<String>,
+    /// Buyer completing the curve, or the migration authority
+    pub user: Option<String>,
+    pub trigger: GraduationTrigger,
+    pub signature: String,
+    pub slot: u64,
+    #[serde(default)]
+    pub transaction_index: Option<u64>,
+    /// When the transaction was received
+    pub timestamp: DateTime<Utc>,
+    /// Chain time of the transaction's block, extrapolated from nearby slots while its
+    /// block meta is pending
+    #[serde(default)]
+    pub block_time: Option<DateTime<Utc>>,
+    /// Recovered from RPC after a gap in the stream
+    #[serde(default)]
+    pub backfilled: bool,
+    /// Explorer pages of the event, filled in before publishing
+    #[serde(default)]
+    pub links: Option<Links>,
+}
+
+/// What showed a graduation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GraduationTrigger {
+    /// `CompleteEvent` of the buy that sold out the curve
+    CurveComplete,
+    /// `migrate` instruction moving the liquidity to the AMM
+    Migrate,
+    /// Legacy `withdraw` of the liquidity by the migration authority
+    Withdraw,
+}
+
+impl GraduationTrigger {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::CurveComplete => "curve_complete",
+            Self::Migrate => "migrate",
+            Self::Withdraw => "withdraw",
+        }
+    }
+}
+
+/// Liquidity added to or removed from an AMM pool
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Liquidity {
+    /// AMM of the pool
+    pub launchpad: LaunchpadType,
+    pub action: LiquidityAction,
+    pub pool: String,
+    pub token_address: String,
+    pub quote_mint: Option<String>,
+    /// Wallet depositing or withdrawing
+    pub provider: Option<String>,
+    /// LP tokens minted or burned, as requested by the instruction
+    pub lp_amount: u64,
+    /// Token and quote amounts moved in or out of the pool's vaults, in raw units
+    pub token_amount: Option<u64>,
+    pub quote_amount: Option<u64>,
+    pub signature: String,
+    pub slot: u64,
+    #[serde(default)]
+    pub transaction_index: Option<u64>,
+    /// When the transaction was received
+    pub timestamp: DateTime<Utc>,
+    /// Chain time of the transaction's block, extrapolated from nearby slots while its
+    /// block meta is pending
+    #[serde(default)]
+    pub block_time: Option<DateTime<Utc>>,
+    /// Recovered from RPC after a gap in the stream
+    #[serde(default)]
+    pub backfilled: bool,
+    /// Explorer pages of the event, filled in before publishing
+    #[serde(default)]
+    pub links: Option<Links>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LiquidityAction {
+    Add,
+    Remove,
+}
+
+impl LiquidityAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Add => "add",
+            Self::Remove => "remove",
+        }
+    }
+}
+
+/// Authority of a mint that can be revoked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthorityType {
+    /// Can mint more tokens
+    Mint,
+    /// Can freeze holders' token accounts
+    Freeze,
+}
+
+impl AuthorityType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Mint => "mint",
+            Self::Freeze => "freeze",
+        }
+    }
+}
+
+/// Mint or freeze authority of a launched token set to none after its launch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthorityRevoked {
+    /// Launchpad the token was launched on
+    pub launchpad: LaunchpadType,
+    pub token_address: String,
+    pub authority: AuthorityType,
+    /// Authority that signed the revocation
+    pub revoked_by: Option<String>,
+    pub signature: String,
+    pub slot: u64,
+    #[serde(default)]
+    pub transaction_index: Option<u64>,
+    /// When the transaction was received
+    pub timestamp: DateTime<Utc>,
+    /// Chain time of the transaction's block, extrapolated from nearby slots while its
+    /// block meta is pending
+    #[serde(default)]
+    pub block_time: Option<DateTime<Utc>>,
+    /// Recovered from RPC after a gap in the stream
+    #[serde(default)]
+    pub backfilled: bool,
+    /// Explorer pages of the event, filled in before publishing
+    #[serde(default)]
+    pub links: Option<Links>,
+}
+
+/// Creator of a launched token selling it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DevSell {
+    pub launchpad: LaunchpadType,
+    pub token_address: String,
+    pub creator: String,
+    /// Raw token amount sold
+    pub token_amount: u64,
+    /// SOL received in lamports
+    pub sol_amount: u64,
+    pub launch_signature: String,
+    /// Time from the launch to the sell, by block time where both are known
+    pub elapsed_ms: u64,
+    pub signature: String,
+    pub slot: u64,
+    #[serde(default)]
+    pub transaction_index: Option<u64>,
+    /// When the transaction was received
+    pub timestamp: DateTime<Utc>,
+    /// Chain time of the transaction's block, extrapolated from nearby slots while its
+    /// block meta is pending
+    #[serde(default)]
+    pub block_time: Option<DateTime<Utc>>,
+    /// Explorer pages of the event, filled in before publishing
+    #[serde(default)]
+    pub links: Option<Links>,
+}
+
+/// Creator of a launch moving a large share of the supply out of their token account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatorTransfer {
+    pub launchpad: LaunchpadType,
+    pub token_address: String,
+    pub creator: String,
+    /// Creator's token account the tokens left
+    pub source: String,
+    /// Token account receiving them
+    pub destination: String,
+    /// Owner of the destination, when it was created in the same transaction
+    pub recipient: Option<String>,
+    /// Raw token amount
+    pub amount: u64,
+    /// Share of the supply moved, in percent
+    pub percent_of_supply: f64,
+    pub signature: String,
+    pub slot: u64,
+    #[serde(default)]
+    pub transaction_index: Option<u64>,
+    /// When the transaction was received
+    pub timestamp: DateTime<Utc>,
+    /// Chain time of the transaction's block, extrapolated from nearby slots while its
+    /// block meta is pending
+    #[serde(default)]
+    pub block_time: Option<DateTime<Utc>>,
+    /// Recovered from RPC after a gap in the stream
+    #[serde(default)]
+    pub backfilled: bool,
+    /// Explorer pages of the event, filled in before publishing
+    #[serde(default)]
+    pub links: Option<Links>,
+}
+
+/// Buys of one wallet within the first slots of a launch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FirstBuyer {
+    pub wallet: String,
+    pub buys: u64,
+    /// SOL spent in lamports
+    pub sol_amount: u64,
+    /// Raw token amount bought
+    pub token_amount: u64,
+    pub first_slot: u64,
+    #[serde(default)]
+    pub first_transaction_index: Option<u64>,
+    /// Bought in the launch transaction itself
+    pub in_launch_transaction: bool,
+    pub is_creator: bool,
+}
+
+/// Wallets that bought a launch within the first slots after it, published once the
+/// window closed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FirstBuyers {
+    pub launchpad: LaunchpadType,
+    pub token_address: String,
+    pub creator: Option<String>,
+    pub launch_signature: String,
+    pub launch_slot: u64,
+    pub window_slots: u64,
+    /// Ordered by first buy, slot and position in the slot
+    pub buyers: Vec<FirstBuyer>,
+    /// SOL spent by the listed buyers in lamports
+    pub total_sol_amount: u64,
+    /// When the launch was received
+    pub timestamp: DateTime<Utc>,
+    /// Chain time of the launch's block
+    #[serde(default)]
+    pub block_time: Option<DateTime<Utc>>,
+    /// Explorer pages of the launch, filled in before publishing
+    #[serde(default)]
+    pub links: Option<Links>,
+}
+
+/// LP tokens of a pool seen created being burned, locking its liquidity for good
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LpBurned {
+    /// Launchpad of the pool's migration, the AMM itself for pools created directly
+    pub launchpad: LaunchpadType,
+    pub pool: String,
+    pub token_address: String,
+    pub lp_mint: String,
+    /// Owner of the burned LP tokens
+    pub owner: Option<String>,
+    /// LP tokens burned, in raw units
+    pub amount: u64,
+    pub signature: String,
+    pub slot: u64,
+    #[serde(default)]
+    pub transaction_index: Option<u64>,
+    /// When the transaction was received
+    pub timestamp: DateTime<Utc>,
+    /// Chain time of the transaction's block, extrapolated from nearby slots while its
+    /// block meta is pending
+    #[serde(default)]
+    pub block_time: Option<DateTime<Utc>>,
+    /// Recovered from RPC after a gap in the stream
+    #[serde(default)]
+    pub backfilled: bool,
+    /// Explorer pages of the event, filled in before publishing
+    #[serde(default)]
+    pub links: Option<Links>,
+}
+
+/// Bonding curve progress of a tracked launch, read from its curve account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CurveProgress {
+    pub launchpad: LaunchpadType,
+    pub token_address: String,
+    pub bonding_curve: String,
+    /// Share of the curve's tokens sold, in percent
+    pub percent_complete: f64,
+    pub virtual_sol_reserves: u64,
+    pub virtual_token_reserves: u64,
+    pub real_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    /// Set once the curve is sold out
+    pub complete: bool,
+    /// Slot of the account update
+    pub slot: u64,
+    /// When the account update was received
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Buy or sell of a launched token
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Trade {
+    pub launchpad: LaunchpadType,
+    pub token_address: String,
+    pub trader: String,
+    /// SOL side of the trade in lamports
+    pub amount: u64,
+    /// Raw token amount
+    pub token_amount: u64,
+    pub is_buy: bool,
+    pub fees: Option<TradeFees>,
+    /// Bonding curve progress towards graduation after the trade, in percent
+    #[serde(default)]
+    pub curve_progress: Option<f64>,
+    /// Price of one whole token in SOL after the trade, from the curve's reserves
+    #[serde(default)]
+    pub price_sol: Option<f64>,
+    /// Price times the supply, in SOL
+    #[serde(default)]
+    pub market_cap_sol: Option<f64>,
+    /// Decoded from the instruction alone, without the program's event. Amounts on the
+    /// side the trader did not fix are the slippage bound, not what was traded.
+    #[serde(default)]
+    pub estimated: bool,
+    pub signature: String,
+    pub slot: u64,
+    #[serde(default)]
+    pub transaction_index: Option<u64>,
+    /// When the transaction was received
+    pub timestamp: DateTime<Utc>,
+    /// Chain time of the transaction's block, extrapolated from nearby slots while its
+    /// block meta is pending
+    #[serde(default)]
+    pub block_time: Option<DateTime<Utc>>,
+}
+
+/// Fees paid on a trade, in lamports
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TradeFees {
+    pub fee_recipient: String,
+    pub protocol_fee: u64,
+    pub creator: Option<String>,
+    pub creator_fee: u64,
+}
+
+/// Recognized launchpad instruction that is neither a launch nor a trade, e.g. a
+/// change of the platform's fees
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProgramEvent {
+    pub launchpad: LaunchpadType,
+    /// Instruction name, e.g. `set_params`
+    pub event_type: String,
+    /// Decoded arguments worth reporting, e.g. new fee parameters
+    pub detail: Option<String>,
+    pub signature: String,
+    pub slot: u64,
+    #[serde(default)]
+    pub transaction_index: Option<u64>,
+    /// When the transaction was received
+    pub timestamp: DateTime<Utc>,
+    /// Chain time of the transaction's block, extrapolated from nearby slots while its
+    /// block meta is pending
+    #[serde(default)]
+    pub block_time: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug)]
 pub enum ParseResult {
     TokenLaunch(TokenLaunch),
-    Trade {
-        launchpad: LaunchpadType,
-        token_address: String,
-        trader: String,
-        amount: u64,
-        signature: String,
-        timestamp: DateTime<Utc>,
-    },
+    PartialParse(PartialParse),
+    FeeClaim(FeeClaim),
+    Trade(Trade),
+    Migration(Migration),
+    Graduation(Graduation),
+    Liquidity(Liquidity),
     Other {
         launchpad: LaunchpadType,
         event_type: String,
+        /// Decoded arguments worth reporting, e.g. new fee parameters
+        detail: Option<String>,
         signature: String,
     },
+    /// Recognized but left out by configuration, e.g. a dev buy below the minimum
+    Skipped {
+        launchpad: LaunchpadType,
+        reason: String,
+    },
     NotRelevant,
 }
 
+/// Sets the initial buy of each launch among `results` from the creator's buys of its
+/// mint in the same transaction. Left empty when none matched, as the buy may just not
+/// have been decoded, or when one of them is estimated, its amounts being slippage bounds.
+pub fn fill_initial_buys(results: &mut [ParseResult]) {
+    let buys: Vec<(String, String, u64, u64, bool)> = results
+        .iter()
+        .filter_map(|result| match result {
+            ParseResult::Trade(trade) if trade.is_buy => Some((
+                trade.token_address.clone(),
+                trade.trader.clone(),
+                trade.amount,
+                trade.token_amount,
+                trade.estimated,
+            )),
+            _ => None,
+        })
+        .collect();
+
+    for result in results.iter_mut() {
+        if let ParseResult::TokenLaunch(launch) = result
+            && let Some(creator) = &launch.creator
+        {
+            let creator_buys = buys.iter().filter(|(mint, trader, _, _, _)| {
+                *mint == launch.token_address && trader == creator
+            });
+            if creator_buys.clone().next().is_none()
+                || creator_buys
+                    .clone()
+                    .any(|(_, _, _, _, estimated)| *estimated)
+            {
+                continue;
+            }
+            let (sol, tokens) = creator_buys.fold(
+                (0u64, 0u64),
+                |(sol, tokens), (_, _, amount, token_amount, _)| {
+                    (
+                        sol.saturating_add(*amount),
+                        tokens.saturating_add(*token_amount),
+                    )
+                },
+            );
+            launch.initial_buy_sol = Some(sol);
+            launch.initial_buy_tokens = Some(tokens);
+        }
+    }
+}
+
+/// Event a parser can produce, named like the `type` of batched events
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventKind {
+    TokenLaunch,
+    PartialParse,
+    FeeClaim,
+    Migration,
+    Graduation,
+    Trade,
+    Liquidity,
+}
+
+/// Launch metadata field, named like its JSON key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MetadataField {
+    Name,
+    Symbol,
+    Uri,
+    InitialSupply,
+    MintAuthority,
+    FeeRecipient,
+    TokenExtensions,
+}
+
+impl MetadataField {
+    /// Name, symbol and uri, as read from the Metaplex metadata created in the launch
+    pub const METAPLEX: [MetadataField; 3] = [Self::Name, Self::Symbol, Self::Uri];
+}
+
+/// What a parser extracts, so consumers can tell which fields to expect per launchpad
+#[derive(Debug, Clone, Serialize)]
+pub struct ParserCapabilities {
+    pub events: Vec<EventKind>,
+    /// Metadata fields the parser fills itself. The pipeline completes name, symbol and
+    /// uri from Metaplex, token extensions from Token-2022 mints, and supply and mint
+    /// authority with enrichment.
+    pub metadata: Vec<MetadataField>,
+}
+
 pub trait LaunchpadParser: Send + Sync {
     /// Returns the program IDs that this parser handles
     fn get_program_ids(&self) -> Vec<String>;
@@ -59,4 +868,79 @@ pub trait LaunchpadParser: Send + Sync {
 
     /// Get the launchpad type
     fn get_launchpad_type(&self) -> LaunchpadType;
+
+    /// Events produced and metadata fields filled by this parser
+    fn capabilities(&self) -> ParserCapabilities;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{launch, start_time};
+    use serde_json::json;
+
+    fn creator_launch() -> TokenLaunch {
+        let mut launch = launch("sig", 1, start_time());
+        launch.creator = Some("creator".to_string());
+        launch
+    }
+
+    fn buy(token_address: &str, amount: u64, estimated: bool) -> ParseResult {
+        ParseResult::Trade(
+            serde_json::from_value(json!({
+                "launchpad": "Pumpfun",
+                "token_address": token_address,
+                "trader": "creator",
+                "amount": amount,
+                "token_amount": amount * 10,
+                "is_buy": true,
+                "fees": null,
+                "estimated": estimated,
+                "signature": "sig",
+                "slot": 1,
+                "timestamp": start_time(),
+            }))
+            .unwrap(),
+        )
+    }
+
+    fn initial_buy(results: &[ParseResult]) -> (Option<u64>, Option<u64>) {
+        match &results[0] {
+            ParseResult::TokenLaunch(launch) => (launch.initial_buy_sol, launch.initial_buy_tokens),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn initial_buy_sums_the_creators_buys() {
+        let mut results = vec![
+            ParseResult::TokenLaunch(creator_launch()),
+            buy("mint-sig", 2, false),
+            buy("mint-sig", 3, false),
+            buy("other", 7, false),
+        ];
+        fill_initial_buys(&mut results);
+        assert_eq!(initial_buy(&results), (Some(5), Some(50)));
+    }
+
+    #[test]
+    fn initial_buy_is_unknown_without_a_matching_buy() {
+        let mut results = vec![
+            ParseResult::TokenLaunch(creator_launch()),
+            buy("other", 7, false),
+        ];
+        fill_initial_buys(&mut results);
+        assert_eq!(initial_buy(&results), (None, None));
+    }
+
+    #[test]
+    fn initial_buy_is_unknown_with_an_estimated_buy() {
+        let mut results = vec![
+            ParseResult::TokenLaunch(creator_launch()),
+            buy("mint-sig", 2, false),
+            buy("mint-sig", 3, true),
+        ];
+        fill_initial_buys(&mut results);
+        assert_eq!(initial_buy(&results), (None, None));
+    }
 }
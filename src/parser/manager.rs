@@ -1,24 +1,154 @@
-use crate::geyser::{QueuedTransaction, TransactionQueue};
-use crate::parser::{LaunchpadParser, ParseResult, TokenLaunch};
-use crate::rabbitmq::RabbitMQProducer;
-use std::collections::HashMap;
-use std::sync::Arc;
+use crate::config::explorer::Explorer;
+use crate::config::first_buyers::FirstBuyersConfig;
+use crate::config::parsers::ParsersConfig;
+use crate::config::partition::PartitionConfig;
+#[cfg(feature = "enrichment")]
+use crate::enrichment::EnrichmentHandle;
+use crate::geyser::{AccountSubscriptions, QueuedTransaction, TransactionQueue};
+use crate::metrics;
+use crate::monitor::{
+    Digester, DropReason, DroppedTransaction, ErrorRecorder, FeeTracker, LaunchClusterer,
+    LaunchTracker, OpsError, OpsErrorKind, ParserStats, drops, ops,
+};
+use crate::parser::first_buyers::FirstBuyersCollector;
+use crate::parser::jito::TipTracker;
+use crate::parser::launchpad_parser::LaunchMetadata;
+use crate::parser::{
+    AuthorityRevoked, CreatorTransfer, DevSell, FeeClaim, Graduation, LaunchpadParser,
+    LaunchpadType, Links, Liquidity, LpBurned, Migration, ParseResult, ParserCapabilities,
+    PartialParse, ProgramEvent, TokenLaunch, Trade,
+};
+use crate::parser::{metadata, normalize, spl, token_2022};
+use crate::sink::EventSink;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, Mutex};
 use tokio::time::{Duration, sleep};
 use tracing::{error, info, trace, warn};
 
 pub struct ParserManager {
     parsers: Vec<Box<dyn LaunchpadParser + Send + Sync>>,
     program_id_to_parser: HashMap<String, usize>,
-    rabbit_producer: Option<Arc<RabbitMQProducer>>,
+    sink: Option<Arc<dyn EventSink>>,
+    recorder: Option<ErrorRecorder>,
+    digester: Option<Digester>,
+    fees: Option<FeeTracker>,
+    tracker: Option<LaunchTracker>,
+    account_subscriptions: Option<AccountSubscriptions>,
+    explorer: Option<Explorer>,
+    stats: Option<ParserStats>,
+    clusterer: Option<LaunchClusterer>,
+    first_buyers: Option<FirstBuyersCollector>,
+    /// Hand trades to the sink, not only to the trackers
+    publish_trades: bool,
+    /// Publish other recognized instructions instead of recording them as dropped
+    publish_program_events: bool,
+    /// Tokens whose graduation was published, so completion and migration report once
+    graduated: Mutex<GraduatedTokens>,
+    /// LP mints of pools seen created, so burns of them are published
+    lp_mints: Mutex<RecentMap<LpPool>>,
+    /// Positions of recent transactions paying a Jito tip, to flag bundled launches
+    tips: TipTracker,
+    /// Mints seen launched, so revocations of their authorities and sells by their
+    /// creators are published
+    launched_mints: Mutex<RecentMap<LaunchedMint>>,
+    #[cfg(feature = "enrichment")]
+    enricher: Option<EnrichmentHandle>,
+}
+
+/// Capabilities of a registered parser, served on `/parsers`
+#[derive(Debug, Clone, Serialize)]
+pub struct LaunchpadCapabilities {
+    pub launchpad: LaunchpadType,
+    pub program_ids: Vec<String>,
+    #[serde(flatten)]
+    pub capabilities: ParserCapabilities,
+}
+
+/// Tokens seen graduating, the oldest forgotten past `GRADUATED_TOKENS_CAPACITY`
+#[derive(Default)]
+struct GraduatedTokens {
+    tokens: HashSet<String>,
+    order: VecDeque<String>,
+}
+
+const GRADUATED_TOKENS_CAPACITY: usize = 10_000;
+
+impl GraduatedTokens {
+    /// Whether the token graduates for the first time
+    fn insert(&mut self, token: &str) -> bool {
+        if !self.tokens.insert(token.to_string()) {
+            return false;
+        }
+        self.order.push_back(token.to_string());
+        if self.order.len() > GRADUATED_TOKENS_CAPACITY
+            && let Some(oldest) = self.order.pop_front()
+        {
+            self.tokens.remove(&oldest);
+        }
+        true
+    }
+}
+
+/// Pool behind an LP mint
+#[derive(Clone)]
+struct LpPool {
+    launchpad: LaunchpadType,
+    pool: String,
+    token_address: String,
+}
+
+/// Launch of a mint
+#[derive(Clone)]
+struct LaunchedMint {
+    launchpad: LaunchpadType,
+    signature: String,
+    creator: Option<String>,
+    /// Block time where known, else when the launch was received
+    launched_at: DateTime<Utc>,
+}
+
+/// Values of recently seen addresses, the oldest forgotten past `RECENT_MAP_CAPACITY`
+struct RecentMap<V> {
+    entries: HashMap<String, V>,
+    order: VecDeque<String>,
+}
+
+const RECENT_MAP_CAPACITY: usize = 10_000;
+
+impl<V> Default for RecentMap<V> {
+    fn default() -> Self {
+        Self {
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+}
+
+impl<V> RecentMap<V> {
+    fn insert(&mut self, address: &str, value: V) {
+        if self.entries.insert(address.to_string(), value).is_some() {
+            return;
+        }
+        self.order.push_back(address.to_string());
+        if self.order.len() > RECENT_MAP_CAPACITY
+            && let Some(oldest) = self.order.pop_front()
+        {
+            self.entries.remove(&oldest);
+        }
+    }
 }
 
 impl ParserManager {
-    pub fn new(rabbit_producer: Option<Arc<RabbitMQProducer>>) -> Self {
+    pub fn new(sink: Option<Arc<dyn EventSink>>, config: &ParsersConfig) -> Self {
         let mut parsers: Vec<Box<dyn LaunchpadParser + Send + Sync>> = Vec::new();
         let mut program_id_to_parser = HashMap::new();
 
         // Add PumpFun parser
-        let pumpfun_parser = Box::new(crate::parser::pumpfun::PumpfunParser::new());
+        let pumpfun_parser = Box::new(crate::parser::pumpfun::PumpfunParser::new(
+            config.pumpfun.clone(),
+        ));
         let parser_index = parsers.len();
         for program_id in pumpfun_parser.get_program_ids() {
             program_id_to_parser.insert(program_id, parser_index);
@@ -26,20 +156,354 @@ impl ParserManager {
         parsers.push(pumpfun_parser);
 
         // Add Meteora DBC parser
-        let meteora_parser = Box::new(crate::parser::meteora::MeteoraParser::new());
+        let meteora_parser = Box::new(crate::parser::meteora::MeteoraParser::new(
+            config.meteora.clone(),
+        ));
         let parser_index = parsers.len();
         for program_id in meteora_parser.get_program_ids() {
             program_id_to_parser.insert(program_id, parser_index);
         }
         parsers.push(meteora_parser);
 
+        // Add Moonshot parser
+        let moonshot_parser = Box::new(crate::parser::moonshot::MoonshotParser::new());
+        let parser_index = parsers.len();
+        for program_id in moonshot_parser.get_program_ids() {
+            program_id_to_parser.insert(program_id, parser_index);
+        }
+        parsers.push(moonshot_parser);
+
+        // Add Raydium LaunchLab parser, also reporting LetsBonk.fun launches
+        let launchlab_parser = Box::new(crate::parser::launchlab::LaunchLabParser::new(
+            config.launchlab.clone(),
+        ));
+        let parser_index = parsers.len();
+        for program_id in launchlab_parser.get_program_ids() {
+            program_id_to_parser.insert(program_id, parser_index);
+        }
+        parsers.push(launchlab_parser);
+
+        // Add Vertigo parser
+        let vertigo_parser = Box::new(crate::parser::vertigo::VertigoParser::new());
+        let parser_index = parsers.len();
+        for program_id in vertigo_parser.get_program_ids() {
+            program_id_to_parser.insert(program_id, parser_index);
+        }
+        parsers.push(vertigo_parser);
+
+        // Add Fluxbeam parser, reporting tokens launched with a new pool
+        let fluxbeam_parser = Box::new(crate::parser::fluxbeam::FluxbeamParser::new());
+        let parser_index = parsers.len();
+        for program_id in fluxbeam_parser.get_program_ids() {
+            program_id_to_parser.insert(program_id, parser_index);
+        }
+        parsers.push(fluxbeam_parser);
+
+        // Add PumpSwap parser, reporting pump.fun graduations
+        let pumpswap_parser = Box::new(crate::parser::pumpswap::PumpSwapParser::new());
+        let parser_index = parsers.len();
+        for program_id in pumpswap_parser.get_program_ids() {
+            program_id_to_parser.insert(program_id, parser_index);
+        }
+        parsers.push(pumpswap_parser);
+
+        // Add Raydium CPMM parser, reporting LaunchLab graduations and new pools
+        let raydium_cpmm_parser = Box::new(crate::parser::raydium_cpmm::RaydiumCpmmParser::new());
+        let parser_index = parsers.len();
+        for program_id in raydium_cpmm_parser.get_program_ids() {
+            program_id_to_parser.insert(program_id, parser_index);
+        }
+        parsers.push(raydium_cpmm_parser);
+
+        // Add Raydium CLMM parser, reporting new concentrated liquidity pools
+        let raydium_clmm_parser = Box::new(crate::parser::raydium_clmm::RaydiumClmmParser::new());
+        let parser_index = parsers.len();
+        for program_id in raydium_clmm_parser.get_program_ids() {
+            program_id_to_parser.insert(program_id, parser_index);
+        }
+        parsers.push(raydium_clmm_parser);
+
+        // Add Meteora DLMM parser, reporting new liquidity book pairs
+        let meteora_dlmm_parser = Box::new(crate::parser::meteora_dlmm::MeteoraDlmmParser::new());
+        let parser_index = parsers.len();
+        for program_id in meteora_dlmm_parser.get_program_ids() {
+            program_id_to_parser.insert(program_id, parser_index);
+        }
+        parsers.push(meteora_dlmm_parser);
+
+        // Add Daos.fun parser
+        let daos_fun_parser = Box::new(crate::parser::daos::DaosFunParser::new(
+            config.daos_fun.clone(),
+        ));
+        let parser_index = parsers.len();
+        for program_id in daos_fun_parser.get_program_ids() {
+            program_id_to_parser.insert(program_id, parser_index);
+        }
+        parsers.push(daos_fun_parser);
+
+        // Add Believe parser, only once its program is configured
+        if !config.believe.program_ids.is_empty() {
+            let believe_parser = Box::new(crate::parser::believe::BelieveParser::new(
+                config.believe.clone(),
+            ));
+            let parser_index = parsers.len();
+            for program_id in believe_parser.get_program_ids() {
+                program_id_to_parser.insert(program_id, parser_index);
+            }
+            parsers.push(believe_parser);
+        }
+
+        // Add Bags.fm parser, only once its program is configured
+        if !config.bags.program_ids.is_empty() {
+            let bags_parser = Box::new(crate::parser::bags::BagsParser::new(config.bags.clone()));
+            let parser_index = parsers.len();
+            for program_id in bags_parser.get_program_ids() {
+                program_id_to_parser.insert(program_id, parser_index);
+            }
+            parsers.push(bags_parser);
+        }
+
+        // Add Virtuals parser, only once its program is configured
+        if !config.virtuals.program_ids.is_empty() {
+            let virtuals_parser = Box::new(crate::parser::virtuals::VirtualsParser::new(
+                config.virtuals.clone(),
+            ));
+            let parser_index = parsers.len();
+            for program_id in virtuals_parser.get_program_ids() {
+                program_id_to_parser.insert(program_id, parser_index);
+            }
+            parsers.push(virtuals_parser);
+        }
+
+        // Add Time.fun parser, only once its program is configured
+        if !config.time_fun.program_ids.is_empty() {
+            let time_fun_parser = Box::new(crate::parser::time_fun::TimeFunParser::new(
+                config.time_fun.clone(),
+            ));
+            let parser_index = parsers.len();
+            for program_id in time_fun_parser.get_program_ids() {
+                program_id_to_parser.insert(program_id, parser_index);
+            }
+            parsers.push(time_fun_parser);
+        }
+
+        // Add the launchpads configured without code, skipping invalid entries
+        for generic in &config.generic {
+            let generic_parser = match crate::parser::generic::GenericAnchorParser::new(generic) {
+                Ok(parser) => Box::new(parser),
+                Err(e) => {
+                    error!("❌ Skipping generic parser {}: {:#}", generic.name, e);
+                    continue;
+                }
+            };
+            let parser_index = parsers.len();
+            for program_id in generic_parser.get_program_ids() {
+                program_id_to_parser.insert(program_id, parser_index);
+            }
+            parsers.push(generic_parser);
+        }
+
+        // Add the discriminator table, one parser per configured program
+        for table_parser in crate::parser::discriminator_table::DiscriminatorTableParser::from_table(
+            &config.discriminator_table,
+        ) {
+            let table_parser = Box::new(table_parser);
+            let parser_index = parsers.len();
+            for program_id in table_parser.get_program_ids() {
+                program_id_to_parser.insert(program_id, parser_index);
+            }
+            parsers.push(table_parser);
+        }
+
+        // Add generic SPL mint parser, only when enabled
+        if config.spl_mint.enabled {
+            let spl_mint_parser = Box::new(crate::parser::spl_mint::SplMintParser::new(
+                config.spl_mint.clone(),
+            ));
+            let parser_index = parsers.len();
+            for program_id in spl_mint_parser.get_program_ids() {
+                program_id_to_parser.insert(program_id, parser_index);
+            }
+            parsers.push(spl_mint_parser);
+        }
+
         Self {
             parsers,
             program_id_to_parser,
-            rabbit_producer,
+            sink,
+            recorder: None,
+            digester: None,
+            fees: None,
+            tracker: None,
+            account_subscriptions: None,
+            explorer: None,
+            stats: None,
+            clusterer: None,
+            first_buyers: None,
+            publish_trades: true,
+            publish_program_events: false,
+            graduated: Mutex::default(),
+            lp_mints: Mutex::default(),
+            launched_mints: Mutex::default(),
+            tips: TipTracker::default(),
+            #[cfg(feature = "enrichment")]
+            enricher: None,
         }
     }
 
+    /// Keeps only the parsers of launchpads assigned to this instance
+    pub fn with_partition(mut self, partition: &PartitionConfig) -> Self {
+        let names: Vec<String> = self
+            .parsers
+            .iter()
+            .map(|parser| parser.get_launchpad_type().to_string())
+            .collect();
+        self.parsers = std::mem::take(&mut self.parsers)
+            .into_iter()
+            .zip(&names)
+            .filter(|(_, name)| partition.assigns(name, &names))
+            .map(|(parser, _)| parser)
+            .collect();
+
+        self.program_id_to_parser = HashMap::new();
+        for (parser_index, parser) in self.parsers.iter().enumerate() {
+            for program_id in parser.get_program_ids() {
+                self.program_id_to_parser.insert(program_id, parser_index);
+            }
+        }
+        self
+    }
+
+    /// Launchpads with a registered parser
+    pub fn launchpads(&self) -> Vec<LaunchpadType> {
+        self.parsers
+            .iter()
+            .map(|parser| parser.get_launchpad_type())
+            .collect()
+    }
+
+    /// Sets the display-safe name and symbol, counting metadata that tried to spoof its display
+    fn normalize_metadata(&self, metadata: &mut LaunchMetadata, launchpad: &LaunchpadType) {
+        metadata.normalized = normalize::normalize(metadata);
+        if metadata
+            .normalized
+            .as_ref()
+            .is_some_and(|normalized| normalized.bidi_override)
+        {
+            warn!(
+                "⚠️ {} token {:?} uses bidi overrides",
+                launchpad, metadata.name
+            );
+            metrics::global().inc_counter(
+                "metadata_bidi_override_total",
+                &[("launchpad", &launchpad.to_string())],
+            );
+        }
+    }
+
+    /// Program IDs of each registered parser, by launchpad name in registration order
+    pub fn program_ids_by_launchpad(&self) -> Vec<(String, Vec<String>)> {
+        self.parsers
+            .iter()
+            .map(|parser| {
+                (
+                    parser.get_launchpad_type().to_string(),
+                    parser.get_program_ids(),
+                )
+            })
+            .collect()
+    }
+
+    /// Events and metadata fields of each registered parser
+    pub fn capabilities(&self) -> Vec<LaunchpadCapabilities> {
+        self.parsers
+            .iter()
+            .map(|parser| LaunchpadCapabilities {
+                launchpad: parser.get_launchpad_type(),
+                program_ids: parser.get_program_ids(),
+                capabilities: parser.capabilities(),
+            })
+            .collect()
+    }
+
+    /// Program IDs handled by the registered parsers
+    pub fn program_ids(&self) -> Vec<String> {
+        self.program_id_to_parser.keys().cloned().collect()
+    }
+
+    /// Whether trades are published, they still feed the trackers otherwise
+    pub fn with_trade_publishing(mut self, enabled: bool) -> Self {
+        self.publish_trades = enabled;
+        self
+    }
+
+    /// Whether recognized instructions that are neither launches nor trades are published
+    pub fn with_program_event_publishing(mut self, enabled: bool) -> Self {
+        self.publish_program_events = enabled;
+        self
+    }
+
+    /// Records raw samples of transactions that make a parser fail
+    pub fn with_recorder(mut self, recorder: ErrorRecorder) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
+    /// Feeds launches and trades into the periodic digest
+    pub fn with_digester(mut self, digester: Digester) -> Self {
+        self.digester = Some(digester);
+        self
+    }
+
+    /// Accumulates fees paid on parsed trades per token
+    pub fn with_fee_tracker(mut self, fees: FeeTracker) -> Self {
+        self.fees = Some(fees);
+        self
+    }
+
+    /// Publishes the wallets buying each launch within its first slots
+    pub fn with_first_buyers(mut self, config: FirstBuyersConfig) -> Self {
+        self.first_buyers = Some(FirstBuyersCollector::new(config));
+        self
+    }
+
+    /// Follows trading on launched tokens for their status events
+    pub fn with_tracker(mut self, tracker: LaunchTracker) -> Self {
+        self.tracker = Some(tracker);
+        self
+    }
+
+    /// Subscribes to the pool accounts of launches through the Geyser client
+    pub fn with_account_subscriptions(mut self, subscriptions: AccountSubscriptions) -> Self {
+        self.account_subscriptions = Some(subscriptions);
+        self
+    }
+
+    /// Adds explorer links to published launches, partial parses and fee claims
+    pub fn with_explorer(mut self, explorer: Explorer) -> Self {
+        self.explorer = Some(explorer);
+        self
+    }
+
+    /// Counts matches, launches and errors per parser across restarts
+    pub fn with_stats(mut self, stats: ParserStats) -> Self {
+        self.stats = Some(stats);
+        self
+    }
+
+    /// Tags launches with the cluster of related launches they belong to
+    pub fn with_clusterer(mut self, clusterer: LaunchClusterer) -> Self {
+        self.clusterer = Some(clusterer);
+        self
+    }
+
+    /// Hands launches to the mint enrichment worker, which publishes them once enriched
+    #[cfg(feature = "enrichment")]
+    pub fn with_enricher(mut self, enricher: EnrichmentHandle) -> Self {
+        self.enricher = Some(enricher);
+        self
+    }
+
     /// Start processing transactions from the queue
     pub async fn start_processing(&self, queue: Arc<TransactionQueue>) {
         info!("🚀 Starting transaction parser manager");
@@ -54,6 +518,11 @@ impl ParserManager {
 
             trace!("📦 Processing batch of {} transactions", transactions.len());
 
+            // Bundles usually pay their tip in the last transaction, after the launch
+            for transaction in &transactions {
+                self.tips.record(transaction);
+            }
+
             for transaction in transactions {
                 if let Err(e) = self.process_transaction(&transaction).await {
                     error!(
@@ -70,36 +539,192 @@ impl ParserManager {
         &self,
         transaction: &QueuedTransaction,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        // The stream reaching a slot closes the first buyers windows before it
+        self.publish_first_buyers(transaction.slot).await;
+
         let mut relevant_parsers = Vec::new();
 
-        // Check which parsers should handle this transaction based on program IDs in instructions
-        for instruction in &transaction.instructions {
-            if let Some(&parser_index) = self.program_id_to_parser.get(&instruction.program_id) {
-                if !relevant_parsers.contains(&parser_index) {
-                    relevant_parsers.push(parser_index);
-                }
+        // Check which parsers should handle this transaction based on program IDs in instructions,
+        // inner ones included so calls routed through aggregators are seen
+        for instruction in transaction
+            .instructions
+            .iter()
+            .chain(&transaction.inner_instructions)
+        {
+            if let Some(&parser_index) = self.program_id_to_parser.get(&instruction.program_id)
+                && !relevant_parsers.contains(&parser_index)
+            {
+                relevant_parsers.push(parser_index);
             }
         }
 
+        // Launchpads create their mints through the token program too, the generic mint
+        // parser only gets transactions no launchpad parser matched
+        if relevant_parsers.len() > 1 {
+            relevant_parsers.retain(|&parser_index| {
+                !matches!(
+                    self.parsers[parser_index].get_launchpad_type(),
+                    LaunchpadType::Unknown
+                )
+            });
+        }
+
         if relevant_parsers.is_empty() {
+            // Burns of LP tokens and authority changes go through the token program only
+            if self.handle_token_program(transaction).await {
+                return Ok(());
+            }
+            drops::record(DroppedTransaction::new(
+                DropReason::NoMatchingParser,
+                &transaction.signature,
+                transaction.slot,
+            ));
             return Ok(());
         }
 
+        // Why the transaction produced no event, recorded once all parsers ran. Skips are
+        // recorded as they come.
+        let mut accounted = false;
+        let mut failure: Option<(LaunchpadType, String)> = None;
+        let mut ignored: Vec<(LaunchpadType, String)> = Vec::new();
+
         // Process with each relevant parser
         for &parser_index in &relevant_parsers {
             if let Some(parser) = self.parsers.get(parser_index) {
+                let launchpad = parser.get_launchpad_type();
+                if let Some(stats) = &self.stats {
+                    stats.record_match(&launchpad);
+                }
+
                 match parser.parse_transaction(transaction) {
                     Ok(results) => {
                         for result in results {
+                            accounted |= !matches!(
+                                result,
+                                ParseResult::Other { .. } | ParseResult::NotRelevant
+                            );
                             match result {
-                                ParseResult::TokenLaunch(launch) => {
+                                ParseResult::TokenLaunch(mut launch) => {
+                                    // Metadata the launchpad parser could not decode, from the
+                                    // Metaplex account created for the mint alongside
+                                    if metadata::fill_missing(
+                                        &mut launch.metadata,
+                                        transaction,
+                                        &launch.token_address,
+                                    ) {
+                                        metrics::global().inc_counter(
+                                            "metadata_filled_total",
+                                            &[("launchpad", &launchpad.to_string())],
+                                        );
+                                    }
+                                    self.normalize_metadata(&mut launch.metadata, &launchpad);
+                                    if launch.metadata.token_extensions.is_none() {
+                                        launch.metadata.token_extensions =
+                                            token_2022::mint_extensions(
+                                                transaction,
+                                                &launch.token_address,
+                                            );
+                                    }
+                                    if let Some(stats) = &self.stats {
+                                        stats.record_launch(&launchpad);
+                                    }
+                                    if let Some(clusterer) = &self.clusterer {
+                                        clusterer.assign(&mut launch, transaction);
+                                    }
+                                    launch.bundled = self.tips.bundled(transaction);
+                                    if let Some(subscriptions) = &self.account_subscriptions
+                                        && let Some(creator) = &launch.creator
+                                        && let Some(account) = spl::initialized_token_account(
+                                            transaction,
+                                            &launch.token_address,
+                                            creator,
+                                        )
+                                    {
+                                        subscriptions.track_creator_account(&launch, &account);
+                                    }
+                                    if launch.bundled {
+                                        metrics::global().inc_counter(
+                                            "bundled_launches_total",
+                                            &[("launchpad", &launchpad.to_string())],
+                                        );
+                                    }
                                     self.handle_token_launch(launch).await?;
                                 }
-                                ParseResult::Trade { .. } => {
-                                    // Skip trading events for now, only interested in launches
+                                ParseResult::PartialParse(mut partial) => {
+                                    self.normalize_metadata(&mut partial.metadata, &launchpad);
+                                    if let Some(stats) = &self.stats {
+                                        stats.record_partial_parse(&launchpad);
+                                    }
+                                    if let Some(recorder) = &self.recorder {
+                                        let reason = format!(
+                                            "partial parse, missing: {}",
+                                            partial.missing_fields.join(", ")
+                                        );
+                                        recorder
+                                            .record(
+                                                transaction,
+                                                &partial.launchpad.to_string(),
+                                                &reason,
+                                            )
+                                            .await;
+                                    }
+                                    self.handle_partial_parse(partial).await;
+                                }
+                                ParseResult::FeeClaim(claim) => {
+                                    self.handle_fee_claim(claim).await;
+                                }
+                                ParseResult::Trade(trade) => {
+                                    self.handle_trade(&trade).await;
+                                }
+                                ParseResult::Migration(migration) => {
+                                    self.handle_migration(migration).await;
+                                }
+                                ParseResult::Graduation(graduation) => {
+                                    self.handle_graduation(graduation).await;
                                 }
-                                ParseResult::Other { .. } => {
-                                    // Skip other events for now, only interested in launches
+                                ParseResult::Liquidity(liquidity) => {
+                                    self.handle_liquidity(liquidity).await;
+                                }
+                                ParseResult::Other {
+                                    launchpad: event_launchpad,
+                                    event_type,
+                                    detail,
+                                    signature,
+                                } => {
+                                    if self.publish_program_events {
+                                        accounted = true;
+                                        self.handle_program_event(ProgramEvent {
+                                            launchpad: event_launchpad,
+                                            event_type,
+                                            detail,
+                                            signature,
+                                            slot: transaction.slot,
+                                            transaction_index: transaction.transaction_index,
+                                            timestamp: transaction.received_time,
+                                            block_time: transaction.block_time,
+                                        })
+                                        .await;
+                                    } else {
+                                        let event = match detail {
+                                            Some(detail) => format!("{} {}", event_type, detail),
+                                            None => event_type,
+                                        };
+                                        ignored.push((launchpad.clone(), event));
+                                    }
+                                }
+                                ParseResult::Skipped {
+                                    launchpad: skipped,
+                                    reason,
+                                } => {
+                                    drops::record(
+                                        DroppedTransaction::new(
+                                            DropReason::Policy,
+                                            &transaction.signature,
+                                            transaction.slot,
+                                        )
+                                        .with_launchpad(skipped.to_string())
+                                        .with_detail(reason),
+                                    );
                                 }
                                 ParseResult::NotRelevant => {
                                     // Skip irrelevant transactions
@@ -109,22 +734,742 @@ impl ParserManager {
                     }
                     Err(e) => {
                         warn!("⚠️  Parser error for {}: {}", transaction.signature, e);
+                        if let Some(stats) = &self.stats {
+                            stats.record_error(&launchpad);
+                        }
+                        let parser_name = launchpad.to_string();
+                        failure.get_or_insert_with(|| (launchpad.clone(), e.to_string()));
+                        ops::report(
+                            OpsError::new(OpsErrorKind::ParserFailure, &parser_name, &e)
+                                .with_transaction(&transaction.signature, transaction.slot),
+                        );
+                        if let Some(recorder) = &self.recorder {
+                            recorder
+                                .record(transaction, &parser_name, &e.to_string())
+                                .await;
+                        }
                     }
                 }
             }
         }
 
+        // The pool may be created in the same transaction, after its parser ran
+        accounted |= self.handle_token_program(transaction).await;
+
+        // After the launches are recorded, so wallets funded in the launch itself count
+        if let Some(tracker) = &self.tracker {
+            tracker.record_funding(transaction);
+        }
+
+        if !accounted {
+            let dropped = if let Some((launchpad, error)) = failure {
+                DroppedTransaction::new(
+                    DropReason::ExtractionFailure,
+                    &transaction.signature,
+                    transaction.slot,
+                )
+                .with_launchpad(launchpad.to_string())
+                .with_detail(error)
+            } else if let Some((launchpad, _)) = ignored.first() {
+                let events: Vec<&str> = ignored.iter().map(|(_, event)| event.as_str()).collect();
+                DroppedTransaction::new(
+                    DropReason::IgnoredInstruction,
+                    &transaction.signature,
+                    transaction.slot,
+                )
+                .with_launchpad(launchpad.to_string())
+                .with_detail(events.join(", "))
+            } else {
+                let launchpads: Vec<String> = relevant_parsers
+                    .iter()
+                    .map(|&index| self.parsers[index].get_launchpad_type().to_string())
+                    .collect();
+                DroppedTransaction::new(
+                    DropReason::DiscriminatorMismatch,
+                    &transaction.signature,
+                    transaction.slot,
+                )
+                .with_detail(launchpads.join(", "))
+            };
+            drops::record(dropped);
+        }
+
         Ok(())
     }
 
+    /// Handle a launch instruction that could only be partially extracted
+    async fn handle_partial_parse(&self, mut partial: PartialParse) {
+        metrics::global().inc_counter(
+            "partial_parses_total",
+            &[("launchpad", &partial.launchpad.to_string())],
+        );
+
+        warn!(
+            "⚠️  Partial {} {} parse in {} (confidence {:.2}, missing: {})",
+            partial.launchpad,
+            partial.instruction,
+            partial.signature,
+            partial.confidence,
+            partial.missing_fields.join(", ")
+        );
+
+        partial.links = self.explorer.map(|explorer| {
+            Links::new(
+                explorer,
+                &partial.signature,
+                partial.token_address.as_deref(),
+                partial.creator.as_deref(),
+            )
+        });
+
+        // Publish to the event sink, if available
+        if let Some(sink) = &self.sink
+            && let Err(e) = sink.publish_partial_parse(&partial).await
+        {
+            warn!("Failed to publish partial parse: {}", e);
+            ops::report(
+                OpsError::new(OpsErrorKind::SinkFailure, "parser", &e)
+                    .with_transaction(&partial.signature, partial.slot),
+            );
+        }
+    }
+
+    /// Handle a trade, trades feed the digest volume, fee and launch tracking and are published
+    /// unless `PUBLISH_TRADES=false`. Alerts raised by the tracker are published either way.
+    async fn handle_trade(&self, trade: &Trade) {
+        if let Some(digester) = &self.digester {
+            digester.record_trade(&trade.launchpad, &trade.token_address, trade.amount);
+        }
+        metrics::global().inc_counter(
+            "trades_total",
+            &[
+                ("launchpad", &trade.launchpad.to_string()),
+                ("side", if trade.is_buy { "buy" } else { "sell" }),
+            ],
+        );
+        if self.publish_trades
+            && let Some(sink) = &self.sink
+            && let Err(e) = sink.publish_trade(trade).await
+        {
+            warn!("Failed to publish trade: {}", e);
+            ops::report(
+                OpsError::new(OpsErrorKind::SinkFailure, "parser", &e)
+                    .with_transaction(&trade.signature, trade.slot),
+            );
+        }
+        if !trade.is_buy {
+            self.handle_dev_sell(trade).await;
+        }
+        if let Some(first_buyers) = &self.first_buyers {
+            first_buyers.record_trade(trade);
+        }
+        if let (Some(fees), Some(trade_fees)) = (&self.fees, &trade.fees) {
+            fees.record(
+                &trade.launchpad,
+                &trade.token_address,
+                trade_fees,
+                trade.timestamp,
+            );
+        }
+        let Some(tracker) = &self.tracker else {
+            return;
+        };
+        for alert in tracker.record_trade(trade) {
+            metrics::global().inc_counter(
+                "token_alerts_total",
+                &[("launchpad", &trade.launchpad.to_string())],
+            );
+            warn!("🚨 Alert on {}: {:?}", trade.token_address, alert);
+
+            if let Some(sink) = &self.sink
+                && let Err(e) = sink.publish_alert(&alert).await
+            {
+                warn!("Failed to publish token alert: {}", e);
+                ops::report(
+                    OpsError::new(OpsErrorKind::SinkFailure, "parser", &e)
+                        .with_transaction(&trade.signature, trade.slot),
+                );
+            }
+        }
+    }
+
+    /// Handle a partner or creator fee claim
+    async fn handle_fee_claim(&self, mut claim: FeeClaim) {
+        metrics::global().inc_counter(
+            "fee_claims_total",
+            &[
+                ("launchpad", &claim.launchpad.to_string()),
+                ("kind", &format!("{:?}", claim.kind)),
+            ],
+        );
+
+        claim.links = self.explorer.map(|explorer| {
+            Links::new(
+                explorer,
+                &claim.signature,
+                claim.base_mint.as_deref(),
+                claim.claimer.as_deref(),
+            )
+        });
+
+        // Publish to the event sink, if available
+        if let Some(sink) = &self.sink
+            && let Err(e) = sink.publish_fee_claim(&claim).await
+        {
+            warn!("Failed to publish fee claim: {}", e);
+            ops::report(
+                OpsError::new(OpsErrorKind::SinkFailure, "parser", &e)
+                    .with_transaction(&claim.signature, claim.slot),
+            );
+        }
+    }
+
+    /// Handle a token graduating to an AMM pool
+    async fn handle_migration(&self, mut migration: Migration) {
+        metrics::global().inc_counter(
+            "migrations_total",
+            &[("launchpad", &migration.launchpad.to_string())],
+        );
+        info!(
+            "🎓 {} token {} migrated to pool {}",
+            migration.launchpad, migration.token_address, migration.pool
+        );
+
+        migration.links = self.explorer.map(|explorer| {
+            Links::new(
+                explorer,
+                &migration.signature,
+                Some(&migration.token_address),
+                migration.creator.as_deref(),
+            )
+        });
+
+        if let Some(lp_mint) = &migration.lp_mint {
+            self.lp_mints.lock().unwrap().insert(
+                lp_mint,
+                LpPool {
+                    launchpad: migration.launchpad.clone(),
+                    pool: migration.pool.clone(),
+                    token_address: migration.token_address.clone(),
+                },
+            );
+        }
+
+        // Publish to the event sink, if available
+        if let Some(sink) = &self.sink
+            && let Err(e) = sink.publish_migration(&migration).await
+        {
+            warn!("Failed to publish migration: {}", e);
+            ops::report(
+                OpsError::new(OpsErrorKind::SinkFailure, "parser", &e)
+                    .with_transaction(&migration.signature, migration.slot),
+            );
+        }
+    }
+
+    /// Handle liquidity added to or removed from a pool
+    async fn handle_liquidity(&self, mut liquidity: Liquidity) {
+        metrics::global().inc_counter(
+            "liquidity_events_total",
+            &[
+                ("launchpad", &liquidity.launchpad.to_string()),
+                ("action", liquidity.action.as_str()),
+            ],
+        );
+        info!(
+            "💧 {} liquidity {} on pool {} of {} ({})",
+            liquidity.launchpad,
+            liquidity.action.as_str(),
+            liquidity.pool,
+            liquidity.token_address,
+            liquidity.signature
+        );
+
+        liquidity.links = self.explorer.map(|explorer| {
+            Links::new(
+                explorer,
+                &liquidity.signature,
+                Some(&liquidity.token_address),
+                liquidity.provider.as_deref(),
+            )
+        });
+
+        if let Some(sink) = &self.sink
+            && let Err(e) = sink.publish_liquidity(&liquidity).await
+        {
+            warn!("Failed to publish liquidity change: {}", e);
+            ops::report(
+                OpsError::new(OpsErrorKind::SinkFailure, "parser", &e)
+                    .with_transaction(&liquidity.signature, liquidity.slot),
+            );
+        }
+    }
+
+    /// Publish a recognized instruction that is neither a launch nor a trade
+    async fn handle_program_event(&self, event: ProgramEvent) {
+        metrics::global().inc_counter(
+            "program_events_total",
+            &[
+                ("launchpad", &event.launchpad.to_string()),
+                ("event_type", &event.event_type),
+            ],
+        );
+
+        if let Some(sink) = &self.sink
+            && let Err(e) = sink.publish_program_event(&event).await
+        {
+            warn!("Failed to publish {} event: {}", event.event_type, e);
+            ops::report(
+                OpsError::new(OpsErrorKind::SinkFailure, "parser", &e)
+                    .with_transaction(&event.signature, event.slot),
+            );
+        }
+    }
+
+    /// Publish the first buyers of launches whose window closed before the slot
+    async fn publish_first_buyers(&self, slot: u64) {
+        let Some(first_buyers) = &self.first_buyers else {
+            return;
+        };
+        for mut summary in first_buyers.take_closed(slot) {
+            metrics::global().inc_counter(
+                "first_buyers_total",
+                &[("launchpad", &summary.launchpad.to_string())],
+            );
+            info!(
+                "🎯 {} token {} had {} buyers in its first {} slots",
+                summary.launchpad,
+                summary.token_address,
+                summary.buyers.len(),
+                summary.window_slots
+            );
+
+            summary.links = self.explorer.map(|explorer| {
+                Links::new(
+                    explorer,
+                    &summary.launch_signature,
+                    Some(&summary.token_address),
+                    summary.creator.as_deref(),
+                )
+            });
+
+            if let Some(sink) = &self.sink
+                && let Err(e) = sink.publish_first_buyers(&summary).await
+            {
+                warn!("Failed to publish first buyers: {}", e);
+                ops::report(
+                    OpsError::new(OpsErrorKind::SinkFailure, "parser", &e)
+                        .with_transaction(&summary.launch_signature, summary.launch_slot),
+                );
+            }
+        }
+    }
+
+    /// Publish a sell by the creator of a token seen launched
+    async fn handle_dev_sell(&self, trade: &Trade) {
+        let Some(launch) = self
+            .launched_mints
+            .lock()
+            .unwrap()
+            .entries
+            .get(&trade.token_address)
+            .filter(|launch| launch.creator.as_ref() == Some(&trade.trader))
+            .cloned()
+        else {
+            return;
+        };
+
+        let sold_at = trade.block_time.unwrap_or(trade.timestamp);
+        let mut sell = DevSell {
+            launchpad: launch.launchpad,
+            token_address: trade.token_address.clone(),
+            creator: trade.trader.clone(),
+            token_amount: trade.token_amount,
+            sol_amount: trade.amount,
+            launch_signature: launch.signature,
+            elapsed_ms: (sold_at - launch.launched_at).num_milliseconds().max(0) as u64,
+            signature: trade.signature.clone(),
+            slot: trade.slot,
+            transaction_index: trade.transaction_index,
+            timestamp: trade.timestamp,
+            block_time: trade.block_time,
+            links: None,
+        };
+
+        metrics::global().inc_counter(
+            "dev_sells_total",
+            &[("launchpad", &sell.launchpad.to_string())],
+        );
+        info!(
+            "💸 {} creator {} sold {} of {} {}ms after launch ({})",
+            sell.launchpad,
+            sell.creator,
+            sell.token_amount,
+            sell.token_address,
+            sell.elapsed_ms,
+            sell.signature
+        );
+
+        sell.links = self.explorer.map(|explorer| {
+            Links::new(
+                explorer,
+                &sell.signature,
+                Some(&sell.token_address),
+                Some(&sell.creator),
+            )
+        });
+
+        if let Some(sink) = &self.sink
+            && let Err(e) = sink.publish_dev_sell(&sell).await
+        {
+            warn!("Failed to publish dev sell: {}", e);
+            ops::report(
+                OpsError::new(OpsErrorKind::SinkFailure, "parser", &e)
+                    .with_transaction(&sell.signature, sell.slot),
+            );
+        }
+    }
+
+    /// Publish LP burns, authority revocations and creator transfers of tokens seen
+    /// before, whether there were any
+    async fn handle_token_program(&self, transaction: &QueuedTransaction) -> bool {
+        let burned = self.handle_lp_burns(transaction).await;
+        let revoked = self.handle_revocations(transaction).await;
+        let transferred = self.handle_creator_transfers(transaction).await;
+        burned || revoked || transferred
+    }
+
+    /// Publish large transfers out of followed creator token accounts, whether there were
+    /// any
+    async fn handle_creator_transfers(&self, transaction: &QueuedTransaction) -> bool {
+        let Some(subscriptions) = &self.account_subscriptions else {
+            return false;
+        };
+        let Some(min_percent) = subscriptions.creator_transfer_min_percent() else {
+            return false;
+        };
+
+        let mut found = false;
+        for source in &transaction.accounts {
+            let Some(creator) = subscriptions.creator_account(source) else {
+                continue;
+            };
+            for transfer in spl::outgoing_transfers(transaction, source) {
+                let percent_of_supply =
+                    transfer.amount as f64 / creator.supply.max(1) as f64 * 100.0;
+                if percent_of_supply < min_percent {
+                    continue;
+                }
+                found = true;
+                self.handle_creator_transfer(CreatorTransfer {
+                    launchpad: creator.launchpad.clone(),
+                    token_address: creator.token_address.clone(),
+                    creator: creator.creator.clone(),
+                    source: source.clone(),
+                    recipient: spl::initialized_account_owner(transaction, &transfer.destination),
+                    destination: transfer.destination,
+                    amount: transfer.amount,
+                    percent_of_supply,
+                    signature: transaction.signature.clone(),
+                    slot: transaction.slot,
+                    transaction_index: transaction.transaction_index,
+                    timestamp: transaction.received_time,
+                    block_time: transaction.block_time,
+                    backfilled: transaction.backfilled,
+                    links: None,
+                })
+                .await;
+            }
+        }
+        found
+    }
+
+    /// Handle the creator of a launch moving a large share of its supply
+    async fn handle_creator_transfer(&self, mut transfer: CreatorTransfer) {
+        metrics::global().inc_counter(
+            "creator_transfers_total",
+            &[("launchpad", &transfer.launchpad.to_string())],
+        );
+        info!(
+            "📦 {} creator {} moved {:.1}% of {} to {} ({})",
+            transfer.launchpad,
+            transfer.creator,
+            transfer.percent_of_supply,
+            transfer.token_address,
+            transfer
+                .recipient
+                .as_deref()
+                .unwrap_or(&transfer.destination),
+            transfer.signature
+        );
+
+        transfer.links = self.explorer.map(|explorer| {
+            Links::new(
+                explorer,
+                &transfer.signature,
+                Some(&transfer.token_address),
+                Some(&transfer.creator),
+            )
+        });
+
+        if let Some(sink) = &self.sink
+            && let Err(e) = sink.publish_creator_transfer(&transfer).await
+        {
+            warn!("Failed to publish creator transfer: {}", e);
+            ops::report(
+                OpsError::new(OpsErrorKind::SinkFailure, "parser", &e)
+                    .with_transaction(&transfer.signature, transfer.slot),
+            );
+        }
+    }
+
+    /// Publish revoked authorities of mints launched in earlier transactions, whether
+    /// there were any
+    async fn handle_revocations(&self, transaction: &QueuedTransaction) -> bool {
+        let revocations: Vec<_> = {
+            let launched = self.launched_mints.lock().unwrap();
+            if launched.entries.is_empty() {
+                return false;
+            }
+            // Launchpads revoke authorities while creating the mint, already reflected
+            // in the launch
+            spl::revocations(transaction)
+                .into_iter()
+                .filter_map(|revocation| {
+                    let launch = launched.entries.get(&revocation.mint)?;
+                    (launch.signature != transaction.signature)
+                        .then(|| (launch.launchpad.clone(), revocation))
+                })
+                .collect()
+        };
+
+        let found = !revocations.is_empty();
+        for (launchpad, revocation) in revocations {
+            self.handle_authority_revoked(AuthorityRevoked {
+                launchpad,
+                token_address: revocation.mint,
+                authority: revocation.authority,
+                revoked_by: revocation.revoked_by,
+                signature: transaction.signature.clone(),
+                slot: transaction.slot,
+                transaction_index: transaction.transaction_index,
+                timestamp: transaction.received_time,
+                block_time: transaction.block_time,
+                backfilled: transaction.backfilled,
+                links: None,
+            })
+            .await;
+        }
+        found
+    }
+
+    /// Handle a mint or freeze authority of a launched token being revoked
+    async fn handle_authority_revoked(&self, mut revocation: AuthorityRevoked) {
+        metrics::global().inc_counter(
+            "authority_revocations_total",
+            &[
+                ("launchpad", &revocation.launchpad.to_string()),
+                ("authority", revocation.authority.as_str()),
+            ],
+        );
+        info!(
+            "🔓 {} token {} {} authority revoked in {}",
+            revocation.launchpad,
+            revocation.token_address,
+            revocation.authority.as_str(),
+            revocation.signature
+        );
+
+        revocation.links = self.explorer.map(|explorer| {
+            Links::new(
+                explorer,
+                &revocation.signature,
+                Some(&revocation.token_address),
+                revocation.revoked_by.as_deref(),
+            )
+        });
+
+        if let Some(sink) = &self.sink
+            && let Err(e) = sink.publish_authority_revoked(&revocation).await
+        {
+            warn!("Failed to publish authority revocation: {}", e);
+            ops::report(
+                OpsError::new(OpsErrorKind::SinkFailure, "parser", &e)
+                    .with_transaction(&revocation.signature, revocation.slot),
+            );
+        }
+    }
+
+    /// Publish burns of LP tokens of pools seen created, whether there were any
+    async fn handle_lp_burns(&self, transaction: &QueuedTransaction) -> bool {
+        let burns: Vec<_> = {
+            let lp_mints = self.lp_mints.lock().unwrap();
+            if lp_mints.entries.is_empty() {
+                return false;
+            }
+            spl::burns(transaction)
+                .into_iter()
+                .filter_map(|burn| Some((lp_mints.entries.get(&burn.mint)?.clone(), burn)))
+                .collect()
+        };
+
+        let found = !burns.is_empty();
+        for (pool, burn) in burns {
+            self.handle_lp_burn(LpBurned {
+                launchpad: pool.launchpad,
+                pool: pool.pool,
+                token_address: pool.token_address,
+                lp_mint: burn.mint,
+                owner: burn.owner,
+                amount: burn.amount,
+                signature: transaction.signature.clone(),
+                slot: transaction.slot,
+                transaction_index: transaction.transaction_index,
+                timestamp: transaction.received_time,
+                block_time: transaction.block_time,
+                backfilled: transaction.backfilled,
+                links: None,
+            })
+            .await;
+        }
+        found
+    }
+
+    /// Handle LP tokens of a pool being burned
+    async fn handle_lp_burn(&self, mut burn: LpBurned) {
+        metrics::global().inc_counter(
+            "lp_burns_total",
+            &[("launchpad", &burn.launchpad.to_string())],
+        );
+        info!(
+            "🔥 {} LP tokens of pool {} burned ({} of {}) in {}",
+            burn.launchpad, burn.pool, burn.amount, burn.lp_mint, burn.signature
+        );
+
+        burn.links = self.explorer.map(|explorer| {
+            Links::new(
+                explorer,
+                &burn.signature,
+                Some(&burn.token_address),
+                burn.owner.as_deref(),
+            )
+        });
+
+        if let Some(sink) = &self.sink
+            && let Err(e) = sink.publish_lp_burned(&burn).await
+        {
+            warn!("Failed to publish LP burn: {}", e);
+            ops::report(
+                OpsError::new(OpsErrorKind::SinkFailure, "parser", &e)
+                    .with_transaction(&burn.signature, burn.slot),
+            );
+        }
+    }
+
+    /// Handle a completed bonding curve, published once per token
+    async fn handle_graduation(&self, mut graduation: Graduation) {
+        if !self
+            .graduated
+            .lock()
+            .unwrap()
+            .insert(&graduation.token_address)
+        {
+            trace!(
+                "Graduation of {} already published, skipping {}",
+                graduation.token_address, graduation.signature
+            );
+            return;
+        }
+
+        let trigger = graduation.trigger.as_str();
+        metrics::global().inc_counter(
+            "graduations_total",
+            &[
+                ("launchpad", &graduation.launchpad.to_string()),
+                ("trigger", trigger),
+            ],
+        );
+        info!(
+            "🎓 {} token {} graduated ({}) in {}",
+            graduation.launchpad, graduation.token_address, trigger, graduation.signature
+        );
+
+        // The curve takes no more trades, stop watching it
+        if let Some(subscriptions) = &self.account_subscriptions
+            && let Some(curve) = &graduation.bonding_curve
+        {
+            subscriptions.untrack(curve);
+        }
+
+        graduation.links = self.explorer.map(|explorer| {
+            Links::new(
+                explorer,
+                &graduation.signature,
+                Some(&graduation.token_address),
+                graduation.user.as_deref(),
+            )
+        });
+
+        if let Some(sink) = &self.sink
+            && let Err(e) = sink.publish_graduation(&graduation).await
+        {
+            warn!("Failed to publish graduation: {}", e);
+            ops::report(
+                OpsError::new(OpsErrorKind::SinkFailure, "parser", &e)
+                    .with_transaction(&graduation.signature, graduation.slot),
+            );
+        }
+    }
+
     /// Handle a detected token launch
     async fn handle_token_launch(
         &self,
-        launch: TokenLaunch,
+        mut launch: TokenLaunch,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        metrics::global().inc_counter(
+            "launches_total",
+            &[("launchpad", &launch.launchpad.to_string())],
+        );
+
+        if let Some(digester) = &self.digester {
+            digester.record_launch(&launch);
+        }
+        if let Some(tracker) = &self.tracker {
+            tracker.record_launch(&launch);
+        }
+        if let Some(subscriptions) = &self.account_subscriptions {
+            subscriptions.track(&launch);
+        }
+        if let Some(first_buyers) = &self.first_buyers
+            && !first_buyers.record_launch(&launch)
+        {
+            metrics::global().inc_counter(
+                "first_buyers_skipped_total",
+                &[("launchpad", &launch.launchpad.to_string())],
+            );
+        }
+        self.launched_mints.lock().unwrap().insert(
+            &launch.token_address,
+            LaunchedMint {
+                launchpad: launch.launchpad.clone(),
+                signature: launch.signature.clone(),
+                creator: launch.creator.clone(),
+                launched_at: launch.block_time.unwrap_or(launch.timestamp),
+            },
+        );
+
+        launch.links = self.explorer.map(|explorer| {
+            Links::new(
+                explorer,
+                &launch.signature,
+                Some(&launch.token_address),
+                launch.creator.as_deref(),
+            )
+        });
+
         info!("===================");
         info!("=== TOKEN LAUNCH ===");
-        info!("Launchpad: {:?}", launch.launchpad);
+        info!("Launchpad: {}", launch.launchpad);
         info!("CA: {}", launch.token_address);
         if let Some(creator) = &launch.creator {
             info!("Creator: {}", creator);
@@ -135,16 +1480,147 @@ impl ParserManager {
         if let Some(symbol) = &launch.metadata.symbol {
             info!("Symbol: {}", symbol);
         }
-        info!("Verify: https://solscan.io/tx/{}", launch.signature);
+        match &launch.links {
+            Some(links) => info!("Verify: {}", links.transaction),
+            None => info!("Verify: https://solscan.io/tx/{}", launch.signature),
+        }
         info!("===================");
 
-        // Publish to RabbitMQ, if producer is available
-        if let Some(producer) = &self.rabbit_producer {
-            if let Err(e) = producer.publish_token_launch(&launch).await {
-                warn!("Failed to publish token launch to RabbitMQ: {}", e);
-            }
+        // Enrichment publishes on its own
+        #[cfg(feature = "enrichment")]
+        if let Some(enricher) = &self.enricher {
+            enricher.submit(launch).await;
+            return Ok(());
+        }
+
+        // Publish to the event sink, if available
+        if let Some(sink) = &self.sink
+            && let Err(e) = sink.publish_token_launch(&launch).await
+        {
+            warn!("Failed to publish token launch: {}", e);
+            ops::report(
+                OpsError::new(OpsErrorKind::SinkFailure, "parser", &e)
+                    .with_transaction(&launch.signature, launch.slot),
+            );
         }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::parsers::DiscriminatorEntry;
+    use crate::parser::GraduationTrigger;
+    use crate::testing::{RecordingSink, initialize_mint, instruction, start_time, transaction};
+
+    const TABLE_PROGRAM_ID: &str = "Niche111111111111111111111111111111111111111";
+
+    /// Manager with the SPL mint parser and a discriminator table launchpad
+    fn manager() -> (Arc<RecordingSink>, ParserManager) {
+        let mut config = ParsersConfig::default();
+        config.spl_mint.enabled = true;
+        config.discriminator_table = vec![DiscriminatorEntry {
+            program_id: TABLE_PROGRAM_ID.to_string(),
+            discriminator: vec![1; 8],
+            mint_account_index: 1,
+            creator_account_index: None,
+            launchpad_label: "Niche".to_string(),
+        }];
+        let sink = Arc::new(RecordingSink::default());
+        let manager = ParserManager::new(Some(sink.clone()), &config);
+        (sink, manager)
+    }
+
+    fn graduation(trigger: GraduationTrigger, signature: &str) -> Graduation {
+        Graduation {
+            launchpad: LaunchpadType::Pumpfun,
+            token_address: "mint".to_string(),
+            bonding_curve: Some("curve".to_string()),
+            user: None,
+            trigger,
+            signature: signature.to_string(),
+            slot: 1,
+            transaction_index: None,
+            timestamp: start_time(),
+            block_time: None,
+            backfilled: false,
+            links: None,
+        }
+    }
+
+    #[test]
+    fn recent_map_forgets_the_oldest_address_past_capacity() {
+        let mut map = RecentMap::default();
+        for i in 0..=RECENT_MAP_CAPACITY {
+            map.insert(&i.to_string(), i);
+        }
+        // Updating an address keeps its place in line
+        map.insert("1", 0);
+
+        assert_eq!(map.entries.len(), RECENT_MAP_CAPACITY);
+        assert_eq!(map.order.len(), RECENT_MAP_CAPACITY);
+        assert!(!map.entries.contains_key("0"));
+        assert_eq!(map.entries.get("1"), Some(&0));
+        assert_eq!(map.order.front().map(String::as_str), Some("1"));
+    }
+
+    #[tokio::test]
+    async fn graduation_is_published_once_per_token() {
+        let (sink, manager) = manager();
+
+        manager
+            .handle_graduation(graduation(GraduationTrigger::CurveComplete, "complete"))
+            .await;
+        manager
+            .handle_graduation(graduation(GraduationTrigger::Migrate, "migrate"))
+            .await;
+
+        let graduations = sink.graduations.lock().unwrap();
+        let [graduation] = graduations.as_slice() else {
+            panic!("expected one graduation, got {}", graduations.len());
+        };
+        assert_eq!(graduation.signature, "complete");
+    }
+
+    #[tokio::test]
+    async fn mint_parser_yields_to_a_matching_launchpad_parser() {
+        let (sink, manager) = manager();
+        let launch = transaction(
+            &["creator", "mint"],
+            vec![
+                initialize_mint(1, 9),
+                instruction(TABLE_PROGRAM_ID, &[0, 1], vec![1; 8]),
+            ],
+            Vec::new(),
+        );
+
+        manager.process_transaction(&launch).await.unwrap();
+
+        let launches = sink.launches.lock().unwrap();
+        let [launch] = launches.as_slice() else {
+            panic!("expected one launch, got {}", launches.len());
+        };
+        assert!(matches!(&launch.launchpad, LaunchpadType::Custom(label) if label == "Niche"));
+        assert_eq!(launch.token_address, "mint");
+    }
+
+    #[tokio::test]
+    async fn mint_outside_launchpads_is_unknown() {
+        let (sink, manager) = manager();
+        let mint = transaction(
+            &["creator", "mint"],
+            vec![initialize_mint(1, 9)],
+            Vec::new(),
+        );
+
+        manager.process_transaction(&mint).await.unwrap();
+
+        let launches = sink.launches.lock().unwrap();
+        let [launch] = launches.as_slice() else {
+            panic!("expected one launch, got {}", launches.len());
+        };
+        assert!(matches!(launch.launchpad, LaunchpadType::Unknown));
+    }
+}
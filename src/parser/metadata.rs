@@ -0,0 +1,105 @@
+use crate::geyser::QueuedTransaction;
+use crate::parser::anchor::BorshReader;
+use crate::parser::launchpad_parser::LaunchMetadata;
+
+const TOKEN_METADATA_PROGRAM_ID: &str = "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s";
+/// `CreateMetadataAccountV3`, followed by `DataV2 { name, symbol, uri, .. }`
+const CREATE_METADATA_V3_TAG: u8 = 33;
+/// `Create`, followed by the `CreateArgs::V1` variant and `AssetData { name, symbol, uri, .. }`
+const CREATE_TAG: u8 = 42;
+/// Position of the mint in the `CreateMetadataAccountV3` and `Create` accounts
+const CREATE_METADATA_V3_MINT_INDEX: usize = 1;
+const CREATE_MINT_INDEX: usize = 2;
+const TOKEN_2022_PROGRAM_ID: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PMa9U1zx5Vf2wZ";
+/// Token metadata interface `Initialize`, followed by name, symbol and uri. Token-2022
+/// mints with the metadata extension store their metadata on the mint this way.
+const TOKEN_METADATA_INITIALIZE_DISCRIMINATOR: [u8; 8] = [210, 225, 30, 162, 88, 184, 77, 141];
+/// Position of the mint in the token metadata interface `Initialize` accounts
+const TOKEN_METADATA_INITIALIZE_MINT_INDEX: usize = 2;
+
+/// Metaplex metadata account, or Token-2022 on-mint metadata, created in a transaction
+#[derive(Debug, Clone)]
+pub struct CreatedMetadata {
+    pub mint: Option<String>,
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    pub uri: Option<String>,
+}
+
+/// Metadata accounts created by the Token Metadata program's `CreateMetadataAccountV3` and
+/// `Create` (V1), and metadata initialized on Token-2022 mints, in the transaction, outer
+/// and inner instructions in execution order
+pub fn created_metadata(transaction: &QueuedTransaction) -> Vec<CreatedMetadata> {
+    transaction
+        .instructions
+        .iter()
+        .chain(&transaction.inner_instructions)
+        .filter(|instr| !instr.truncated)
+        .filter_map(|instr| {
+            let (data, mint_index) = match instr.program_id.as_str() {
+                TOKEN_METADATA_PROGRAM_ID => match instr.data.first() {
+                    Some(&CREATE_METADATA_V3_TAG) => {
+                        (instr.data.get(1..)?, CREATE_METADATA_V3_MINT_INDEX)
+                    }
+                    Some(&CREATE_TAG) => (instr.data.get(2..)?, CREATE_MINT_INDEX),
+                    _ => return None,
+                },
+                TOKEN_2022_PROGRAM_ID
+                    if instr
+                        .data
+                        .starts_with(&TOKEN_METADATA_INITIALIZE_DISCRIMINATOR) =>
+                {
+                    (instr.data.get(8..)?, TOKEN_METADATA_INITIALIZE_MINT_INDEX)
+                }
+                _ => return None,
+            };
+
+            let mut reader = BorshReader::new(data);
+            let name = reader.string();
+            let symbol = name.as_ref().and_then(|_| reader.string());
+            let uri = symbol.as_ref().and_then(|_| reader.string());
+
+            Some(CreatedMetadata {
+                mint: instr
+                    .accounts
+                    .get(mint_index)
+                    .and_then(|idx| transaction.accounts.get(*idx as usize))
+                    .cloned(),
+                name,
+                symbol,
+                uri,
+            })
+        })
+        .collect()
+}
+
+/// Fills name, symbol and uri the launchpad parser left empty from the metadata account
+/// created for `mint` in the same transaction. Returns whether anything was filled.
+pub fn fill_missing(
+    metadata: &mut LaunchMetadata,
+    transaction: &QueuedTransaction,
+    mint: &str,
+) -> bool {
+    if metadata.name.is_some() && metadata.symbol.is_some() && metadata.uri.is_some() {
+        return false;
+    }
+    let Some(created) = created_metadata(transaction)
+        .into_iter()
+        .find(|created| created.mint.as_deref() == Some(mint))
+    else {
+        return false;
+    };
+
+    let mut filled = false;
+    for (field, value) in [
+        (&mut metadata.name, created.name),
+        (&mut metadata.symbol, created.symbol),
+        (&mut metadata.uri, created.uri),
+    ] {
+        if field.is_none() && value.is_some() {
+            *field = value;
+            filled = true;
+        }
+    }
+    filled
+}
@@ -1,25 +1,125 @@
-use crate::geyser::QueuedTransaction;
+use crate::config::parsers::{MeteoraConfig, PartnerConfig};
+use crate::geyser::{QueuedTransaction, TransactionInstruction};
+use crate::parser::anchor::{self, BorshReader};
 use crate::parser::{
-    LaunchpadParser, LaunchpadType, ParseResult, TokenLaunch, launchpad_parser::LaunchMetadata,
+    EventKind, FeeClaim, FeeClaimKind, LaunchpadParser, LaunchpadType, MetadataField, ParseResult,
+    ParserCapabilities, PartialParse, TokenLaunch, launchpad_parser::LaunchMetadata,
 };
 use tracing::{debug, info};
 
+/// Fee claim instruction and the event reporting the claimed amounts
+struct FeeClaimSpec {
+    kind: FeeClaimKind,
+    instruction: [u8; 8],
+    event: [u8; 8],
+    /// Account index of the fee claimer (partner) or creator
+    claimer_index: usize,
+    base_mint_index: Option<usize>,
+    /// Trading fee events carry base and quote amounts, surplus events only quote
+    trading_fee: bool,
+}
+
+/// DBC fee claims from meteoraDBC.json
+const FEE_CLAIMS: [FeeClaimSpec; 4] = [
+    // claim_trading_fee / EvtClaimTradingFee
+    FeeClaimSpec {
+        kind: FeeClaimKind::PartnerTradingFee,
+        instruction: [8, 236, 89, 49, 152, 125, 177, 81],
+        event: [26, 83, 117, 240, 92, 202, 112, 254],
+        claimer_index: 9,
+        base_mint_index: Some(7),
+        trading_fee: true,
+    },
+    // claim_creator_trading_fee / EvtClaimCreatorTradingFee
+    FeeClaimSpec {
+        kind: FeeClaimKind::CreatorTradingFee,
+        instruction: [82, 220, 250, 189, 3, 85, 107, 45],
+        event: [154, 228, 215, 202, 133, 155, 214, 138],
+        claimer_index: 8,
+        base_mint_index: Some(6),
+        trading_fee: true,
+    },
+    // partner_withdraw_surplus / EvtPartnerWithdrawSurplus
+    FeeClaimSpec {
+        kind: FeeClaimKind::PartnerSurplus,
+        instruction: [168, 173, 72, 100, 201, 98, 38, 92],
+        event: [195, 56, 152, 9, 232, 72, 35, 22],
+        claimer_index: 6,
+        base_mint_index: None,
+        trading_fee: false,
+    },
+    // creator_withdraw_surplus / EvtCreatorWithdrawSurplus
+    FeeClaimSpec {
+        kind: FeeClaimKind::CreatorSurplus,
+        instruction: [165, 3, 137, 7, 28, 134, 76, 80],
+        event: [152, 73, 21, 15, 66, 87, 53, 157],
+        claimer_index: 6,
+        base_mint_index: None,
+        trading_fee: false,
+    },
+];
+
+/// Account index of the pool config in DBC initialize instructions
+const CONFIG_ACCOUNT_INDEX: usize = 0;
+
 pub struct MeteoraParser {
     program_ids: Vec<String>,
+    config: MeteoraConfig,
 }
 
 impl MeteoraParser {
-    pub fn new() -> Self {
+    pub fn new(config: MeteoraConfig) -> Self {
         Self {
             // Only MeteoraDBC program
             program_ids: vec![
                 // Meteora DBC program
                 "dbcij3LWUppWqq96dh6gJWwBifmcGfLSB5D4DuSMaqN".to_string(),
             ],
+            config,
+        }
+    }
+
+    /// Listed partner of the pool config used by `instruction`
+    fn partner(
+        &self,
+        transaction: &QueuedTransaction,
+        instruction: &TransactionInstruction,
+    ) -> Option<&PartnerConfig> {
+        instruction
+            .accounts
+            .get(CONFIG_ACCOUNT_INDEX)
+            .and_then(|idx| transaction.accounts.get(*idx as usize))
+            .and_then(|config| self.config.partner_configs.get(config))
+    }
+
+    /// Why launches on the pool config used by `instruction` are not reported, `None`
+    /// when they are
+    fn partner_skip_reason(
+        &self,
+        transaction: &QueuedTransaction,
+        instruction: &TransactionInstruction,
+    ) -> Option<String> {
+        match self.partner(transaction, instruction) {
+            Some(partner) if partner.skip => {
+                debug!(
+                    "Skipping MeteoraDBC launch of partner {} in {}",
+                    partner.name, transaction.signature
+                );
+                Some(format!("partner {} skipped", partner.name))
+            }
+            Some(_) => None,
+            None if self.config.only_listed_partners => Some("partner not listed".to_string()),
+            None => None,
         }
     }
 }
 
+impl Default for MeteoraParser {
+    fn default() -> Self {
+        Self::new(MeteoraConfig::default())
+    }
+}
+
 impl LaunchpadParser for MeteoraParser {
     fn get_program_ids(&self) -> Vec<String> {
         self.program_ids.clone()
@@ -29,6 +129,17 @@ impl LaunchpadParser for MeteoraParser {
         LaunchpadType::Meteora
     }
 
+    fn capabilities(&self) -> ParserCapabilities {
+        ParserCapabilities {
+            events: vec![
+                EventKind::TokenLaunch,
+                EventKind::PartialParse,
+                EventKind::FeeClaim,
+            ],
+            metadata: vec![MetadataField::Name, MetadataField::Symbol],
+        }
+    }
+
     fn parse_transaction(
         &self,
         transaction: &QueuedTransaction,
@@ -38,9 +149,29 @@ impl LaunchpadParser for MeteoraParser {
             transaction.signature
         );
 
-        for instr in &transaction.instructions {
+        let mut results = Vec::new();
+
+        // Every pool initialized in the transaction is reported, batch deployers create several
+        for instr in transaction
+            .instructions
+            .iter()
+            .chain(&transaction.inner_instructions)
+        {
             if self.program_ids.contains(&instr.program_id) && instr.data.len() >= 8 {
                 let discriminator = &instr.data[0..8];
+                let is_initialize = discriminator == [140, 85, 215, 176, 102, 54, 104, 79]
+                    || discriminator == [169, 118, 51, 78, 145, 110, 220, 155];
+                if is_initialize && let Some(reason) = self.partner_skip_reason(transaction, instr)
+                {
+                    results.push(ParseResult::Skipped {
+                        launchpad: self
+                            .partner(transaction, instr)
+                            .and_then(|partner| partner.launchpad.clone())
+                            .unwrap_or(LaunchpadType::Meteora),
+                        reason,
+                    });
+                    continue;
+                }
 
                 // MeteoraDBC instructions (from meteoraDBC.json)
                 if discriminator == [140, 85, 215, 176, 102, 54, 104, 79] {
@@ -49,27 +180,35 @@ impl LaunchpadParser for MeteoraParser {
                         transaction.signature
                     );
 
-                    if let Some(token_launch) =
-                        self.extract_token_launch_meteora_dbc(transaction, instr)?
-                    {
-                        return Ok(vec![ParseResult::TokenLaunch(token_launch)]);
-                    }
+                    results.push(self.extract_token_launch_meteora_dbc(
+                        transaction,
+                        instr,
+                        "initialize_virtual_pool_with_spl_token",
+                    )?);
                 } else if discriminator == [169, 118, 51, 78, 145, 110, 220, 155] {
                     info!(
                         "🎯 Found MeteoraDBC initialize_virtual_pool_with_token2022 in: {}",
                         transaction.signature
                     );
 
-                    if let Some(token_launch) =
-                        self.extract_token_launch_meteora_dbc(transaction, instr)?
-                    {
-                        return Ok(vec![ParseResult::TokenLaunch(token_launch)]);
-                    }
+                    results.push(self.extract_token_launch_meteora_dbc(
+                        transaction,
+                        instr,
+                        "initialize_virtual_pool_with_token2022",
+                    )?);
                 }
             }
         }
 
-        Ok(vec![ParseResult::NotRelevant])
+        if self.config.emit_fee_claims {
+            results.extend(self.extract_fee_claims(transaction));
+        }
+
+        if results.is_empty() {
+            results.push(ParseResult::NotRelevant);
+        }
+
+        Ok(results)
     }
 }
 
@@ -79,35 +218,130 @@ impl MeteoraParser {
         &self,
         transaction: &QueuedTransaction,
         instruction: &crate::geyser::TransactionInstruction,
-    ) -> Result<Option<TokenLaunch>, Box<dyn std::error::Error + Send + Sync>> {
+        instruction_name: &str,
+    ) -> Result<ParseResult, Box<dyn std::error::Error + Send + Sync>> {
+        // Launchpads built on DBC, like Jupiter Studio, are told apart by their pool config
+        let launchpad = self
+            .partner(transaction, instruction)
+            .and_then(|partner| partner.launchpad.clone())
+            .unwrap_or(LaunchpadType::Meteora);
+
+        // Creator should be account index 2
+        let creator = instruction
+            .accounts
+            .get(2)
+            .and_then(|idx| transaction.accounts.get(*idx as usize))
+            .cloned();
+        let metadata = self.extract_metadata_from_meteora_dbc_instruction(&instruction.data);
+
         // Try to find the base_mint from instruction accounts
         // According to MeteoraDBC IDL, account index 3 should be base_mint (newly created token)
-        if let Some(mint_idx) = instruction.accounts.get(3) {
-            if let Some(mint_address) = transaction.accounts.get(*mint_idx as usize) {
-                // Creator should be account index 2
-                let creator = instruction
-                    .accounts
-                    .get(2)
-                    .and_then(|idx| transaction.accounts.get(*idx as usize))
-                    .cloned();
-
-                let token_launch = TokenLaunch {
+        let Some(mint_address) = instruction
+            .accounts
+            .get(3)
+            .and_then(|idx| transaction.accounts.get(*idx as usize))
+        else {
+            debug!("❌ Could not extract mint from MeteoraDBC initialize instruction");
+            return Ok(ParseResult::PartialParse(PartialParse::new(
+                launchpad,
+                instruction_name,
+                transaction,
+                None,
+                creator,
+                metadata,
+            )));
+        };
+
+        // Account 5 is the virtual pool
+        let pool = instruction
+            .accounts
+            .get(5)
+            .and_then(|idx| transaction.accounts.get(*idx as usize))
+            .cloned();
+
+        let token_launch = TokenLaunch::new(
+            launchpad.clone(),
+            transaction,
+            mint_address.clone(),
+            creator,
+            metadata,
+        )
+        .with_pool(pool);
+
+        debug!(
+            "✅ Extracted MeteoraDBC token launch ({}): {}",
+            launchpad, mint_address
+        );
+        Ok(ParseResult::TokenLaunch(token_launch))
+    }
+
+    /// Extract partner and creator fee claims from the events DBC emits through self-CPI.
+    /// Amounts come from the events, claimer and base mint from the matching claim instruction.
+    fn extract_fee_claims(&self, transaction: &QueuedTransaction) -> Vec<ParseResult> {
+        let is_dbc = |instr: &&TransactionInstruction| {
+            self.program_ids.contains(&instr.program_id) && !instr.truncated
+        };
+        let mut claim_instructions: Vec<&TransactionInstruction> = transaction
+            .instructions
+            .iter()
+            .chain(&transaction.inner_instructions)
+            .filter(is_dbc)
+            .collect();
+
+        let mut claims = Vec::new();
+        for event in transaction.inner_instructions.iter().filter(is_dbc) {
+            for spec in &FEE_CLAIMS {
+                let Some(body) = anchor::cpi_event(&event.data, &spec.event) else {
+                    continue;
+                };
+
+                let mut reader = BorshReader::new(body);
+                let amounts = reader.pubkey().and_then(|pool| {
+                    let base_amount = if spec.trading_fee { reader.u64()? } else { 0 };
+                    Some((pool, base_amount, reader.u64()?))
+                });
+                let Some((pool, base_amount, quote_amount)) = amounts else {
+                    debug!("❌ Could not decode MeteoraDBC {:?} event", spec.kind);
+                    continue;
+                };
+
+                // Each claim instruction pairs with the first event of its kind that follows it
+                let instruction = claim_instructions
+                    .iter()
+                    .position(|instr| instr.data.starts_with(&spec.instruction))
+                    .map(|position| claim_instructions.remove(position));
+                let account = |index: usize| {
+                    instruction
+                        .and_then(|instr| instr.accounts.get(index))
+                        .and_then(|idx| transaction.accounts.get(*idx as usize))
+                        .cloned()
+                };
+
+                info!(
+                    "💸 MeteoraDBC {:?} claim on pool {} in: {}",
+                    spec.kind, pool, transaction.signature
+                );
+
+                claims.push(ParseResult::FeeClaim(FeeClaim {
                     launchpad: LaunchpadType::Meteora,
-                    token_address: mint_address.clone(),
-                    creator,
+                    kind: spec.kind,
+                    pool,
+                    claimer: account(spec.claimer_index),
+                    base_mint: spec.base_mint_index.and_then(account),
+                    base_amount,
+                    quote_amount,
                     signature: transaction.signature.clone(),
                     slot: transaction.slot,
+                    transaction_index: transaction.transaction_index,
                     timestamp: transaction.received_time,
-                    metadata: self.extract_metadata_from_meteora_dbc_instruction(&instruction.data),
-                };
-
-                debug!("✅ Extracted MeteoraDBC token launch: {}", mint_address);
-                return Ok(Some(token_launch));
+                    block_time: transaction.block_time,
+                    backfilled: transaction.backfilled,
+                    links: None,
+                }));
             }
         }
 
-        debug!("❌ Could not extract mint from MeteoraDBC initialize instruction");
-        Ok(None)
+        claims
     }
 
     /// Extract metadata from MeteoraDBC instruction data
@@ -122,6 +356,9 @@ impl MeteoraParser {
                 uri: None,
                 initial_supply: None,
                 mint_authority: None,
+                fee_recipient: None,
+                token_extensions: None,
+                normalized: None,
             };
         }
 
@@ -140,6 +377,9 @@ impl MeteoraParser {
                     uri: None,
                     initial_supply: None,
                     mint_authority: None,
+                    fee_recipient: None,
+                    token_extensions: None,
+                    normalized: None,
                 };
             }
         }
@@ -150,6 +390,9 @@ impl MeteoraParser {
             uri: None,
             initial_supply: None,
             mint_authority: None,
+            fee_recipient: None,
+            token_extensions: None,
+            normalized: None,
         }
     }
 
@@ -181,3 +424,111 @@ impl MeteoraParser {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geyser::encoding::encode_pubkey;
+    use crate::testing::{borsh_string, instruction, transaction};
+
+    const PROGRAM_ID: &str = "dbcij3LWUppWqq96dh6gJWwBifmcGfLSB5D4DuSMaqN";
+
+    /// `initialize_virtual_pool_with_spl_token` over config, authority, creator, base mint,
+    /// quote mint and pool
+    fn initialize_transaction(accounts: &[u8]) -> QueuedTransaction {
+        let mut data = vec![140, 85, 215, 176, 102, 54, 104, 79];
+        data.extend(borsh_string("Meteora Token"));
+        data.extend(borsh_string("MET"));
+        data.extend(borsh_string("https://example.com/met.json"));
+        transaction(
+            &[
+                "creator",
+                "config",
+                "pool_authority",
+                "base_mint",
+                "So11111111111111111111111111111111111111112",
+                "pool",
+            ],
+            vec![instruction(PROGRAM_ID, accounts, data)],
+            Vec::new(),
+        )
+    }
+
+    #[test]
+    fn initialize_is_decoded_as_a_launch() {
+        let transaction = initialize_transaction(&[1, 2, 0, 3, 4, 5]);
+
+        let results = MeteoraParser::default()
+            .parse_transaction(&transaction)
+            .unwrap();
+        let [ParseResult::TokenLaunch(launch)] = results.as_slice() else {
+            panic!("expected one launch, got {} results", results.len());
+        };
+        assert!(matches!(launch.launchpad, LaunchpadType::Meteora));
+        assert_eq!(launch.token_address, "base_mint");
+        assert_eq!(launch.creator.as_deref(), Some("creator"));
+        assert_eq!(launch.pool.as_deref(), Some("pool"));
+        assert_eq!(launch.metadata.name.as_deref(), Some("Meteora Token"));
+        assert_eq!(launch.metadata.symbol.as_deref(), Some("MET"));
+    }
+
+    #[test]
+    fn initialize_without_a_base_mint_is_a_partial_parse() {
+        let transaction = initialize_transaction(&[1, 2, 0]);
+
+        let results = MeteoraParser::default()
+            .parse_transaction(&transaction)
+            .unwrap();
+        let [ParseResult::PartialParse(partial)] = results.as_slice() else {
+            panic!("expected one partial parse, got {} results", results.len());
+        };
+        assert_eq!(
+            partial.instruction,
+            "initialize_virtual_pool_with_spl_token"
+        );
+        assert_eq!(partial.creator.as_deref(), Some("creator"));
+        assert_eq!(partial.missing_fields, ["token_address"]);
+        assert_eq!(partial.confidence, 0.75);
+    }
+
+    #[test]
+    fn creator_trading_fee_claim_pairs_event_and_instruction() {
+        let mut event = anchor::EVENT_IX_TAG.to_vec();
+        event.extend([154, 228, 215, 202, 133, 155, 214, 138]);
+        event.extend([5; 32]);
+        event.extend(1_000u64.to_le_bytes());
+        event.extend(2_000_000u64.to_le_bytes());
+        let transaction = transaction(
+            &[
+                "creator",
+                "a1",
+                "a2",
+                "a3",
+                "a4",
+                "a5",
+                "base_mint",
+                "a7",
+                PROGRAM_ID,
+            ],
+            vec![instruction(
+                PROGRAM_ID,
+                &[1, 2, 3, 4, 5, 7, 6, 7, 0],
+                vec![82, 220, 250, 189, 3, 85, 107, 45],
+            )],
+            vec![instruction(PROGRAM_ID, &[8], event)],
+        );
+
+        let results = MeteoraParser::default()
+            .parse_transaction(&transaction)
+            .unwrap();
+        let [ParseResult::FeeClaim(claim)] = results.as_slice() else {
+            panic!("expected one fee claim, got {} results", results.len());
+        };
+        assert_eq!(claim.kind, FeeClaimKind::CreatorTradingFee);
+        assert_eq!(claim.pool, encode_pubkey(&[5; 32]));
+        assert_eq!(claim.claimer.as_deref(), Some("creator"));
+        assert_eq!(claim.base_mint.as_deref(), Some("base_mint"));
+        assert_eq!(claim.base_amount, 1_000);
+        assert_eq!(claim.quote_amount, 2_000_000);
+    }
+}
@@ -0,0 +1,204 @@
+use crate::geyser::{QueuedTransaction, TransactionInstruction};
+use crate::parser::anchor::BorshReader;
+use crate::parser::spl;
+use crate::parser::{
+    EventKind, LaunchpadParser, LaunchpadType, Migration, ParseResult, ParserCapabilities,
+};
+use tracing::{debug, info};
+
+/// `initialize_lb_pair` instruction discriminator (Anchor `global:initialize_lb_pair`)
+const INITIALIZE_LB_PAIR_DISCRIMINATOR: [u8; 8] = [45, 154, 237, 210, 221, 15, 166, 92];
+
+/// Account positions in the `initialize_lb_pair` instruction
+const POOL_ACCOUNT_INDEX: usize = 0; // `lb_pair`
+const TOKEN_X_MINT_ACCOUNT_INDEX: usize = 2; // `token_mint_x`
+const TOKEN_Y_MINT_ACCOUNT_INDEX: usize = 3; // `token_mint_y`
+const CREATOR_ACCOUNT_INDEX: usize = 8; // `funder`
+
+/// Meteora DLMM (dynamic liquidity) pair creation, reported as `MeteoraDlmm` migrations
+/// with the pair's bin step
+pub struct MeteoraDlmmParser {
+    program_id: String,
+}
+
+impl MeteoraDlmmParser {
+    pub fn new() -> Self {
+        Self {
+            program_id: "LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo".to_string(),
+        }
+    }
+}
+
+impl Default for MeteoraDlmmParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LaunchpadParser for MeteoraDlmmParser {
+    fn get_program_ids(&self) -> Vec<String> {
+        vec![self.program_id.clone()]
+    }
+
+    fn get_launchpad_type(&self) -> LaunchpadType {
+        LaunchpadType::MeteoraDlmm
+    }
+
+    fn capabilities(&self) -> ParserCapabilities {
+        ParserCapabilities {
+            events: vec![EventKind::Migration],
+            metadata: Vec::new(),
+        }
+    }
+
+    fn parse_transaction(
+        &self,
+        transaction: &QueuedTransaction,
+    ) -> Result<Vec<ParseResult>, Box<dyn std::error::Error + Send + Sync>> {
+        debug!(
+            "🔍 Parsing Meteora DLMM transaction: {}",
+            transaction.signature
+        );
+
+        let mut results = Vec::new();
+        for instr in transaction
+            .instructions
+            .iter()
+            .chain(&transaction.inner_instructions)
+        {
+            if instr.program_id == self.program_id
+                && !instr.truncated
+                && instr.data.starts_with(&INITIALIZE_LB_PAIR_DISCRIMINATOR)
+            {
+                info!(
+                    "🎓 Found Meteora DLMM initialize_lb_pair instruction in: {}",
+                    transaction.signature
+                );
+                results.extend(self.extract_pool(transaction, instr));
+            }
+        }
+
+        if results.is_empty() {
+            results.push(ParseResult::NotRelevant);
+        }
+        Ok(results)
+    }
+}
+
+impl MeteoraDlmmParser {
+    /// Extract the new pair from an initialize_lb_pair instruction
+    fn extract_pool(
+        &self,
+        transaction: &QueuedTransaction,
+        instruction: &TransactionInstruction,
+    ) -> Option<ParseResult> {
+        let account = |index: usize| {
+            instruction
+                .accounts
+                .get(index)
+                .and_then(|idx| transaction.accounts.get(*idx as usize))
+                .cloned()
+        };
+
+        let (Some(pool), Some(token_x), Some(token_y)) = (
+            account(POOL_ACCOUNT_INDEX),
+            account(TOKEN_X_MINT_ACCOUNT_INDEX),
+            account(TOKEN_Y_MINT_ACCOUNT_INDEX),
+        ) else {
+            debug!(
+                "❌ Could not extract mints or pool from Meteora DLMM initialize_lb_pair instruction"
+            );
+            return None;
+        };
+
+        let (mint, quote_mint) = spl::split_pair(token_x, token_y);
+
+        // Arguments: active_id (i32), bin_step (u16)
+        let mut reader = BorshReader::new(&instruction.data[8..]);
+        let bin_step = reader.skip(4).and_then(|_| reader.u16());
+
+        let migration = Migration {
+            launchpad: LaunchpadType::MeteoraDlmm,
+            token_address: mint.clone(),
+            pool,
+            quote_mint: Some(quote_mint),
+            creator: account(CREATOR_ACCOUNT_INDEX),
+            bin_step,
+            bonding_curve: None,
+            base_reserve: None,
+            quote_reserve: None,
+            lp_mint: None,
+            signature: transaction.signature.clone(),
+            slot: transaction.slot,
+            transaction_index: transaction.transaction_index,
+            timestamp: transaction.received_time,
+            block_time: transaction.block_time,
+            backfilled: transaction.backfilled,
+            links: None,
+        };
+
+        debug!("✅ Extracted Meteora DLMM pool for: {}", mint);
+        Some(ParseResult::Migration(migration))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{instruction, transaction};
+
+    const PROGRAM_ID: &str = "LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo";
+    const WSOL_MINT: &str = "So11111111111111111111111111111111111111112";
+
+    fn initialize_lb_pair(data: Vec<u8>) -> QueuedTransaction {
+        transaction(
+            &[
+                "pair",
+                "bin_array_bitmap",
+                WSOL_MINT,
+                "mint",
+                "reserve_x",
+                "reserve_y",
+                "oracle",
+                "preset",
+                "funder",
+            ],
+            vec![instruction(PROGRAM_ID, &[0, 1, 2, 3, 4, 5, 6, 7, 8], data)],
+            Vec::new(),
+        )
+    }
+
+    #[test]
+    fn initialize_lb_pair_is_decoded_with_the_bin_step() {
+        let mut data = INITIALIZE_LB_PAIR_DISCRIMINATOR.to_vec();
+        data.extend((-120i32).to_le_bytes());
+        data.extend(80u16.to_le_bytes());
+
+        let results = MeteoraDlmmParser::new()
+            .parse_transaction(&initialize_lb_pair(data))
+            .unwrap();
+        let [ParseResult::Migration(migration)] = results.as_slice() else {
+            panic!("expected one migration, got {} results", results.len());
+        };
+        assert!(matches!(migration.launchpad, LaunchpadType::MeteoraDlmm));
+        assert_eq!(migration.token_address, "mint");
+        assert_eq!(migration.quote_mint.as_deref(), Some(WSOL_MINT));
+        assert_eq!(migration.pool, "pair");
+        assert_eq!(migration.creator.as_deref(), Some("funder"));
+        assert_eq!(migration.bin_step, Some(80));
+    }
+
+    #[test]
+    fn initialize_lb_pair_without_arguments_has_no_bin_step() {
+        let results = MeteoraDlmmParser::new()
+            .parse_transaction(&initialize_lb_pair(
+                INITIALIZE_LB_PAIR_DISCRIMINATOR.to_vec(),
+            ))
+            .unwrap();
+        let [ParseResult::Migration(migration)] = results.as_slice() else {
+            panic!("expected one migration, got {} results", results.len());
+        };
+        assert_eq!(migration.token_address, "mint");
+        assert_eq!(migration.bin_step, None);
+    }
+}
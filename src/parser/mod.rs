@@ -1,7 +1,38 @@
+pub mod accounts;
+pub mod anchor;
+pub mod bags;
+pub mod believe;
+pub mod daos;
+pub mod discriminator_table;
+pub mod first_buyers;
+pub mod fluxbeam;
+pub mod generic;
+pub mod jito;
+pub mod launchlab;
 pub mod launchpad_parser;
 pub mod manager;
+pub mod metadata;
 pub mod meteora;
+pub mod meteora_dlmm;
+pub mod moonshot;
+pub mod normalize;
+pub mod pricing;
 pub mod pumpfun;
+pub mod pumpswap;
+pub mod raydium_clmm;
+pub mod raydium_cpmm;
+pub mod spl;
+pub mod spl_mint;
+pub mod time_fun;
+pub mod token_2022;
+pub mod vertigo;
+pub mod virtuals;
 
-pub use launchpad_parser::{LaunchpadParser, LaunchpadType, ParseResult, TokenLaunch};
-pub use manager::ParserManager;
+pub use launchpad_parser::{
+    AuthorityRevoked, AuthorityType, CreatorTransfer, CurveProgress, DevSell, EnrichmentError,
+    EventKind, FeeClaim, FeeClaimKind, FirstBuyer, FirstBuyers, Graduation, GraduationTrigger,
+    LaunchpadParser, LaunchpadType, Links, Liquidity, LiquidityAction, LpBurned, MetadataField,
+    Migration, ParseResult, ParserCapabilities, PartialParse, ProgramEvent, TokenExtensions,
+    TokenLaunch, Trade, TradeFees,
+};
+pub use manager::{LaunchpadCapabilities, ParserManager};
@@ -0,0 +1,253 @@
+use crate::geyser::QueuedTransaction;
+use crate::parser::{
+    EventKind, LaunchpadParser, LaunchpadType, MetadataField, ParseResult, ParserCapabilities,
+    PartialParse, TokenLaunch, launchpad_parser::LaunchMetadata,
+};
+use tracing::{debug, info};
+
+/// `token_mint` instruction discriminator
+const TOKEN_MINT_DISCRIMINATOR: [u8; 8] = [3, 44, 164, 184, 123, 13, 245, 179];
+
+/// Other Moonshot instruction discriminators
+const INSTRUCTIONS: &[(&str, [u8; 8])] = &[
+    ("buy", [102, 6, 61, 18, 1, 218, 235, 234]),
+    ("sell", [51, 230, 133, 164, 1, 127, 131, 173]),
+    ("migrate_funds", [42, 229, 10, 231, 189, 62, 193, 174]),
+];
+
+/// Account positions in the `token_mint` instruction
+const CREATOR_ACCOUNT_INDEX: usize = 0; // `sender`
+const CURVE_ACCOUNT_INDEX: usize = 2; // `curve_account`
+const MINT_ACCOUNT_INDEX: usize = 3; // `mint`
+
+pub struct MoonshotParser {
+    program_id: String,
+}
+
+impl MoonshotParser {
+    pub fn new() -> Self {
+        Self {
+            program_id: "MoonCVVNZFSYkqNXP6bxHLPL6QQJiMagDL3qcqUQTrG".to_string(),
+        }
+    }
+}
+
+impl Default for MoonshotParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LaunchpadParser for MoonshotParser {
+    fn get_program_ids(&self) -> Vec<String> {
+        vec![self.program_id.clone()]
+    }
+
+    fn get_launchpad_type(&self) -> LaunchpadType {
+        LaunchpadType::Moonshot
+    }
+
+    fn capabilities(&self) -> ParserCapabilities {
+        ParserCapabilities {
+            events: vec![EventKind::TokenLaunch, EventKind::PartialParse],
+            metadata: vec![
+                MetadataField::Name,
+                MetadataField::Symbol,
+                MetadataField::Uri,
+                MetadataField::InitialSupply,
+            ],
+        }
+    }
+
+    fn parse_transaction(
+        &self,
+        transaction: &QueuedTransaction,
+    ) -> Result<Vec<ParseResult>, Box<dyn std::error::Error + Send + Sync>> {
+        debug!("🔍 Parsing Moonshot transaction: {}", transaction.signature);
+
+        let mut results = Vec::new();
+        for instr in transaction
+            .instructions
+            .iter()
+            .chain(&transaction.inner_instructions)
+        {
+            if instr.program_id != self.program_id || instr.data.len() < 8 {
+                continue;
+            }
+            let discriminator = &instr.data[0..8];
+
+            if discriminator == TOKEN_MINT_DISCRIMINATOR {
+                info!(
+                    "🎯 Found Moonshot token_mint instruction in: {}",
+                    transaction.signature
+                );
+                results.push(self.extract_token_launch(transaction, instr)?);
+            } else if let Some((name, _)) = INSTRUCTIONS.iter().find(|(_, d)| d == discriminator) {
+                results.push(ParseResult::Other {
+                    launchpad: LaunchpadType::Moonshot,
+                    event_type: name.to_string(),
+                    detail: None,
+                    signature: transaction.signature.clone(),
+                });
+            }
+        }
+
+        if results.is_empty() {
+            results.push(ParseResult::NotRelevant);
+        }
+        Ok(results)
+    }
+}
+
+impl MoonshotParser {
+    /// Extract token launch information from token_mint instruction
+    fn extract_token_launch(
+        &self,
+        transaction: &QueuedTransaction,
+        instruction: &crate::geyser::TransactionInstruction,
+    ) -> Result<ParseResult, Box<dyn std::error::Error + Send + Sync>> {
+        let account = |index: usize| {
+            instruction
+                .accounts
+                .get(index)
+                .and_then(|idx| transaction.accounts.get(*idx as usize))
+                .cloned()
+        };
+        let creator = account(CREATOR_ACCOUNT_INDEX);
+        let metadata = self.extract_metadata_from_instruction(&instruction.data);
+
+        let Some(mint_address) = account(MINT_ACCOUNT_INDEX) else {
+            debug!("❌ Could not extract mint from Moonshot token_mint instruction");
+            return Ok(ParseResult::PartialParse(PartialParse::new(
+                LaunchpadType::Moonshot,
+                "token_mint",
+                transaction,
+                None,
+                creator,
+                metadata,
+            )));
+        };
+
+        let token_launch = TokenLaunch::new(
+            LaunchpadType::Moonshot,
+            transaction,
+            mint_address.clone(),
+            creator,
+            metadata,
+        )
+        .with_pool(account(CURVE_ACCOUNT_INDEX));
+
+        debug!("✅ Extracted Moonshot token launch: {}", mint_address);
+        Ok(ParseResult::TokenLaunch(token_launch))
+    }
+
+    /// Extract metadata from instruction data
+    fn extract_metadata_from_instruction(&self, data: &[u8]) -> LaunchMetadata {
+        // token_mint args (after discriminator): TokenMintParams { name: string, symbol: string,
+        // uri: string, decimals: u8, collateral_currency: u8, amount: u64, curve_type: u8,
+        // migration_target: u8 }
+
+        // Skip discriminator (8 bytes)
+        let name = self.extract_string_from_data(data, 8);
+        let symbol = name
+            .as_ref()
+            .and_then(|(_, cursor)| self.extract_string_from_data(data, *cursor));
+        let uri = symbol
+            .as_ref()
+            .and_then(|(_, cursor)| self.extract_string_from_data(data, *cursor));
+        // `amount` is the total supply minted to the curve, after decimals and collateral_currency
+        let initial_supply = uri.as_ref().and_then(|(_, cursor)| {
+            let bytes = data.get(cursor + 2..cursor + 10)?;
+            Some(u64::from_le_bytes(bytes.try_into().ok()?))
+        });
+
+        LaunchMetadata {
+            name: name.map(|(s, _)| s),
+            symbol: symbol.map(|(s, _)| s),
+            uri: uri.map(|(s, _)| s),
+            initial_supply,
+            mint_authority: None,
+            fee_recipient: None,
+            token_extensions: None,
+            normalized: None,
+        }
+    }
+
+    /// Extract string from instruction data
+    fn extract_string_from_data(&self, data: &[u8], start: usize) -> Option<(String, usize)> {
+        let len_bytes = data.get(start..start + 4)?;
+        let len = u32::from_le_bytes(len_bytes.try_into().ok()?) as usize;
+
+        let str_start = start + 4;
+        let str_end = str_start.checked_add(len)?;
+        let bytes = data.get(str_start..str_end)?;
+
+        String::from_utf8(bytes.to_vec())
+            .ok()
+            .map(|string| (string, str_end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{borsh_string, instruction, transaction};
+
+    const PROGRAM_ID: &str = "MoonCVVNZFSYkqNXP6bxHLPL6QQJiMagDL3qcqUQTrG";
+
+    #[test]
+    fn token_mint_is_decoded_with_its_supply() {
+        let mut data = TOKEN_MINT_DISCRIMINATOR.to_vec();
+        data.extend(borsh_string("Moon Token"));
+        data.extend(borsh_string("MOON"));
+        data.extend(borsh_string("https://example.com/moon.json"));
+        data.extend([9, 0]); // decimals, collateral_currency
+        data.extend(1_000_000_000_000_000_000u64.to_le_bytes());
+        data.extend([1, 0]); // curve_type, migration_target
+        let transaction = transaction(
+            &["sender", "backend", "curve", "mint"],
+            vec![instruction(PROGRAM_ID, &[0, 1, 2, 3], data)],
+            Vec::new(),
+        );
+
+        let results = MoonshotParser::new()
+            .parse_transaction(&transaction)
+            .unwrap();
+        let [ParseResult::TokenLaunch(launch)] = results.as_slice() else {
+            panic!("expected one launch, got {} results", results.len());
+        };
+        assert_eq!(launch.token_address, "mint");
+        assert_eq!(launch.creator.as_deref(), Some("sender"));
+        assert_eq!(launch.pool.as_deref(), Some("curve"));
+        assert_eq!(launch.metadata.name.as_deref(), Some("Moon Token"));
+        assert_eq!(launch.metadata.symbol.as_deref(), Some("MOON"));
+        assert_eq!(
+            launch.metadata.uri.as_deref(),
+            Some("https://example.com/moon.json")
+        );
+        assert_eq!(
+            launch.metadata.initial_supply,
+            Some(1_000_000_000_000_000_000)
+        );
+    }
+
+    #[test]
+    fn trades_are_reported_as_other_instructions() {
+        let transaction = transaction(
+            &["trader"],
+            vec![instruction(PROGRAM_ID, &[0], INSTRUCTIONS[1].1.to_vec())],
+            Vec::new(),
+        );
+
+        let results = MoonshotParser::new()
+            .parse_transaction(&transaction)
+            .unwrap();
+        let [ParseResult::Other { event_type, .. }] = results.as_slice() else {
+            panic!(
+                "expected one other instruction, got {} results",
+                results.len()
+            );
+        };
+        assert_eq!(event_type, "sell");
+    }
+}
@@ -0,0 +1,51 @@
+use crate::parser::launchpad_parser::{LaunchMetadata, NormalizedMetadata};
+use unicode_normalization::UnicodeNormalization;
+
+/// Bidirectional embeddings, overrides and isolates. They reorder the text around them,
+/// e.g. to show a different ticker than the one stored.
+const BIDI_CONTROLS: [char; 9] = [
+    '\u{202A}', '\u{202B}', '\u{202C}', '\u{202D}', '\u{202E}', '\u{2066}', '\u{2067}', '\u{2068}',
+    '\u{2069}',
+];
+/// Invisible marks and zero width characters, dropped without flagging
+const INVISIBLE: [char; 7] = [
+    '\u{200B}', '\u{200C}', '\u{200D}', '\u{200E}', '\u{200F}', '\u{061C}', '\u{FEFF}',
+];
+
+/// Display-safe name and symbol of `metadata`, `None` when it has neither. The raw values
+/// stay in `name` and `symbol`.
+pub fn normalize(metadata: &LaunchMetadata) -> Option<NormalizedMetadata> {
+    if metadata.name.is_none() && metadata.symbol.is_none() {
+        return None;
+    }
+
+    let mut normalized = NormalizedMetadata::default();
+    normalized.name = metadata
+        .name
+        .as_deref()
+        .map(|name| normalize_text(name, &mut normalized));
+    normalized.symbol = metadata
+        .symbol
+        .as_deref()
+        .map(|symbol| normalize_text(symbol, &mut normalized));
+    Some(normalized)
+}
+
+/// NFC normalized `text` without control, bidi and zero width characters, trimmed.
+/// Records on `flags` what was removed.
+fn normalize_text(text: &str, flags: &mut NormalizedMetadata) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.nfc() {
+        if BIDI_CONTROLS.contains(&c) {
+            flags.bidi_override = true;
+        } else if c.is_control() {
+            // Metaplex pads names and symbols with NULs
+            if c != '\0' {
+                flags.control_characters = true;
+            }
+        } else if !INVISIBLE.contains(&c) {
+            out.push(c);
+        }
+    }
+    out.trim().to_string()
+}
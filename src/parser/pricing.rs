@@ -0,0 +1,35 @@
+//! Token prices in SOL from bonding curve reserves. Curves trade on constant product
+//! virtual reserves, so the spot price is the ratio of the SOL and token reserves.
+
+/// Decimals of pump.fun and LaunchLab tokens
+pub const TOKEN_DECIMALS: i32 = 6;
+/// Supply most launchpads mint, 1B tokens with 6 decimals
+pub const DEFAULT_SUPPLY: u64 = 1_000_000_000_000_000;
+/// Virtual reserves of a new pump.fun bonding curve
+pub const PUMP_INITIAL_VIRTUAL_SOL_RESERVES: u64 = 30_000_000_000;
+pub const PUMP_INITIAL_VIRTUAL_TOKEN_RESERVES: u64 = 1_073_000_000_000_000;
+
+const LAMPORTS_PER_SOL: f64 = 1_000_000_000.0;
+
+/// Price of one whole token and market cap of the supply, in SOL
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Price {
+    pub price_sol: f64,
+    pub market_cap_sol: f64,
+}
+
+impl Price {
+    /// Spot price of a curve holding `sol_reserves` lamports against `token_reserves`
+    /// raw tokens, with the market cap of `supply` raw tokens. `None` for an empty curve.
+    pub fn from_reserves(sol_reserves: u64, token_reserves: u64, supply: u64) -> Option<Self> {
+        if token_reserves == 0 {
+            return None;
+        }
+        let scale = 10f64.powi(TOKEN_DECIMALS);
+        let price_sol = (sol_reserves as f64 / LAMPORTS_PER_SOL) / (token_reserves as f64 / scale);
+        Some(Self {
+            price_sol,
+            market_cap_sol: price_sol * supply as f64 / scale,
+        })
+    }
+}
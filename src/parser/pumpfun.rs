@@ -1,21 +1,50 @@
+use crate::config::parsers::PumpfunConfig;
 use crate::geyser::QueuedTransaction;
+use crate::parser::anchor::{self, BorshReader};
+use crate::parser::pricing::{self, Price};
 use crate::parser::{
-    LaunchpadParser, LaunchpadType, ParseResult, TokenLaunch, launchpad_parser::LaunchMetadata,
+    EventKind, Graduation, GraduationTrigger, LaunchpadParser, LaunchpadType, MetadataField,
+    ParseResult, ParserCapabilities, PartialParse, TokenLaunch, Trade, TradeFees,
+    launchpad_parser::{LaunchMetadata, fill_initial_buys},
 };
+use crate::parser::{accounts, spl};
 use tracing::{debug, info};
 
+/// `TradeEvent` discriminator, emitted through self-CPI on every buy and sell
+const TRADE_EVENT_DISCRIMINATOR: [u8; 8] = [189, 219, 127, 211, 78, 230, 97, 238];
+/// `CompleteEvent` discriminator, emitted through self-CPI by the buy that sells out the curve
+const COMPLETE_EVENT_DISCRIMINATOR: [u8; 8] = [95, 114, 97, 156, 212, 46, 152, 8];
+/// `migrate` instruction discriminator, and the legacy `withdraw` used before PumpSwap
+const MIGRATE_DISCRIMINATOR: [u8; 8] = [155, 234, 231, 146, 236, 158, 162, 30];
+const WITHDRAW_DISCRIMINATOR: [u8; 8] = [183, 18, 70, 156, 148, 109, 161, 34];
+/// `buy` and `sell` instruction discriminators (pumpIDL.json)
+const BUY_DISCRIMINATOR: [u8; 8] = [102, 6, 61, 18, 1, 218, 235, 234];
+const SELL_DISCRIMINATOR: [u8; 8] = [51, 230, 133, 164, 1, 127, 131, 173];
+/// Admin instructions changing the global config (pumpIDL.json)
+const SET_PARAMS_DISCRIMINATOR: [u8; 8] = [27, 234, 178, 52, 147, 2, 187, 141];
+const UPDATE_GLOBAL_AUTHORITY_DISCRIMINATOR: [u8; 8] = [227, 181, 74, 196, 208, 21, 97, 213];
+const LAMPORTS_PER_SOL: f64 = 1_000_000_000.0;
+
 pub struct PumpfunParser {
     program_id: String,
+    config: PumpfunConfig,
 }
 
 impl PumpfunParser {
-    pub fn new() -> Self {
+    pub fn new(config: PumpfunConfig) -> Self {
         Self {
             program_id: "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P".to_string(),
+            config,
         }
     }
 }
 
+impl Default for PumpfunParser {
+    fn default() -> Self {
+        Self::new(PumpfunConfig::default())
+    }
+}
+
 impl LaunchpadParser for PumpfunParser {
     fn get_program_ids(&self) -> Vec<String> {
         vec![self.program_id.clone()]
@@ -25,14 +54,33 @@ impl LaunchpadParser for PumpfunParser {
         LaunchpadType::Pumpfun
     }
 
+    fn capabilities(&self) -> ParserCapabilities {
+        ParserCapabilities {
+            events: vec![
+                EventKind::TokenLaunch,
+                EventKind::PartialParse,
+                EventKind::Trade,
+                EventKind::Graduation,
+            ],
+            metadata: vec![MetadataField::Name, MetadataField::Symbol],
+        }
+    }
+
     fn parse_transaction(
         &self,
         transaction: &QueuedTransaction,
     ) -> Result<Vec<ParseResult>, Box<dyn std::error::Error + Send + Sync>> {
         debug!("🔍 Parsing PumpFun transaction: {}", transaction.signature);
 
-        // Check for create instruction discriminator: [24, 30, 200, 40, 5, 28, 7, 119]
-        for instr in &transaction.instructions {
+        let mut results = Vec::new();
+
+        // Check for create instruction discriminator: [24, 30, 200, 40, 5, 28, 7, 119].
+        // Vanity mint helpers call create through CPI, so inner instructions are checked too.
+        for instr in transaction
+            .instructions
+            .iter()
+            .chain(&transaction.inner_instructions)
+        {
             if instr.program_id == self.program_id
                 && instr.data.len() >= 8
                 && instr.data[0..8] == [24, 30, 200, 40, 5, 28, 7, 119]
@@ -42,46 +90,481 @@ impl LaunchpadParser for PumpfunParser {
                     transaction.signature
                 );
 
-                if let Some(token_launch) = self.extract_token_launch(transaction, instr)? {
-                    return Ok(vec![ParseResult::TokenLaunch(token_launch)]);
+                // Batch deployers create several tokens in one transaction
+                results.push(self.extract_token_launch(transaction, instr)?);
+            }
+        }
+
+        // Trades, including the dev buy of a launch, are reported through TradeEvent
+        for instr in &transaction.inner_instructions {
+            if instr.program_id == self.program_id
+                && !instr.truncated
+                && let Some(trade) = self.extract_trade(transaction, &instr.data)
+            {
+                results.push(trade);
+            }
+        }
+
+        // Buys and sells whose TradeEvent is missing, e.g. cut by the inner instruction
+        // size limit, are read from the instruction itself
+        for instr in transaction
+            .instructions
+            .iter()
+            .chain(&transaction.inner_instructions)
+        {
+            if instr.program_id != self.program_id {
+                continue;
+            }
+            if let Some(trade) = self.extract_instruction_trade(transaction, instr)
+                && !results.iter().any(|result| {
+                    matches!(result, ParseResult::Trade(event)
+                        if event.token_address == trade.token_address
+                            && event.trader == trade.trader
+                            && event.is_buy == trade.is_buy)
+                })
+            {
+                results.push(ParseResult::Trade(trade));
+            }
+        }
+
+        // A curve completes on the buy selling it out, the migration follows in a later
+        // transaction. Both are reported, the manager keeps the first per token.
+        for instr in &transaction.inner_instructions {
+            if instr.program_id == self.program_id
+                && !instr.truncated
+                && let Some(graduation) = self.extract_curve_complete(transaction, &instr.data)
+            {
+                results.push(ParseResult::Graduation(graduation));
+            }
+        }
+        for instr in transaction
+            .instructions
+            .iter()
+            .chain(&transaction.inner_instructions)
+        {
+            if instr.program_id != self.program_id {
+                continue;
+            }
+            if let Some(graduation) = self.extract_migrate(transaction, instr)
+                && !results.iter().any(|result| {
+                    matches!(result, ParseResult::Graduation(complete)
+                        if complete.token_address == graduation.token_address)
+                })
+            {
+                results.push(ParseResult::Graduation(graduation));
+            }
+        }
+
+        // Changes of the platform's fees and curve parameters, reported for operators
+        for instr in transaction
+            .instructions
+            .iter()
+            .chain(&transaction.inner_instructions)
+        {
+            if instr.program_id == self.program_id
+                && let Some((event_type, detail)) = Self::extract_admin_instruction(&instr.data)
+            {
+                info!(
+                    "⚙️ PumpFun global config changed in {}: {} {}",
+                    transaction.signature,
+                    event_type,
+                    detail.as_deref().unwrap_or_default()
+                );
+                results.push(ParseResult::Other {
+                    launchpad: LaunchpadType::Pumpfun,
+                    event_type: event_type.to_string(),
+                    detail,
+                    signature: transaction.signature.clone(),
+                });
+            }
+        }
+
+        fill_initial_buys(&mut results);
+        for result in &mut results {
+            if let ParseResult::TokenLaunch(launch) = result
+                && launch.initial_buy_sol.is_none()
+                && self.rules_out_dev_buy(transaction, launch)
+            {
+                launch.initial_buy_sol = Some(0);
+                launch.initial_buy_tokens = Some(0);
+            }
+        }
+
+        // Launches with a dev buy below the configured minimum are skipped. An unknown dev
+        // buy, e.g. read from an estimated trade, is not held against the launch.
+        if self.config.min_dev_buy_sol > 0.0 {
+            let skipped: Vec<String> = results
+                .iter()
+                .filter_map(|result| match result {
+                    ParseResult::TokenLaunch(launch) => {
+                        let dev_buy = launch.initial_buy_sol?;
+                        if (dev_buy as f64) >= self.config.min_dev_buy_sol * LAMPORTS_PER_SOL {
+                            return None;
+                        }
+                        debug!(
+                            "Skipping PumpFun launch of {} in {} with dev buy of {} lamports",
+                            launch.token_address, transaction.signature, dev_buy
+                        );
+                        Some(launch.token_address.clone())
+                    }
+                    _ => None,
+                })
+                .collect();
+            for result in &mut results {
+                if let ParseResult::TokenLaunch(launch) = result
+                    && skipped.contains(&launch.token_address)
+                {
+                    *result = ParseResult::Skipped {
+                        launchpad: launch.launchpad.clone(),
+                        reason: "dev buy below min_dev_buy_sol".to_string(),
+                    };
                 }
             }
         }
 
-        Ok(vec![ParseResult::NotRelevant])
+        if !self.config.emit_trades {
+            results.retain(|result| !matches!(result, ParseResult::Trade(_)));
+        }
+
+        if results.is_empty() {
+            results.push(ParseResult::NotRelevant);
+        }
+
+        Ok(results)
     }
 }
 
 impl PumpfunParser {
+    /// Name of a `set_params` or `update_global_authority` instruction, with the new
+    /// fees and curve parameters of `set_params`
+    fn extract_admin_instruction(data: &[u8]) -> Option<(&'static str, Option<String>)> {
+        if data.starts_with(&UPDATE_GLOBAL_AUTHORITY_DISCRIMINATOR) {
+            return Some(("update_global_authority", None));
+        }
+        if !data.starts_with(&SET_PARAMS_DISCRIMINATOR) {
+            return None;
+        }
+
+        // Older layouts without the creator fee still report the change
+        Some(("set_params", Self::describe_set_params(&data[8..])))
+    }
+
+    /// New fees and curve parameters of a `set_params` instruction
+    fn describe_set_params(args: &[u8]) -> Option<String> {
+        let mut reader = BorshReader::new(args);
+        let initial_virtual_token_reserves = reader.u64()?;
+        let initial_virtual_sol_reserves = reader.u64()?;
+        let initial_real_token_reserves = reader.u64()?;
+        let token_total_supply = reader.u64()?;
+        let fee_basis_points = reader.u64()?;
+        reader.pubkey()?; // withdraw_authority
+        let enable_migrate = reader.bool()?;
+        let pool_migration_fee = reader.u64()?;
+        let creator_fee_basis_points = reader.u64()?;
+        Some(format!(
+            "fee_basis_points={} creator_fee_basis_points={} \
+             pool_migration_fee={} enable_migrate={} token_total_supply={} \
+             initial_virtual_token_reserves={} initial_virtual_sol_reserves={} \
+             initial_real_token_reserves={}",
+            fee_basis_points,
+            creator_fee_basis_points,
+            pool_migration_fee,
+            enable_migrate,
+            token_total_supply,
+            initial_virtual_token_reserves,
+            initial_virtual_sol_reserves,
+            initial_real_token_reserves
+        ))
+    }
+
     /// Extract token launch information from create instruction
     fn extract_token_launch(
         &self,
         transaction: &QueuedTransaction,
         instruction: &crate::geyser::TransactionInstruction,
-    ) -> Result<Option<TokenLaunch>, Box<dyn std::error::Error + Send + Sync>> {
-        // Try to find the mint from instruction accounts
-        // According to IDL, account 0 should be the mint
-        if let Some(mint_idx) = instruction.accounts.get(0) {
-            if let Some(mint_address) = transaction.accounts.get(*mint_idx as usize) {
-                let creator = transaction.accounts.get(0).cloned();
-
-                let token_launch = TokenLaunch {
-                    launchpad: LaunchpadType::Pumpfun,
-                    token_address: mint_address.clone(),
-                    creator,
-                    signature: transaction.signature.clone(),
-                    slot: transaction.slot,
-                    timestamp: transaction.received_time,
-                    metadata: self.extract_metadata_from_instruction(&instruction.data),
-                };
+    ) -> Result<ParseResult, Box<dyn std::error::Error + Send + Sync>> {
+        // Account 7 is the signing `user`, not necessarily the fee payer. Vanity mint helpers
+        // sign through their own accounts, the `creator` argument names the wallet then.
+        let creator = instruction
+            .accounts
+            .get(7)
+            .and_then(|idx| transaction.accounts.get(*idx as usize))
+            .filter(|user| transaction.is_signer(user))
+            .cloned()
+            .or_else(|| self.extract_creator_from_instruction(&instruction.data))
+            .or_else(|| transaction.fee_payer.clone());
+        let metadata = self.extract_metadata_from_instruction(&instruction.data);
 
-                debug!("✅ Extracted PumpFun token launch: {}", mint_address);
-                return Ok(Some(token_launch));
-            }
+        // According to IDL, account 0 should be the mint. Helpers passing the accounts in
+        // another order are caught by checking against the mints initialized in the transaction.
+        let accounts: Vec<&String> = instruction
+            .accounts
+            .iter()
+            .filter_map(|idx| transaction.accounts.get(*idx as usize))
+            .collect();
+        let initialized = spl::initialized_mints(transaction);
+        let mint_address = match accounts.first() {
+            Some(first) if initialized.is_empty() || initialized.contains(first) => Some(*first),
+            _ => accounts
+                .iter()
+                .find(|account| initialized.contains(account))
+                .copied(),
+        };
+        let Some(mint_address) = mint_address else {
+            debug!("❌ Could not extract mint from PumpFun create instruction");
+            return Ok(ParseResult::PartialParse(PartialParse::new(
+                LaunchpadType::Pumpfun,
+                "create",
+                transaction,
+                None,
+                creator,
+                metadata,
+            )));
+        };
+
+        // Account 2 is the bonding curve
+        let pool = instruction
+            .accounts
+            .get(2)
+            .and_then(|idx| transaction.accounts.get(*idx as usize))
+            .cloned();
+
+        // The supply is only known after enrichment, pump.fun always mints the default
+        let price = Price::from_reserves(
+            pricing::PUMP_INITIAL_VIRTUAL_SOL_RESERVES,
+            pricing::PUMP_INITIAL_VIRTUAL_TOKEN_RESERVES,
+            pricing::DEFAULT_SUPPLY,
+        );
+
+        let token_launch = TokenLaunch::new(
+            LaunchpadType::Pumpfun,
+            transaction,
+            mint_address.clone(),
+            creator,
+            metadata,
+        )
+        .with_pool(pool)
+        .with_price(price);
+
+        debug!("✅ Extracted PumpFun token launch: {}", mint_address);
+        Ok(ParseResult::TokenLaunch(token_launch))
+    }
+
+    /// Extract a trade with its fees from a TradeEvent self-CPI instruction
+    fn extract_trade(&self, transaction: &QueuedTransaction, data: &[u8]) -> Option<ParseResult> {
+        let body = anchor::cpi_event(data, &TRADE_EVENT_DISCRIMINATOR)?;
+        let mut reader = BorshReader::new(body);
+
+        // mint, sol_amount, token_amount, is_buy, user, timestamp
+        let mint = reader.pubkey()?;
+        let sol_amount = reader.u64()?;
+        let token_amount = reader.u64()?;
+        let is_buy = reader.bool()?;
+        let user = reader.pubkey()?;
+        reader.i64()?;
+
+        // virtual_sol, virtual_token, real_sol, real_token reserves
+        let virtual_sol_reserves = reader.u64()?;
+        let virtual_token_reserves = reader.u64()?;
+        reader.skip(8)?;
+        let real_token_reserves = reader.u64()?;
+        let curve_progress = accounts::progress(real_token_reserves);
+        let price = Price::from_reserves(
+            virtual_sol_reserves,
+            virtual_token_reserves,
+            pricing::DEFAULT_SUPPLY,
+        );
+
+        // fee_recipient, fee_basis_points, fee, then creator, creator_fee_basis_points, creator_fee.
+        // Events from before creator fees were introduced end after the protocol fee.
+        let fees = reader.pubkey().and_then(|fee_recipient| {
+            reader.u64()?;
+            let protocol_fee = reader.u64()?;
+            let (creator, creator_fee) = match (reader.pubkey(), reader.u64(), reader.u64()) {
+                (Some(creator), Some(_), Some(creator_fee)) => (Some(creator), creator_fee),
+                _ => (None, 0),
+            };
+            Some(TradeFees {
+                fee_recipient,
+                protocol_fee,
+                creator,
+                creator_fee,
+            })
+        });
+
+        Some(ParseResult::Trade(Trade {
+            launchpad: LaunchpadType::Pumpfun,
+            token_address: mint,
+            trader: user,
+            amount: sol_amount,
+            token_amount,
+            is_buy,
+            fees,
+            curve_progress: Some(curve_progress),
+            price_sol: price.map(|price| price.price_sol),
+            market_cap_sol: price.map(|price| price.market_cap_sol),
+            estimated: false,
+            signature: transaction.signature.clone(),
+            slot: transaction.slot,
+            transaction_index: transaction.transaction_index,
+            timestamp: transaction.received_time,
+            block_time: transaction.block_time,
+        }))
+    }
+
+    /// Extract a graduation from a CompleteEvent self-CPI instruction
+    fn extract_curve_complete(
+        &self,
+        transaction: &QueuedTransaction,
+        data: &[u8],
+    ) -> Option<Graduation> {
+        let body = anchor::cpi_event(data, &COMPLETE_EVENT_DISCRIMINATOR)?;
+        let mut reader = BorshReader::new(body);
+
+        // user, mint, bonding_curve, timestamp
+        let user = reader.pubkey()?;
+        let mint = reader.pubkey()?;
+        let bonding_curve = reader.pubkey()?;
+
+        Some(self.graduation(
+            transaction,
+            mint,
+            Some(bonding_curve),
+            Some(user),
+            GraduationTrigger::CurveComplete,
+        ))
+    }
+
+    /// Extract a graduation from a `migrate` or legacy `withdraw` instruction
+    fn extract_migrate(
+        &self,
+        transaction: &QueuedTransaction,
+        instruction: &crate::geyser::TransactionInstruction,
+    ) -> Option<Graduation> {
+        // Both take the mint at 2 and the bonding curve at 3, the signing user at 5 for
+        // `migrate` and at 6 for `withdraw`
+        let (trigger, user_index) = if instruction.data.starts_with(&MIGRATE_DISCRIMINATOR) {
+            (GraduationTrigger::Migrate, 5)
+        } else if instruction.data.starts_with(&WITHDRAW_DISCRIMINATOR) {
+            (GraduationTrigger::Withdraw, 6)
+        } else {
+            return None;
+        };
+        let account = |index: usize| {
+            instruction
+                .accounts
+                .get(index)
+                .and_then(|idx| transaction.accounts.get(*idx as usize))
+                .cloned()
+        };
+
+        Some(self.graduation(
+            transaction,
+            account(2)?,
+            account(3),
+            account(user_index),
+            trigger,
+        ))
+    }
+
+    fn graduation(
+        &self,
+        transaction: &QueuedTransaction,
+        token_address: String,
+        bonding_curve: Option<String>,
+        user: Option<String>,
+        trigger: GraduationTrigger,
+    ) -> Graduation {
+        info!(
+            "🎓 PumpFun curve of {} complete ({:?}) in: {}",
+            token_address, trigger, transaction.signature
+        );
+        Graduation {
+            launchpad: LaunchpadType::Pumpfun,
+            token_address,
+            bonding_curve,
+            user,
+            trigger,
+            signature: transaction.signature.clone(),
+            slot: transaction.slot,
+            transaction_index: transaction.transaction_index,
+            timestamp: transaction.received_time,
+            block_time: transaction.block_time,
+            backfilled: transaction.backfilled,
+            links: None,
         }
+    }
+
+    /// Extract a trade from a `buy` or `sell` instruction. The instruction only carries the
+    /// slippage bound, so the SOL amount is `max_sol_cost` for buys and `min_sol_output`
+    /// for sells, fees are unknown and the trade is marked estimated.
+    fn extract_instruction_trade(
+        &self,
+        transaction: &QueuedTransaction,
+        instruction: &crate::geyser::TransactionInstruction,
+    ) -> Option<Trade> {
+        let is_buy = if instruction.data.starts_with(&BUY_DISCRIMINATOR) {
+            true
+        } else if instruction.data.starts_with(&SELL_DISCRIMINATOR) {
+            false
+        } else {
+            return None;
+        };
+        let mut reader = BorshReader::new(&instruction.data[8..]);
+        let token_amount = reader.u64()?;
+        let sol_amount = reader.u64()?;
+
+        // Account 2 is the mint, account 6 the signing user
+        let account = |index: usize| {
+            instruction
+                .accounts
+                .get(index)
+                .and_then(|idx| transaction.accounts.get(*idx as usize))
+                .cloned()
+        };
+
+        Some(Trade {
+            launchpad: LaunchpadType::Pumpfun,
+            token_address: account(2)?,
+            trader: account(6)?,
+            amount: sol_amount,
+            token_amount,
+            is_buy,
+            fees: None,
+            curve_progress: None,
+            price_sol: None,
+            market_cap_sol: None,
+            estimated: true,
+            signature: transaction.signature.clone(),
+            slot: transaction.slot,
+            transaction_index: transaction.transaction_index,
+            timestamp: transaction.received_time,
+            block_time: transaction.block_time,
+        })
+    }
 
-        debug!("❌ Could not extract mint from PumpFun create instruction");
-        Ok(None)
+    /// Whether the transaction shows the creator of `launch` did not buy it: every `buy`
+    /// instruction of the program is someone else's, and no instruction of the program
+    /// was truncated, so no TradeEvent of a buy in another form may have been cut.
+    fn rules_out_dev_buy(&self, transaction: &QueuedTransaction, launch: &TokenLaunch) -> bool {
+        let Some(creator) = &launch.creator else {
+            return false;
+        };
+        transaction
+            .instructions
+            .iter()
+            .chain(&transaction.inner_instructions)
+            .filter(|instr| instr.program_id == self.program_id)
+            .all(|instr| {
+                !instr.truncated
+                    && (!instr.data.starts_with(&BUY_DISCRIMINATOR)
+                        || self
+                            .extract_instruction_trade(transaction, instr)
+                            .is_some_and(|trade| {
+                                trade.token_address != launch.token_address
+                                    || trade.trader != *creator
+                            }))
+            })
     }
 
     /// Extract metadata from instruction data
@@ -96,6 +579,9 @@ impl PumpfunParser {
                 uri: None,
                 initial_supply: None,
                 mint_authority: None,
+                fee_recipient: None,
+                token_extensions: None,
+                normalized: None,
             };
         }
 
@@ -114,6 +600,9 @@ impl PumpfunParser {
                     uri: None,
                     initial_supply: None,
                     mint_authority: None,
+                    fee_recipient: None,
+                    token_extensions: None,
+                    normalized: None,
                 };
             }
         }
@@ -124,9 +613,21 @@ impl PumpfunParser {
             uri: None,
             initial_supply: None,
             mint_authority: None,
+            fee_recipient: None,
+            token_extensions: None,
+            normalized: None,
         }
     }
 
+    /// Extract the `creator` argument following name, symbol and uri
+    fn extract_creator_from_instruction(&self, data: &[u8]) -> Option<String> {
+        let mut reader = BorshReader::new(data.get(8..)?);
+        reader.string()?;
+        reader.string()?;
+        reader.string()?;
+        reader.pubkey()
+    }
+
     /// Extract string from instruction data
     fn extract_string_from_data(&self, data: &[u8], start: usize) -> Option<(String, usize)> {
         if start + 4 > data.len() {
@@ -155,3 +656,151 @@ impl PumpfunParser {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geyser::encoding::encode_pubkey;
+    use crate::testing::{borsh_string, instruction, transaction};
+
+    const PROGRAM_ID: &str = "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P";
+
+    /// `TradeEvent` of a 0.5 SOL buy of 17M tokens of mint `[1; 32]` by user `[2; 32]`
+    fn trade_event() -> Vec<u8> {
+        let mut data = anchor::EVENT_IX_TAG.to_vec();
+        data.extend(TRADE_EVENT_DISCRIMINATOR);
+        data.extend([1; 32]);
+        data.extend(500_000_000u64.to_le_bytes());
+        data.extend(17_000_000_000_000u64.to_le_bytes());
+        data.push(1);
+        data.extend([2; 32]);
+        data.extend(1_700_000_000i64.to_le_bytes());
+        for reserves in [
+            40_000_000_000u64,
+            800_000_000_000_000,
+            10_000_000_000,
+            520_000_000_000_000,
+        ] {
+            data.extend(reserves.to_le_bytes());
+        }
+        data.extend([3; 32]);
+        data.extend(95u64.to_le_bytes());
+        data.extend(4_750_000u64.to_le_bytes());
+        data.extend([4; 32]);
+        data.extend(5u64.to_le_bytes());
+        data.extend(250_000u64.to_le_bytes());
+        data
+    }
+
+    /// `create` as sent by the pump.fun frontend, the user paying and signing
+    fn create_transaction() -> QueuedTransaction {
+        let mut data = vec![24, 30, 200, 40, 5, 28, 7, 119];
+        data.extend(borsh_string("Pump Token"));
+        data.extend(borsh_string("PUMP"));
+        data.extend(borsh_string("https://ipfs.io/ipfs/QmPump"));
+        data.extend([7; 32]);
+        transaction(
+            &[
+                "user",
+                "mint",
+                "bonding_curve",
+                "associated_bonding_curve",
+                "metadata",
+                "TSLvdd1pWpHVjahSpsvCXUbgwsL3JAcvokwaKt1eokM",
+                "4wTV1YmiEkRvAtNtsSGPtUrqRYQMe5SKy2uB4Jjaxnjf",
+                "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s",
+                "11111111111111111111111111111111",
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+                "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+                "SysvarRent111111111111111111111111111111111",
+                "Ce6TQqeHC9p8KetsN6JsjHK7UTZk7nasjjnr7XxXp9F1",
+                PROGRAM_ID,
+            ],
+            vec![instruction(
+                PROGRAM_ID,
+                &[1, 5, 2, 3, 6, 7, 4, 0, 8, 9, 10, 11, 12, 13],
+                data,
+            )],
+            Vec::new(),
+        )
+    }
+
+    #[test]
+    fn create_is_decoded_with_the_launch_price() {
+        let results = PumpfunParser::default()
+            .parse_transaction(&create_transaction())
+            .unwrap();
+        let Some(ParseResult::TokenLaunch(launch)) = results.first() else {
+            panic!("expected a launch first");
+        };
+
+        assert_eq!(launch.token_address, "mint");
+        assert_eq!(launch.creator.as_deref(), Some("user"));
+        assert_eq!(launch.pool.as_deref(), Some("bonding_curve"));
+        assert_eq!(launch.metadata.name.as_deref(), Some("Pump Token"));
+        assert_eq!(launch.metadata.symbol.as_deref(), Some("PUMP"));
+
+        // 30 virtual SOL against 1.073B virtual tokens
+        let price = launch.price_sol.expect("launch price");
+        assert!((price - 30.0 / 1_073_000_000.0).abs() < 1e-15);
+        let market_cap = launch.market_cap_sol.expect("launch market cap");
+        assert!((market_cap - 30_000.0 / 1_073.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn create_without_a_mint_is_a_partial_parse() {
+        let mut transaction = create_transaction();
+        transaction.instructions[0].accounts.clear();
+
+        let results = PumpfunParser::default()
+            .parse_transaction(&transaction)
+            .unwrap();
+        let Some(ParseResult::PartialParse(partial)) = results.first() else {
+            panic!("expected a partial parse first");
+        };
+        assert_eq!(partial.instruction, "create");
+        assert_eq!(partial.token_address, None);
+        // Without the signing user account the `creator` argument names the wallet
+        assert_eq!(
+            partial.creator.as_deref(),
+            Some(encode_pubkey(&[7; 32]).as_str())
+        );
+        assert_eq!(partial.metadata.symbol.as_deref(), Some("PUMP"));
+        assert_eq!(partial.missing_fields, ["token_address"]);
+    }
+
+    #[test]
+    fn trade_event_is_decoded_with_fees() {
+        let transaction = transaction(
+            &["user", PROGRAM_ID],
+            Vec::new(),
+            vec![instruction(PROGRAM_ID, &[1], trade_event())],
+        );
+
+        let results = PumpfunParser::default()
+            .parse_transaction(&transaction)
+            .unwrap();
+        let [ParseResult::Trade(trade)] = results.as_slice() else {
+            panic!("expected one trade, got {} results", results.len());
+        };
+        assert_eq!(trade.token_address, encode_pubkey(&[1; 32]));
+        assert_eq!(trade.trader, encode_pubkey(&[2; 32]));
+        assert!(trade.is_buy);
+        assert!(!trade.estimated);
+        assert_eq!(trade.amount, 500_000_000);
+        assert_eq!(trade.token_amount, 17_000_000_000_000);
+
+        let fees = trade.fees.as_ref().expect("fees");
+        assert_eq!(fees.fee_recipient, encode_pubkey(&[3; 32]));
+        assert_eq!(fees.protocol_fee, 4_750_000);
+        assert_eq!(
+            fees.creator.as_deref(),
+            Some(encode_pubkey(&[4; 32]).as_str())
+        );
+        assert_eq!(fees.creator_fee, 250_000);
+
+        // 40 virtual SOL against 800M virtual tokens
+        let price = trade.price_sol.expect("trade price");
+        assert!((price - 40.0 / 800_000_000.0).abs() < 1e-15);
+    }
+}
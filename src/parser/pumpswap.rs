@@ -0,0 +1,394 @@
+use crate::geyser::{QueuedTransaction, TransactionInstruction};
+use crate::parser::anchor::BorshReader;
+use crate::parser::spl;
+use crate::parser::{
+    EventKind, LaunchpadParser, LaunchpadType, Liquidity, LiquidityAction, Migration, ParseResult,
+    ParserCapabilities,
+};
+use tracing::{debug, info};
+
+/// `create_pool` instruction discriminator (Anchor `global:create_pool`)
+const CREATE_POOL_DISCRIMINATOR: [u8; 8] = [233, 146, 209, 142, 207, 104, 64, 188];
+/// `deposit` and `withdraw` instruction discriminators (Anchor `global:deposit`,
+/// `global:withdraw`)
+const DEPOSIT_DISCRIMINATOR: [u8; 8] = [242, 35, 198, 137, 82, 225, 242, 182];
+const WITHDRAW_DISCRIMINATOR: [u8; 8] = [183, 18, 70, 156, 148, 109, 161, 34];
+/// pump.fun bonding curve program, its `migrate` creates the PumpSwap pool through CPI
+const PUMPFUN_PROGRAM_ID: &str = "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P";
+/// pump.fun `migrate` discriminator, with the mint at 2 and the bonding curve at 3
+const PUMPFUN_MIGRATE_DISCRIMINATOR: [u8; 8] = [155, 234, 231, 146, 236, 158, 162, 30];
+const PUMPFUN_MIGRATE_MINT_INDEX: usize = 2;
+const PUMPFUN_MIGRATE_BONDING_CURVE_INDEX: usize = 3;
+
+/// Account positions in the `create_pool` instruction
+const POOL_ACCOUNT_INDEX: usize = 0; // `pool`
+const CREATOR_ACCOUNT_INDEX: usize = 2; // `creator`
+const BASE_MINT_ACCOUNT_INDEX: usize = 3; // `base_mint`
+const QUOTE_MINT_ACCOUNT_INDEX: usize = 4; // `quote_mint`
+const LP_MINT_ACCOUNT_INDEX: usize = 5; // `lp_mint`
+
+/// Account positions in the `deposit` and `withdraw` instructions
+const LIQUIDITY_POOL_ACCOUNT_INDEX: usize = 0; // `pool`
+const LIQUIDITY_USER_ACCOUNT_INDEX: usize = 2; // `user`
+const LIQUIDITY_BASE_MINT_ACCOUNT_INDEX: usize = 3; // `base_mint`
+const LIQUIDITY_QUOTE_MINT_ACCOUNT_INDEX: usize = 4; // `quote_mint`
+const LIQUIDITY_BASE_VAULT_ACCOUNT_INDEX: usize = 9; // `pool_base_token_account`
+const LIQUIDITY_QUOTE_VAULT_ACCOUNT_INDEX: usize = 10; // `pool_quote_token_account`
+
+/// PumpSwap (Pump AMM) pool creation and liquidity changes. Pools created by pump.fun's
+/// `migrate` are graduations and reported as `Pumpfun` migrations, others as `PumpSwap`.
+pub struct PumpSwapParser {
+    program_id: String,
+}
+
+impl PumpSwapParser {
+    pub fn new() -> Self {
+        Self {
+            program_id: "pAMMBay6oceH9fJKBRHGP5D4bD4sWpmSwMn52FMfXEA".to_string(),
+        }
+    }
+}
+
+impl Default for PumpSwapParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LaunchpadParser for PumpSwapParser {
+    fn get_program_ids(&self) -> Vec<String> {
+        vec![self.program_id.clone()]
+    }
+
+    fn get_launchpad_type(&self) -> LaunchpadType {
+        LaunchpadType::PumpSwap
+    }
+
+    fn capabilities(&self) -> ParserCapabilities {
+        ParserCapabilities {
+            events: vec![EventKind::Migration, EventKind::Liquidity],
+            metadata: Vec::new(),
+        }
+    }
+
+    fn parse_transaction(
+        &self,
+        transaction: &QueuedTransaction,
+    ) -> Result<Vec<ParseResult>, Box<dyn std::error::Error + Send + Sync>> {
+        debug!("🔍 Parsing PumpSwap transaction: {}", transaction.signature);
+
+        // Graduations call create_pool through CPI from pump.fun's migrate
+        let called_by_pumpfun = transaction
+            .instructions
+            .iter()
+            .any(|instr| instr.program_id == PUMPFUN_PROGRAM_ID);
+        let instructions = transaction
+            .instructions
+            .iter()
+            .map(|instr| (instr, false))
+            .chain(
+                transaction
+                    .inner_instructions
+                    .iter()
+                    .map(|instr| (instr, called_by_pumpfun)),
+            );
+
+        // Bonding curves migrated by pump.fun's migrate, by mint, to tie each graduation's
+        // pool to its curve
+        let migrated_curves: Vec<(String, String)> = transaction
+            .instructions
+            .iter()
+            .filter(|instr| {
+                instr.program_id == PUMPFUN_PROGRAM_ID
+                    && instr.data.starts_with(&PUMPFUN_MIGRATE_DISCRIMINATOR)
+            })
+            .filter_map(|instr| {
+                let account = |index: usize| {
+                    instr
+                        .accounts
+                        .get(index)
+                        .and_then(|idx| transaction.accounts.get(*idx as usize))
+                        .cloned()
+                };
+                Some((
+                    account(PUMPFUN_MIGRATE_MINT_INDEX)?,
+                    account(PUMPFUN_MIGRATE_BONDING_CURVE_INDEX)?,
+                ))
+            })
+            .collect();
+
+        let mut results = Vec::new();
+        for (instr, graduation) in instructions {
+            if instr.program_id == self.program_id
+                && !instr.truncated
+                && instr.data.starts_with(&CREATE_POOL_DISCRIMINATOR)
+            {
+                info!(
+                    "🎓 Found PumpSwap create_pool instruction in: {}",
+                    transaction.signature
+                );
+                results.extend(self.extract_migration(
+                    transaction,
+                    instr,
+                    graduation,
+                    &migrated_curves,
+                ));
+            } else if instr.program_id == self.program_id
+                && !instr.truncated
+                && let Some(liquidity) = self.extract_liquidity(transaction, instr)
+            {
+                results.push(ParseResult::Liquidity(liquidity));
+            }
+        }
+
+        if results.is_empty() {
+            results.push(ParseResult::NotRelevant);
+        }
+        Ok(results)
+    }
+}
+
+impl PumpSwapParser {
+    /// Extract the liquidity change of a deposit or withdraw instruction
+    fn extract_liquidity(
+        &self,
+        transaction: &QueuedTransaction,
+        instruction: &TransactionInstruction,
+    ) -> Option<Liquidity> {
+        let action = if instruction.data.starts_with(&DEPOSIT_DISCRIMINATOR) {
+            LiquidityAction::Add
+        } else if instruction.data.starts_with(&WITHDRAW_DISCRIMINATOR) {
+            LiquidityAction::Remove
+        } else {
+            return None;
+        };
+        let account = |index: usize| {
+            instruction
+                .accounts
+                .get(index)
+                .and_then(|idx| transaction.accounts.get(*idx as usize))
+                .cloned()
+        };
+
+        // deposit: lp_token_amount_out, max_base_amount_in, max_quote_amount_in
+        // withdraw: lp_token_amount_in, min_base_amount_out, min_quote_amount_out
+        let lp_amount = BorshReader::new(&instruction.data[8..]).u64()?;
+        let incoming = action == LiquidityAction::Add;
+        let moved = |index: usize| {
+            account(index).and_then(|vault| spl::transferred(transaction, &vault, incoming))
+        };
+
+        Some(Liquidity {
+            launchpad: LaunchpadType::PumpSwap,
+            action,
+            pool: account(LIQUIDITY_POOL_ACCOUNT_INDEX)?,
+            token_address: account(LIQUIDITY_BASE_MINT_ACCOUNT_INDEX)?,
+            quote_mint: account(LIQUIDITY_QUOTE_MINT_ACCOUNT_INDEX),
+            provider: account(LIQUIDITY_USER_ACCOUNT_INDEX),
+            lp_amount,
+            token_amount: moved(LIQUIDITY_BASE_VAULT_ACCOUNT_INDEX),
+            quote_amount: moved(LIQUIDITY_QUOTE_VAULT_ACCOUNT_INDEX),
+            signature: transaction.signature.clone(),
+            slot: transaction.slot,
+            transaction_index: transaction.transaction_index,
+            timestamp: transaction.received_time,
+            block_time: transaction.block_time,
+            backfilled: transaction.backfilled,
+            links: None,
+        })
+    }
+
+    /// Extract the new pool and its initial reserves from a create_pool instruction
+    fn extract_migration(
+        &self,
+        transaction: &QueuedTransaction,
+        instruction: &TransactionInstruction,
+        graduation: bool,
+        migrated_curves: &[(String, String)],
+    ) -> Option<ParseResult> {
+        let account = |index: usize| {
+            instruction
+                .accounts
+                .get(index)
+                .and_then(|idx| transaction.accounts.get(*idx as usize))
+                .cloned()
+        };
+
+        let (Some(mint), Some(pool)) = (
+            account(BASE_MINT_ACCOUNT_INDEX),
+            account(POOL_ACCOUNT_INDEX),
+        ) else {
+            debug!("❌ Could not extract mint or pool from PumpSwap create_pool instruction");
+            return None;
+        };
+
+        // index, base_amount_in, quote_amount_in
+        let mut reader = BorshReader::new(&instruction.data[8..]);
+        let reserves = reader
+            .u16()
+            .and_then(|_| Some((reader.u64()?, reader.u64()?)));
+        if reserves.is_none() {
+            debug!("Could not read reserves from PumpSwap create_pool instruction");
+        }
+        let bonding_curve = migrated_curves
+            .iter()
+            .find(|(migrated, _)| *migrated == mint)
+            .map(|(_, curve)| curve.clone());
+
+        let migration = Migration {
+            launchpad: if graduation {
+                LaunchpadType::Pumpfun
+            } else {
+                LaunchpadType::PumpSwap
+            },
+            token_address: mint.clone(),
+            pool,
+            quote_mint: account(QUOTE_MINT_ACCOUNT_INDEX),
+            creator: account(CREATOR_ACCOUNT_INDEX),
+            bin_step: None,
+            bonding_curve,
+            base_reserve: reserves.map(|(base, _)| base),
+            quote_reserve: reserves.map(|(_, quote)| quote),
+            lp_mint: account(LP_MINT_ACCOUNT_INDEX),
+            signature: transaction.signature.clone(),
+            slot: transaction.slot,
+            transaction_index: transaction.transaction_index,
+            timestamp: transaction.received_time,
+            block_time: transaction.block_time,
+            backfilled: transaction.backfilled,
+            links: None,
+        };
+
+        debug!("✅ Extracted PumpSwap pool for: {}", mint);
+        Some(ParseResult::Migration(migration))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{instruction, transaction};
+
+    const PROGRAM_ID: &str = "pAMMBay6oceH9fJKBRHGP5D4bD4sWpmSwMn52FMfXEA";
+    const TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+
+    fn create_pool() -> Vec<u8> {
+        let mut data = CREATE_POOL_DISCRIMINATOR.to_vec();
+        data.extend(0u16.to_le_bytes());
+        data.extend(206_900_000_000_000u64.to_le_bytes());
+        data.extend(84_990_359_679u64.to_le_bytes());
+        data
+    }
+
+    fn accounts() -> [&'static str; 8] {
+        [
+            "creator",
+            "pool",
+            "global_config",
+            "mint",
+            "So11111111111111111111111111111111111111112",
+            "lp_mint",
+            "curve",
+            PUMPFUN_PROGRAM_ID,
+        ]
+    }
+
+    #[test]
+    fn pool_created_by_migrate_is_a_pumpfun_graduation() {
+        let transaction = transaction(
+            &accounts(),
+            vec![instruction(
+                PUMPFUN_PROGRAM_ID,
+                &[2, 0, 3, 6, 0],
+                PUMPFUN_MIGRATE_DISCRIMINATOR.to_vec(),
+            )],
+            vec![instruction(PROGRAM_ID, &[1, 2, 0, 3, 4, 5], create_pool())],
+        );
+
+        let results = PumpSwapParser::new()
+            .parse_transaction(&transaction)
+            .unwrap();
+        let [ParseResult::Migration(migration)] = results.as_slice() else {
+            panic!("expected one migration, got {} results", results.len());
+        };
+        assert!(matches!(migration.launchpad, LaunchpadType::Pumpfun));
+        assert_eq!(migration.token_address, "mint");
+        assert_eq!(migration.pool, "pool");
+        assert_eq!(migration.creator.as_deref(), Some("creator"));
+        assert_eq!(migration.lp_mint.as_deref(), Some("lp_mint"));
+        assert_eq!(migration.bonding_curve.as_deref(), Some("curve"));
+        assert_eq!(migration.base_reserve, Some(206_900_000_000_000));
+        assert_eq!(migration.quote_reserve, Some(84_990_359_679));
+    }
+
+    #[test]
+    fn pool_created_directly_is_a_pumpswap_migration() {
+        let transaction = transaction(
+            &accounts(),
+            vec![instruction(PROGRAM_ID, &[1, 2, 0, 3, 4, 5], create_pool())],
+            Vec::new(),
+        );
+
+        let results = PumpSwapParser::new()
+            .parse_transaction(&transaction)
+            .unwrap();
+        let [ParseResult::Migration(migration)] = results.as_slice() else {
+            panic!("expected one migration, got {} results", results.len());
+        };
+        assert!(matches!(migration.launchpad, LaunchpadType::PumpSwap));
+        assert_eq!(migration.bonding_curve, None);
+    }
+
+    #[test]
+    fn deposit_reports_the_transferred_amounts() {
+        let mut deposit = DEPOSIT_DISCRIMINATOR.to_vec();
+        for amount in [1_000u64, 2_000_000, 3_000_000] {
+            deposit.extend(amount.to_le_bytes());
+        }
+        let transfer = |amount: u64| {
+            let mut data = vec![3];
+            data.extend(amount.to_le_bytes());
+            data
+        };
+        let transaction = transaction(
+            &[
+                "provider",
+                "pool",
+                "global_config",
+                "mint",
+                "So11111111111111111111111111111111111111112",
+                "lp_mint",
+                "user_base",
+                "user_quote",
+                "base_vault",
+                "quote_vault",
+            ],
+            vec![instruction(
+                PROGRAM_ID,
+                &[1, 2, 0, 3, 4, 5, 6, 7, 7, 8, 9],
+                deposit,
+            )],
+            vec![
+                instruction(TOKEN_PROGRAM_ID, &[6, 8, 0], transfer(1_900_000)),
+                instruction(TOKEN_PROGRAM_ID, &[7, 9, 0], transfer(2_800_000)),
+            ],
+        );
+
+        let results = PumpSwapParser::new()
+            .parse_transaction(&transaction)
+            .unwrap();
+        let [ParseResult::Liquidity(liquidity)] = results.as_slice() else {
+            panic!(
+                "expected one liquidity change, got {} results",
+                results.len()
+            );
+        };
+        assert_eq!(liquidity.action, LiquidityAction::Add);
+        assert_eq!(liquidity.pool, "pool");
+        assert_eq!(liquidity.token_address, "mint");
+        assert_eq!(liquidity.provider.as_deref(), Some("provider"));
+        assert_eq!(liquidity.lp_amount, 1_000);
+        assert_eq!(liquidity.token_amount, Some(1_900_000));
+        assert_eq!(liquidity.quote_amount, Some(2_800_000));
+    }
+}
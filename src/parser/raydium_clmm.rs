@@ -0,0 +1,186 @@
+use crate::geyser::{QueuedTransaction, TransactionInstruction};
+use crate::parser::spl;
+use crate::parser::{
+    EventKind, LaunchpadParser, LaunchpadType, Migration, ParseResult, ParserCapabilities,
+};
+use tracing::{debug, info};
+
+/// `create_pool` instruction discriminator (Anchor `global:create_pool`)
+const CREATE_POOL_DISCRIMINATOR: [u8; 8] = [233, 146, 209, 142, 207, 104, 64, 188];
+
+/// Account positions in the `create_pool` instruction
+const CREATOR_ACCOUNT_INDEX: usize = 0; // `pool_creator`
+const POOL_ACCOUNT_INDEX: usize = 2; // `pool_state`
+const TOKEN_0_MINT_ACCOUNT_INDEX: usize = 3; // `token_mint_0`
+const TOKEN_1_MINT_ACCOUNT_INDEX: usize = 4; // `token_mint_1`
+
+/// Raydium CLMM (concentrated liquidity) pool creation, reported as `RaydiumClmm` migrations
+pub struct RaydiumClmmParser {
+    program_id: String,
+}
+
+impl RaydiumClmmParser {
+    pub fn new() -> Self {
+        Self {
+            program_id: "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK".to_string(),
+        }
+    }
+}
+
+impl Default for RaydiumClmmParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LaunchpadParser for RaydiumClmmParser {
+    fn get_program_ids(&self) -> Vec<String> {
+        vec![self.program_id.clone()]
+    }
+
+    fn get_launchpad_type(&self) -> LaunchpadType {
+        LaunchpadType::RaydiumClmm
+    }
+
+    fn capabilities(&self) -> ParserCapabilities {
+        ParserCapabilities {
+            events: vec![EventKind::Migration],
+            metadata: Vec::new(),
+        }
+    }
+
+    fn parse_transaction(
+        &self,
+        transaction: &QueuedTransaction,
+    ) -> Result<Vec<ParseResult>, Box<dyn std::error::Error + Send + Sync>> {
+        debug!(
+            "🔍 Parsing Raydium CLMM transaction: {}",
+            transaction.signature
+        );
+
+        let mut results = Vec::new();
+        for instr in transaction
+            .instructions
+            .iter()
+            .chain(&transaction.inner_instructions)
+        {
+            if instr.program_id == self.program_id
+                && !instr.truncated
+                && instr.data.starts_with(&CREATE_POOL_DISCRIMINATOR)
+            {
+                info!(
+                    "🎓 Found Raydium CLMM create_pool instruction in: {}",
+                    transaction.signature
+                );
+                results.extend(self.extract_pool(transaction, instr));
+            }
+        }
+
+        if results.is_empty() {
+            results.push(ParseResult::NotRelevant);
+        }
+        Ok(results)
+    }
+}
+
+impl RaydiumClmmParser {
+    /// Extract the new pool from a create_pool instruction
+    fn extract_pool(
+        &self,
+        transaction: &QueuedTransaction,
+        instruction: &TransactionInstruction,
+    ) -> Option<ParseResult> {
+        let account = |index: usize| {
+            instruction
+                .accounts
+                .get(index)
+                .and_then(|idx| transaction.accounts.get(*idx as usize))
+                .cloned()
+        };
+
+        let (Some(pool), Some(token_0), Some(token_1)) = (
+            account(POOL_ACCOUNT_INDEX),
+            account(TOKEN_0_MINT_ACCOUNT_INDEX),
+            account(TOKEN_1_MINT_ACCOUNT_INDEX),
+        ) else {
+            debug!("❌ Could not extract mints or pool from Raydium CLMM create_pool instruction");
+            return None;
+        };
+
+        let (mint, quote_mint) = spl::split_pair(token_0, token_1);
+
+        let migration = Migration {
+            launchpad: LaunchpadType::RaydiumClmm,
+            token_address: mint.clone(),
+            pool,
+            quote_mint: Some(quote_mint),
+            creator: account(CREATOR_ACCOUNT_INDEX),
+            bin_step: None,
+            bonding_curve: None,
+            base_reserve: None,
+            quote_reserve: None,
+            lp_mint: None,
+            signature: transaction.signature.clone(),
+            slot: transaction.slot,
+            transaction_index: transaction.transaction_index,
+            timestamp: transaction.received_time,
+            block_time: transaction.block_time,
+            backfilled: transaction.backfilled,
+            links: None,
+        };
+
+        debug!("✅ Extracted Raydium CLMM pool for: {}", mint);
+        Some(ParseResult::Migration(migration))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{instruction, transaction};
+
+    const PROGRAM_ID: &str = "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK";
+    const WSOL_MINT: &str = "So11111111111111111111111111111111111111112";
+
+    #[test]
+    fn create_pool_is_decoded_with_the_token_side() {
+        let mut data = CREATE_POOL_DISCRIMINATOR.to_vec();
+        data.extend(1u128.to_le_bytes());
+        data.extend(0u64.to_le_bytes());
+        let transaction = transaction(
+            &["creator", "amm_config", "pool", "mint", WSOL_MINT],
+            vec![instruction(PROGRAM_ID, &[0, 1, 2, 3, 4], data)],
+            Vec::new(),
+        );
+
+        let results = RaydiumClmmParser::new()
+            .parse_transaction(&transaction)
+            .unwrap();
+        let [ParseResult::Migration(migration)] = results.as_slice() else {
+            panic!("expected one migration, got {} results", results.len());
+        };
+        assert!(matches!(migration.launchpad, LaunchpadType::RaydiumClmm));
+        assert_eq!(migration.token_address, "mint");
+        assert_eq!(migration.quote_mint.as_deref(), Some(WSOL_MINT));
+        assert_eq!(migration.pool, "pool");
+        assert_eq!(migration.creator.as_deref(), Some("creator"));
+    }
+
+    #[test]
+    fn create_pool_missing_mints_is_not_relevant() {
+        let transaction = transaction(
+            &["creator", "amm_config", "pool"],
+            vec![instruction(
+                PROGRAM_ID,
+                &[0, 1, 2],
+                CREATE_POOL_DISCRIMINATOR.to_vec(),
+            )],
+            Vec::new(),
+        );
+
+        let results = RaydiumClmmParser::new()
+            .parse_transaction(&transaction)
+            .unwrap();
+        assert!(matches!(results.as_slice(), [ParseResult::NotRelevant]));
+    }
+}
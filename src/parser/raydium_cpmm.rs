@@ -0,0 +1,332 @@
+use crate::geyser::{QueuedTransaction, TransactionInstruction};
+use crate::parser::anchor::BorshReader;
+use crate::parser::spl;
+use crate::parser::{
+    EventKind, LaunchpadParser, LaunchpadType, Liquidity, LiquidityAction, Migration, ParseResult,
+    ParserCapabilities,
+};
+use tracing::{debug, info};
+
+/// `initialize` instruction discriminator (Anchor `global:initialize`)
+const INITIALIZE_DISCRIMINATOR: [u8; 8] = [175, 175, 109, 31, 13, 152, 155, 237];
+/// `deposit` and `withdraw` instruction discriminators (Anchor `global:deposit`,
+/// `global:withdraw`)
+const DEPOSIT_DISCRIMINATOR: [u8; 8] = [242, 35, 198, 137, 82, 225, 242, 182];
+const WITHDRAW_DISCRIMINATOR: [u8; 8] = [183, 18, 70, 156, 148, 109, 161, 34];
+/// LaunchLab program, its `migrate_to_cpswap` creates the CPMM pool through CPI
+const LAUNCHLAB_PROGRAM_ID: &str = "LanMV9sAd7wArD4vJFi2qDdfnVhFxYSUg6eADduJ3uj";
+/// pump.fun bonding curve program
+const PUMPFUN_PROGRAM_ID: &str = "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P";
+
+/// Account positions in the `initialize` instruction
+const CREATOR_ACCOUNT_INDEX: usize = 0; // `creator`
+const POOL_ACCOUNT_INDEX: usize = 3; // `pool_state`
+const TOKEN_0_MINT_ACCOUNT_INDEX: usize = 4; // `token_0_mint`
+const TOKEN_1_MINT_ACCOUNT_INDEX: usize = 5; // `token_1_mint`
+const LP_MINT_ACCOUNT_INDEX: usize = 6; // `lp_mint`
+
+/// Account positions in the `deposit` and `withdraw` instructions
+const LIQUIDITY_OWNER_ACCOUNT_INDEX: usize = 0; // `owner`
+const LIQUIDITY_POOL_ACCOUNT_INDEX: usize = 2; // `pool_state`
+const LIQUIDITY_TOKEN_0_VAULT_ACCOUNT_INDEX: usize = 6; // `token_0_vault`
+const LIQUIDITY_TOKEN_1_VAULT_ACCOUNT_INDEX: usize = 7; // `token_1_vault`
+const LIQUIDITY_TOKEN_0_MINT_ACCOUNT_INDEX: usize = 10; // `vault_0_mint`
+const LIQUIDITY_TOKEN_1_MINT_ACCOUNT_INDEX: usize = 11; // `vault_1_mint`
+
+/// Raydium CPMM pool creation and liquidity changes. Pools created in a LaunchLab or pump.fun transaction
+/// are reported as migrations of that launchpad, others as `RaydiumCpmm`.
+pub struct RaydiumCpmmParser {
+    program_id: String,
+}
+
+impl RaydiumCpmmParser {
+    pub fn new() -> Self {
+        Self {
+            program_id: "CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C".to_string(),
+        }
+    }
+}
+
+impl Default for RaydiumCpmmParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LaunchpadParser for RaydiumCpmmParser {
+    fn get_program_ids(&self) -> Vec<String> {
+        vec![self.program_id.clone()]
+    }
+
+    fn get_launchpad_type(&self) -> LaunchpadType {
+        LaunchpadType::RaydiumCpmm
+    }
+
+    fn capabilities(&self) -> ParserCapabilities {
+        ParserCapabilities {
+            events: vec![EventKind::Migration, EventKind::Liquidity],
+            metadata: Vec::new(),
+        }
+    }
+
+    fn parse_transaction(
+        &self,
+        transaction: &QueuedTransaction,
+    ) -> Result<Vec<ParseResult>, Box<dyn std::error::Error + Send + Sync>> {
+        debug!(
+            "🔍 Parsing Raydium CPMM transaction: {}",
+            transaction.signature
+        );
+
+        // Graduations create the pool from the launchpad's own migrate instruction
+        let launchpad = transaction
+            .instructions
+            .iter()
+            .find_map(|instr| match instr.program_id.as_str() {
+                LAUNCHLAB_PROGRAM_ID => Some(LaunchpadType::LaunchLab),
+                PUMPFUN_PROGRAM_ID => Some(LaunchpadType::Pumpfun),
+                _ => None,
+            })
+            .unwrap_or(LaunchpadType::RaydiumCpmm);
+
+        let mut results = Vec::new();
+        for instr in transaction
+            .instructions
+            .iter()
+            .chain(&transaction.inner_instructions)
+        {
+            if instr.program_id == self.program_id
+                && !instr.truncated
+                && instr.data.starts_with(&INITIALIZE_DISCRIMINATOR)
+            {
+                info!(
+                    "🎓 Found Raydium CPMM initialize instruction in: {}",
+                    transaction.signature
+                );
+                results.extend(self.extract_pool(transaction, instr, launchpad.clone()));
+            } else if instr.program_id == self.program_id
+                && !instr.truncated
+                && let Some(liquidity) = self.extract_liquidity(transaction, instr)
+            {
+                results.push(ParseResult::Liquidity(liquidity));
+            }
+        }
+
+        if results.is_empty() {
+            results.push(ParseResult::NotRelevant);
+        }
+        Ok(results)
+    }
+}
+
+impl RaydiumCpmmParser {
+    /// Extract the liquidity change of a deposit or withdraw instruction
+    fn extract_liquidity(
+        &self,
+        transaction: &QueuedTransaction,
+        instruction: &TransactionInstruction,
+    ) -> Option<Liquidity> {
+        let action = if instruction.data.starts_with(&DEPOSIT_DISCRIMINATOR) {
+            LiquidityAction::Add
+        } else if instruction.data.starts_with(&WITHDRAW_DISCRIMINATOR) {
+            LiquidityAction::Remove
+        } else {
+            return None;
+        };
+        let account = |index: usize| {
+            instruction
+                .accounts
+                .get(index)
+                .and_then(|idx| transaction.accounts.get(*idx as usize))
+                .cloned()
+        };
+
+        // lp_token_amount, then the bounds on token 0 and token 1
+        let lp_amount = BorshReader::new(&instruction.data[8..]).u64()?;
+        let mint_0 = account(LIQUIDITY_TOKEN_0_MINT_ACCOUNT_INDEX)?;
+        let mint_1 = account(LIQUIDITY_TOKEN_1_MINT_ACCOUNT_INDEX)?;
+        let incoming = action == LiquidityAction::Add;
+        let moved = |index: usize| {
+            account(index).and_then(|vault| spl::transferred(transaction, &vault, incoming))
+        };
+        let amount_0 = moved(LIQUIDITY_TOKEN_0_VAULT_ACCOUNT_INDEX);
+        let amount_1 = moved(LIQUIDITY_TOKEN_1_VAULT_ACCOUNT_INDEX);
+
+        // The pool's mints are sorted by address, the token is the side that is not the quote
+        let (token, quote) = spl::split_pair(mint_0.clone(), mint_1);
+        let (token_amount, quote_amount) = if token == mint_0 {
+            (amount_0, amount_1)
+        } else {
+            (amount_1, amount_0)
+        };
+
+        Some(Liquidity {
+            launchpad: LaunchpadType::RaydiumCpmm,
+            action,
+            pool: account(LIQUIDITY_POOL_ACCOUNT_INDEX)?,
+            token_address: token,
+            quote_mint: Some(quote),
+            provider: account(LIQUIDITY_OWNER_ACCOUNT_INDEX),
+            lp_amount,
+            token_amount,
+            quote_amount,
+            signature: transaction.signature.clone(),
+            slot: transaction.slot,
+            transaction_index: transaction.transaction_index,
+            timestamp: transaction.received_time,
+            block_time: transaction.block_time,
+            backfilled: transaction.backfilled,
+            links: None,
+        })
+    }
+
+    /// Extract the new pool from an initialize instruction
+    fn extract_pool(
+        &self,
+        transaction: &QueuedTransaction,
+        instruction: &TransactionInstruction,
+        launchpad: LaunchpadType,
+    ) -> Option<ParseResult> {
+        let account = |index: usize| {
+            instruction
+                .accounts
+                .get(index)
+                .and_then(|idx| transaction.accounts.get(*idx as usize))
+                .cloned()
+        };
+
+        let (Some(pool), Some(token_0), Some(token_1)) = (
+            account(POOL_ACCOUNT_INDEX),
+            account(TOKEN_0_MINT_ACCOUNT_INDEX),
+            account(TOKEN_1_MINT_ACCOUNT_INDEX),
+        ) else {
+            debug!("❌ Could not extract mints or pool from Raydium CPMM initialize instruction");
+            return None;
+        };
+
+        let (mint, quote_mint) = spl::split_pair(token_0, token_1);
+
+        let migration = Migration {
+            launchpad,
+            token_address: mint.clone(),
+            pool,
+            quote_mint: Some(quote_mint),
+            creator: account(CREATOR_ACCOUNT_INDEX),
+            bin_step: None,
+            bonding_curve: None,
+            base_reserve: None,
+            quote_reserve: None,
+            lp_mint: account(LP_MINT_ACCOUNT_INDEX),
+            signature: transaction.signature.clone(),
+            slot: transaction.slot,
+            transaction_index: transaction.transaction_index,
+            timestamp: transaction.received_time,
+            block_time: transaction.block_time,
+            backfilled: transaction.backfilled,
+            links: None,
+        };
+
+        debug!("✅ Extracted Raydium CPMM pool for: {}", mint);
+        Some(ParseResult::Migration(migration))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{instruction, transaction};
+
+    const PROGRAM_ID: &str = "CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C";
+    const TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+    const WSOL_MINT: &str = "So11111111111111111111111111111111111111112";
+
+    #[test]
+    fn pool_created_by_launchlab_is_its_migration() {
+        // WSOL sorts before the token, so the token is token 1
+        let transaction = transaction(
+            &[
+                "creator",
+                "amm_config",
+                "authority",
+                "pool",
+                WSOL_MINT,
+                "mint",
+                "lp_mint",
+                LAUNCHLAB_PROGRAM_ID,
+            ],
+            vec![instruction(LAUNCHLAB_PROGRAM_ID, &[0], vec![136; 8])],
+            vec![instruction(
+                PROGRAM_ID,
+                &[0, 1, 2, 3, 4, 5, 6],
+                INITIALIZE_DISCRIMINATOR.to_vec(),
+            )],
+        );
+
+        let results = RaydiumCpmmParser::new()
+            .parse_transaction(&transaction)
+            .unwrap();
+        let [ParseResult::Migration(migration)] = results.as_slice() else {
+            panic!("expected one migration, got {} results", results.len());
+        };
+        assert!(matches!(migration.launchpad, LaunchpadType::LaunchLab));
+        assert_eq!(migration.token_address, "mint");
+        assert_eq!(migration.quote_mint.as_deref(), Some(WSOL_MINT));
+        assert_eq!(migration.pool, "pool");
+        assert_eq!(migration.creator.as_deref(), Some("creator"));
+        assert_eq!(migration.lp_mint.as_deref(), Some("lp_mint"));
+    }
+
+    #[test]
+    fn withdraw_reports_the_token_and_quote_side() {
+        let mut withdraw = WITHDRAW_DISCRIMINATOR.to_vec();
+        for amount in [5_000u64, 0, 0] {
+            withdraw.extend(amount.to_le_bytes());
+        }
+        let transfer = |amount: u64| {
+            let mut data = vec![3];
+            data.extend(amount.to_le_bytes());
+            data
+        };
+        let transaction = transaction(
+            &[
+                "owner",
+                "authority",
+                "pool",
+                "owner_lp",
+                "owner_0",
+                "owner_1",
+                "vault_0",
+                "vault_1",
+                WSOL_MINT,
+                "mint",
+            ],
+            vec![instruction(
+                PROGRAM_ID,
+                &[0, 1, 2, 3, 4, 5, 6, 7, 1, 1, 8, 9],
+                withdraw,
+            )],
+            vec![
+                instruction(TOKEN_PROGRAM_ID, &[6, 4, 1], transfer(700_000_000)),
+                instruction(TOKEN_PROGRAM_ID, &[7, 5, 1], transfer(42_000_000_000)),
+            ],
+        );
+
+        let results = RaydiumCpmmParser::new()
+            .parse_transaction(&transaction)
+            .unwrap();
+        let [ParseResult::Liquidity(liquidity)] = results.as_slice() else {
+            panic!(
+                "expected one liquidity change, got {} results",
+                results.len()
+            );
+        };
+        assert_eq!(liquidity.action, LiquidityAction::Remove);
+        assert_eq!(liquidity.pool, "pool");
+        assert_eq!(liquidity.token_address, "mint");
+        assert_eq!(liquidity.quote_mint.as_deref(), Some(WSOL_MINT));
+        assert_eq!(liquidity.provider.as_deref(), Some("owner"));
+        assert_eq!(liquidity.lp_amount, 5_000);
+        assert_eq!(liquidity.token_amount, Some(42_000_000_000));
+        assert_eq!(liquidity.quote_amount, Some(700_000_000));
+    }
+}
@@ -0,0 +1,294 @@
+use crate::geyser::encoding::encode_pubkey;
+use crate::geyser::{QueuedTransaction, TransactionInstruction};
+use crate::parser::launchpad_parser::{AuthorityType, LaunchMetadata};
+use crate::parser::metadata;
+
+pub(crate) const TOKEN_PROGRAM_IDS: [&str; 2] = [
+    "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+    "TokenzQdBNbLqP5VEhdkAS6EPFLC1PMa9U1zx5Vf2wZ",
+];
+/// SPL token `InitializeMint` and `InitializeMint2`, the mint is their first account
+pub(crate) const INITIALIZE_MINT_TAGS: [u8; 2] = [0, 20];
+/// SPL token `Transfer` (amount at 1, source and destination as the first two accounts)
+/// and `TransferChecked` (amount at 1, source, mint, destination)
+const TRANSFER_TAG: u8 = 3;
+const TRANSFER_CHECKED_TAG: u8 = 12;
+/// SPL token `Burn` and `BurnChecked`, amount at 1, taking the token account, the mint
+/// and the owner
+const BURN_TAGS: [u8; 2] = [8, 15];
+/// SPL token `SetAuthority`, followed by the authority type and the new authority as a
+/// `COption<Pubkey>`, taking the mint or token account and its current authority
+const SET_AUTHORITY_TAG: u8 = 6;
+/// SPL token `InitializeAccount`, `InitializeAccount2` and `InitializeAccount3`,
+/// taking the token account and then its mint
+const INITIALIZE_ACCOUNT_TAGS: [u8; 3] = [1, 16, 18];
+/// `InitializeAccount` takes the owner as third account, the others carry it at 1
+const INITIALIZE_ACCOUNT_TAG: u8 = 1;
+
+/// Mints taken as the quote side of a pair, in order of preference: WSOL, USDC, USD1
+const QUOTE_MINTS: [&str; 3] = [
+    "So11111111111111111111111111111111111111112",
+    "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+    "USD1ttGY1N17NEEHLmELoaybftRBUSErhqYiQzvEmuB",
+];
+
+/// Outer and inner instructions of the given programs
+fn instructions<'a>(
+    transaction: &'a QueuedTransaction,
+    program_ids: &'a [&str],
+) -> impl Iterator<Item = &'a TransactionInstruction> {
+    transaction
+        .instructions
+        .iter()
+        .chain(&transaction.inner_instructions)
+        .filter(|instr| !instr.truncated && program_ids.contains(&instr.program_id.as_str()))
+}
+
+/// Splits the mints of a pool sorted by address into the traded token and its quote,
+/// the side that is WSOL, USDC or USD1. Without a known quote the order is kept.
+pub fn split_pair(token_0: String, token_1: String) -> (String, String) {
+    let quote_rank = |mint: &str| QUOTE_MINTS.iter().position(|quote| *quote == mint);
+    match (quote_rank(&token_0), quote_rank(&token_1)) {
+        (Some(rank_0), Some(rank_1)) if rank_0 < rank_1 => (token_1, token_0),
+        (Some(_), None) => (token_1, token_0),
+        _ => (token_0, token_1),
+    }
+}
+
+/// Mint initialized by the token program in the transaction, for launchpads
+/// whose create instruction layout is not decoded
+pub fn initialized_mint(transaction: &QueuedTransaction) -> Option<String> {
+    initialized_mints(transaction).into_iter().next()
+}
+
+/// All mints initialized by the token program in the transaction, in execution order
+pub fn initialized_mints(transaction: &QueuedTransaction) -> Vec<String> {
+    instructions(transaction, &TOKEN_PROGRAM_IDS)
+        .filter(|instr| {
+            instr
+                .data
+                .first()
+                .is_some_and(|tag| INITIALIZE_MINT_TAGS.contains(tag))
+        })
+        .filter_map(|instr| instr.accounts.first())
+        .filter_map(|idx| transaction.accounts.get(*idx as usize))
+        .cloned()
+        .collect()
+}
+
+/// Mint of a token account initialized in the transaction, for pool instructions
+/// taking vaults rather than mints
+pub fn initialized_account_mint(transaction: &QueuedTransaction, account: &str) -> Option<String> {
+    instructions(transaction, &TOKEN_PROGRAM_IDS)
+        .filter(|instr| {
+            instr
+                .data
+                .first()
+                .is_some_and(|tag| INITIALIZE_ACCOUNT_TAGS.contains(tag))
+        })
+        .find(|instr| {
+            instr
+                .accounts
+                .first()
+                .and_then(|idx| transaction.accounts.get(*idx as usize))
+                .is_some_and(|initialized| initialized == account)
+        })
+        .and_then(|instr| instr.accounts.get(1))
+        .and_then(|idx| transaction.accounts.get(*idx as usize))
+        .cloned()
+}
+
+/// Owner of a token account initialized in the transaction
+pub fn initialized_account_owner(transaction: &QueuedTransaction, account: &str) -> Option<String> {
+    initialized_accounts(transaction)
+        .find(|(initialized, _, _)| initialized == account)
+        .map(|(_, _, owner)| owner)
+}
+
+/// Token account of `mint` initialized for `owner` in the transaction, e.g. the
+/// creator's account receiving the dev buy
+pub fn initialized_token_account(
+    transaction: &QueuedTransaction,
+    mint: &str,
+    owner: &str,
+) -> Option<String> {
+    initialized_accounts(transaction)
+        .find(|(_, initialized_mint, initialized_owner)| {
+            initialized_mint == mint && initialized_owner == owner
+        })
+        .map(|(account, _, _)| account)
+}
+
+/// Token accounts initialized in the transaction with their mint and owner
+fn initialized_accounts(
+    transaction: &QueuedTransaction,
+) -> impl Iterator<Item = (String, String, String)> + '_ {
+    let account = |instr: &TransactionInstruction, index: usize| {
+        instr
+            .accounts
+            .get(index)
+            .and_then(|idx| transaction.accounts.get(*idx as usize))
+            .cloned()
+    };
+    instructions(transaction, &TOKEN_PROGRAM_IDS)
+        .filter(|instr| {
+            instr
+                .data
+                .first()
+                .is_some_and(|tag| INITIALIZE_ACCOUNT_TAGS.contains(tag))
+        })
+        .filter_map(move |instr| {
+            let owner = if instr.data.first() == Some(&INITIALIZE_ACCOUNT_TAG) {
+                account(instr, 2)?
+            } else {
+                encode_pubkey(instr.data.get(1..33)?)
+            };
+            Some((account(instr, 0)?, account(instr, 1)?, owner))
+        })
+}
+
+/// Tokens moved out of a token account by a top-level transfer
+pub struct OutgoingTransfer {
+    pub destination: String,
+    pub amount: u64,
+}
+
+/// Transfers out of a token account signed directly in the transaction. Transfers made
+/// by a program, sells and swaps among them, are inner instructions and left out.
+pub fn outgoing_transfers(transaction: &QueuedTransaction, account: &str) -> Vec<OutgoingTransfer> {
+    let key = |index: Option<&u8>| index.and_then(|idx| transaction.accounts.get(*idx as usize));
+    transaction
+        .instructions
+        .iter()
+        .filter(|instr| !instr.truncated && TOKEN_PROGRAM_IDS.contains(&instr.program_id.as_str()))
+        .filter_map(|instr| {
+            let destination = match instr.data.first() {
+                Some(&TRANSFER_TAG) => instr.accounts.get(1),
+                Some(&TRANSFER_CHECKED_TAG) => instr.accounts.get(2),
+                _ => return None,
+            };
+            if key(instr.accounts.first())? != account {
+                return None;
+            }
+            Some(OutgoingTransfer {
+                destination: key(destination)?.clone(),
+                amount: u64::from_le_bytes(instr.data.get(1..9)?.try_into().ok()?),
+            })
+        })
+        .collect()
+}
+
+/// Total moved into (`incoming`) or out of a token account by token transfers in the
+/// transaction, `None` when none touched it
+pub fn transferred(transaction: &QueuedTransaction, account: &str, incoming: bool) -> Option<u64> {
+    let mut total = None;
+    for instr in instructions(transaction, &TOKEN_PROGRAM_IDS) {
+        let (source, destination) = match instr.data.first() {
+            Some(&TRANSFER_TAG) => (instr.accounts.first(), instr.accounts.get(1)),
+            Some(&TRANSFER_CHECKED_TAG) => (instr.accounts.first(), instr.accounts.get(2)),
+            _ => continue,
+        };
+        let side = if incoming { destination } else { source };
+        let Some(amount) = instr
+            .data
+            .get(1..9)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(u64::from_le_bytes)
+        else {
+            continue;
+        };
+        if side
+            .and_then(|idx| transaction.accounts.get(*idx as usize))
+            .is_some_and(|touched| touched == account)
+        {
+            total = Some(total.unwrap_or(0u64).saturating_add(amount));
+        }
+    }
+    total
+}
+
+/// Tokens burned by an SPL token `Burn` or `BurnChecked`
+pub struct Burn {
+    pub mint: String,
+    pub owner: Option<String>,
+    pub amount: u64,
+}
+
+/// All token burns in the transaction, in execution order
+pub fn burns(transaction: &QueuedTransaction) -> Vec<Burn> {
+    let account = |instr: &TransactionInstruction, index: usize| {
+        instr
+            .accounts
+            .get(index)
+            .and_then(|idx| transaction.accounts.get(*idx as usize))
+            .cloned()
+    };
+    instructions(transaction, &TOKEN_PROGRAM_IDS)
+        .filter(|instr| {
+            instr
+                .data
+                .first()
+                .is_some_and(|tag| BURN_TAGS.contains(tag))
+        })
+        .filter_map(|instr| {
+            Some(Burn {
+                mint: account(instr, 1)?,
+                owner: account(instr, 2),
+                amount: u64::from_le_bytes(instr.data.get(1..9)?.try_into().ok()?),
+            })
+        })
+        .collect()
+}
+
+/// Mint or freeze authority of a mint set to none by an SPL token `SetAuthority`
+pub struct Revocation {
+    pub mint: String,
+    pub authority: AuthorityType,
+    /// Authority that signed the revocation
+    pub revoked_by: Option<String>,
+}
+
+/// All mint and freeze authority revocations in the transaction, in execution order
+pub fn revocations(transaction: &QueuedTransaction) -> Vec<Revocation> {
+    let account = |instr: &TransactionInstruction, index: usize| {
+        instr
+            .accounts
+            .get(index)
+            .and_then(|idx| transaction.accounts.get(*idx as usize))
+            .cloned()
+    };
+    instructions(transaction, &TOKEN_PROGRAM_IDS)
+        .filter(|instr| {
+            instr.data.first() == Some(&SET_AUTHORITY_TAG) && instr.data.get(2) == Some(&0)
+        })
+        .filter_map(|instr| {
+            let authority = match instr.data.get(1)? {
+                0 => AuthorityType::Mint,
+                1 => AuthorityType::Freeze,
+                _ => return None,
+            };
+            Some(Revocation {
+                mint: account(instr, 0)?,
+                authority,
+                revoked_by: account(instr, 1),
+            })
+        })
+        .collect()
+}
+
+/// Name, symbol and uri of the first Metaplex metadata account created in the transaction,
+/// for launches whose mint is not known yet
+pub fn created_metadata(transaction: &QueuedTransaction) -> LaunchMetadata {
+    let created = metadata::created_metadata(transaction).into_iter().next();
+
+    LaunchMetadata {
+        name: created.as_ref().and_then(|created| created.name.clone()),
+        symbol: created.as_ref().and_then(|created| created.symbol.clone()),
+        uri: created.and_then(|created| created.uri),
+        initial_supply: None,
+        mint_authority: None,
+        fee_recipient: None,
+        token_extensions: None,
+        normalized: None,
+    }
+}
@@ -0,0 +1,179 @@
+use crate::config::parsers::SplMintConfig;
+use crate::geyser::QueuedTransaction;
+use crate::geyser::encoding::encode_pubkey;
+use crate::parser::spl::{self, INITIALIZE_MINT_TAGS, TOKEN_PROGRAM_IDS};
+use crate::parser::{
+    EventKind, LaunchpadParser, LaunchpadType, MetadataField, ParseResult, ParserCapabilities,
+    TokenLaunch,
+};
+use tracing::{debug, info};
+
+/// Mints created outside the known launchpads, from the token program's `InitializeMint`
+/// and `InitializeMint2` in SPL Token and Token-2022. Both start with the tag, decimals
+/// and the mint authority. The manager only hands this parser transactions no launchpad
+/// parser matched.
+pub struct SplMintParser {
+    config: SplMintConfig,
+}
+
+impl SplMintParser {
+    pub fn new(config: SplMintConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl Default for SplMintParser {
+    fn default() -> Self {
+        Self::new(SplMintConfig::default())
+    }
+}
+
+impl LaunchpadParser for SplMintParser {
+    fn get_program_ids(&self) -> Vec<String> {
+        TOKEN_PROGRAM_IDS.iter().map(|id| id.to_string()).collect()
+    }
+
+    fn get_launchpad_type(&self) -> LaunchpadType {
+        LaunchpadType::Unknown
+    }
+
+    fn capabilities(&self) -> ParserCapabilities {
+        ParserCapabilities {
+            events: vec![EventKind::TokenLaunch],
+            metadata: vec![
+                MetadataField::Name,
+                MetadataField::Symbol,
+                MetadataField::Uri,
+                MetadataField::MintAuthority,
+            ],
+        }
+    }
+
+    fn parse_transaction(
+        &self,
+        transaction: &QueuedTransaction,
+    ) -> Result<Vec<ParseResult>, Box<dyn std::error::Error + Send + Sync>> {
+        debug!(
+            "🔍 Parsing token program transaction: {}",
+            transaction.signature
+        );
+
+        let mut results = Vec::new();
+        for instr in transaction
+            .instructions
+            .iter()
+            .chain(&transaction.inner_instructions)
+        {
+            if instr.truncated
+                || !TOKEN_PROGRAM_IDS.contains(&instr.program_id.as_str())
+                || !instr
+                    .data
+                    .first()
+                    .is_some_and(|tag| INITIALIZE_MINT_TAGS.contains(tag))
+            {
+                continue;
+            }
+            let Some(mint) = instr
+                .accounts
+                .first()
+                .and_then(|idx| transaction.accounts.get(*idx as usize))
+            else {
+                continue;
+            };
+            // Zero decimals are NFTs and other non-fungible mints
+            let decimals = instr.data.get(1).copied().unwrap_or_default();
+            if decimals == 0 && !self.config.include_nfts {
+                continue;
+            }
+
+            info!(
+                "🎯 Found mint initialized outside known launchpads in: {}",
+                transaction.signature
+            );
+            let mint_authority = instr.data.get(2..34).map(encode_pubkey);
+            results.push(self.extract_token_launch(transaction, mint.clone(), mint_authority));
+        }
+
+        if results.is_empty() {
+            results.push(ParseResult::NotRelevant);
+        }
+        Ok(results)
+    }
+}
+
+impl SplMintParser {
+    /// Extract token launch information from the initialized mint
+    fn extract_token_launch(
+        &self,
+        transaction: &QueuedTransaction,
+        mint_address: String,
+        mint_authority: Option<String>,
+    ) -> ParseResult {
+        let mut metadata = spl::created_metadata(transaction);
+        metadata.mint_authority = mint_authority;
+
+        let token_launch = TokenLaunch::new(
+            LaunchpadType::Unknown,
+            transaction,
+            mint_address.clone(),
+            transaction.fee_payer.clone(),
+            metadata,
+        );
+
+        debug!(
+            "✅ Extracted unknown launchpad token launch: {}",
+            mint_address
+        );
+        ParseResult::TokenLaunch(token_launch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{create_metadata, initialize_mint, transaction};
+
+    fn mint_transaction(decimals: u8) -> QueuedTransaction {
+        transaction(
+            &["payer", "mint", "metadata"],
+            vec![
+                initialize_mint(1, decimals),
+                create_metadata(2, 1, "Plain", "PLN", "https://example.com/pln.json"),
+            ],
+            Vec::new(),
+        )
+    }
+
+    #[test]
+    fn initialized_mint_is_reported_with_its_authority() {
+        let results = SplMintParser::default()
+            .parse_transaction(&mint_transaction(6))
+            .unwrap();
+        let [ParseResult::TokenLaunch(launch)] = results.as_slice() else {
+            panic!("expected one launch, got {} results", results.len());
+        };
+        assert!(matches!(launch.launchpad, LaunchpadType::Unknown));
+        assert_eq!(launch.token_address, "mint");
+        assert_eq!(launch.creator.as_deref(), Some("payer"));
+        assert_eq!(
+            launch.metadata.mint_authority.as_deref(),
+            Some(encode_pubkey(&[9; 32]).as_str())
+        );
+        assert_eq!(launch.metadata.symbol.as_deref(), Some("PLN"));
+    }
+
+    #[test]
+    fn zero_decimal_mints_need_include_nfts() {
+        let results = SplMintParser::default()
+            .parse_transaction(&mint_transaction(0))
+            .unwrap();
+        assert!(matches!(results.as_slice(), [ParseResult::NotRelevant]));
+
+        let parser = SplMintParser::new(SplMintConfig {
+            enabled: true,
+            include_nfts: true,
+        });
+        let results = parser.parse_transaction(&mint_transaction(0)).unwrap();
+        assert!(matches!(results.as_slice(), [ParseResult::TokenLaunch(_)]));
+    }
+}
@@ -0,0 +1,195 @@
+use crate::config::parsers::TimeFunConfig;
+use crate::geyser::{QueuedTransaction, TransactionInstruction};
+use crate::parser::spl;
+use crate::parser::{
+    EventKind, LaunchpadParser, LaunchpadType, MetadataField, ParseResult, ParserCapabilities,
+    PartialParse, TokenLaunch,
+};
+use tracing::{debug, info};
+
+/// Time.fun creator token launches. A creator token is minted by a Time.fun instruction
+/// in a transaction that initializes a new mint, unless a create discriminator is configured.
+pub struct TimeFunParser {
+    config: TimeFunConfig,
+}
+
+impl TimeFunParser {
+    pub fn new(config: TimeFunConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl Default for TimeFunParser {
+    fn default() -> Self {
+        Self::new(TimeFunConfig::default())
+    }
+}
+
+impl LaunchpadParser for TimeFunParser {
+    fn get_program_ids(&self) -> Vec<String> {
+        self.config.program_ids.clone()
+    }
+
+    fn get_launchpad_type(&self) -> LaunchpadType {
+        LaunchpadType::TimeFun
+    }
+
+    fn capabilities(&self) -> ParserCapabilities {
+        ParserCapabilities {
+            events: vec![EventKind::TokenLaunch, EventKind::PartialParse],
+            metadata: MetadataField::METAPLEX.to_vec(),
+        }
+    }
+
+    fn parse_transaction(
+        &self,
+        transaction: &QueuedTransaction,
+    ) -> Result<Vec<ParseResult>, Box<dyn std::error::Error + Send + Sync>> {
+        debug!("🔍 Parsing Time.fun transaction: {}", transaction.signature);
+
+        let create = transaction
+            .instructions
+            .iter()
+            .chain(&transaction.inner_instructions)
+            .find(|instr| {
+                self.config.program_ids.contains(&instr.program_id)
+                    && self
+                        .config
+                        .create_discriminator
+                        .is_none_or(|discriminator| instr.data.starts_with(&discriminator))
+            });
+        let Some(create) = create else {
+            return Ok(vec![ParseResult::NotRelevant]);
+        };
+
+        // Without a discriminator, buying and selling time is told apart by not creating a mint
+        if self.config.create_discriminator.is_none()
+            && self.config.mint_account_index.is_none()
+            && spl::initialized_mint(transaction).is_none()
+        {
+            return Ok(vec![ParseResult::NotRelevant]);
+        }
+
+        info!(
+            "🎯 Found Time.fun creator token in: {}",
+            transaction.signature
+        );
+        Ok(vec![self.extract_token_launch(transaction, create)])
+    }
+}
+
+impl TimeFunParser {
+    /// Extract token launch information from the create instruction
+    fn extract_token_launch(
+        &self,
+        transaction: &QueuedTransaction,
+        instruction: &TransactionInstruction,
+    ) -> ParseResult {
+        let account = |index: usize| {
+            instruction
+                .accounts
+                .get(index)
+                .and_then(|idx| transaction.accounts.get(*idx as usize))
+                .cloned()
+        };
+
+        // The creator whose time is sold, who need not be the one paying for the mint
+        let creator = match self.config.creator_account_index {
+            Some(index) => account(index),
+            None => transaction.instruction_signer(instruction),
+        };
+        let mint = match self.config.mint_account_index {
+            Some(index) => account(index),
+            None => spl::initialized_mint(transaction),
+        };
+        let metadata = spl::created_metadata(transaction);
+
+        let Some(mint_address) = mint else {
+            debug!("❌ Could not extract mint from Time.fun create instruction");
+            return ParseResult::PartialParse(PartialParse::new(
+                LaunchpadType::TimeFun,
+                "create_creator_token",
+                transaction,
+                None,
+                creator,
+                metadata,
+            ));
+        };
+
+        let token_launch = TokenLaunch::new(
+            LaunchpadType::TimeFun,
+            transaction,
+            mint_address.clone(),
+            creator,
+            metadata,
+        );
+
+        debug!("✅ Extracted Time.fun creator token: {}", mint_address);
+        ParseResult::TokenLaunch(token_launch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{initialize_mint, instruction, transaction};
+
+    const PROGRAM_ID: &str = "TimeFun1111111111111111111111111111111111111";
+    const CREATE_DISCRIMINATOR: [u8; 8] = [4; 8];
+
+    fn parser(creator_account_index: Option<usize>) -> TimeFunParser {
+        TimeFunParser::new(TimeFunConfig {
+            program_ids: vec![PROGRAM_ID.to_string()],
+            create_discriminator: Some(CREATE_DISCRIMINATOR),
+            mint_account_index: None,
+            creator_account_index,
+        })
+    }
+
+    #[test]
+    fn create_credits_the_configured_creator() {
+        // Paid for by the platform on behalf of the creator selling their time
+        let transaction = transaction(
+            &["platform", "creator", "mint"],
+            vec![
+                initialize_mint(2, 6),
+                instruction(PROGRAM_ID, &[0, 1, 2], CREATE_DISCRIMINATOR.to_vec()),
+            ],
+            Vec::new(),
+        );
+
+        let results = parser(Some(1)).parse_transaction(&transaction).unwrap();
+        let [ParseResult::TokenLaunch(launch)] = results.as_slice() else {
+            panic!("expected one launch, got {} results", results.len());
+        };
+        assert!(matches!(launch.launchpad, LaunchpadType::TimeFun));
+        assert_eq!(launch.token_address, "mint");
+        assert_eq!(launch.creator.as_deref(), Some("creator"));
+    }
+
+    #[test]
+    fn create_without_a_mint_is_a_partial_parse() {
+        let transaction = transaction(
+            &["creator"],
+            vec![instruction(PROGRAM_ID, &[0], CREATE_DISCRIMINATOR.to_vec())],
+            Vec::new(),
+        );
+
+        let results = parser(None).parse_transaction(&transaction).unwrap();
+        let [ParseResult::PartialParse(partial)] = results.as_slice() else {
+            panic!("expected one partial parse, got {} results", results.len());
+        };
+        assert_eq!(partial.creator.as_deref(), Some("creator"));
+    }
+
+    #[test]
+    fn other_instructions_are_not_relevant() {
+        let transaction = transaction(
+            &["buyer"],
+            vec![instruction(PROGRAM_ID, &[0], vec![5; 8])],
+            Vec::new(),
+        );
+        let results = parser(None).parse_transaction(&transaction).unwrap();
+        assert!(matches!(results.as_slice(), [ParseResult::NotRelevant]));
+    }
+}
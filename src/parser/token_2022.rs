@@ -0,0 +1,82 @@
+use crate::geyser::{QueuedTransaction, TransactionInstruction};
+use crate::parser::anchor::BorshReader;
+use crate::parser::launchpad_parser::TokenExtensions;
+
+const TOKEN_2022_PROGRAM_ID: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PMa9U1zx5Vf2wZ";
+/// `InitializeMint` and `InitializeMint2`
+const INITIALIZE_MINT_TAGS: [u8; 2] = [0, 20];
+/// `TransferFeeExtension`, its `InitializeTransferFeeConfig` sub-instruction is 0
+const TRANSFER_FEE_EXTENSION_TAG: u8 = 26;
+/// `InitializePermanentDelegate`, followed by the delegate
+const INITIALIZE_PERMANENT_DELEGATE_TAG: u8 = 35;
+/// `TransferHookExtension`, its `Initialize` sub-instruction is 0
+const TRANSFER_HOOK_EXTENSION_TAG: u8 = 36;
+/// An all zero `OptionalNonZeroPubkey`
+const NO_PUBKEY: &str = "11111111111111111111111111111111";
+
+/// Extensions initialized on `mint` when the Token-2022 program creates it in the
+/// transaction, `None` for mints of the original token program or created elsewhere.
+/// Extensions are initialized before the mint itself, all of them take the mint first.
+pub fn mint_extensions(transaction: &QueuedTransaction, mint: &str) -> Option<TokenExtensions> {
+    let instructions: Vec<&TransactionInstruction> = transaction
+        .instructions
+        .iter()
+        .chain(&transaction.inner_instructions)
+        .filter(|instr| {
+            !instr.truncated
+                && instr.program_id == TOKEN_2022_PROGRAM_ID
+                && instr
+                    .accounts
+                    .first()
+                    .and_then(|idx| transaction.accounts.get(*idx as usize))
+                    .is_some_and(|account| account == mint)
+        })
+        .collect();
+
+    let initialized = instructions.iter().any(|instr| {
+        instr
+            .data
+            .first()
+            .is_some_and(|tag| INITIALIZE_MINT_TAGS.contains(tag))
+    });
+    if !initialized {
+        return None;
+    }
+
+    let mut extensions = TokenExtensions::default();
+    for instr in instructions {
+        let Some((&tag, data)) = instr.data.split_first() else {
+            continue;
+        };
+        let mut reader = BorshReader::new(data);
+        match tag {
+            // `COption<Pubkey>` config and withdraw authorities, then basis points and maximum fee
+            TRANSFER_FEE_EXTENSION_TAG if data.first() == Some(&0) => {
+                let fee = reader.skip(1).and_then(|_| {
+                    for _ in 0..2 {
+                        if reader.bool()? {
+                            reader.skip(32)?;
+                        }
+                    }
+                    Some((reader.u16()?, reader.u64()?))
+                });
+                if let Some((basis_points, maximum_fee)) = fee {
+                    extensions.transfer_fee_basis_points = Some(basis_points);
+                    extensions.maximum_transfer_fee = Some(maximum_fee);
+                }
+            }
+            INITIALIZE_PERMANENT_DELEGATE_TAG => {
+                extensions.permanent_delegate = reader.pubkey();
+            }
+            // `OptionalNonZeroPubkey` authority, then the hook program
+            TRANSFER_HOOK_EXTENSION_TAG if data.first() == Some(&0) => {
+                extensions.transfer_hook_program = reader
+                    .skip(1 + 32)
+                    .and_then(|_| reader.pubkey())
+                    .filter(|program| program != NO_PUBKEY);
+            }
+            _ => {}
+        }
+    }
+    Some(extensions)
+}
@@ -0,0 +1,206 @@
+use crate::geyser::{QueuedTransaction, TransactionInstruction};
+use crate::parser::{
+    EventKind, LaunchpadParser, LaunchpadType, MetadataField, ParseResult, ParserCapabilities,
+    PartialParse, TokenLaunch, spl,
+};
+use tracing::{debug, info};
+
+/// `create` instruction discriminator, opening a launch pool
+const CREATE_DISCRIMINATOR: [u8; 8] = [24, 30, 200, 40, 5, 28, 7, 119];
+
+/// Other Vertigo instruction discriminators
+const INSTRUCTIONS: &[(&str, [u8; 8])] = &[
+    ("buy", [102, 6, 61, 18, 1, 218, 235, 234]),
+    ("sell", [51, 230, 133, 164, 1, 127, 131, 173]),
+    ("claim", [62, 198, 214, 193, 213, 159, 108, 210]),
+];
+
+/// Account positions in the `create` instruction
+const CREATOR_ACCOUNT_INDEX: usize = 1; // `owner`
+const MINT_ACCOUNT_INDEX: usize = 4; // `mint_b`, the launched token; `mint_a` is the quote mint
+const POOL_ACCOUNT_INDEX: usize = 6; // `pool`
+
+pub struct VertigoParser {
+    program_id: String,
+}
+
+impl VertigoParser {
+    pub fn new() -> Self {
+        Self {
+            program_id: "vrTGoBuy5rYSxAfV3jaRJWHH6nN9WK4NRExGxsk1bCJ".to_string(),
+        }
+    }
+}
+
+impl Default for VertigoParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LaunchpadParser for VertigoParser {
+    fn get_program_ids(&self) -> Vec<String> {
+        vec![self.program_id.clone()]
+    }
+
+    fn get_launchpad_type(&self) -> LaunchpadType {
+        LaunchpadType::Vertigo
+    }
+
+    fn capabilities(&self) -> ParserCapabilities {
+        ParserCapabilities {
+            events: vec![EventKind::TokenLaunch, EventKind::PartialParse],
+            metadata: MetadataField::METAPLEX.to_vec(),
+        }
+    }
+
+    fn parse_transaction(
+        &self,
+        transaction: &QueuedTransaction,
+    ) -> Result<Vec<ParseResult>, Box<dyn std::error::Error + Send + Sync>> {
+        debug!("🔍 Parsing Vertigo transaction: {}", transaction.signature);
+
+        let mut results = Vec::new();
+        for instr in transaction
+            .instructions
+            .iter()
+            .chain(&transaction.inner_instructions)
+        {
+            if instr.program_id != self.program_id || instr.data.len() < 8 {
+                continue;
+            }
+            let discriminator = &instr.data[0..8];
+
+            if discriminator == CREATE_DISCRIMINATOR {
+                info!(
+                    "🎯 Found Vertigo create instruction in: {}",
+                    transaction.signature
+                );
+                results.push(self.extract_token_launch(transaction, instr));
+            } else if let Some((name, _)) = INSTRUCTIONS.iter().find(|(_, d)| d == discriminator) {
+                results.push(ParseResult::Other {
+                    launchpad: LaunchpadType::Vertigo,
+                    event_type: name.to_string(),
+                    detail: None,
+                    signature: transaction.signature.clone(),
+                });
+            }
+        }
+
+        if results.is_empty() {
+            results.push(ParseResult::NotRelevant);
+        }
+        Ok(results)
+    }
+}
+
+impl VertigoParser {
+    /// Extract token launch information from the create instruction. The instruction
+    /// carries no name or symbol, those come from the Metaplex metadata created alongside.
+    fn extract_token_launch(
+        &self,
+        transaction: &QueuedTransaction,
+        instruction: &TransactionInstruction,
+    ) -> ParseResult {
+        let account = |index: usize| {
+            instruction
+                .accounts
+                .get(index)
+                .and_then(|idx| transaction.accounts.get(*idx as usize))
+                .cloned()
+        };
+        let creator = account(CREATOR_ACCOUNT_INDEX);
+        let metadata = spl::created_metadata(transaction);
+
+        let Some(mint_address) = account(MINT_ACCOUNT_INDEX) else {
+            debug!("❌ Could not extract mint from Vertigo create instruction");
+            return ParseResult::PartialParse(PartialParse::new(
+                LaunchpadType::Vertigo,
+                "create",
+                transaction,
+                None,
+                creator,
+                metadata,
+            ));
+        };
+
+        let token_launch = TokenLaunch::new(
+            LaunchpadType::Vertigo,
+            transaction,
+            mint_address.clone(),
+            creator,
+            metadata,
+        )
+        .with_pool(account(POOL_ACCOUNT_INDEX));
+
+        debug!("✅ Extracted Vertigo token launch: {}", mint_address);
+        ParseResult::TokenLaunch(token_launch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{create_metadata, instruction, transaction};
+
+    const PROGRAM_ID: &str = "vrTGoBuy5rYSxAfV3jaRJWHH6nN9WK4NRExGxsk1bCJ";
+
+    #[test]
+    fn create_is_decoded_with_the_metaplex_metadata() {
+        let transaction = transaction(
+            &[
+                "payer",
+                "owner",
+                "token_wallet_authority",
+                "mint_a",
+                "mint",
+                "token_wallet",
+                "pool",
+                "metadata",
+            ],
+            vec![
+                instruction(
+                    PROGRAM_ID,
+                    &[0, 1, 2, 3, 4, 5, 6],
+                    CREATE_DISCRIMINATOR.to_vec(),
+                ),
+                create_metadata(7, 4, "Vert", "VRT", "https://example.com/vrt.json"),
+            ],
+            Vec::new(),
+        );
+
+        let results = VertigoParser::new()
+            .parse_transaction(&transaction)
+            .unwrap();
+        let [ParseResult::TokenLaunch(launch)] = results.as_slice() else {
+            panic!("expected one launch, got {} results", results.len());
+        };
+        assert!(matches!(launch.launchpad, LaunchpadType::Vertigo));
+        assert_eq!(launch.token_address, "mint");
+        assert_eq!(launch.creator.as_deref(), Some("owner"));
+        assert_eq!(launch.pool.as_deref(), Some("pool"));
+        assert_eq!(launch.metadata.name.as_deref(), Some("Vert"));
+        assert_eq!(launch.metadata.symbol.as_deref(), Some("VRT"));
+    }
+
+    #[test]
+    fn buy_is_reported_by_name() {
+        let transaction = transaction(
+            &["trader"],
+            vec![instruction(
+                PROGRAM_ID,
+                &[0],
+                vec![102, 6, 61, 18, 1, 218, 235, 234],
+            )],
+            Vec::new(),
+        );
+
+        let results = VertigoParser::new()
+            .parse_transaction(&transaction)
+            .unwrap();
+        let [ParseResult::Other { event_type, .. }] = results.as_slice() else {
+            panic!("expected one instruction, got {} results", results.len());
+        };
+        assert_eq!(event_type, "buy");
+    }
+}
@@ -0,0 +1,196 @@
+use crate::config::parsers::VirtualsConfig;
+use crate::geyser::{QueuedTransaction, TransactionInstruction};
+use crate::parser::spl;
+use crate::parser::{
+    EventKind, LaunchpadParser, LaunchpadType, MetadataField, ParseResult, ParserCapabilities,
+    PartialParse, TokenLaunch,
+};
+use tracing::{debug, info};
+
+/// Virtuals Protocol agent token launches on Solana. An agent launch is a Virtuals
+/// instruction in a transaction that initializes the agent token's mint, unless a
+/// launch discriminator is configured.
+pub struct VirtualsParser {
+    config: VirtualsConfig,
+}
+
+impl VirtualsParser {
+    pub fn new(config: VirtualsConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl Default for VirtualsParser {
+    fn default() -> Self {
+        Self::new(VirtualsConfig::default())
+    }
+}
+
+impl LaunchpadParser for VirtualsParser {
+    fn get_program_ids(&self) -> Vec<String> {
+        self.config.program_ids.clone()
+    }
+
+    fn get_launchpad_type(&self) -> LaunchpadType {
+        LaunchpadType::Virtuals
+    }
+
+    fn capabilities(&self) -> ParserCapabilities {
+        ParserCapabilities {
+            events: vec![EventKind::TokenLaunch, EventKind::PartialParse],
+            metadata: MetadataField::METAPLEX.to_vec(),
+        }
+    }
+
+    fn parse_transaction(
+        &self,
+        transaction: &QueuedTransaction,
+    ) -> Result<Vec<ParseResult>, Box<dyn std::error::Error + Send + Sync>> {
+        debug!("🔍 Parsing Virtuals transaction: {}", transaction.signature);
+
+        let launch = transaction
+            .instructions
+            .iter()
+            .chain(&transaction.inner_instructions)
+            .find(|instr| {
+                self.config.program_ids.contains(&instr.program_id)
+                    && self
+                        .config
+                        .launch_discriminator
+                        .is_none_or(|discriminator| instr.data.starts_with(&discriminator))
+            });
+        let Some(launch) = launch else {
+            return Ok(vec![ParseResult::NotRelevant]);
+        };
+
+        // Without a discriminator, trades are told apart by not creating a mint
+        if self.config.launch_discriminator.is_none()
+            && self.config.mint_account_index.is_none()
+            && spl::initialized_mint(transaction).is_none()
+        {
+            return Ok(vec![ParseResult::NotRelevant]);
+        }
+
+        info!(
+            "🎯 Found Virtuals agent launch in: {}",
+            transaction.signature
+        );
+        Ok(vec![self.extract_token_launch(transaction, launch)])
+    }
+}
+
+impl VirtualsParser {
+    /// Extract token launch information from the launch instruction
+    fn extract_token_launch(
+        &self,
+        transaction: &QueuedTransaction,
+        instruction: &TransactionInstruction,
+    ) -> ParseResult {
+        let account = |index: usize| {
+            instruction
+                .accounts
+                .get(index)
+                .and_then(|idx| transaction.accounts.get(*idx as usize))
+                .cloned()
+        };
+
+        let deployer = match self.config.deployer_account_index {
+            Some(index) => account(index),
+            None => transaction.instruction_signer(instruction),
+        };
+        let mint = match self.config.mint_account_index {
+            Some(index) => account(index),
+            None => spl::initialized_mint(transaction),
+        };
+        let metadata = spl::created_metadata(transaction);
+
+        let Some(mint_address) = mint else {
+            debug!("❌ Could not extract agent token mint from Virtuals launch");
+            return ParseResult::PartialParse(PartialParse::new(
+                LaunchpadType::Virtuals,
+                "launch",
+                transaction,
+                None,
+                deployer,
+                metadata,
+            ));
+        };
+
+        let token_launch = TokenLaunch::new(
+            LaunchpadType::Virtuals,
+            transaction,
+            mint_address.clone(),
+            deployer,
+            metadata,
+        )
+        .with_pool(self.config.pool_account_index.and_then(account));
+
+        debug!("✅ Extracted Virtuals agent token launch: {}", mint_address);
+        ParseResult::TokenLaunch(token_launch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{create_metadata, initialize_mint, instruction, transaction};
+
+    const PROGRAM_ID: &str = "Virtuals11111111111111111111111111111111111";
+
+    fn parser(launch_discriminator: Option<[u8; 8]>) -> VirtualsParser {
+        VirtualsParser::new(VirtualsConfig {
+            program_ids: vec![PROGRAM_ID.to_string()],
+            launch_discriminator,
+            mint_account_index: None,
+            deployer_account_index: None,
+            pool_account_index: Some(2),
+        })
+    }
+
+    /// Launch by `deployer` initializing the agent token's mint and its metadata
+    fn launch_transaction(data: Vec<u8>) -> QueuedTransaction {
+        transaction(
+            &["deployer", "mint", "bonding_curve", "metadata"],
+            vec![
+                initialize_mint(1, 6),
+                create_metadata(3, 1, "Agent", "AGNT", "https://example.com/agent.json"),
+                instruction(PROGRAM_ID, &[0, 1, 2], data),
+            ],
+            Vec::new(),
+        )
+    }
+
+    #[test]
+    fn launch_initializing_a_mint_is_decoded() {
+        let results = parser(None)
+            .parse_transaction(&launch_transaction(vec![1; 8]))
+            .unwrap();
+        let [ParseResult::TokenLaunch(launch)] = results.as_slice() else {
+            panic!("expected one launch, got {} results", results.len());
+        };
+        assert!(matches!(launch.launchpad, LaunchpadType::Virtuals));
+        assert_eq!(launch.token_address, "mint");
+        assert_eq!(launch.creator.as_deref(), Some("deployer"));
+        assert_eq!(launch.pool.as_deref(), Some("bonding_curve"));
+        assert_eq!(launch.metadata.symbol.as_deref(), Some("AGNT"));
+    }
+
+    #[test]
+    fn launch_discriminator_is_required_when_configured() {
+        let results = parser(Some([2; 8]))
+            .parse_transaction(&launch_transaction(vec![1; 8]))
+            .unwrap();
+        assert!(matches!(results.as_slice(), [ParseResult::NotRelevant]));
+    }
+
+    #[test]
+    fn instructions_without_a_new_mint_are_not_relevant() {
+        let transaction = transaction(
+            &["trader"],
+            vec![instruction(PROGRAM_ID, &[0], vec![1; 8])],
+            Vec::new(),
+        );
+        let results = parser(None).parse_transaction(&transaction).unwrap();
+        assert!(matches!(results.as_slice(), [ParseResult::NotRelevant]));
+    }
+}
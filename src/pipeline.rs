@@ -0,0 +1,553 @@
+//! The service wiring: RabbitMQ publishing, the Geyser streams, the parser manager and the
+//! monitors around it, each component configured from the environment.
+
+use std::sync::Arc;
+use tokio::task::JoinHandle;
+use tokio::time::{Duration, sleep};
+use tracing::{debug, error, info, warn};
+
+use crate::admin::{self, AdminState};
+use crate::cache;
+use crate::clock::{self, SharedClock};
+use crate::config;
+use crate::config::accounts::AccountTrackingConfig;
+use crate::config::admin::AdminConfig;
+use crate::config::backfill::BackfillConfig;
+use crate::config::bench::BenchConfig;
+use crate::config::cache::CacheConfig;
+use crate::config::clusters::ClusterConfig;
+use crate::config::digest::DigestConfig;
+use crate::config::drops::DropLogConfig;
+use crate::config::enrichment::EnrichmentConfig;
+use crate::config::explorer::ExplorerConfig;
+use crate::config::filter_plan::FilterPlanConfig;
+use crate::config::filters::PreFilterConfig;
+use crate::config::first_buyers::FirstBuyersConfig;
+use crate::config::gossip::GossipConfig;
+use crate::config::grpc::{Config, GeyserConfig};
+use crate::config::history::HistoryConfig;
+use crate::config::latency::LatencyConfig;
+use crate::config::partition::PartitionConfig;
+use crate::config::publish::PublishConfig;
+use crate::config::queue::QueueConfig;
+use crate::config::rabbit::RabbitMQConfig;
+use crate::config::recorder::RecorderConfig;
+use crate::config::refetch::RefetchConfig;
+use crate::config::stats::StatsConfig;
+use crate::config::tracker::TrackerConfig;
+use crate::config::watchdog::WatchdogConfig;
+use crate::enrichment::MintEnricher;
+use crate::error::{Error, Result};
+use crate::geyser::{
+    AccountSubscriptions, EarlierStreamFilter, FilterPlan, GapDetector, GeyserClient,
+    PreFilterChain, TransactionQueue,
+};
+use crate::metrics;
+use crate::monitor::{
+    Digester, ErrorRecorder, FeeTracker, LatencyGossip, LatencyTracker, LaunchClusterer,
+    LaunchTracker, LaunchWatchdog, ParseBenchmark, ParserStats, drops, ops,
+};
+use crate::parser::ParserManager;
+use crate::rabbitmq::{ControlListener, EventSigner, GossipExchange, RabbitMQProducer};
+use crate::rpc::{Backfiller, Refetcher, RpcClient};
+use crate::sink::{
+    EventSink, LatencySink, LaunchHistory, PausableSink, PublishControls, RawPassthrough, RawSink,
+    SlotBatchSink, ThrottledSink, TransactionBatchSink,
+};
+
+/// What the pipeline prints instead of running
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dump {
+    /// Transaction filters compiled from the registered parsers
+    FilterPlan,
+    /// Subscription request sent to the Geyser endpoint
+    Subscription,
+}
+
+/// The full service: Geyser streams into the parser manager, events published to RabbitMQ,
+/// with the admin server and monitors enabled in the environment
+pub struct Pipeline {
+    geyser_config: GeyserConfig,
+    config: Config,
+    rabbitmq_cfg: RabbitMQConfig,
+    dump: Option<Dump>,
+    clock: SharedClock,
+}
+
+impl Pipeline {
+    pub fn new(geyser_config: GeyserConfig, config: Config, rabbitmq_cfg: RabbitMQConfig) -> Self {
+        Self {
+            geyser_config,
+            config,
+            rabbitmq_cfg,
+            dump: None,
+            clock: clock::system(),
+        }
+    }
+
+    /// Loads the configuration file and environment, and sets up logging
+    pub async fn from_env() -> Result<Self> {
+        let ((geyser_config, config), rabbitmq_cfg) = config::init().await?;
+        Ok(Self::new(geyser_config, config, rabbitmq_cfg))
+    }
+
+    /// Prints the filter plan or the subscription request instead of running
+    pub fn with_dump(mut self, dump: Dump) -> Self {
+        self.dump = Some(dump);
+        self
+    }
+
+    /// Replaces the clock every time-dependent component measures windows, TTLs and
+    /// timestamps with
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Starts every enabled component. Returns `None` once a dump was printed.
+    pub async fn start(self) -> Result<Option<RunningPipeline>> {
+        let Self {
+            geyser_config,
+            config,
+            rabbitmq_cfg,
+            dump,
+            clock,
+        } = self;
+
+        // Initialize RabbitMQ producer
+        let signer = rabbitmq_cfg
+            .signing_key_path
+            .as_deref()
+            .map(EventSigner::from_file)
+            .transpose()
+            .map_err(Error::SigningKey)?;
+        let mut producer = RabbitMQProducer::new(rabbitmq_cfg.clone());
+        if let Some(signer) = signer {
+            producer = producer.with_signer(signer);
+        }
+        if let Err(e) = producer.init().await {
+            error!("Failed to initialize RabbitMQ producer: {e}");
+        }
+
+        // Instances in other regions or on other Geyser vendors compare detection latency
+        let gossip_cfg = GossipConfig::from_env().await?;
+        let gossip = gossip_cfg
+            .enabled
+            .then(|| LatencyGossip::new(gossip_cfg).with_clock(clock.clone()));
+        if let Some(gossip) = &gossip {
+            let _gossip_handle = gossip.clone().start();
+            let _exchange_handle =
+                GossipExchange::new(rabbitmq_cfg.clone(), producer.clone(), gossip.clone()).start();
+        }
+
+        let publish_cfg = PublishConfig::from_env().await?;
+
+        // Consumers can ask for less traffic through the control queue
+        let (throttle, _throttle_handle) = ThrottledSink::start(
+            Arc::new(producer),
+            publish_cfg.throttle_buffer,
+            clock.clone(),
+        );
+
+        // Publishing can be paused (admin API, SIGUSR1/SIGUSR2) while ingestion continues
+        let publish = PausableSink::new(Arc::new(throttle.clone()), publish_cfg.pause_buffer);
+        #[cfg(unix)]
+        tokio::spawn(publish.clone().listen_for_signals());
+
+        if rabbitmq_cfg.control_enabled {
+            let controls = PublishControls {
+                publish: publish.clone(),
+                throttle: throttle.clone(),
+            };
+            let _control_handle = ControlListener::new(rabbitmq_cfg, controls).start();
+        }
+        // The raw passthrough shares the pause and throttling, not history and batching
+        let raw_sink: Arc<dyn RawSink> = Arc::new(publish.clone());
+        let mut producer: Arc<dyn EventSink> = Arc::new(publish.clone());
+
+        // Per-slot batches in addition to the individual events
+        if publish_cfg.slot_batch_enabled {
+            let slot_batches = SlotBatchSink::new(producer, &publish_cfg).with_clock(clock.clone());
+            let _slot_batch_handle = slot_batches.start();
+            producer = Arc::new(slot_batches);
+        }
+
+        // Per-transaction batches in addition to the individual events
+        if publish_cfg.transaction_batch_enabled {
+            let transaction_batches =
+                TransactionBatchSink::new(producer, &publish_cfg).with_clock(clock.clone());
+            let _transaction_batch_handle = transaction_batches.start();
+            producer = Arc::new(transaction_batches);
+        }
+
+        // Launch history sits in front of the pause so catch-up snapshots include held back launches
+        let history_cfg = HistoryConfig::from_env().await?;
+        let mut history = None;
+        if history_cfg.enabled {
+            match LaunchHistory::open(&history_cfg, producer.clone(), clock.clone()).await {
+                Ok(h) => {
+                    producer = Arc::new(h.clone());
+                    history = Some(h);
+                }
+                Err(e) => warn!("Launch history disabled: {e:#}"),
+            }
+        }
+
+        // Block time to publish latency per launchpad, enriched launches included
+        let latency_cfg = LatencyConfig::from_env().await?;
+        let latency = LatencyTracker::new(latency_cfg).with_clock(clock.clone());
+        let _latency_handle = latency.clone().start();
+        let mut latency_sink = LatencySink::new(producer, latency.clone());
+        if let Some(gossip) = &gossip {
+            latency_sink = latency_sink.with_gossip(gossip.clone());
+        }
+        producer = Arc::new(latency_sink);
+
+        // Operational errors go to their own routing key for alerting
+        ops::set_clock(clock.clone());
+        let _ops_handle = ops::start_reporter(producer.clone());
+
+        debug!("geyser_config: {:?}", geyser_config);
+        debug!("config: {:?}", config);
+
+        // Error sample recorder never writes credentials to disk
+        let recorder_cfg = RecorderConfig::from_env().await?;
+        let recorder = recorder_cfg.enabled.then(|| {
+            let mut secrets = vec![
+                geyser_config.x_token.clone(),
+                geyser_config.grpc_endpoint.clone(),
+                geyser_config.rpc_endpoint.clone(),
+            ];
+            secrets.extend(geyser_config.proxy.clone());
+            ErrorRecorder::new(&recorder_cfg, secrets).with_clock(clock.clone())
+        });
+
+        // RPC enrichment of launch mints, opt-in
+        let enrichment_cfg = EnrichmentConfig::from_env().await?;
+        let rpc_client = RpcClient::new(geyser_config.rpc_endpoint.clone(), &enrichment_cfg);
+
+        // Create parser manager (parsers are automatically registered), limited to this
+        // instance's launchpads when several instances share the broker
+        let partition_cfg = PartitionConfig::from_env().await?;
+        let mut parser_manager = ParserManager::new(Some(producer.clone()), &config.parsers)
+            .with_trade_publishing(publish_cfg.trades_enabled)
+            .with_program_event_publishing(publish_cfg.program_events_enabled);
+        // Explorer links on published events
+        let explorer_cfg = ExplorerConfig::from_env().await?;
+        if let Some(explorer) = explorer_cfg.explorer {
+            parser_manager = parser_manager.with_explorer(explorer);
+        }
+        let mut config = config;
+        if partition_cfg.is_partitioned() {
+            parser_manager = parser_manager.with_partition(&partition_cfg);
+            config.restrict_accounts(&parser_manager.program_ids());
+            info!(
+                "🧩 Partitioned instance handling {:?}",
+                parser_manager.launchpads()
+            );
+        }
+
+        // Transaction filters compiled from the registered parsers instead of the config file,
+        // `--dump-filter-plan` prints the plan and exits
+        let filter_plan_cfg = FilterPlanConfig::from_env().await?;
+        let dump_filter_plan = dump == Some(Dump::FilterPlan);
+        let mut stream_configs = Vec::new();
+        let mut filter_plan = None;
+        if filter_plan_cfg.enabled || dump_filter_plan {
+            let plan =
+                FilterPlan::compile(&parser_manager.program_ids_by_launchpad(), &filter_plan_cfg)?;
+            if dump_filter_plan {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&plan).unwrap_or_default()
+                );
+                return Ok(None);
+            }
+            info!(
+                "🗺️ Filter plan: {} program IDs in {} transaction filters over {} streams",
+                plan.accounts,
+                plan.streams
+                    .iter()
+                    .map(|stream| stream.filters.len())
+                    .sum::<usize>(),
+                plan.streams.len()
+            );
+            stream_configs = plan.configs(&config);
+            if !stream_configs.is_empty() {
+                config = stream_configs.remove(0);
+            }
+            filter_plan = Some(plan);
+        }
+
+        // Create Geyser client with queue limits
+        let queue_cfg = QueueConfig::from_env().await?;
+        // Transactions that produced no event, with the reason, for `/debug/drops`
+        let drop_log_cfg = DropLogConfig::from_env().await?;
+        drops::set_capacity(drop_log_cfg.capacity);
+        drops::set_clock(clock.clone());
+        let pre_filter_cfg = PreFilterConfig::from_env().await?;
+        let pre_filters = PreFilterChain::from_config(&pre_filter_cfg, &config.transactions);
+        let mut geyser_client = GeyserClient::new(geyser_config, config, &queue_cfg)
+            .with_pre_filters(pre_filters)
+            .with_raw_capture(recorder.is_some())
+            .with_clock(clock.clone());
+
+        // Undecoded matched transactions for consumers with their own decoders
+        if publish_cfg.raw_passthrough_enabled {
+            let (passthrough, _raw_handle) =
+                RawPassthrough::start(raw_sink, publish_cfg.raw_passthrough_buffer);
+            geyser_client = geyser_client.with_raw_passthrough(passthrough);
+        }
+
+        // Pool accounts of fresh launches added to the live subscription until graduation
+        let account_tracking_cfg = AccountTrackingConfig::from_env().await?;
+        if account_tracking_cfg.enabled {
+            let curve_progress = account_tracking_cfg.curve_progress;
+            let subscriptions =
+                AccountSubscriptions::new(account_tracking_cfg).with_clock(clock.clone());
+            geyser_client = geyser_client.with_account_subscriptions(subscriptions.clone());
+            if curve_progress {
+                let _curve_progress_handle = subscriptions.start_curve_progress(producer.clone());
+            }
+            parser_manager = parser_manager.with_account_subscriptions(subscriptions);
+        }
+
+        // Blocks the stream skipped are fetched from RPC and their events flagged `backfilled`
+        let backfill_cfg = BackfillConfig::from_env().await?;
+        if backfill_cfg.enabled {
+            let (gaps, gap_receiver) = GapDetector::new();
+            let gaps = gaps.with_clock(clock.clone());
+            geyser_client = geyser_client.with_gap_detector(gaps.clone());
+            let backfiller = Backfiller::new(
+                rpc_client.clone(),
+                geyser_client.get_queue().clone(),
+                gaps,
+                parser_manager.program_ids(),
+                backfill_cfg,
+            )
+            .with_clock(clock.clone());
+            let _backfill_handle = backfiller.start(gap_receiver);
+        }
+
+        // Matched transactions arriving without instructions are fetched again from RPC
+        let refetch_cfg = RefetchConfig::from_env().await?;
+        if refetch_cfg.enabled {
+            let (refetcher, _refetch_handle) = Refetcher::start(
+                rpc_client.clone(),
+                geyser_client.get_queue().clone(),
+                refetch_cfg,
+            );
+            geyser_client = geyser_client.with_refetcher(refetcher);
+        }
+
+        // `--dump-subscription` prints the derived subscription request and exits
+        if dump == Some(Dump::Subscription) {
+            let snapshot = geyser_client.subscription_snapshot().await;
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&snapshot["request"]).unwrap_or_default()
+            );
+            return Ok(None);
+        }
+
+        // Start client in background
+        let mut geyser_handle = geyser_client.start();
+
+        // Further streams of the filter plan feed the same queue, the first to stop ends the process
+        if let Some(plan) = &filter_plan
+            && !stream_configs.is_empty()
+        {
+            let mut handles = vec![geyser_handle];
+            for (index, stream_config) in stream_configs.into_iter().enumerate() {
+                let pre_filters = PreFilterChain::default()
+                    .with_filter(EarlierStreamFilter::new(&plan.earlier_accounts(index + 1)))
+                    .append(PreFilterChain::from_config(
+                        &pre_filter_cfg,
+                        &stream_config.transactions,
+                    ));
+                handles.push(geyser_client.for_stream(stream_config, pre_filters).start());
+            }
+            geyser_handle = tokio::spawn(async move {
+                match futures::future::select_all(handles).await.0 {
+                    Ok(result) => result,
+                    Err(e) => Err(e.into()),
+                }
+            });
+        }
+
+        if let Some(recorder) = recorder {
+            parser_manager = parser_manager.with_recorder(recorder);
+        }
+        let fees = FeeTracker::default().with_clock(clock.clone());
+        parser_manager = parser_manager.with_fee_tracker(fees.clone());
+
+        // Periodic digest on its own routing key for lightweight consumers
+        let digest_cfg = DigestConfig::from_env().await?;
+        if digest_cfg.enabled {
+            let digester = Digester::new(digest_cfg).with_clock(clock.clone());
+            let _digest_handle = digester.clone().start(producer.clone());
+            parser_manager = parser_manager.with_digester(digester);
+        }
+
+        // Per-wallet trading on fresh launches, published as a status after the window
+        let tracker_cfg = TrackerConfig::from_env().await?;
+        let tracker = tracker_cfg.enabled.then(|| {
+            LaunchTracker::new(tracker_cfg)
+                .with_fee_tracker(fees.clone())
+                .with_clock(clock.clone())
+        });
+        if let Some(tracker) = &tracker {
+            let _tracker_handle = tracker.clone().start(producer.clone());
+            parser_manager = parser_manager.with_tracker(tracker.clone());
+        }
+
+        // Wallets buying each launch within its first slots, published as one summary
+        let first_buyers_cfg = FirstBuyersConfig::from_env().await?;
+        if first_buyers_cfg.enabled {
+            parser_manager = parser_manager.with_first_buyers(first_buyers_cfg);
+        }
+
+        // Related launches grouped under a shared cluster_id
+        let cluster_cfg = ClusterConfig::from_env().await?;
+        if cluster_cfg.enabled {
+            parser_manager = parser_manager
+                .with_clusterer(LaunchClusterer::new(cluster_cfg).with_clock(clock.clone()));
+        }
+
+        // Per-parser counters kept across restarts to compare builds after a deploy
+        let stats_cfg = StatsConfig::from_env().await?;
+        let mut stats = None;
+        if stats_cfg.enabled {
+            match ParserStats::open(stats_cfg, clock.clone()).await {
+                Ok(s) => {
+                    let _stats_handle = s.clone().start();
+                    parser_manager = parser_manager.with_stats(s.clone());
+                    stats = Some(s);
+                }
+                Err(e) => warn!("Parser statistics disabled: {e:#}"),
+            }
+        }
+
+        if enrichment_cfg.enabled {
+            let mut enricher = MintEnricher::new(rpc_client, enrichment_cfg, Some(producer));
+
+            let cache_cfg = CacheConfig::from_env().await?;
+            match cache::from_config(&cache_cfg).await {
+                Ok(Some(cache)) => enricher = enricher.with_cache(cache),
+                Ok(None) => {}
+                Err(e) => warn!(
+                    "Enrichment cache unavailable, continuing without it: {:#}",
+                    e
+                ),
+            }
+
+            let (enricher, _enrichment_handle) = enricher.start();
+            parser_manager = parser_manager.with_enricher(enricher);
+        }
+
+        info!("Parser manager initialized with all launchpad parsers");
+        let parser_capabilities = Arc::new(parser_manager.capabilities());
+        let launchpads: Vec<String> = parser_manager
+            .launchpads()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        // Start parser manager processing
+        let queue = geyser_client.get_queue().clone();
+        let _parser_handle = tokio::spawn(async move {
+            parser_manager.start_processing(Arc::new(queue)).await;
+        });
+
+        info!("Parser manager started successfully");
+
+        // Start admin HTTP server (metrics)
+        let admin_cfg = AdminConfig::from_env().await?;
+        if admin_cfg.enabled {
+            let admin_state = AdminState {
+                geyser: geyser_client.clone(),
+                publish,
+                throttle,
+                history,
+                fees,
+                tracker,
+                stats,
+                latency,
+                gossip,
+                parsers: parser_capabilities,
+                clock: clock.clone(),
+            };
+            let _admin_handle = admin::start(admin_cfg, admin_state);
+        }
+
+        // Parse capacity estimate on startup and every interval
+        let bench_cfg = BenchConfig::from_env().await?;
+        if bench_cfg.enabled {
+            let _bench_handle = ParseBenchmark::new(bench_cfg).start();
+        }
+
+        // Start launch watchdog
+        let mut watchdog_cfg = WatchdogConfig::from_env().await?;
+        watchdog_cfg.restrict_launchpads(&launchpads);
+        if watchdog_cfg.enabled && !watchdog_cfg.launchpads.is_empty() {
+            let _watchdog_handle = LaunchWatchdog::new(watchdog_cfg)
+                .with_clock(clock.clone())
+                .start();
+        }
+
+        Ok(Some(RunningPipeline {
+            geyser: geyser_handle,
+            queue: geyser_client.get_queue().clone(),
+            queue_cfg,
+        }))
+    }
+}
+
+/// A started pipeline, its components run in background tasks
+pub struct RunningPipeline {
+    geyser: JoinHandle<anyhow::Result<()>>,
+    queue: TransactionQueue,
+    queue_cfg: QueueConfig,
+}
+
+impl RunningPipeline {
+    /// Logs the queue status until the Geyser client stops, which only happens once its
+    /// reconnect budget is spent
+    pub async fn run(mut self) -> Result<()> {
+        loop {
+            tokio::select! {
+                _ = sleep(Duration::from_secs(10)) => {}
+                result = &mut self.geyser => {
+                    // Let the critical alert reach the broker
+                    sleep(Duration::from_secs(2)).await;
+                    return Err(Error::GeyserStopped(match result {
+                        Ok(Err(e)) => e,
+                        Ok(Ok(())) => anyhow::anyhow!("Geyser client stopped"),
+                        Err(e) => e.into(),
+                    }));
+                }
+            }
+            let queue_size = self.queue.len().await;
+            let queue_bytes = self.queue.bytes().await;
+            let oldest_ms = self
+                .queue
+                .oldest_age()
+                .await
+                .map_or(0, |age| age.as_millis());
+            metrics::global().set_gauge("queue_oldest_age_ms", &[], oldest_ms as f64);
+            if queue_size > self.queue_cfg.max_items / 2
+                || queue_bytes > self.queue_cfg.max_bytes / 2
+            {
+                warn!(
+                    "Queue status: {} elements ({} bytes, oldest waiting {}ms)",
+                    queue_size, queue_bytes, oldest_ms
+                );
+            } else if queue_size > 0 {
+                info!(
+                    "Queue status: {} elements ({} bytes, oldest waiting {}ms)",
+                    queue_size, queue_bytes, oldest_ms
+                );
+            }
+        }
+    }
+}
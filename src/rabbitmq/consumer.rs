@@ -1,7 +1,7 @@
 use anyhow::{Context, Result};
 use futures::StreamExt;
 use lapin::{
-    Channel, Connection, ConnectionProperties, Consumer, ExchangeKind,
+    Channel, Connection, Consumer, ExchangeKind,
     options::{
         BasicAckOptions, BasicConsumeOptions, ExchangeDeclareOptions, QueueBindOptions,
         QueueDeclareOptions,
@@ -9,15 +9,18 @@ use lapin::{
     types::FieldTable,
 };
 use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
 use tracing::{debug, error, info, warn};
 
 use crate::config::rabbit::RabbitMQConfig;
 use crate::parser::TokenLaunch;
+use crate::rabbitmq::{EventVerifier, nodes};
 
 pub struct RabbitMQConsumer {
     config: RabbitMQConfig,
     connection: Option<Arc<Connection>>,
     channel: Option<Channel>,
+    verifier: Option<Arc<EventVerifier>>,
 }
 
 impl RabbitMQConsumer {
@@ -26,19 +29,22 @@ impl RabbitMQConsumer {
             config,
             connection: None,
             channel: None,
+            verifier: None,
         }
     }
 
+    /// Drops messages that are not signed by the trusted producer key
+    pub fn with_verifier(mut self, verifier: EventVerifier) -> Self {
+        self.verifier = Some(Arc::new(verifier));
+        self
+    }
+
     /// Set up the connection, exchange and queue
     pub async fn init(&mut self) -> Result<()> {
         info!("🐰 Initializing RabbitMQ consumer...");
 
-        // Create connection
-        let connection = Connection::connect(&self.config.url, ConnectionProperties::default())
-            .await
-            .context("Failed to connect to RabbitMQ")?;
-
-        info!("✅ Connected to RabbitMQ: {}", self.config.url);
+        // Create connection to the first reachable node
+        let connection = nodes::connect(&self.config, &AtomicUsize::new(0)).await?;
 
         // Create channel
         let channel = connection
@@ -121,7 +127,9 @@ impl RabbitMQConsumer {
                 self.config.queue_name
             );
 
-            let handle = tokio::spawn(async move { Self::consume_messages(consumer).await });
+            let verifier = self.verifier.clone();
+            let handle =
+                tokio::spawn(async move { Self::consume_messages(consumer, verifier).await });
 
             Ok(handle)
         } else {
@@ -130,7 +138,10 @@ impl RabbitMQConsumer {
     }
 
     /// Consume messages loop
-    async fn consume_messages(mut consumer: Consumer) -> Result<()> {
+    async fn consume_messages(
+        mut consumer: Consumer,
+        verifier: Option<Arc<EventVerifier>>,
+    ) -> Result<()> {
         info!("📥 Starting message consumption loop...");
 
         while let Some(delivery) = consumer.next().await {
@@ -138,6 +149,17 @@ impl RabbitMQConsumer {
                 Ok(delivery) => {
                     let payload = &delivery.data;
 
+                    if let Some(verifier) = &verifier
+                        && let Err(e) =
+                            verifier.verify(payload, delivery.properties.headers().as_ref())
+                    {
+                        error!("❌ Rejecting unverified message: {:#}", e);
+                        if let Err(ack_err) = delivery.ack(BasicAckOptions::default()).await {
+                            error!("❌ Failed to acknowledge rejected message: {}", ack_err);
+                        }
+                        continue;
+                    }
+
                     match serde_json::from_slice::<TokenLaunch>(payload) {
                         Ok(token_launch) => {
                             info!("📨 Received token launch: {}", token_launch.token_address);
@@ -177,7 +199,7 @@ impl RabbitMQConsumer {
         // Placeholder for business logic
         info!("===================");
         info!("=== CONSUMED TOKEN LAUNCH ===");
-        info!("Launchpad: {:?}", token_launch.launchpad);
+        info!("Launchpad: {}", token_launch.launchpad);
         info!("CA: {}", token_launch.token_address);
         if let Some(creator) = &token_launch.creator {
             info!("Creator: {}", creator);
@@ -0,0 +1,116 @@
+use anyhow::{Context, Result};
+use futures::StreamExt;
+use lapin::{
+    options::{BasicAckOptions, BasicConsumeOptions, QueueBindOptions, QueueDeclareOptions},
+    types::FieldTable,
+};
+use std::sync::atomic::AtomicUsize;
+use tokio::task::JoinHandle;
+use tokio::time::{Duration, sleep};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::config::rabbit::RabbitMQConfig;
+use crate::rabbitmq::nodes;
+use crate::sink::{ControlCommand, PublishControls};
+
+/// Wait before reconnecting after the control consumer stopped
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// Listens on the control queue for flow control commands from consumers
+pub struct ControlListener {
+    config: RabbitMQConfig,
+    controls: PublishControls,
+}
+
+impl ControlListener {
+    pub fn new(config: RabbitMQConfig, controls: PublishControls) -> Self {
+        Self { config, controls }
+    }
+
+    /// Starts consuming commands in a separate task, reconnecting whenever the
+    /// consumer stops
+    pub fn start(self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            loop {
+                match self.run().await {
+                    Ok(()) => warn!("🎛️  Control queue consumption ended, reconnecting"),
+                    Err(e) => error!("❌ Control queue listener failed: {:#}", e),
+                }
+                sleep(RECONNECT_DELAY).await;
+            }
+        })
+    }
+
+    async fn run(&self) -> Result<()> {
+        let connection = nodes::connect(&self.config, &AtomicUsize::new(0)).await?;
+        let channel = connection
+            .create_channel()
+            .await
+            .context("Failed to create channel")?;
+
+        // Every instance must see every command, a shared queue would spread them over
+        // the instances, so each reads its own temporary queue
+        let queue = format!("{}.{}", self.config.control_queue, Uuid::new_v4());
+        channel
+            .queue_declare(
+                &queue,
+                QueueDeclareOptions {
+                    exclusive: true,
+                    auto_delete: true,
+                    ..Default::default()
+                },
+                FieldTable::default(),
+            )
+            .await
+            .context("Failed to declare control queue")?;
+
+        channel
+            .queue_bind(
+                &queue,
+                &self.config.exchange_name,
+                &self.config.control_routing_key,
+                QueueBindOptions::default(),
+                FieldTable::default(),
+            )
+            .await
+            .context("Failed to bind control queue to exchange")?;
+
+        let mut consumer = channel
+            .basic_consume(
+                &queue,
+                "publish_control",
+                BasicConsumeOptions::default(),
+                FieldTable::default(),
+            )
+            .await
+            .context("Failed to create control consumer")?;
+
+        info!(
+            "🎛️  Listening for control commands on queue {} (routing key {})",
+            queue, self.config.control_routing_key
+        );
+
+        while let Some(delivery) = consumer.next().await {
+            let delivery = match delivery {
+                Ok(delivery) => delivery,
+                Err(e) => {
+                    error!("❌ Error receiving control command: {}", e);
+                    tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+                    continue;
+                }
+            };
+
+            match serde_json::from_slice::<ControlCommand>(&delivery.data) {
+                Ok(command) => self.controls.apply(command).await,
+                Err(e) => warn!("⚠️  Ignoring malformed control command: {}", e),
+            }
+
+            if let Err(e) = delivery.ack(BasicAckOptions::default()).await {
+                error!("❌ Failed to acknowledge control command: {}", e);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+use anyhow::{Context, Result};
+use futures::StreamExt;
+use lapin::{
+    options::{BasicAckOptions, BasicConsumeOptions, QueueBindOptions, QueueDeclareOptions},
+    types::FieldTable,
+};
+use std::sync::atomic::AtomicUsize;
+use tokio::task::JoinHandle;
+use tokio::time::{Duration, MissedTickBehavior, interval};
+use tracing::{error, info, warn};
+
+use crate::config::rabbit::RabbitMQConfig;
+use crate::monitor::{GossipBatch, LatencyGossip};
+use crate::rabbitmq::{RabbitMQProducer, nodes};
+
+/// Exchanges launch sightings with the other instances over the broker: publishes this
+/// instance's on the gossip routing key and feeds the others' into `LatencyGossip`
+pub struct GossipExchange {
+    config: RabbitMQConfig,
+    producer: RabbitMQProducer,
+    gossip: LatencyGossip,
+}
+
+impl GossipExchange {
+    pub fn new(config: RabbitMQConfig, producer: RabbitMQProducer, gossip: LatencyGossip) -> Self {
+        Self {
+            config,
+            producer,
+            gossip,
+        }
+    }
+
+    /// Starts publishing and consuming sightings in separate tasks
+    pub fn start(self) -> JoinHandle<Result<()>> {
+        let publisher = Self::publish(self.producer.clone(), self.gossip.clone());
+        tokio::spawn(publisher);
+        tokio::spawn(async move {
+            self.listen()
+                .await
+                .inspect_err(|e| error!("❌ Latency gossip listener failed: {:#}", e))
+        })
+    }
+
+    async fn publish(producer: RabbitMQProducer, gossip: LatencyGossip) {
+        let routing_key = gossip.config().routing_key.clone();
+        let mut ticker = interval(Duration::from_millis(gossip.config().flush_interval_ms));
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            let Some(batch) = gossip.take_pending() else {
+                continue;
+            };
+            if let Err(e) = producer.publish_event(&routing_key, &batch).await {
+                warn!(
+                    "⚠️  Failed to publish {} sightings: {:#}",
+                    batch.sightings.len(),
+                    e
+                );
+            }
+        }
+    }
+
+    async fn listen(self) -> Result<()> {
+        let connection = nodes::connect(&self.config, &AtomicUsize::new(0)).await?;
+        let channel = connection
+            .create_channel()
+            .await
+            .context("Failed to create channel")?;
+
+        // Every instance needs all sightings, so each reads its own temporary queue
+        let queue = channel
+            .queue_declare(
+                "",
+                QueueDeclareOptions {
+                    exclusive: true,
+                    auto_delete: true,
+                    ..Default::default()
+                },
+                FieldTable::default(),
+            )
+            .await
+            .context("Failed to declare gossip queue")?;
+        let routing_key = &self.gossip.config().routing_key;
+
+        channel
+            .queue_bind(
+                queue.name().as_str(),
+                &self.config.exchange_name,
+                routing_key,
+                QueueBindOptions::default(),
+                FieldTable::default(),
+            )
+            .await
+            .context("Failed to bind gossip queue to exchange")?;
+
+        let mut consumer = channel
+            .basic_consume(
+                queue.name().as_str(),
+                "latency_gossip",
+                BasicConsumeOptions::default(),
+                FieldTable::default(),
+            )
+            .await
+            .context("Failed to create gossip consumer")?;
+
+        info!(
+            "🛰️  Comparing detection latency as {} (routing key {})",
+            self.gossip.source(),
+            routing_key
+        );
+
+        while let Some(delivery) = consumer.next().await {
+            let delivery = match delivery {
+                Ok(delivery) => delivery,
+                Err(e) => {
+                    error!("❌ Error receiving sightings: {}", e);
+                    tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+                    continue;
+                }
+            };
+
+            match serde_json::from_slice::<GossipBatch>(&delivery.data) {
+                Ok(batch) => self.gossip.receive(batch),
+                Err(e) => warn!("⚠️  Ignoring malformed sightings: {}", e),
+            }
+
+            if let Err(e) = delivery.ack(BasicAckOptions::default()).await {
+                error!("❌ Failed to acknowledge sightings: {}", e);
+            }
+        }
+
+        warn!("🛰️  Latency gossip consumption ended");
+        Ok(())
+    }
+}
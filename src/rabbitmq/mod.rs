@@ -1,5 +1,12 @@
 pub mod consumer;
+pub mod control;
+pub mod gossip;
+pub mod nodes;
 pub mod producer;
+pub mod signing;
 
 pub use consumer::RabbitMQConsumer;
+pub use control::ControlListener;
+pub use gossip::GossipExchange;
 pub use producer::RabbitMQProducer;
+pub use signing::{EventSigner, EventVerifier};
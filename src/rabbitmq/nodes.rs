@@ -0,0 +1,263 @@
+use anyhow::{Context, Result, anyhow, bail};
+use lapin::{Connection, ConnectionProperties};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::net::UdpSocket;
+use tokio::time::{Duration, timeout};
+use tracing::{debug, info, warn};
+
+use crate::config::rabbit::RabbitMQConfig;
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const DNS_TIMEOUT: Duration = Duration::from_secs(5);
+const DNS_TYPE_SRV: u16 = 33;
+
+/// Connects to the first reachable broker node, starting at `next`.
+/// On success `next` points past the connected node, so the following
+/// reconnect moves on to another node.
+pub async fn connect(config: &RabbitMQConfig, next: &AtomicUsize) -> Result<Connection> {
+    let nodes = node_urls(config).await;
+    if nodes.is_empty() {
+        bail!("No RabbitMQ nodes configured");
+    }
+
+    let start = next.load(Ordering::Relaxed);
+    let mut last_error = None;
+    for offset in 0..nodes.len() {
+        let index = (start + offset) % nodes.len();
+        let url = &nodes[index];
+
+        match timeout(
+            CONNECT_TIMEOUT,
+            Connection::connect(url, ConnectionProperties::default()),
+        )
+        .await
+        {
+            Ok(Ok(connection)) => {
+                info!("✅ Connected to RabbitMQ node {}", redact(url));
+                next.store(index + 1, Ordering::Relaxed);
+                return Ok(connection);
+            }
+            Ok(Err(e)) => {
+                warn!("⚠️ RabbitMQ node {} unavailable: {}", redact(url), e);
+                last_error = Some(anyhow!(e));
+            }
+            Err(_) => {
+                warn!("⚠️ RabbitMQ node {} timed out", redact(url));
+                last_error = Some(anyhow!("connection timed out"));
+            }
+        }
+    }
+
+    Err(last_error
+        .unwrap_or_else(|| anyhow!("no nodes"))
+        .context("Failed to connect to RabbitMQ"))
+}
+
+/// Broker URLs to try: the nodes behind `RABBITMQ_SRV` when set and resolvable,
+/// otherwise the comma separated `RABBITMQ_URL` list
+pub async fn node_urls(config: &RabbitMQConfig) -> Vec<String> {
+    let urls = config.urls();
+    let Some(record) = &config.srv_record else {
+        return urls;
+    };
+    let Some(template) = urls.first() else {
+        return urls;
+    };
+
+    match lookup_srv(record).await {
+        Ok(targets) if !targets.is_empty() => {
+            debug!("Resolved {} RabbitMQ nodes from {}", targets.len(), record);
+            targets
+                .iter()
+                .map(|target| with_host(template, &target.host, target.port))
+                .collect()
+        }
+        Ok(_) => {
+            warn!(
+                "⚠️ SRV record {} has no targets, using RABBITMQ_URL",
+                record
+            );
+            urls
+        }
+        Err(e) => {
+            warn!(
+                "⚠️ Failed to resolve SRV record {}, using RABBITMQ_URL: {:#}",
+                record, e
+            );
+            urls
+        }
+    }
+}
+
+/// Replaces host and port of an AMQP URL, keeping scheme, credentials and vhost
+fn with_host(url: &str, host: &str, port: u16) -> String {
+    let (scheme, rest) = url.split_once("://").unwrap_or(("amqp", url));
+    let (authority, path) = match rest.find('/') {
+        Some(index) => rest.split_at(index),
+        None => (rest, ""),
+    };
+    let userinfo = authority
+        .rsplit_once('@')
+        .map(|(userinfo, _)| format!("{}@", userinfo))
+        .unwrap_or_default();
+
+    format!("{}://{}{}:{}{}", scheme, userinfo, host, port, path)
+}
+
+/// URL without credentials, for logs
+pub fn redact(url: &str) -> String {
+    match (url.split_once("://"), url.rsplit_once('@')) {
+        (Some((scheme, _)), Some((_, host))) => format!("{}://{}", scheme, host),
+        _ => url.to_string(),
+    }
+}
+
+/// Target of an SRV record
+struct SrvTarget {
+    priority: u16,
+    weight: u16,
+    port: u16,
+    host: String,
+}
+
+/// Resolves an SRV record against the first nameserver in /etc/resolv.conf.
+/// Targets are ordered by priority, then by descending weight.
+async fn lookup_srv(name: &str) -> Result<Vec<SrvTarget>> {
+    let nameserver = std::fs::read_to_string("/etc/resolv.conf")
+        .ok()
+        .and_then(|conf| {
+            conf.lines().find_map(|line| {
+                let mut parts = line.split_whitespace();
+                (parts.next() == Some("nameserver"))
+                    .then(|| parts.next().map(str::to_string))
+                    .flatten()
+            })
+        })
+        .unwrap_or_else(|| "127.0.0.1".to_string());
+    let server = if nameserver.contains(':') {
+        format!("[{}]:53", nameserver)
+    } else {
+        format!("{}:53", nameserver)
+    };
+
+    let id = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.subsec_nanos() as u16);
+    let query = build_query(id, name);
+
+    let bind = if nameserver.contains(':') {
+        "[::]:0"
+    } else {
+        "0.0.0.0:0"
+    };
+    let socket = UdpSocket::bind(bind).await?;
+    socket
+        .connect(&server)
+        .await
+        .with_context(|| format!("Failed to reach nameserver {}", server))?;
+    socket.send(&query).await?;
+
+    let mut buffer = vec![0u8; 4096];
+    let len = timeout(DNS_TIMEOUT, socket.recv(&mut buffer))
+        .await
+        .context("DNS query timed out")??;
+
+    let mut targets = parse_srv_response(id, &buffer[..len])?;
+    targets.sort_by(|a, b| a.priority.cmp(&b.priority).then(b.weight.cmp(&a.weight)));
+    Ok(targets)
+}
+
+fn build_query(id: u16, name: &str) -> Vec<u8> {
+    let mut query = Vec::with_capacity(name.len() + 18);
+    query.extend_from_slice(&id.to_be_bytes());
+    // Recursion desired, one question
+    query.extend_from_slice(&[0x01, 0x00, 0, 1, 0, 0, 0, 0, 0, 0]);
+    for label in name.trim_end_matches('.').split('.') {
+        query.push(label.len() as u8);
+        query.extend_from_slice(label.as_bytes());
+    }
+    query.push(0);
+    query.extend_from_slice(&DNS_TYPE_SRV.to_be_bytes());
+    // Class IN
+    query.extend_from_slice(&[0, 1]);
+    query
+}
+
+fn parse_srv_response(id: u16, message: &[u8]) -> Result<Vec<SrvTarget>> {
+    let u16_at = |offset: usize| -> Result<u16> {
+        let bytes = message
+            .get(offset..offset + 2)
+            .context("Truncated DNS response")?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    };
+
+    if u16_at(0)? != id {
+        bail!("DNS response id mismatch");
+    }
+    let flags = u16_at(2)?;
+    if flags & 0x0200 != 0 {
+        bail!("DNS response truncated");
+    }
+    match flags & 0x000f {
+        0 => {}
+        3 => return Ok(Vec::new()),
+        rcode => bail!("DNS query failed with rcode {}", rcode),
+    }
+    let questions = u16_at(4)?;
+    let answers = u16_at(6)?;
+
+    let mut offset = 12;
+    for _ in 0..questions {
+        offset = read_name(message, offset)?.1 + 4;
+    }
+
+    let mut targets = Vec::new();
+    for _ in 0..answers {
+        offset = read_name(message, offset)?.1;
+        let record_type = u16_at(offset)?;
+        let data_len = u16_at(offset + 8)? as usize;
+        let data = offset + 10;
+
+        if record_type == DNS_TYPE_SRV {
+            targets.push(SrvTarget {
+                priority: u16_at(data)?,
+                weight: u16_at(data + 2)?,
+                port: u16_at(data + 4)?,
+                host: read_name(message, data + 6)?.0,
+            });
+        }
+        offset = data + data_len;
+    }
+
+    Ok(targets)
+}
+
+/// Reads a possibly compressed domain name, returns it with the offset after it
+fn read_name(message: &[u8], start: usize) -> Result<(String, usize)> {
+    let mut labels = Vec::new();
+    let mut offset = start;
+    let mut end = None;
+
+    // Bounded to guard against pointer loops
+    for _ in 0..128 {
+        let len = *message.get(offset).context("Truncated DNS name")? as usize;
+        if len == 0 {
+            return Ok((labels.join("."), end.unwrap_or(offset + 1)));
+        }
+        if len & 0xc0 == 0xc0 {
+            let low = *message.get(offset + 1).context("Truncated DNS name")? as usize;
+            end.get_or_insert(offset + 2);
+            offset = ((len & 0x3f) << 8) | low;
+            continue;
+        }
+
+        let label = message
+            .get(offset + 1..offset + 1 + len)
+            .context("Truncated DNS label")?;
+        labels.push(String::from_utf8_lossy(label).into_owned());
+        offset += 1 + len;
+    }
+
+    bail!("DNS name too long")
+}
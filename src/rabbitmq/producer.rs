@@ -1,43 +1,79 @@
 use anyhow::{Context, Result};
+use futures::FutureExt;
+use futures::future::BoxFuture;
 use lapin::{
-    BasicProperties, Channel, Connection, ConnectionProperties, ExchangeKind,
+    BasicProperties, Channel, Connection, ExchangeKind,
     options::{BasicPublishOptions, ExchangeDeclareOptions, QueueBindOptions, QueueDeclareOptions},
     types::FieldTable,
 };
+use serde::Serialize;
 use serde_json;
 use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
 use tokio::sync::Mutex;
+use tokio::time::{Duration, Instant};
 use tracing::{debug, info, warn};
 
 use crate::config::rabbit::RabbitMQConfig;
-use crate::parser::TokenLaunch;
+use crate::monitor::{Digest, OpsError, TokenAlert, TokenStatus};
+use crate::parser::{
+    AuthorityRevoked, CreatorTransfer, CurveProgress, DevSell, FeeClaim, FirstBuyers, Graduation,
+    Liquidity, LpBurned, Migration, PartialParse, ProgramEvent, TokenLaunch, Trade,
+};
+use crate::rabbitmq::{EventSigner, nodes};
+use crate::sink::{EventSink, RawTransaction, SlotBatch, TransactionBatch};
+
+/// Wait after a failed reconnect before trying the nodes again
+const RECONNECT_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Connection and channel to the broker node currently in use
+struct BrokerLink {
+    connection: Connection,
+    channel: Channel,
+}
 
 #[derive(Clone)]
 pub struct RabbitMQProducer {
     config: RabbitMQConfig,
-    connection: Option<Arc<Connection>>,
-    channel: Option<Arc<Mutex<Channel>>>,
+    link: Arc<Mutex<Option<BrokerLink>>>,
+    /// Node tried first on the next connect, advanced past each connected node
+    next_node: Arc<AtomicUsize>,
+    /// No reconnect attempts before this, so an unreachable cluster fails publishes fast
+    retry_after: Arc<Mutex<Option<Instant>>>,
+    signer: Option<Arc<EventSigner>>,
 }
 
 impl RabbitMQProducer {
     pub fn new(config: RabbitMQConfig) -> Self {
         Self {
             config,
-            connection: None,
-            channel: None,
+            link: Arc::new(Mutex::new(None)),
+            next_node: Arc::new(AtomicUsize::new(0)),
+            retry_after: Arc::new(Mutex::new(None)),
+            signer: None,
         }
     }
 
+    /// Signs every published payload, signature goes into the message headers
+    pub fn with_signer(mut self, signer: EventSigner) -> Self {
+        info!("🔏 Signing published events as {}", signer.signer());
+        self.signer = Some(Arc::new(signer));
+        self
+    }
+
     /// Initialize connection, exchange and queue declarations
-    pub async fn init(&mut self) -> Result<()> {
+    pub async fn init(&self) -> Result<()> {
         info!("🐰 Initializing RabbitMQ producer...");
 
-        // Create connection
-        let connection = Connection::connect(&self.config.url, ConnectionProperties::default())
-            .await
-            .context("Failed to connect to RabbitMQ")?;
+        *self.link.lock().await = Some(self.connect().await?);
 
-        info!("✅ Connected to RabbitMQ: {}", self.config.url);
+        info!("🚀 RabbitMQ producer initialized successfully");
+        Ok(())
+    }
+
+    /// Connects to the next reachable node and declares exchange and queue on it
+    async fn connect(&self) -> Result<BrokerLink> {
+        let connection = nodes::connect(&self.config, &self.next_node).await?;
 
         // Create channel
         let channel = connection
@@ -95,68 +131,389 @@ impl RabbitMQProducer {
             self.config.queue_name, self.config.exchange_name, self.config.routing_key
         );
 
-        self.connection = Some(Arc::new(connection));
-        self.channel = Some(Arc::new(Mutex::new(channel)));
-
-        info!("🚀 RabbitMQ producer initialized successfully");
-        Ok(())
+        Ok(BrokerLink {
+            connection,
+            channel,
+        })
     }
 
     /// Publish a token launch event to RabbitMQ
     pub async fn publish_token_launch(&self, token_launch: &TokenLaunch) -> Result<()> {
-        if let Some(channel_arc) = &self.channel {
-            let channel = channel_arc.lock().await;
-
-            // Serialize token launch to JSON
-            let payload =
-                serde_json::to_vec(token_launch).context("Failed to serialize token launch")?;
-
-            // Publish message
-            channel
-                .basic_publish(
-                    &self.config.exchange_name,
-                    &self.config.routing_key,
-                    BasicPublishOptions::default(),
-                    &payload,
-                    BasicProperties::default()
-                        .with_content_type("application/json".into())
-                        .with_delivery_mode(2), // Persistent message
-                )
-                .await
-                .context("Failed to publish message")?;
+        self.publish_event(&self.config.routing_key, token_launch)
+            .await?;
+
+        debug!(
+            "📤 Published token launch to RabbitMQ: {} ({})",
+            token_launch.token_address, token_launch.signature
+        );
+
+        Ok(())
+    }
+
+    /// Publish a partially extracted launch to RabbitMQ
+    pub async fn publish_partial_parse(&self, partial: &PartialParse) -> Result<()> {
+        self.publish_event(&self.config.partial_routing_key, partial)
+            .await?;
+
+        debug!(
+            "📤 Published partial parse to RabbitMQ: {}",
+            partial.signature
+        );
+
+        Ok(())
+    }
+
+    /// Publish a partner or creator fee claim to RabbitMQ
+    pub async fn publish_fee_claim(&self, claim: &FeeClaim) -> Result<()> {
+        self.publish_event(&self.config.fee_claim_routing_key, claim)
+            .await?;
+
+        debug!(
+            "📤 Published fee claim to RabbitMQ: {:?} on {} ({})",
+            claim.kind, claim.pool, claim.signature
+        );
+
+        Ok(())
+    }
+
+    /// Publish a graduation to an AMM pool to RabbitMQ
+    pub async fn publish_migration(&self, migration: &Migration) -> Result<()> {
+        self.publish_event(&self.config.migration_routing_key, migration)
+            .await?;
+
+        debug!(
+            "📤 Published migration to RabbitMQ: {} to {} ({})",
+            migration.token_address, migration.pool, migration.signature
+        );
+
+        Ok(())
+    }
+
+    /// Publish a bonding curve completion to RabbitMQ
+    pub async fn publish_graduation(&self, graduation: &Graduation) -> Result<()> {
+        self.publish_event(&self.config.graduation_routing_key, graduation)
+            .await?;
+
+        debug!(
+            "📤 Published graduation to RabbitMQ: {} ({})",
+            graduation.token_address, graduation.signature
+        );
+
+        Ok(())
+    }
+
+    /// Publish liquidity added to or removed from a pool to RabbitMQ
+    pub async fn publish_liquidity(&self, liquidity: &Liquidity) -> Result<()> {
+        self.publish_event(&self.config.liquidity_routing_key, liquidity)
+            .await?;
+
+        debug!(
+            "📤 Published liquidity {} on {} to RabbitMQ ({})",
+            liquidity.action.as_str(),
+            liquidity.pool,
+            liquidity.signature
+        );
+
+        Ok(())
+    }
+
+    /// Publish burned LP tokens of a pool to RabbitMQ
+    pub async fn publish_lp_burned(&self, burn: &LpBurned) -> Result<()> {
+        self.publish_event(&self.config.lp_burn_routing_key, burn)
+            .await?;
+
+        debug!(
+            "📤 Published LP burn of {} on {} to RabbitMQ ({})",
+            burn.amount, burn.pool, burn.signature
+        );
+
+        Ok(())
+    }
+
+    /// Publish bonding curve progress to RabbitMQ
+    pub async fn publish_curve_progress(&self, progress: &CurveProgress) -> Result<()> {
+        self.publish_event(&self.config.curve_progress_routing_key, progress)
+            .await?;
+
+        debug!(
+            "📤 Published {:.1}% curve progress of {} to RabbitMQ",
+            progress.percent_complete, progress.token_address
+        );
+
+        Ok(())
+    }
+
+    /// Publish a recognized instruction to RabbitMQ, under the routing key of its launchpad and type
+    pub async fn publish_program_event(&self, event: &ProgramEvent) -> Result<()> {
+        let launchpad = event.launchpad.to_string();
+        let routing_key = format!(
+            "{}.{}.{}",
+            self.config.event_routing_key_prefix,
+            launchpad.to_lowercase(),
+            event.event_type
+        );
+        self.publish_event(&routing_key, event).await?;
+
+        debug!(
+            "📤 Published {} event to RabbitMQ ({})",
+            event.event_type, event.signature
+        );
+
+        Ok(())
+    }
+
+    /// Publish a creator transfer to RabbitMQ
+    pub async fn publish_creator_transfer(&self, transfer: &CreatorTransfer) -> Result<()> {
+        self.publish_event(&self.config.creator_transfer_routing_key, transfer)
+            .await?;
+
+        debug!(
+            "📤 Published creator transfer of {} to RabbitMQ ({})",
+            transfer.token_address, transfer.signature
+        );
+
+        Ok(())
+    }
+
+    /// Publish the first buyers of a launch to RabbitMQ
+    pub async fn publish_first_buyers(&self, summary: &FirstBuyers) -> Result<()> {
+        self.publish_event(&self.config.first_buyers_routing_key, summary)
+            .await?;
+
+        debug!(
+            "📤 Published {} first buyers of {} to RabbitMQ",
+            summary.buyers.len(),
+            summary.token_address
+        );
+
+        Ok(())
+    }
+
+    /// Publish a creator selling their token to RabbitMQ
+    pub async fn publish_dev_sell(&self, sell: &DevSell) -> Result<()> {
+        self.publish_event(&self.config.dev_sell_routing_key, sell)
+            .await?;
+
+        debug!(
+            "📤 Published dev sell of {} to RabbitMQ ({})",
+            sell.token_address, sell.signature
+        );
+
+        Ok(())
+    }
+
+    /// Publish a revoked mint or freeze authority to RabbitMQ
+    pub async fn publish_authority_revoked(&self, revocation: &AuthorityRevoked) -> Result<()> {
+        self.publish_event(&self.config.authority_routing_key, revocation)
+            .await?;
+
+        debug!(
+            "📤 Published {} authority revocation of {} to RabbitMQ ({})",
+            revocation.authority.as_str(),
+            revocation.token_address,
+            revocation.signature
+        );
+
+        Ok(())
+    }
+
+    /// Publish a buy or sell to RabbitMQ, under the routing key of its launchpad
+    pub async fn publish_trade(&self, trade: &Trade) -> Result<()> {
+        let launchpad = trade.launchpad.to_string();
+        let routing_key = format!(
+            "{}.{}",
+            self.config.trade_routing_key_prefix,
+            launchpad.to_lowercase()
+        );
+        self.publish_event(&routing_key, trade).await?;
+
+        debug!(
+            "📤 Published {} of {} to RabbitMQ ({})",
+            if trade.is_buy { "buy" } else { "sell" },
+            trade.token_address,
+            trade.signature
+        );
+
+        Ok(())
+    }
+
+    /// Publish the trading status of a freshly launched token to RabbitMQ
+    pub async fn publish_token_status(&self, status: &TokenStatus) -> Result<()> {
+        self.publish_event(&self.config.status_routing_key, status)
+            .await?;
+
+        debug!(
+            "📤 Published token status to RabbitMQ: {} ({} early buyers)",
+            status.token_address,
+            status.early_buyers.len()
+        );
+
+        Ok(())
+    }
+
+    /// Publish an alert raised on a tracked token to RabbitMQ
+    pub async fn publish_alert(&self, alert: &TokenAlert) -> Result<()> {
+        self.publish_event(&self.config.alert_routing_key, alert)
+            .await?;
+
+        debug!("📤 Published token alert to RabbitMQ: {:?}", alert);
+
+        Ok(())
+    }
+
+    /// Publish a periodic activity digest to RabbitMQ
+    pub async fn publish_digest(&self, digest: &Digest) -> Result<()> {
+        self.publish_event(&self.config.digest_routing_key, digest)
+            .await?;
+
+        debug!(
+            "📤 Published digest to RabbitMQ: {} launches",
+            digest.total_launches
+        );
+
+        Ok(())
+    }
 
-            debug!(
-                "📤 Published token launch to RabbitMQ: {} ({})",
-                token_launch.token_address, token_launch.signature
-            );
+    /// Publish an operational error to RabbitMQ
+    pub async fn publish_ops_error(&self, error: &OpsError) -> Result<()> {
+        self.publish_event(&self.config.errors_routing_key, error)
+            .await?;
+
+        debug!(
+            "📤 Published ops error to RabbitMQ: {:?} in {}",
+            error.kind, error.component
+        );
+
+        Ok(())
+    }
+
+    /// Publish all events of a slot as one batch to RabbitMQ
+    pub async fn publish_slot_batch(&self, batch: &SlotBatch) -> Result<()> {
+        self.publish_event(&self.config.slot_batch_routing_key, batch)
+            .await?;
+
+        debug!(
+            "📤 Published slot batch to RabbitMQ: slot {} ({} events)",
+            batch.slot,
+            batch.events.len()
+        );
+
+        Ok(())
+    }
+
+    /// Publish all events of a transaction as one batch to RabbitMQ
+    pub async fn publish_transaction_batch(&self, batch: &TransactionBatch) -> Result<()> {
+        self.publish_event(&self.config.transaction_batch_routing_key, batch)
+            .await?;
+
+        debug!(
+            "📤 Published transaction batch to RabbitMQ: {} ({} events)",
+            batch.signature,
+            batch.events.len()
+        );
+
+        Ok(())
+    }
+
+    /// Publish an undecoded matched transaction to RabbitMQ
+    pub async fn publish_raw_transaction(&self, transaction: &RawTransaction) -> Result<()> {
+        self.publish_event(&self.config.raw_routing_key, transaction)
+            .await?;
+
+        debug!(
+            "📤 Published raw transaction to RabbitMQ: {}",
+            transaction.signature
+        );
+
+        Ok(())
+    }
+
+    /// Serialize an event to JSON and publish it with the given routing key
+    /// Reconnects, to the next node, when the current connection was lost
+    pub async fn publish_event<T: Serialize>(&self, routing_key: &str, event: &T) -> Result<()> {
+        // Serialize event to JSON
+        let payload = serde_json::to_vec(event).context("Failed to serialize event")?;
+
+        let mut properties = BasicProperties::default()
+            .with_content_type("application/json".into())
+            .with_delivery_mode(2); // Persistent message
+        if let Some(signer) = &self.signer {
+            properties = properties.with_headers(signer.headers(&payload));
+        }
+
+        let mut link = self.link.lock().await;
+        if link
+            .as_ref()
+            .is_none_or(|link| !link.connection.status().connected())
+        {
+            *link = Some(self.reconnect_link().await?);
+        }
+        let Some(current) = link.as_ref() else {
+            return Err(anyhow::anyhow!("RabbitMQ producer not initialized"));
+        };
 
-            Ok(())
-        } else {
-            Err(anyhow::anyhow!("RabbitMQ producer not initialized"))
+        // Publish message
+        let result = current
+            .channel
+            .basic_publish(
+                &self.config.exchange_name,
+                routing_key,
+                BasicPublishOptions::default(),
+                &payload,
+                properties,
+            )
+            .await
+            .context("Failed to publish message");
+        if result.is_err() && !current.connection.status().connected() {
+            // Next publish moves on to another node
+            *link = None;
         }
+        result?;
+
+        Ok(())
     }
 
-    /// Simple health-check helper
+    /// Simple health-check helper, `false` while a publish holds the connection
     pub fn is_connected(&self) -> bool {
-        if let Some(connection) = &self.connection {
-            connection.status().connected()
-        } else {
-            false
-        }
+        self.link.try_lock().is_ok_and(|link| {
+            link.as_ref()
+                .is_some_and(|link| link.connection.status().connected())
+        })
+    }
+
+    /// Attempt to reconnect on connection loss, moving on to the next node
+    pub async fn reconnect(&self) -> Result<()> {
+        let mut link = self.link.lock().await;
+        *link = Some(self.reconnect_link().await?);
+        Ok(())
     }
 
-    /// Attempt to reconnect on connection loss
-    pub async fn reconnect(&mut self) -> Result<()> {
+    /// Connects unless the last attempt failed less than RECONNECT_BACKOFF ago
+    async fn reconnect_link(&self) -> Result<BrokerLink> {
+        let mut retry_after = self.retry_after.lock().await;
+        if retry_after.is_some_and(|at| Instant::now() < at) {
+            return Err(anyhow::anyhow!(
+                "RabbitMQ unavailable, waiting to reconnect"
+            ));
+        }
+
         warn!("🔄 Attempting to reconnect to RabbitMQ...");
-        self.connection = None;
-        self.channel = None;
-        self.init().await
+        match self.connect().await {
+            Ok(link) => {
+                *retry_after = None;
+                Ok(link)
+            }
+            Err(e) => {
+                *retry_after = Some(Instant::now() + RECONNECT_BACKOFF);
+                Err(e)
+            }
+        }
     }
 
     /// Gracefully close connection
     pub async fn close(&self) -> Result<()> {
-        if let Some(connection) = &self.connection {
-            connection
+        if let Some(link) = self.link.lock().await.as_ref() {
+            link.connection
                 .close(200, "Normal shutdown")
                 .await
                 .context("Failed to close connection")?;
@@ -165,3 +522,104 @@ impl RabbitMQProducer {
         Ok(())
     }
 }
+
+impl EventSink for RabbitMQProducer {
+    fn publish_token_launch<'a>(&'a self, launch: &'a TokenLaunch) -> BoxFuture<'a, Result<()>> {
+        RabbitMQProducer::publish_token_launch(self, launch).boxed()
+    }
+
+    fn publish_partial_parse<'a>(&'a self, partial: &'a PartialParse) -> BoxFuture<'a, Result<()>> {
+        RabbitMQProducer::publish_partial_parse(self, partial).boxed()
+    }
+
+    fn publish_digest<'a>(&'a self, digest: &'a Digest) -> BoxFuture<'a, Result<()>> {
+        RabbitMQProducer::publish_digest(self, digest).boxed()
+    }
+
+    fn publish_ops_error<'a>(&'a self, error: &'a OpsError) -> BoxFuture<'a, Result<()>> {
+        RabbitMQProducer::publish_ops_error(self, error).boxed()
+    }
+
+    fn publish_slot_batch<'a>(&'a self, batch: &'a SlotBatch) -> BoxFuture<'a, Result<()>> {
+        RabbitMQProducer::publish_slot_batch(self, batch).boxed()
+    }
+
+    fn publish_transaction_batch<'a>(
+        &'a self,
+        batch: &'a TransactionBatch,
+    ) -> BoxFuture<'a, Result<()>> {
+        RabbitMQProducer::publish_transaction_batch(self, batch).boxed()
+    }
+
+    fn publish_fee_claim<'a>(&'a self, claim: &'a FeeClaim) -> BoxFuture<'a, Result<()>> {
+        RabbitMQProducer::publish_fee_claim(self, claim).boxed()
+    }
+
+    fn publish_migration<'a>(&'a self, migration: &'a Migration) -> BoxFuture<'a, Result<()>> {
+        RabbitMQProducer::publish_migration(self, migration).boxed()
+    }
+
+    fn publish_graduation<'a>(&'a self, graduation: &'a Graduation) -> BoxFuture<'a, Result<()>> {
+        RabbitMQProducer::publish_graduation(self, graduation).boxed()
+    }
+
+    fn publish_liquidity<'a>(&'a self, liquidity: &'a Liquidity) -> BoxFuture<'a, Result<()>> {
+        RabbitMQProducer::publish_liquidity(self, liquidity).boxed()
+    }
+
+    fn publish_lp_burned<'a>(&'a self, burn: &'a LpBurned) -> BoxFuture<'a, Result<()>> {
+        RabbitMQProducer::publish_lp_burned(self, burn).boxed()
+    }
+
+    fn publish_curve_progress<'a>(
+        &'a self,
+        progress: &'a CurveProgress,
+    ) -> BoxFuture<'a, Result<()>> {
+        RabbitMQProducer::publish_curve_progress(self, progress).boxed()
+    }
+
+    fn publish_program_event<'a>(&'a self, event: &'a ProgramEvent) -> BoxFuture<'a, Result<()>> {
+        RabbitMQProducer::publish_program_event(self, event).boxed()
+    }
+
+    fn publish_creator_transfer<'a>(
+        &'a self,
+        transfer: &'a CreatorTransfer,
+    ) -> BoxFuture<'a, Result<()>> {
+        RabbitMQProducer::publish_creator_transfer(self, transfer).boxed()
+    }
+
+    fn publish_first_buyers<'a>(&'a self, summary: &'a FirstBuyers) -> BoxFuture<'a, Result<()>> {
+        RabbitMQProducer::publish_first_buyers(self, summary).boxed()
+    }
+
+    fn publish_dev_sell<'a>(&'a self, sell: &'a DevSell) -> BoxFuture<'a, Result<()>> {
+        RabbitMQProducer::publish_dev_sell(self, sell).boxed()
+    }
+
+    fn publish_authority_revoked<'a>(
+        &'a self,
+        revocation: &'a AuthorityRevoked,
+    ) -> BoxFuture<'a, Result<()>> {
+        RabbitMQProducer::publish_authority_revoked(self, revocation).boxed()
+    }
+
+    fn publish_trade<'a>(&'a self, trade: &'a Trade) -> BoxFuture<'a, Result<()>> {
+        RabbitMQProducer::publish_trade(self, trade).boxed()
+    }
+
+    fn publish_token_status<'a>(&'a self, status: &'a TokenStatus) -> BoxFuture<'a, Result<()>> {
+        RabbitMQProducer::publish_token_status(self, status).boxed()
+    }
+
+    fn publish_alert<'a>(&'a self, alert: &'a TokenAlert) -> BoxFuture<'a, Result<()>> {
+        RabbitMQProducer::publish_alert(self, alert).boxed()
+    }
+
+    fn publish_raw_transaction<'a>(
+        &'a self,
+        transaction: &'a RawTransaction,
+    ) -> BoxFuture<'a, Result<()>> {
+        RabbitMQProducer::publish_raw_transaction(self, transaction).boxed()
+    }
+}
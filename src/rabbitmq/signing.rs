@@ -0,0 +1,94 @@
+use anyhow::{Context, Result, bail};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use lapin::types::{AMQPValue, FieldTable};
+
+/// Header carrying the base58 ed25519 signature of the message body
+pub const SIGNATURE_HEADER: &str = "x-signature";
+/// Header carrying the base58 public key of the signing service
+pub const SIGNER_HEADER: &str = "x-signer";
+
+/// Signs published payloads with the service key
+pub struct EventSigner {
+    key: SigningKey,
+    signer: String,
+}
+
+impl EventSigner {
+    /// Loads the key from a file holding the base64 encoded 32 byte secret seed
+    pub fn from_file(path: &str) -> Result<Self> {
+        let encoded = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read signing key {}", path))?;
+        let seed = BASE64
+            .decode(encoded.trim())
+            .context("Signing key is not valid base64")?;
+        let Ok(seed) = <[u8; 32]>::try_from(seed.as_slice()) else {
+            bail!("Signing key must be 32 bytes, got {}", seed.len());
+        };
+
+        let key = SigningKey::from_bytes(&seed);
+        let signer = bs58::encode(key.verifying_key().as_bytes()).into_string();
+
+        Ok(Self { key, signer })
+    }
+
+    /// Base58 public key consumers should trust
+    pub fn signer(&self) -> &str {
+        &self.signer
+    }
+
+    /// Headers to attach to a message with the given body
+    pub fn headers(&self, payload: &[u8]) -> FieldTable {
+        let signature = self.key.sign(payload);
+
+        let mut headers = FieldTable::default();
+        headers.insert(
+            SIGNATURE_HEADER.into(),
+            AMQPValue::LongString(bs58::encode(signature.to_bytes()).into_string().into()),
+        );
+        headers.insert(
+            SIGNER_HEADER.into(),
+            AMQPValue::LongString(self.signer.clone().into()),
+        );
+        headers
+    }
+}
+
+/// Checks that consumed payloads were signed by the trusted service key
+pub struct EventVerifier {
+    key: VerifyingKey,
+}
+
+impl EventVerifier {
+    /// Builds a verifier from the base58 public key of the producer
+    pub fn from_pubkey(pubkey: &str) -> Result<Self> {
+        let bytes = bs58::decode(pubkey)
+            .into_vec()
+            .context("Verify key is not valid base58")?;
+        let Ok(bytes) = <[u8; 32]>::try_from(bytes.as_slice()) else {
+            bail!("Verify key must be 32 bytes, got {}", bytes.len());
+        };
+        let key = VerifyingKey::from_bytes(&bytes).context("Invalid ed25519 public key")?;
+
+        Ok(Self { key })
+    }
+
+    pub fn verify(&self, payload: &[u8], headers: Option<&FieldTable>) -> Result<()> {
+        let Some(signature) = headers.and_then(|h| h.inner().get(SIGNATURE_HEADER)) else {
+            bail!("Message is not signed");
+        };
+        let AMQPValue::LongString(signature) = signature else {
+            bail!("Malformed {} header", SIGNATURE_HEADER);
+        };
+
+        let bytes = bs58::decode(signature.as_bytes())
+            .into_vec()
+            .context("Signature is not valid base58")?;
+        let signature = Signature::from_slice(&bytes).context("Malformed signature")?;
+
+        self.key
+            .verify(payload, &signature)
+            .context("Signature does not match payload")
+    }
+}
@@ -0,0 +1,261 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use futures::{StreamExt, stream};
+use serde_json::{Value, json};
+use std::collections::HashSet;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+use crate::clock::{self, SharedClock};
+use crate::config::backfill::BackfillConfig;
+use crate::geyser::{
+    GapDetector, QueuedTransaction, SlotGap, TransactionInstruction, TransactionQueue,
+    TransactionVersion,
+};
+use crate::metrics;
+use crate::rpc::RpcClient;
+
+/// Blocks fetched at once, the RPC client's own concurrency limit still applies
+const BLOCK_FETCH_CONCURRENCY: usize = 4;
+
+/// Fetches blocks the stream skipped from RPC and queues their transactions for parsing,
+/// flagged as backfilled
+pub struct Backfiller {
+    rpc: RpcClient,
+    queue: TransactionQueue,
+    gaps: GapDetector,
+    program_ids: HashSet<String>,
+    config: BackfillConfig,
+    clock: SharedClock,
+}
+
+impl Backfiller {
+    /// Only transactions calling one of `program_ids` are queued
+    pub fn new(
+        rpc: RpcClient,
+        queue: TransactionQueue,
+        gaps: GapDetector,
+        program_ids: Vec<String>,
+        config: BackfillConfig,
+    ) -> Self {
+        Self {
+            rpc,
+            queue,
+            gaps,
+            program_ids: program_ids.into_iter().collect(),
+            config,
+            clock: clock::system(),
+        }
+    }
+
+    /// Replaces the clock the backfill delay is measured and transactions are stamped with
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Backfills gaps as they are detected, one at a time
+    pub fn start(self, mut receiver: mpsc::UnboundedReceiver<SlotGap>) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            while let Some(gap) = receiver.recv().await {
+                let waited = self.clock.instant().duration_since(gap.detected_at);
+                let delay = Duration::from_millis(self.config.delay_ms);
+                tokio::time::sleep(delay.saturating_sub(waited)).await;
+
+                match self.backfill(gap).await {
+                    Ok(queued) => info!(
+                        "🩹 Backfilled slots {}..={}, {} transactions queued",
+                        gap.first, gap.last, queued
+                    ),
+                    Err(e) => {
+                        warn!(
+                            "Backfill of slots {}..={} failed: {:#}",
+                            gap.first, gap.last, e
+                        );
+                        metrics::global().inc_counter("backfill_errors_total", &[]);
+                    }
+                }
+            }
+        })
+    }
+
+    /// Queues the relevant transactions of the gap's blocks. Long gaps are cut to their
+    /// most recent `max_slots` slots. Returns the number of transactions queued.
+    async fn backfill(&self, gap: SlotGap) -> Result<usize> {
+        let first = gap
+            .first
+            .max(gap.last.saturating_sub(self.config.max_slots - 1));
+        if first > gap.first {
+            warn!(
+                "Gap of {} slots exceeds BACKFILL_MAX_SLOTS, skipping slots {}..{}",
+                gap.slots(),
+                gap.first,
+                first
+            );
+            metrics::global().add_counter("backfill_skipped_slots_total", &[], first - gap.first);
+        }
+
+        // Blocks that arrived after all, out of order, are not fetched again
+        let slots: Vec<u64> = self
+            .rpc
+            .call(
+                "getBlocks",
+                json!([first, gap.last, { "commitment": "confirmed" }]),
+            )
+            .await?
+            .as_array()
+            .context("getBlocks returned no slot array")?
+            .iter()
+            .filter_map(Value::as_u64)
+            .filter(|slot| !self.gaps.seen(*slot))
+            .collect();
+
+        let mut blocks = stream::iter(slots)
+            .map(|slot| async move { (slot, self.fetch_block(slot).await) })
+            .buffered(BLOCK_FETCH_CONCURRENCY);
+
+        let mut queued = 0;
+        while let Some((slot, result)) = blocks.next().await {
+            match result {
+                Ok(transactions) => {
+                    metrics::global().inc_counter("backfill_blocks_total", &[]);
+                    queued += transactions.len();
+                    for transaction in transactions {
+                        self.queue.push(transaction).await;
+                    }
+                }
+                Err(e) => {
+                    debug!("Could not backfill block {}: {:#}", slot, e);
+                    metrics::global().inc_counter("backfill_errors_total", &[]);
+                }
+            }
+        }
+        metrics::global().add_counter("backfill_transactions_total", &[], queued as u64);
+        Ok(queued)
+    }
+
+    /// Fetches a block and decodes its successful transactions calling a parsed program
+    async fn fetch_block(&self, slot: u64) -> Result<Vec<QueuedTransaction>> {
+        let block = self
+            .rpc
+            .call(
+                "getBlock",
+                json!([slot, {
+                    "encoding": "json",
+                    "transactionDetails": "full",
+                    "maxSupportedTransactionVersion": 0,
+                    "rewards": false,
+                    "commitment": "confirmed",
+                }]),
+            )
+            .await?;
+
+        let block_time = block["blockTime"]
+            .as_i64()
+            .and_then(|unix| DateTime::from_timestamp(unix, 0));
+
+        let transactions = block["transactions"]
+            .as_array()
+            .context("getBlock returned no transactions")?;
+        let received_time = self.clock.now();
+        Ok(transactions
+            .iter()
+            .enumerate()
+            .filter_map(|(index, tx)| {
+                decode_transaction(slot, Some(index as u64), block_time, received_time, tx)
+            })
+            .filter(|tx| {
+                tx.instructions
+                    .iter()
+                    .chain(&tx.inner_instructions)
+                    .any(|instr| self.program_ids.contains(&instr.program_id))
+            })
+            .collect())
+    }
+}
+
+/// Decodes a `json` encoded block transaction the way the Geyser client decodes updates.
+/// Failed transactions are skipped.
+pub(crate) fn decode_transaction(
+    slot: u64,
+    index: Option<u64>,
+    block_time: Option<DateTime<Utc>>,
+    received_time: DateTime<Utc>,
+    tx: &Value,
+) -> Option<QueuedTransaction> {
+    let meta = &tx["meta"];
+    if !meta["err"].is_null() {
+        return None;
+    }
+    let message = &tx["transaction"]["message"];
+    let signature = tx["transaction"]["signatures"][0].as_str()?.to_string();
+
+    // Addresses loaded from lookup tables follow the static keys
+    let strings = |value: &Value| -> Vec<String> {
+        value
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|key| key.as_str().map(str::to_string))
+            .collect()
+    };
+    let mut accounts = strings(&message["accountKeys"]);
+    accounts.extend(strings(&meta["loadedAddresses"]["writable"]));
+    accounts.extend(strings(&meta["loadedAddresses"]["readonly"]));
+
+    let signer_count = message["header"]["numRequiredSignatures"]
+        .as_u64()
+        .unwrap_or(1) as usize;
+    let signers: Vec<String> = accounts.iter().take(signer_count).cloned().collect();
+
+    let instruction = |value: &Value| {
+        Some(TransactionInstruction {
+            program_id: accounts
+                .get(value["programIdIndex"].as_u64()? as usize)?
+                .clone(),
+            accounts: value["accounts"]
+                .as_array()?
+                .iter()
+                .filter_map(|idx| idx.as_u64().map(|idx| idx as u8))
+                .collect(),
+            data: bs58::decode(value["data"].as_str()?).into_vec().ok()?,
+            truncated: false,
+        })
+    };
+    let instructions = message["instructions"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(instruction)
+        .collect();
+    let inner_instructions = meta["innerInstructions"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .flat_map(|inner| inner["instructions"].as_array().into_iter().flatten())
+        .filter_map(instruction)
+        .collect();
+
+    Some(QueuedTransaction {
+        signature,
+        slot,
+        transaction_index: index,
+        received_time,
+        block_time,
+        backfilled: true,
+        // Only transactions calling a parsed program are kept, never votes
+        is_vote: false,
+        version: match tx["version"].as_u64() {
+            Some(_) => TransactionVersion::V0,
+            None => TransactionVersion::Legacy,
+        },
+        fee_payer: signers.first().cloned(),
+        signers,
+        accounts,
+        instructions,
+        inner_instructions,
+        raw: None,
+    })
+}
@@ -0,0 +1,164 @@
+use anyhow::{Context, Result, anyhow, bail};
+use futures::future::try_join_all;
+use serde_json::{Value, json};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tracing::{debug, warn};
+
+use crate::config::enrichment::EnrichmentConfig;
+use crate::metrics;
+
+/// Maximum number of keys accepted by a single getMultipleAccounts call
+const MAX_ACCOUNTS_PER_CALL: usize = 100;
+
+/// Concurrency-limited Solana JSON-RPC client with retry and jittered backoff
+#[derive(Clone)]
+pub struct RpcClient {
+    endpoint: String,
+    http: reqwest::Client,
+    permits: Arc<Semaphore>,
+    max_retries: u32,
+    retry_base: Duration,
+}
+
+impl RpcClient {
+    pub fn new(endpoint: String, config: &EnrichmentConfig) -> Self {
+        Self {
+            endpoint,
+            http: reqwest::Client::new(),
+            permits: Arc::new(Semaphore::new(config.max_concurrency)),
+            max_retries: config.max_retries,
+            retry_base: Duration::from_millis(config.retry_base_ms),
+        }
+    }
+
+    /// Fetches accounts with `jsonParsed` encoding at confirmed commitment,
+    /// one getMultipleAccounts call per 100 keys.
+    /// Returned values are in the same order as `keys`; missing accounts are `None`.
+    pub async fn get_multiple_accounts(&self, keys: &[String]) -> Result<Vec<Option<Value>>> {
+        let calls = keys.chunks(MAX_ACCOUNTS_PER_CALL).map(|chunk| async move {
+            let result = self
+                .call(
+                    "getMultipleAccounts",
+                    json!([chunk, { "encoding": "jsonParsed", "commitment": "confirmed" }]),
+                )
+                .await?;
+
+            let values = result["value"]
+                .as_array()
+                .context("getMultipleAccounts returned no value array")?;
+            if values.len() != chunk.len() {
+                bail!(
+                    "getMultipleAccounts returned {} accounts for {} keys",
+                    values.len(),
+                    chunk.len()
+                );
+            }
+
+            Ok(values
+                .iter()
+                .map(|v| (!v.is_null()).then(|| v.clone()))
+                .collect::<Vec<_>>())
+        });
+
+        Ok(try_join_all(calls).await?.into_iter().flatten().collect())
+    }
+
+    /// Sends several calls as a single JSON-RPC batch request.
+    /// Results are in call order; calls answered with an error are `None`.
+    pub async fn call_batch(&self, calls: &[(&str, Value)]) -> Result<Vec<Option<Value>>> {
+        let body: Vec<Value> = calls
+            .iter()
+            .enumerate()
+            .map(|(id, (method, params))| {
+                json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params })
+            })
+            .collect();
+
+        let response = self.send(&Value::Array(body)).await?;
+        let responses = response
+            .as_array()
+            .context("batch response is not an array")?;
+
+        let mut results = vec![None; calls.len()];
+        for response in responses {
+            let Some(id) = response["id"].as_u64().map(|id| id as usize) else {
+                continue;
+            };
+            if id >= results.len() {
+                continue;
+            }
+            if let Some(error) = response.get("error") {
+                warn!("⚠️ RPC {} failed in batch: {}", calls[id].0, error);
+                continue;
+            }
+            results[id] = response.get("result").cloned();
+        }
+
+        Ok(results)
+    }
+
+    /// Sends a single JSON-RPC call and returns its `result`
+    pub async fn call(&self, method: &str, params: Value) -> Result<Value> {
+        let body = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        let mut response = self.send(&body).await?;
+
+        if let Some(error) = response.get("error") {
+            return Err(anyhow!("RPC {} failed: {}", method, error));
+        }
+
+        response
+            .get_mut("result")
+            .map(Value::take)
+            .with_context(|| format!("RPC {} returned no result", method))
+    }
+
+    /// Posts a request body, holding a concurrency permit and retrying
+    /// rate limits, server errors and transport failures with jittered backoff
+    async fn send(&self, body: &Value) -> Result<Value> {
+        let _permit = self.permits.acquire().await?;
+        let mut attempt = 0;
+
+        loop {
+            metrics::global().inc_counter("rpc_requests_total", &[]);
+
+            let error = match self.http.post(&self.endpoint).json(body).send().await {
+                Ok(response) => {
+                    let status = response.status();
+                    if status.is_success() {
+                        return response
+                            .json()
+                            .await
+                            .context("Failed to decode RPC response");
+                    }
+                    if status.as_u16() != 429 && !status.is_server_error() {
+                        bail!("RPC request failed with status {}", status);
+                    }
+                    anyhow!("RPC request failed with status {}", status)
+                }
+                Err(e) => anyhow!(e).context("RPC request failed"),
+            };
+
+            if attempt >= self.max_retries {
+                return Err(error);
+            }
+
+            let delay = self.backoff(attempt);
+            metrics::global().inc_counter("rpc_retries_total", &[]);
+            debug!("{:#}, retrying in {:?}", error, delay);
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// Exponential backoff with up to one base interval of random jitter
+    fn backoff(&self, attempt: u32) -> Duration {
+        let base = self.retry_base.as_millis() as u64;
+        let exponential = base.saturating_mul(1 << attempt.min(10));
+        let jitter = RandomState::new().build_hasher().finish() % base.max(1);
+        Duration::from_millis(exponential + jitter)
+    }
+}
@@ -0,0 +1,7 @@
+pub mod backfill;
+pub mod client;
+pub mod refetch;
+
+pub use backfill::Backfiller;
+pub use client::RpcClient;
+pub use refetch::Refetcher;
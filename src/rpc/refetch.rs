@@ -0,0 +1,140 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde_json::json;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tokio::sync::mpsc::{self, error::TrySendError};
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+use crate::config::refetch::RefetchConfig;
+use crate::geyser::{QueuedTransaction, TransactionQueue};
+use crate::metrics;
+use crate::rpc::RpcClient;
+use crate::rpc::backfill::decode_transaction;
+
+/// Signatures waiting for their refetch
+const REFETCH_BUFFER: usize = 1024;
+
+/// A matched transaction whose update carried no instructions
+struct EmptyTransaction {
+    signature: String,
+    slot: u64,
+    received_time: DateTime<Utc>,
+}
+
+/// Fetches transactions the stream delivered without instructions from RPC and queues them
+/// for parsing
+#[derive(Clone)]
+pub struct Refetcher {
+    sender: mpsc::Sender<EmptyTransaction>,
+}
+
+impl Refetcher {
+    /// Starts the refetch task
+    pub fn start(
+        rpc: RpcClient,
+        queue: TransactionQueue,
+        config: RefetchConfig,
+    ) -> (Self, JoinHandle<()>) {
+        let (sender, mut receiver) = mpsc::channel::<EmptyTransaction>(REFETCH_BUFFER);
+
+        let handle = tokio::spawn(async move {
+            info!(
+                "🔁 Refetching transactions without instructions after {}ms",
+                config.delay_ms
+            );
+
+            // Refetches wait out their delay before calling RPC, so the RPC client's
+            // concurrency limit alone would not bound the tasks in flight
+            let permits = Arc::new(Semaphore::new(config.concurrency));
+            while let Some(empty) = receiver.recv().await {
+                let Ok(permit) = permits.clone().acquire_owned().await else {
+                    break;
+                };
+                let rpc = rpc.clone();
+                let queue = queue.clone();
+                let config = config.clone();
+                tokio::spawn(async move {
+                    let _permit = permit;
+                    match refetch(&rpc, &config, &empty).await {
+                        Ok(Some(transaction)) => {
+                            metrics::global().inc_counter("refetch_transactions_total", &[]);
+                            queue.push(transaction).await;
+                        }
+                        Ok(None) => {
+                            debug!("Refetched {} has no instructions either", empty.signature);
+                            metrics::global().inc_counter("refetch_empty_total", &[]);
+                        }
+                        Err(e) => {
+                            debug!("Could not refetch {}: {:#}", empty.signature, e);
+                            metrics::global().inc_counter("refetch_errors_total", &[]);
+                        }
+                    }
+                });
+            }
+        });
+
+        (Self { sender }, handle)
+    }
+
+    /// Schedules a refetch of the transaction
+    pub fn request(&self, signature: String, slot: u64, received_time: DateTime<Utc>) {
+        let empty = EmptyTransaction {
+            signature,
+            slot,
+            received_time,
+        };
+        match self.sender.try_send(empty) {
+            Ok(()) => {}
+            Err(TrySendError::Full(_)) => {
+                metrics::global().inc_counter("refetch_dropped_total", &[]);
+            }
+            Err(TrySendError::Closed(empty)) => {
+                warn!("⚠️ Refetcher stopped, dropping {}", empty.signature);
+            }
+        }
+    }
+}
+
+/// Fetches the transaction once confirmed, retrying while RPC does not know it yet.
+/// Returns `None` for failed transactions and ones without instructions.
+async fn refetch(
+    rpc: &RpcClient,
+    config: &RefetchConfig,
+    empty: &EmptyTransaction,
+) -> Result<Option<QueuedTransaction>> {
+    let delay = Duration::from_millis(config.delay_ms);
+    for _ in 0..config.attempts {
+        tokio::time::sleep(delay).await;
+
+        let tx = rpc
+            .call(
+                "getTransaction",
+                json!([empty.signature, {
+                    "encoding": "json",
+                    "maxSupportedTransactionVersion": 0,
+                    "commitment": "confirmed",
+                }]),
+            )
+            .await?;
+        if tx.is_null() {
+            continue;
+        }
+
+        let block_time = tx["blockTime"]
+            .as_i64()
+            .and_then(|unix| DateTime::from_timestamp(unix, 0));
+        let slot = tx["slot"].as_u64().unwrap_or(empty.slot);
+        let transaction = decode_transaction(slot, None, block_time, empty.received_time, &tx).map(
+            |transaction| QueuedTransaction {
+                backfilled: false,
+                ..transaction
+            },
+        );
+        return Ok(transaction
+            .filter(|tx| !tx.instructions.is_empty() || !tx.inner_instructions.is_empty()));
+    }
+    anyhow::bail!("not found after {} attempts", config.attempts)
+}
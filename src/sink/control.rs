@@ -0,0 +1,38 @@
+use serde::Deserialize;
+use tracing::info;
+
+use crate::sink::{PausableSink, ThrottledSink};
+
+/// Flow control command sent by consumers, e.g. `{"command":"slow_down","max_per_sec":50}`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum ControlCommand {
+    Pause,
+    Resume,
+    SlowDown { max_per_sec: u32 },
+    Sample { event: String, rate: f64 },
+    Reset,
+}
+
+/// Publishing controls that commands act on
+#[derive(Clone)]
+pub struct PublishControls {
+    pub publish: PausableSink,
+    pub throttle: ThrottledSink,
+}
+
+impl PublishControls {
+    pub async fn apply(&self, command: ControlCommand) {
+        info!("🎛️  Applying control command: {:?}", command);
+
+        match command {
+            ControlCommand::Pause => self.publish.pause(),
+            ControlCommand::Resume => self.publish.resume().await,
+            ControlCommand::SlowDown { max_per_sec } => {
+                self.throttle.set_rate_limit(Some(max_per_sec))
+            }
+            ControlCommand::Sample { event, rate } => self.throttle.set_sample_rate(&event, rate),
+            ControlCommand::Reset => self.throttle.reset(),
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use futures::FutureExt;
+use futures::future::BoxFuture;
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::fs::{self, OpenOptions};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+use tracing::{info, warn};
+
+use crate::clock::SharedClock;
+use crate::config::history::HistoryConfig;
+use crate::parser::TokenLaunch;
+use crate::sink::EventSink;
+
+struct HistoryState {
+    launches: VecDeque<TokenLaunch>,
+    /// Lines in the file, including ones already expired from memory
+    file_lines: usize,
+}
+
+/// Keeps launches from the retention window in memory and in an append-only JSONL file,
+/// so consumers joining late can catch up before switching to the live stream
+#[derive(Clone)]
+pub struct LaunchHistory {
+    inner: Arc<dyn EventSink>,
+    path: PathBuf,
+    retention: Duration,
+    state: Arc<Mutex<HistoryState>>,
+    clock: SharedClock,
+}
+
+/// Adds a launch, replacing the earlier publish of the same launch for enrichment updates
+fn upsert(launches: &mut VecDeque<TokenLaunch>, launch: TokenLaunch) {
+    if launch.enrichment_update
+        && let Some(earlier) = launches.iter_mut().rev().find(|earlier| {
+            earlier.signature == launch.signature && earlier.token_address == launch.token_address
+        })
+    {
+        *earlier = launch;
+        return;
+    }
+    launches.push_back(launch);
+}
+
+impl LaunchHistory {
+    /// Loads retained launches from disk and compacts the file. The retention window is
+    /// measured with `clock`.
+    pub async fn open(
+        config: &HistoryConfig,
+        inner: Arc<dyn EventSink>,
+        clock: SharedClock,
+    ) -> Result<Self> {
+        let path = PathBuf::from(&config.path);
+        let retention = Duration::hours(config.retention_hours as i64);
+        let cutoff = clock.now() - retention;
+
+        let mut launches = VecDeque::new();
+        match fs::read_to_string(&path).await {
+            Ok(content) => {
+                for line in content.lines() {
+                    match serde_json::from_str::<TokenLaunch>(line) {
+                        Ok(launch) if launch.timestamp >= cutoff => upsert(&mut launches, launch),
+                        Ok(_) => {}
+                        Err(e) => warn!("Skipping unreadable launch history line: {}", e),
+                    }
+                }
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e).context("Failed to read launch history"),
+        }
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .await
+                .context("Failed to create launch history directory")?;
+        }
+
+        let history = Self {
+            inner,
+            path,
+            retention,
+            state: Arc::new(Mutex::new(HistoryState {
+                launches,
+                file_lines: 0,
+            })),
+            clock,
+        };
+
+        let mut state = history.state.lock().await;
+        history.compact(&mut state).await?;
+        info!(
+            "📚 Launch history loaded {} launches from the last {}h",
+            state.launches.len(),
+            config.retention_hours
+        );
+        drop(state);
+
+        Ok(history)
+    }
+
+    /// Launches detected at or after `since`, oldest first
+    pub async fn since(&self, since: DateTime<Utc>) -> Vec<TokenLaunch> {
+        let state = self.state.lock().await;
+        state
+            .launches
+            .iter()
+            .filter(|launch| launch.timestamp >= since)
+            .cloned()
+            .collect()
+    }
+
+    /// Retention window in hours
+    pub fn retention_hours(&self) -> i64 {
+        self.retention.num_hours()
+    }
+
+    async fn record(&self, launch: &TokenLaunch) -> Result<()> {
+        let mut state = self.state.lock().await;
+
+        let cutoff = self.clock.now() - self.retention;
+        while state
+            .launches
+            .front()
+            .is_some_and(|oldest| oldest.timestamp < cutoff)
+        {
+            state.launches.pop_front();
+        }
+        upsert(&mut state.launches, launch.clone());
+
+        // Rewrite once expired lines make up more than half the file
+        if state.file_lines >= 2 * state.launches.len() {
+            return self.compact(&mut state).await;
+        }
+
+        let mut line = serde_json::to_vec(launch)?;
+        line.push(b'\n');
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await?;
+        file.write_all(&line).await?;
+        state.file_lines += 1;
+
+        Ok(())
+    }
+
+    /// Rewrites the file with only the launches still retained
+    async fn compact(&self, state: &mut HistoryState) -> Result<()> {
+        let mut content = Vec::new();
+        for launch in &state.launches {
+            serde_json::to_writer(&mut content, launch)?;
+            content.push(b'\n');
+        }
+
+        let tmp = self.path.with_extension("jsonl.tmp");
+        fs::write(&tmp, &content).await?;
+        fs::rename(&tmp, &self.path).await?;
+        state.file_lines = state.launches.len();
+
+        Ok(())
+    }
+}
+
+impl EventSink for LaunchHistory {
+    fn inner(&self) -> Option<&dyn EventSink> {
+        Some(self.inner.as_ref())
+    }
+
+    fn publish_token_launch<'a>(&'a self, launch: &'a TokenLaunch) -> BoxFuture<'a, Result<()>> {
+        async move {
+            if let Err(e) = self.record(launch).await {
+                warn!("Failed to persist launch {}: {}", launch.signature, e);
+            }
+            self.inner.publish_token_launch(launch).await
+        }
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::{Clock, MockClock};
+    use crate::testing::{RecordingSink, launch, start_time};
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn launches_expire_after_retention() {
+        let clock = MockClock::new(start_time());
+        let path = std::env::temp_dir().join(format!("launch-history-{}.jsonl", Uuid::new_v4()));
+        let config = HistoryConfig {
+            enabled: true,
+            path: path.to_string_lossy().into_owned(),
+            retention_hours: 1,
+        };
+        let history =
+            LaunchHistory::open(&config, Arc::new(RecordingSink::default()), clock.shared())
+                .await
+                .unwrap();
+        let long_ago = start_time() - Duration::days(1);
+
+        history
+            .publish_token_launch(&launch("old", 1, clock.now()))
+            .await
+            .unwrap();
+        clock.advance(std::time::Duration::from_secs(61 * 60));
+        history
+            .publish_token_launch(&launch("new", 2, clock.now()))
+            .await
+            .unwrap();
+        let kept: Vec<String> = history
+            .since(long_ago)
+            .await
+            .into_iter()
+            .map(|launch| launch.signature)
+            .collect();
+        assert_eq!(kept, vec!["new"]);
+
+        // Reloading applies the retention window too
+        clock.advance(std::time::Duration::from_secs(30 * 60));
+        let reopened =
+            LaunchHistory::open(&config, Arc::new(RecordingSink::default()), clock.shared())
+                .await
+                .unwrap();
+        assert_eq!(reopened.since(long_ago).await.len(), 1);
+
+        clock.advance(std::time::Duration::from_secs(31 * 60));
+        let reopened =
+            LaunchHistory::open(&config, Arc::new(RecordingSink::default()), clock.shared())
+                .await
+                .unwrap();
+        assert!(reopened.since(long_ago).await.is_empty());
+
+        let _ = std::fs::remove_file(path);
+    }
+}
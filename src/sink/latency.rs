@@ -0,0 +1,53 @@
+use anyhow::Result;
+use futures::FutureExt;
+use futures::future::BoxFuture;
+use std::sync::Arc;
+
+use crate::monitor::{LatencyGossip, LatencyTracker};
+use crate::parser::TokenLaunch;
+use crate::sink::EventSink;
+
+/// Sink wrapper recording the detection latency of launches handed to publishing.
+/// Other events pass through.
+#[derive(Clone)]
+pub struct LatencySink {
+    inner: Arc<dyn EventSink>,
+    tracker: LatencyTracker,
+    gossip: Option<LatencyGossip>,
+}
+
+impl LatencySink {
+    pub fn new(inner: Arc<dyn EventSink>, tracker: LatencyTracker) -> Self {
+        Self {
+            inner,
+            tracker,
+            gossip: None,
+        }
+    }
+
+    /// Also shares launches with the other instances comparing detection latency
+    pub fn with_gossip(mut self, gossip: LatencyGossip) -> Self {
+        self.gossip = Some(gossip);
+        self
+    }
+}
+
+impl EventSink for LatencySink {
+    fn inner(&self) -> Option<&dyn EventSink> {
+        Some(self.inner.as_ref())
+    }
+
+    fn publish_token_launch<'a>(&'a self, launch: &'a TokenLaunch) -> BoxFuture<'a, Result<()>> {
+        async move {
+            // Updates after a retried enrichment would measure the retry delay
+            if !launch.enrichment_update {
+                self.tracker.record(launch);
+                if let Some(gossip) = &self.gossip {
+                    gossip.observe(launch);
+                }
+            }
+            self.inner.publish_token_launch(launch).await
+        }
+        .boxed()
+    }
+}
@@ -0,0 +1,174 @@
+pub mod control;
+pub mod history;
+pub mod latency;
+pub mod pausable;
+pub mod raw;
+pub mod slot_batch;
+pub mod throttle;
+pub mod transaction_batch;
+
+use anyhow::Result;
+use futures::FutureExt;
+use futures::future::{self, BoxFuture};
+
+use crate::monitor::{Digest, OpsError, TokenAlert, TokenStatus};
+use crate::parser::{
+    AuthorityRevoked, CreatorTransfer, CurveProgress, DevSell, FeeClaim, FirstBuyers, Graduation,
+    Liquidity, LpBurned, Migration, PartialParse, ProgramEvent, TokenLaunch, Trade,
+};
+
+pub use control::{ControlCommand, PublishControls};
+pub use history::LaunchHistory;
+pub use latency::LatencySink;
+pub use pausable::PausableSink;
+pub use raw::{RawPassthrough, RawSink, RawTransaction};
+pub use slot_batch::{SlotBatch, SlotBatchSink, SlotEvent};
+pub use throttle::ThrottledSink;
+pub use transaction_batch::{TransactionBatch, TransactionBatchSink};
+
+/// Destination for parsed events, keeps the parsing pipeline independent of the transport.
+///
+/// Every event has a default implementation handing it to [`EventSink::inner`], so wrappers
+/// only implement the events they act on, and sinks without an inner one drop the events they
+/// do not implement.
+pub trait EventSink: Send + Sync {
+    /// Sink this one wraps, `None` for sinks publishing events themselves
+    fn inner(&self) -> Option<&dyn EventSink> {
+        None
+    }
+
+    /// Publish a detected token launch
+    fn publish_token_launch<'a>(&'a self, launch: &'a TokenLaunch) -> BoxFuture<'a, Result<()>> {
+        forward(self.inner(), |sink| sink.publish_token_launch(launch))
+    }
+
+    /// Publish a partially extracted launch
+    fn publish_partial_parse<'a>(&'a self, partial: &'a PartialParse) -> BoxFuture<'a, Result<()>> {
+        forward(self.inner(), |sink| sink.publish_partial_parse(partial))
+    }
+
+    /// Publish a partner or creator fee claim
+    fn publish_fee_claim<'a>(&'a self, claim: &'a FeeClaim) -> BoxFuture<'a, Result<()>> {
+        forward(self.inner(), |sink| sink.publish_fee_claim(claim))
+    }
+
+    /// Publish a graduation to an AMM pool
+    fn publish_migration<'a>(&'a self, migration: &'a Migration) -> BoxFuture<'a, Result<()>> {
+        forward(self.inner(), |sink| sink.publish_migration(migration))
+    }
+
+    /// Publish a bonding curve completion
+    fn publish_graduation<'a>(&'a self, graduation: &'a Graduation) -> BoxFuture<'a, Result<()>> {
+        forward(self.inner(), |sink| sink.publish_graduation(graduation))
+    }
+
+    /// Publish liquidity added to or removed from a pool
+    fn publish_liquidity<'a>(&'a self, liquidity: &'a Liquidity) -> BoxFuture<'a, Result<()>> {
+        forward(self.inner(), |sink| sink.publish_liquidity(liquidity))
+    }
+
+    /// Publish LP tokens of a pool seen created being burned
+    fn publish_lp_burned<'a>(&'a self, burn: &'a LpBurned) -> BoxFuture<'a, Result<()>> {
+        forward(self.inner(), |sink| sink.publish_lp_burned(burn))
+    }
+
+    /// Publish the bonding curve progress of a tracked launch
+    fn publish_curve_progress<'a>(
+        &'a self,
+        progress: &'a CurveProgress,
+    ) -> BoxFuture<'a, Result<()>> {
+        forward(self.inner(), |sink| sink.publish_curve_progress(progress))
+    }
+
+    /// Publish a recognized instruction that is neither a launch nor a trade
+    fn publish_program_event<'a>(&'a self, event: &'a ProgramEvent) -> BoxFuture<'a, Result<()>> {
+        forward(self.inner(), |sink| sink.publish_program_event(event))
+    }
+
+    /// Publish the creator of a launch transferring a large share of its supply
+    fn publish_creator_transfer<'a>(
+        &'a self,
+        transfer: &'a CreatorTransfer,
+    ) -> BoxFuture<'a, Result<()>> {
+        forward(self.inner(), |sink| sink.publish_creator_transfer(transfer))
+    }
+
+    /// Publish the wallets that bought a launch within its first slots
+    fn publish_first_buyers<'a>(&'a self, summary: &'a FirstBuyers) -> BoxFuture<'a, Result<()>> {
+        forward(self.inner(), |sink| sink.publish_first_buyers(summary))
+    }
+
+    /// Publish the creator of a launched token selling it
+    fn publish_dev_sell<'a>(&'a self, sell: &'a DevSell) -> BoxFuture<'a, Result<()>> {
+        forward(self.inner(), |sink| sink.publish_dev_sell(sell))
+    }
+
+    /// Publish a mint or freeze authority of a launched token being revoked
+    fn publish_authority_revoked<'a>(
+        &'a self,
+        revocation: &'a AuthorityRevoked,
+    ) -> BoxFuture<'a, Result<()>> {
+        forward(self.inner(), |sink| {
+            sink.publish_authority_revoked(revocation)
+        })
+    }
+
+    /// Publish a buy or sell
+    fn publish_trade<'a>(&'a self, trade: &'a Trade) -> BoxFuture<'a, Result<()>> {
+        forward(self.inner(), |sink| sink.publish_trade(trade))
+    }
+
+    /// Publish the trading status of a freshly launched token
+    fn publish_token_status<'a>(&'a self, status: &'a TokenStatus) -> BoxFuture<'a, Result<()>> {
+        forward(self.inner(), |sink| sink.publish_token_status(status))
+    }
+
+    /// Publish an alert raised on a tracked token
+    fn publish_alert<'a>(&'a self, alert: &'a TokenAlert) -> BoxFuture<'a, Result<()>> {
+        forward(self.inner(), |sink| sink.publish_alert(alert))
+    }
+
+    /// Publish a periodic activity digest
+    fn publish_digest<'a>(&'a self, digest: &'a Digest) -> BoxFuture<'a, Result<()>> {
+        forward(self.inner(), |sink| sink.publish_digest(digest))
+    }
+
+    /// Publish an operational error for alerting
+    fn publish_ops_error<'a>(&'a self, error: &'a OpsError) -> BoxFuture<'a, Result<()>> {
+        forward(self.inner(), |sink| sink.publish_ops_error(error))
+    }
+
+    /// Publish all events of a slot as one ordered batch
+    fn publish_slot_batch<'a>(&'a self, batch: &'a SlotBatch) -> BoxFuture<'a, Result<()>> {
+        forward(self.inner(), |sink| sink.publish_slot_batch(batch))
+    }
+
+    /// Publish all events of a transaction as one batch
+    fn publish_transaction_batch<'a>(
+        &'a self,
+        batch: &'a TransactionBatch,
+    ) -> BoxFuture<'a, Result<()>> {
+        forward(self.inner(), |sink| sink.publish_transaction_batch(batch))
+    }
+
+    /// Publish an undecoded transaction of the raw passthrough
+    fn publish_raw_transaction<'a>(
+        &'a self,
+        transaction: &'a RawTransaction,
+    ) -> BoxFuture<'a, Result<()>> {
+        forward(self.inner(), |sink| {
+            EventSink::publish_raw_transaction(sink, transaction)
+        })
+    }
+}
+
+/// Hands an event to the wrapped sink, or drops it when there is none
+fn forward<'a>(
+    inner: Option<&'a dyn EventSink>,
+    publish: impl FnOnce(&'a dyn EventSink) -> BoxFuture<'a, Result<()>>,
+) -> BoxFuture<'a, Result<()>> {
+    match inner {
+        Some(sink) => publish(sink),
+        None => future::ok(()).boxed(),
+    }
+}
@@ -0,0 +1,468 @@
+use anyhow::Result;
+use futures::FutureExt;
+use futures::future::{self, BoxFuture};
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tokio::sync::Mutex;
+use tracing::{info, warn};
+
+use crate::metrics;
+use crate::monitor::{Digest, OpsError, OpsErrorKind, TokenAlert, TokenStatus, ops};
+use crate::parser::{
+    AuthorityRevoked, CreatorTransfer, CurveProgress, DevSell, FeeClaim, FirstBuyers, Graduation,
+    Liquidity, LpBurned, Migration, PartialParse, ProgramEvent, TokenLaunch, Trade,
+};
+use crate::sink::{EventSink, RawTransaction, SlotBatch, TransactionBatch};
+
+/// Event held back while publishing is paused
+enum PendingEvent {
+    TokenLaunch(TokenLaunch),
+    PartialParse(PartialParse),
+    FeeClaim(FeeClaim),
+    Migration(Migration),
+    Graduation(Graduation),
+    Liquidity(Liquidity),
+    LpBurned(LpBurned),
+    CurveProgress(CurveProgress),
+    ProgramEvent(ProgramEvent),
+    CreatorTransfer(CreatorTransfer),
+    FirstBuyers(FirstBuyers),
+    DevSell(DevSell),
+    AuthorityRevoked(AuthorityRevoked),
+    Trade(Trade),
+    TokenStatus(TokenStatus),
+    Alert(TokenAlert),
+    Digest(Digest),
+    SlotBatch(SlotBatch),
+    TransactionBatch(TransactionBatch),
+}
+
+/// Sink wrapper that can pause publishing while ingestion keeps running.
+/// Events produced while paused are buffered (oldest dropped beyond `max_buffered`)
+/// and flushed in order on resume.
+#[derive(Clone)]
+pub struct PausableSink {
+    inner: Arc<dyn EventSink>,
+    paused: Arc<AtomicBool>,
+    buffer: Arc<Mutex<VecDeque<PendingEvent>>>,
+    max_buffered: usize,
+}
+
+impl PausableSink {
+    pub fn new(inner: Arc<dyn EventSink>, max_buffered: usize) -> Self {
+        Self {
+            inner,
+            paused: Arc::new(AtomicBool::new(false)),
+            buffer: Arc::new(Mutex::new(VecDeque::new())),
+            max_buffered,
+        }
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    /// Number of events waiting for resume
+    pub async fn buffered(&self) -> usize {
+        self.buffer.lock().await.len()
+    }
+
+    /// Stops publishing; events are buffered until `resume`
+    pub fn pause(&self) {
+        if !self.paused.swap(true, Ordering::SeqCst) {
+            info!("⏸️  Event publishing paused");
+            metrics::global().set_gauge("publish_paused", &[], 1.0);
+        }
+    }
+
+    /// Resumes publishing and flushes buffered events in order
+    pub async fn resume(&self) {
+        if !self.paused.swap(false, Ordering::SeqCst) {
+            return;
+        }
+        metrics::global().set_gauge("publish_paused", &[], 0.0);
+
+        let mut flushed = 0;
+        // Stop flushing if paused again mid-way, remaining events wait for the next resume
+        while !self.is_paused() {
+            let Some(event) = self.buffer.lock().await.pop_front() else {
+                break;
+            };
+            self.publish_now(&event).await;
+            flushed += 1;
+        }
+        metrics::global().set_gauge("publish_buffered", &[], self.buffered().await as f64);
+
+        info!(
+            "▶️  Event publishing resumed, flushed {} buffered events",
+            flushed
+        );
+    }
+
+    /// Pauses on SIGUSR1 and resumes on SIGUSR2
+    #[cfg(unix)]
+    pub async fn listen_for_signals(self) -> Result<()> {
+        use tokio::signal::unix::{SignalKind, signal};
+
+        let mut pause = signal(SignalKind::user_defined1())?;
+        let mut resume = signal(SignalKind::user_defined2())?;
+
+        loop {
+            tokio::select! {
+                _ = pause.recv() => self.pause(),
+                _ = resume.recv() => self.resume().await,
+            }
+        }
+    }
+
+    async fn publish_now(&self, event: &PendingEvent) {
+        let result = match event {
+            PendingEvent::TokenLaunch(launch) => self.inner.publish_token_launch(launch).await,
+            PendingEvent::PartialParse(partial) => self.inner.publish_partial_parse(partial).await,
+            PendingEvent::FeeClaim(claim) => self.inner.publish_fee_claim(claim).await,
+            PendingEvent::Migration(migration) => self.inner.publish_migration(migration).await,
+            PendingEvent::Graduation(graduation) => self.inner.publish_graduation(graduation).await,
+            PendingEvent::Liquidity(liquidity) => self.inner.publish_liquidity(liquidity).await,
+            PendingEvent::LpBurned(burn) => self.inner.publish_lp_burned(burn).await,
+            PendingEvent::CurveProgress(progress) => {
+                self.inner.publish_curve_progress(progress).await
+            }
+            PendingEvent::ProgramEvent(event) => self.inner.publish_program_event(event).await,
+            PendingEvent::CreatorTransfer(transfer) => {
+                self.inner.publish_creator_transfer(transfer).await
+            }
+            PendingEvent::FirstBuyers(summary) => self.inner.publish_first_buyers(summary).await,
+            PendingEvent::DevSell(sell) => self.inner.publish_dev_sell(sell).await,
+            PendingEvent::AuthorityRevoked(revocation) => {
+                self.inner.publish_authority_revoked(revocation).await
+            }
+            PendingEvent::Trade(trade) => self.inner.publish_trade(trade).await,
+            PendingEvent::TokenStatus(status) => self.inner.publish_token_status(status).await,
+            PendingEvent::Alert(alert) => self.inner.publish_alert(alert).await,
+            PendingEvent::Digest(digest) => self.inner.publish_digest(digest).await,
+            PendingEvent::SlotBatch(batch) => self.inner.publish_slot_batch(batch).await,
+            PendingEvent::TransactionBatch(batch) => {
+                self.inner.publish_transaction_batch(batch).await
+            }
+        };
+
+        if let Err(e) = result {
+            warn!("Failed to publish buffered event: {}", e);
+            ops::report(OpsError::new(OpsErrorKind::SinkFailure, "publish", &e));
+        }
+    }
+
+    /// Buffers the event when paused or while a flush is in progress, returns whether it was buffered
+    async fn hold(&self, event: impl FnOnce() -> PendingEvent) -> bool {
+        let mut buffer = self.buffer.lock().await;
+        if !self.is_paused() && buffer.is_empty() {
+            return false;
+        }
+
+        if buffer.len() >= self.max_buffered {
+            buffer.pop_front();
+            metrics::global().inc_counter("publish_buffer_dropped_total", &[]);
+        }
+        buffer.push_back(event());
+        metrics::global().set_gauge("publish_buffered", &[], buffer.len() as f64);
+
+        true
+    }
+}
+
+impl EventSink for PausableSink {
+    fn inner(&self) -> Option<&dyn EventSink> {
+        Some(self.inner.as_ref())
+    }
+
+    fn publish_token_launch<'a>(&'a self, launch: &'a TokenLaunch) -> BoxFuture<'a, Result<()>> {
+        async move {
+            if self
+                .hold(|| PendingEvent::TokenLaunch(launch.clone()))
+                .await
+            {
+                return Ok(());
+            }
+            self.inner.publish_token_launch(launch).await
+        }
+        .boxed()
+    }
+
+    fn publish_partial_parse<'a>(&'a self, partial: &'a PartialParse) -> BoxFuture<'a, Result<()>> {
+        async move {
+            if self
+                .hold(|| PendingEvent::PartialParse(partial.clone()))
+                .await
+            {
+                return Ok(());
+            }
+            self.inner.publish_partial_parse(partial).await
+        }
+        .boxed()
+    }
+
+    fn publish_digest<'a>(&'a self, digest: &'a Digest) -> BoxFuture<'a, Result<()>> {
+        async move {
+            if self.hold(|| PendingEvent::Digest(digest.clone())).await {
+                return Ok(());
+            }
+            self.inner.publish_digest(digest).await
+        }
+        .boxed()
+    }
+
+    /// Operational errors bypass the pause so alerting keeps working during maintenance
+    fn publish_ops_error<'a>(&'a self, error: &'a OpsError) -> BoxFuture<'a, Result<()>> {
+        self.inner.publish_ops_error(error)
+    }
+
+    /// Raw transactions are dropped while paused rather than buffered, the firehose would
+    /// push the held back events out of the buffer
+    fn publish_raw_transaction<'a>(
+        &'a self,
+        transaction: &'a RawTransaction,
+    ) -> BoxFuture<'a, Result<()>> {
+        if self.is_paused() {
+            metrics::global().inc_counter("raw_paused_dropped_total", &[]);
+            return future::ok(()).boxed();
+        }
+        self.inner.publish_raw_transaction(transaction)
+    }
+
+    fn publish_slot_batch<'a>(&'a self, batch: &'a SlotBatch) -> BoxFuture<'a, Result<()>> {
+        async move {
+            if self.hold(|| PendingEvent::SlotBatch(batch.clone())).await {
+                return Ok(());
+            }
+            self.inner.publish_slot_batch(batch).await
+        }
+        .boxed()
+    }
+
+    fn publish_transaction_batch<'a>(
+        &'a self,
+        batch: &'a TransactionBatch,
+    ) -> BoxFuture<'a, Result<()>> {
+        async move {
+            if self
+                .hold(|| PendingEvent::TransactionBatch(batch.clone()))
+                .await
+            {
+                return Ok(());
+            }
+            self.inner.publish_transaction_batch(batch).await
+        }
+        .boxed()
+    }
+
+    fn publish_fee_claim<'a>(&'a self, claim: &'a FeeClaim) -> BoxFuture<'a, Result<()>> {
+        async move {
+            if self.hold(|| PendingEvent::FeeClaim(claim.clone())).await {
+                return Ok(());
+            }
+            self.inner.publish_fee_claim(claim).await
+        }
+        .boxed()
+    }
+
+    fn publish_migration<'a>(&'a self, migration: &'a Migration) -> BoxFuture<'a, Result<()>> {
+        async move {
+            if self
+                .hold(|| PendingEvent::Migration(migration.clone()))
+                .await
+            {
+                return Ok(());
+            }
+            self.inner.publish_migration(migration).await
+        }
+        .boxed()
+    }
+
+    fn publish_graduation<'a>(&'a self, graduation: &'a Graduation) -> BoxFuture<'a, Result<()>> {
+        async move {
+            if self
+                .hold(|| PendingEvent::Graduation(graduation.clone()))
+                .await
+            {
+                return Ok(());
+            }
+            self.inner.publish_graduation(graduation).await
+        }
+        .boxed()
+    }
+
+    fn publish_liquidity<'a>(&'a self, liquidity: &'a Liquidity) -> BoxFuture<'a, Result<()>> {
+        async move {
+            if self
+                .hold(|| PendingEvent::Liquidity(liquidity.clone()))
+                .await
+            {
+                return Ok(());
+            }
+            self.inner.publish_liquidity(liquidity).await
+        }
+        .boxed()
+    }
+
+    fn publish_lp_burned<'a>(&'a self, burn: &'a LpBurned) -> BoxFuture<'a, Result<()>> {
+        async move {
+            if self.hold(|| PendingEvent::LpBurned(burn.clone())).await {
+                return Ok(());
+            }
+            self.inner.publish_lp_burned(burn).await
+        }
+        .boxed()
+    }
+
+    fn publish_curve_progress<'a>(
+        &'a self,
+        progress: &'a CurveProgress,
+    ) -> BoxFuture<'a, Result<()>> {
+        async move {
+            if self
+                .hold(|| PendingEvent::CurveProgress(progress.clone()))
+                .await
+            {
+                return Ok(());
+            }
+            self.inner.publish_curve_progress(progress).await
+        }
+        .boxed()
+    }
+
+    fn publish_program_event<'a>(&'a self, event: &'a ProgramEvent) -> BoxFuture<'a, Result<()>> {
+        async move {
+            if self
+                .hold(|| PendingEvent::ProgramEvent(event.clone()))
+                .await
+            {
+                return Ok(());
+            }
+            self.inner.publish_program_event(event).await
+        }
+        .boxed()
+    }
+
+    fn publish_creator_transfer<'a>(
+        &'a self,
+        transfer: &'a CreatorTransfer,
+    ) -> BoxFuture<'a, Result<()>> {
+        async move {
+            if self
+                .hold(|| PendingEvent::CreatorTransfer(transfer.clone()))
+                .await
+            {
+                return Ok(());
+            }
+            self.inner.publish_creator_transfer(transfer).await
+        }
+        .boxed()
+    }
+
+    fn publish_first_buyers<'a>(&'a self, summary: &'a FirstBuyers) -> BoxFuture<'a, Result<()>> {
+        async move {
+            if self
+                .hold(|| PendingEvent::FirstBuyers(summary.clone()))
+                .await
+            {
+                return Ok(());
+            }
+            self.inner.publish_first_buyers(summary).await
+        }
+        .boxed()
+    }
+
+    fn publish_dev_sell<'a>(&'a self, sell: &'a DevSell) -> BoxFuture<'a, Result<()>> {
+        async move {
+            if self.hold(|| PendingEvent::DevSell(sell.clone())).await {
+                return Ok(());
+            }
+            self.inner.publish_dev_sell(sell).await
+        }
+        .boxed()
+    }
+
+    fn publish_authority_revoked<'a>(
+        &'a self,
+        revocation: &'a AuthorityRevoked,
+    ) -> BoxFuture<'a, Result<()>> {
+        async move {
+            if self
+                .hold(|| PendingEvent::AuthorityRevoked(revocation.clone()))
+                .await
+            {
+                return Ok(());
+            }
+            self.inner.publish_authority_revoked(revocation).await
+        }
+        .boxed()
+    }
+
+    fn publish_trade<'a>(&'a self, trade: &'a Trade) -> BoxFuture<'a, Result<()>> {
+        async move {
+            if self.hold(|| PendingEvent::Trade(trade.clone())).await {
+                return Ok(());
+            }
+            self.inner.publish_trade(trade).await
+        }
+        .boxed()
+    }
+
+    fn publish_token_status<'a>(&'a self, status: &'a TokenStatus) -> BoxFuture<'a, Result<()>> {
+        async move {
+            if self
+                .hold(|| PendingEvent::TokenStatus(status.clone()))
+                .await
+            {
+                return Ok(());
+            }
+            self.inner.publish_token_status(status).await
+        }
+        .boxed()
+    }
+
+    fn publish_alert<'a>(&'a self, alert: &'a TokenAlert) -> BoxFuture<'a, Result<()>> {
+        async move {
+            if self.hold(|| PendingEvent::Alert(alert.clone())).await {
+                return Ok(());
+            }
+            self.inner.publish_alert(alert).await
+        }
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sink::RawSink;
+    use crate::testing::{RecordingSink, launch, raw_transaction, start_time};
+
+    #[tokio::test]
+    async fn raw_transactions_are_dropped_while_paused() {
+        let recording = Arc::new(RecordingSink::default());
+        let publish = PausableSink::new(recording.clone(), 10);
+        let raw: &dyn RawSink = &publish;
+
+        publish.pause();
+        raw.publish_raw_transaction(&raw_transaction("a", start_time()))
+            .await
+            .unwrap();
+        publish
+            .publish_token_launch(&launch("b", 1, start_time()))
+            .await
+            .unwrap();
+        assert!(recording.raw_transactions.lock().unwrap().is_empty());
+        assert_eq!(publish.buffered().await, 1);
+
+        publish.resume().await;
+        raw.publish_raw_transaction(&raw_transaction("c", start_time()))
+            .await
+            .unwrap();
+        assert_eq!(recording.launches.lock().unwrap().len(), 1);
+        let published = recording.raw_transactions.lock().unwrap();
+        assert_eq!(published.len(), 1);
+        assert_eq!(published[0].signature, "c");
+    }
+}
@@ -0,0 +1,106 @@
+use anyhow::Result;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use chrono::{DateTime, Utc};
+use futures::future::BoxFuture;
+use serde::{Serialize, Serializer};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::error::TrySendError;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+use crate::metrics;
+use crate::monitor::{OpsError, OpsErrorKind, ops};
+use crate::sink::EventSink;
+
+/// Transaction matched by the subscription filters, forwarded undecoded
+#[derive(Debug, Clone, Serialize)]
+pub struct RawTransaction {
+    pub signature: String,
+    pub slot: u64,
+    /// Position of the transaction within its block
+    pub transaction_index: Option<u64>,
+    /// Subscription filters the update matched
+    pub filters: Vec<String>,
+    pub received_time: DateTime<Utc>,
+    /// `SubscribeUpdateTransactionInfo` protobuf, base64 in the published JSON
+    #[serde(serialize_with = "serialize_base64")]
+    pub data: Vec<u8>,
+}
+
+fn serialize_base64<S: Serializer>(data: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&BASE64.encode(data))
+}
+
+/// Destination for raw transactions. Every event sink is one, so the firehose goes
+/// through the pause and throttling of the sink chain it is handed, but skips history
+/// and batching, which only wrap parsed events.
+pub trait RawSink: Send + Sync {
+    fn publish_raw_transaction<'a>(
+        &'a self,
+        transaction: &'a RawTransaction,
+    ) -> BoxFuture<'a, Result<()>>;
+}
+
+impl<S: EventSink + ?Sized> RawSink for S {
+    fn publish_raw_transaction<'a>(
+        &'a self,
+        transaction: &'a RawTransaction,
+    ) -> BoxFuture<'a, Result<()>> {
+        EventSink::publish_raw_transaction(self, transaction)
+    }
+}
+
+/// Hands matched transactions to a publishing task without blocking the Geyser stream.
+/// Transactions are dropped when the buffer is full.
+#[derive(Clone)]
+pub struct RawPassthrough {
+    sender: mpsc::Sender<RawTransaction>,
+}
+
+impl RawPassthrough {
+    /// Starts the publishing task, buffering up to `buffer` transactions
+    pub fn start(sink: Arc<dyn RawSink>, buffer: usize) -> (Self, JoinHandle<()>) {
+        let (sender, mut receiver) = mpsc::channel::<RawTransaction>(buffer.max(1));
+
+        let handle = tokio::spawn(async move {
+            info!("🚰 Raw transaction passthrough started (buffer {})", buffer);
+
+            while let Some(transaction) = receiver.recv().await {
+                match sink.publish_raw_transaction(&transaction).await {
+                    Ok(()) => metrics::global().inc_counter("raw_published_total", &[]),
+                    Err(e) => {
+                        warn!(
+                            "Failed to publish raw transaction {}: {}",
+                            transaction.signature, e
+                        );
+                        ops::report(OpsError::new(
+                            OpsErrorKind::SinkFailure,
+                            "raw_passthrough",
+                            &e,
+                        ));
+                    }
+                }
+            }
+        });
+
+        (Self { sender }, handle)
+    }
+
+    /// Queues a transaction for publishing
+    pub fn forward(&self, transaction: RawTransaction) {
+        match self.sender.try_send(transaction) {
+            Ok(()) => {}
+            Err(TrySendError::Full(_)) => {
+                metrics::global().inc_counter("raw_dropped_total", &[]);
+            }
+            Err(TrySendError::Closed(transaction)) => {
+                warn!(
+                    "⚠️ Raw passthrough stopped, dropping {}",
+                    transaction.signature
+                );
+            }
+        }
+    }
+}
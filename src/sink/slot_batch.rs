@@ -0,0 +1,363 @@
+use anyhow::Result;
+use futures::FutureExt;
+use futures::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tokio::task::JoinHandle;
+use tokio::time::{Duration, interval};
+use tracing::warn;
+
+use crate::clock::{self, SharedClock};
+use crate::config::publish::PublishConfig;
+use crate::monitor::{OpsError, OpsErrorKind, ops};
+use crate::parser::{
+    AuthorityRevoked, CreatorTransfer, DevSell, FeeClaim, Graduation, Liquidity, LpBurned,
+    Migration, PartialParse, ProgramEvent, TokenLaunch, Trade,
+};
+use crate::sink::EventSink;
+
+/// How often slots are checked against the max wait
+const FLUSH_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Event inside a slot or transaction batch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SlotEvent {
+    TokenLaunch(TokenLaunch),
+    PartialParse(PartialParse),
+    FeeClaim(FeeClaim),
+    Migration(Migration),
+    Graduation(Graduation),
+    Liquidity(Liquidity),
+    LpBurned(LpBurned),
+    ProgramEvent(ProgramEvent),
+    CreatorTransfer(CreatorTransfer),
+    DevSell(DevSell),
+    AuthorityRevoked(AuthorityRevoked),
+    Trade(Trade),
+}
+
+impl SlotEvent {
+    fn transaction_index(&self) -> Option<u64> {
+        match self {
+            SlotEvent::TokenLaunch(launch) => launch.transaction_index,
+            SlotEvent::PartialParse(partial) => partial.transaction_index,
+            SlotEvent::FeeClaim(claim) => claim.transaction_index,
+            SlotEvent::Migration(migration) => migration.transaction_index,
+            SlotEvent::Graduation(graduation) => graduation.transaction_index,
+            SlotEvent::Liquidity(liquidity) => liquidity.transaction_index,
+            SlotEvent::LpBurned(burn) => burn.transaction_index,
+            SlotEvent::ProgramEvent(event) => event.transaction_index,
+            SlotEvent::CreatorTransfer(transfer) => transfer.transaction_index,
+            SlotEvent::DevSell(sell) => sell.transaction_index,
+            SlotEvent::AuthorityRevoked(revocation) => revocation.transaction_index,
+            SlotEvent::Trade(trade) => trade.transaction_index,
+        }
+    }
+}
+
+/// All events produced for one slot, ordered by transaction index within the block
+/// (production order for events without one)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlotBatch {
+    pub slot: u64,
+    pub events: Vec<SlotEvent>,
+}
+
+struct PendingSlot {
+    created: Instant,
+    events: Vec<SlotEvent>,
+}
+
+#[derive(Default)]
+struct SlotState {
+    slots: BTreeMap<u64, PendingSlot>,
+    newest_slot: u64,
+}
+
+/// Sink wrapper that forwards events as they come and additionally emits one `SlotBatch`
+/// per slot, once `delay_slots` newer slots were seen or `max_wait` passed
+#[derive(Clone)]
+pub struct SlotBatchSink {
+    inner: Arc<dyn EventSink>,
+    state: Arc<Mutex<SlotState>>,
+    delay_slots: u64,
+    max_wait: Duration,
+    clock: SharedClock,
+}
+
+impl SlotBatchSink {
+    pub fn new(inner: Arc<dyn EventSink>, config: &PublishConfig) -> Self {
+        Self {
+            inner,
+            state: Arc::new(Mutex::new(SlotState::default())),
+            delay_slots: config.slot_batch_delay_slots,
+            max_wait: Duration::from_millis(config.slot_batch_max_wait_ms),
+            clock: clock::system(),
+        }
+    }
+
+    /// Replaces the clock the max wait is measured with
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Flushes slots that waited longer than `max_wait` in a separate task
+    pub fn start(&self) -> JoinHandle<()> {
+        let sink = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = interval(FLUSH_INTERVAL);
+            loop {
+                ticker.tick().await;
+                let ready = sink.take_expired();
+                sink.emit(ready).await;
+            }
+        })
+    }
+
+    /// Removes the slots that waited longer than `max_wait`
+    fn take_expired(&self) -> Vec<SlotBatch> {
+        let now = self.clock.instant();
+        self.take_ready(|_, pending, _| now.duration_since(pending.created) >= self.max_wait)
+    }
+
+    async fn add(&self, slot: u64, event: SlotEvent) {
+        let now = self.clock.instant();
+        {
+            let mut state = self.state.lock().unwrap();
+            state.newest_slot = state.newest_slot.max(slot);
+            state
+                .slots
+                .entry(slot)
+                .or_insert_with(|| PendingSlot {
+                    created: now,
+                    events: Vec::new(),
+                })
+                .events
+                .push(event);
+        }
+
+        let ready =
+            self.take_ready(|slot, _, newest| slot.saturating_add(self.delay_slots) <= newest);
+        self.emit(ready).await;
+    }
+
+    /// Removes the slots matching `ready(slot, pending, newest_slot)`, oldest first
+    fn take_ready(&self, ready: impl Fn(u64, &PendingSlot, u64) -> bool) -> Vec<SlotBatch> {
+        let mut state = self.state.lock().unwrap();
+        let newest = state.newest_slot;
+        let slots: Vec<u64> = state
+            .slots
+            .iter()
+            .filter(|(slot, pending)| ready(**slot, pending, newest))
+            .map(|(slot, _)| *slot)
+            .collect();
+
+        slots
+            .into_iter()
+            .filter_map(|slot| {
+                let mut events = state.slots.remove(&slot)?.events;
+                events.sort_by_key(|event| event.transaction_index().unwrap_or(u64::MAX));
+                Some(SlotBatch { slot, events })
+            })
+            .collect()
+    }
+
+    async fn emit(&self, batches: Vec<SlotBatch>) {
+        for batch in batches {
+            if let Err(e) = self.inner.publish_slot_batch(&batch).await {
+                warn!("Failed to publish batch for slot {}: {}", batch.slot, e);
+                ops::report(OpsError::new(OpsErrorKind::SinkFailure, "slot_batch", &e));
+            }
+        }
+    }
+}
+
+impl EventSink for SlotBatchSink {
+    fn inner(&self) -> Option<&dyn EventSink> {
+        Some(self.inner.as_ref())
+    }
+
+    fn publish_token_launch<'a>(&'a self, launch: &'a TokenLaunch) -> BoxFuture<'a, Result<()>> {
+        async move {
+            let result = self.inner.publish_token_launch(launch).await;
+            self.add(launch.slot, SlotEvent::TokenLaunch(launch.clone()))
+                .await;
+            result
+        }
+        .boxed()
+    }
+
+    fn publish_partial_parse<'a>(&'a self, partial: &'a PartialParse) -> BoxFuture<'a, Result<()>> {
+        async move {
+            let result = self.inner.publish_partial_parse(partial).await;
+            self.add(partial.slot, SlotEvent::PartialParse(partial.clone()))
+                .await;
+            result
+        }
+        .boxed()
+    }
+
+    fn publish_fee_claim<'a>(&'a self, claim: &'a FeeClaim) -> BoxFuture<'a, Result<()>> {
+        async move {
+            let result = self.inner.publish_fee_claim(claim).await;
+            self.add(claim.slot, SlotEvent::FeeClaim(claim.clone()))
+                .await;
+            result
+        }
+        .boxed()
+    }
+
+    fn publish_migration<'a>(&'a self, migration: &'a Migration) -> BoxFuture<'a, Result<()>> {
+        async move {
+            let result = self.inner.publish_migration(migration).await;
+            self.add(migration.slot, SlotEvent::Migration(migration.clone()))
+                .await;
+            result
+        }
+        .boxed()
+    }
+
+    fn publish_graduation<'a>(&'a self, graduation: &'a Graduation) -> BoxFuture<'a, Result<()>> {
+        async move {
+            let result = self.inner.publish_graduation(graduation).await;
+            self.add(graduation.slot, SlotEvent::Graduation(graduation.clone()))
+                .await;
+            result
+        }
+        .boxed()
+    }
+
+    fn publish_liquidity<'a>(&'a self, liquidity: &'a Liquidity) -> BoxFuture<'a, Result<()>> {
+        async move {
+            let result = self.inner.publish_liquidity(liquidity).await;
+            self.add(liquidity.slot, SlotEvent::Liquidity(liquidity.clone()))
+                .await;
+            result
+        }
+        .boxed()
+    }
+
+    fn publish_lp_burned<'a>(&'a self, burn: &'a LpBurned) -> BoxFuture<'a, Result<()>> {
+        async move {
+            let result = self.inner.publish_lp_burned(burn).await;
+            self.add(burn.slot, SlotEvent::LpBurned(burn.clone())).await;
+            result
+        }
+        .boxed()
+    }
+
+    fn publish_program_event<'a>(&'a self, event: &'a ProgramEvent) -> BoxFuture<'a, Result<()>> {
+        async move {
+            let result = self.inner.publish_program_event(event).await;
+            self.add(event.slot, SlotEvent::ProgramEvent(event.clone()))
+                .await;
+            result
+        }
+        .boxed()
+    }
+
+    fn publish_creator_transfer<'a>(
+        &'a self,
+        transfer: &'a CreatorTransfer,
+    ) -> BoxFuture<'a, Result<()>> {
+        async move {
+            let result = self.inner.publish_creator_transfer(transfer).await;
+            self.add(transfer.slot, SlotEvent::CreatorTransfer(transfer.clone()))
+                .await;
+            result
+        }
+        .boxed()
+    }
+
+    fn publish_dev_sell<'a>(&'a self, sell: &'a DevSell) -> BoxFuture<'a, Result<()>> {
+        async move {
+            let result = self.inner.publish_dev_sell(sell).await;
+            self.add(sell.slot, SlotEvent::DevSell(sell.clone())).await;
+            result
+        }
+        .boxed()
+    }
+
+    fn publish_authority_revoked<'a>(
+        &'a self,
+        revocation: &'a AuthorityRevoked,
+    ) -> BoxFuture<'a, Result<()>> {
+        async move {
+            let result = self.inner.publish_authority_revoked(revocation).await;
+            self.add(
+                revocation.slot,
+                SlotEvent::AuthorityRevoked(revocation.clone()),
+            )
+            .await;
+            result
+        }
+        .boxed()
+    }
+
+    fn publish_trade<'a>(&'a self, trade: &'a Trade) -> BoxFuture<'a, Result<()>> {
+        async move {
+            let result = self.inner.publish_trade(trade).await;
+            self.add(trade.slot, SlotEvent::Trade(trade.clone())).await;
+            result
+        }
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::{Clock, MockClock};
+    use crate::testing::{RecordingSink, launch, publish_config, start_time};
+
+    fn slot_batches(clock: &MockClock) -> (Arc<RecordingSink>, SlotBatchSink) {
+        let recorded = Arc::new(RecordingSink::default());
+        let sink =
+            SlotBatchSink::new(recorded.clone(), &publish_config()).with_clock(clock.shared());
+        (recorded, sink)
+    }
+
+    #[tokio::test]
+    async fn slot_expires_after_max_wait() {
+        let clock = MockClock::new(start_time());
+        let (_, sink) = slot_batches(&clock);
+        sink.publish_token_launch(&launch("a", 10, clock.now()))
+            .await
+            .unwrap();
+
+        clock.advance(Duration::from_millis(1999));
+        assert!(sink.take_expired().is_empty());
+
+        clock.advance(Duration::from_millis(1));
+        let batches = sink.take_expired();
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].slot, 10);
+        assert_eq!(batches[0].events.len(), 1);
+        assert!(sink.take_expired().is_empty());
+    }
+
+    #[tokio::test]
+    async fn slot_is_emitted_once_newer_slots_were_seen() {
+        let clock = MockClock::new(start_time());
+        let (recorded, sink) = slot_batches(&clock);
+        for (signature, slot) in [("a", 10), ("b", 10), ("c", 11)] {
+            sink.publish_token_launch(&launch(signature, slot, clock.now()))
+                .await
+                .unwrap();
+        }
+        assert!(recorded.slot_batches.lock().unwrap().is_empty());
+
+        sink.publish_token_launch(&launch("d", 12, clock.now()))
+            .await
+            .unwrap();
+        let batches = recorded.slot_batches.lock().unwrap();
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].slot, 10);
+        assert_eq!(batches[0].events.len(), 2);
+        assert_eq!(recorded.launches.lock().unwrap().len(), 4);
+    }
+}
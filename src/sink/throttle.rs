@@ -0,0 +1,260 @@
+use anyhow::Result;
+use futures::FutureExt;
+use futures::future::BoxFuture;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio::time::{self, Duration, sleep_until};
+use tracing::{debug, info, warn};
+
+use crate::clock::SharedClock;
+use crate::metrics;
+use crate::monitor::{OpsError, OpsErrorKind, ops};
+use crate::parser::{PartialParse, TokenLaunch, Trade};
+use crate::sink::{EventSink, RawTransaction};
+
+/// Current throttling settings
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ThrottleSettings {
+    pub max_per_sec: Option<u32>,
+    /// Share of events kept per event type (`launch`, `partial`, `trade`, `raw`)
+    pub sample_rates: HashMap<String, f64>,
+}
+
+#[derive(Default)]
+struct ThrottleState {
+    settings: ThrottleSettings,
+    next_slot: Option<Instant>,
+    /// Accumulated sampling credit per event type, an event is kept when it reaches 1
+    credit: HashMap<String, f64>,
+}
+
+/// What happens to an event of a throttled type
+enum Admission {
+    /// Sampled out
+    Drop,
+    /// No rate limit, published right away
+    Publish,
+    /// Waits in the buffer for a rate limit slot
+    Queue,
+}
+
+/// Event waiting in the buffer for a rate limit slot
+enum Throttled {
+    Launch(TokenLaunch),
+    Partial(PartialParse),
+    Trade(Trade),
+    Raw(RawTransaction),
+}
+
+impl Throttled {
+    fn kind(&self) -> &'static str {
+        match self {
+            Throttled::Launch(_) => "launch",
+            Throttled::Partial(_) => "partial",
+            Throttled::Trade(_) => "trade",
+            Throttled::Raw(_) => "raw",
+        }
+    }
+
+    async fn publish(&self, sink: &dyn EventSink) -> Result<()> {
+        match self {
+            Throttled::Launch(launch) => sink.publish_token_launch(launch).await,
+            Throttled::Partial(partial) => sink.publish_partial_parse(partial).await,
+            Throttled::Trade(trade) => sink.publish_trade(trade).await,
+            Throttled::Raw(transaction) => sink.publish_raw_transaction(transaction).await,
+        }
+    }
+}
+
+/// Sink wrapper that rate limits and samples launches, partial parses, trades and raw
+/// transactions at runtime, so a degraded downstream can ask for less traffic. Fee claims,
+/// digests, ops errors and slot batches pass through.
+///
+/// Rate limited events wait in a bounded buffer drained by a publisher task, so the parser
+/// publishing them never waits for a slot. Events beyond the buffer are dropped.
+#[derive(Clone)]
+pub struct ThrottledSink {
+    inner: Arc<dyn EventSink>,
+    state: Arc<Mutex<ThrottleState>>,
+    sender: mpsc::Sender<Throttled>,
+    clock: SharedClock,
+}
+
+impl ThrottledSink {
+    /// Starts the publisher task, buffering up to `buffer` rate limited events. Rate limit
+    /// slots are measured with `clock`.
+    pub fn start(
+        inner: Arc<dyn EventSink>,
+        buffer: usize,
+        clock: SharedClock,
+    ) -> (Self, JoinHandle<()>) {
+        let (sender, mut receiver) = mpsc::channel::<Throttled>(buffer.max(1));
+        let throttle = Self {
+            inner,
+            state: Arc::new(Mutex::new(ThrottleState::default())),
+            sender,
+            clock,
+        };
+
+        let publisher = throttle.clone();
+        let handle = tokio::spawn(async move {
+            while let Some(event) = receiver.recv().await {
+                if let Some(slot) = publisher.next_slot() {
+                    sleep_until(time::Instant::from_std(slot)).await;
+                }
+                if let Err(e) = event.publish(publisher.inner.as_ref()).await {
+                    warn!("Failed to publish throttled {}: {}", event.kind(), e);
+                    ops::report(OpsError::new(OpsErrorKind::SinkFailure, "throttle", &e));
+                }
+            }
+        });
+
+        (throttle, handle)
+    }
+
+    pub fn settings(&self) -> ThrottleSettings {
+        self.state.lock().unwrap().settings.clone()
+    }
+
+    /// Caps published events per second, `None` removes the cap
+    pub fn set_rate_limit(&self, max_per_sec: Option<u32>) {
+        let mut state = self.state.lock().unwrap();
+        state.settings.max_per_sec = max_per_sec.filter(|&max| max > 0);
+        state.next_slot = None;
+        info!(
+            "🐢 Publish rate limit set to {:?}/s",
+            state.settings.max_per_sec
+        );
+    }
+
+    /// Keeps only `rate` (0.0 - 1.0) of the events of the given type
+    pub fn set_sample_rate(&self, event: &str, rate: f64) {
+        let mut state = self.state.lock().unwrap();
+        let rate = rate.clamp(0.0, 1.0);
+        if rate >= 1.0 {
+            state.settings.sample_rates.remove(event);
+        } else {
+            state.settings.sample_rates.insert(event.to_string(), rate);
+        }
+        state.credit.remove(event);
+        info!("🎲 Sampling {} events at {:.2}", event, rate);
+    }
+
+    /// Removes all rate limits and sampling
+    pub fn reset(&self) {
+        *self.state.lock().unwrap() = ThrottleState::default();
+        info!("🐇 Publish throttling cleared");
+    }
+
+    /// Samples the event, and tells whether it must wait for a rate limit slot
+    fn admit(&self, event: &str) -> Admission {
+        let mut state = self.state.lock().unwrap();
+
+        if let Some(&rate) = state.settings.sample_rates.get(event) {
+            let credit = state.credit.entry(event.to_string()).or_default();
+            *credit += rate;
+            if *credit < 1.0 {
+                metrics::global().inc_counter("throttle_dropped_total", &[("event", event)]);
+                return Admission::Drop;
+            }
+            *credit -= 1.0;
+        }
+
+        if state.settings.max_per_sec.is_some() {
+            Admission::Queue
+        } else {
+            Admission::Publish
+        }
+    }
+
+    /// Reserves the next rate limit slot, `None` once the limit was removed
+    fn next_slot(&self) -> Option<Instant> {
+        let mut state = self.state.lock().unwrap();
+        let max_per_sec = state.settings.max_per_sec?;
+        let now = self.clock.instant();
+        let slot = state.next_slot.map_or(now, |next| next.max(now));
+        state.next_slot = Some(slot + Duration::from_secs(1) / max_per_sec);
+        Some(slot)
+    }
+
+    /// Buffers a rate limited event, dropping it when the buffer is full
+    fn enqueue(&self, event: Throttled) {
+        let kind = event.kind();
+        if self.sender.try_send(event).is_err() {
+            debug!("Throttle buffer full, dropping {}", kind);
+            metrics::global().inc_counter("throttle_buffer_dropped_total", &[("event", kind)]);
+        }
+    }
+
+    /// Publishes an event of a throttled type according to the current settings
+    async fn throttle(&self, event: Throttled) -> Result<()> {
+        match self.admit(event.kind()) {
+            Admission::Drop => Ok(()),
+            Admission::Publish => event.publish(self.inner.as_ref()).await,
+            Admission::Queue => {
+                self.enqueue(event);
+                Ok(())
+            }
+        }
+    }
+}
+
+impl EventSink for ThrottledSink {
+    fn inner(&self) -> Option<&dyn EventSink> {
+        Some(self.inner.as_ref())
+    }
+
+    fn publish_token_launch<'a>(&'a self, launch: &'a TokenLaunch) -> BoxFuture<'a, Result<()>> {
+        self.throttle(Throttled::Launch(launch.clone())).boxed()
+    }
+
+    fn publish_partial_parse<'a>(&'a self, partial: &'a PartialParse) -> BoxFuture<'a, Result<()>> {
+        self.throttle(Throttled::Partial(partial.clone())).boxed()
+    }
+
+    fn publish_trade<'a>(&'a self, trade: &'a Trade) -> BoxFuture<'a, Result<()>> {
+        self.throttle(Throttled::Trade(trade.clone())).boxed()
+    }
+
+    fn publish_raw_transaction<'a>(
+        &'a self,
+        transaction: &'a RawTransaction,
+    ) -> BoxFuture<'a, Result<()>> {
+        self.throttle(Throttled::Raw(transaction.clone())).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::MockClock;
+    use crate::sink::RawSink;
+    use crate::testing::{RecordingSink, raw_transaction, start_time};
+
+    #[tokio::test]
+    async fn raw_transactions_are_sampled() {
+        let recording = Arc::new(RecordingSink::default());
+        let clock = MockClock::new(start_time());
+        let (throttle, _handle) = ThrottledSink::start(recording.clone(), 10, clock.shared());
+        let raw: &dyn RawSink = &throttle;
+
+        throttle.set_sample_rate("raw", 0.5);
+        for signature in ["a", "b", "c", "d"] {
+            raw.publish_raw_transaction(&raw_transaction(signature, start_time()))
+                .await
+                .unwrap();
+        }
+        let published: Vec<String> = recording
+            .raw_transactions
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|transaction| transaction.signature.clone())
+            .collect();
+        assert_eq!(published, vec!["b", "d"]);
+    }
+}
@@ -0,0 +1,342 @@
+use anyhow::Result;
+use futures::FutureExt;
+use futures::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tokio::task::JoinHandle;
+use tokio::time::{Duration, interval};
+use tracing::warn;
+
+use crate::clock::{self, SharedClock};
+use crate::config::publish::PublishConfig;
+use crate::monitor::{OpsError, OpsErrorKind, ops};
+use crate::parser::{
+    AuthorityRevoked, CreatorTransfer, DevSell, FeeClaim, Graduation, Liquidity, LpBurned,
+    Migration, PartialParse, ProgramEvent, TokenLaunch, Trade,
+};
+use crate::sink::{EventSink, SlotEvent};
+
+/// How often transactions are checked against the wait
+const FLUSH_INTERVAL: Duration = Duration::from_millis(50);
+
+/// All events produced from one transaction, in production order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionBatch {
+    pub signature: String,
+    pub slot: u64,
+    #[serde(default)]
+    pub transaction_index: Option<u64>,
+    pub events: Vec<SlotEvent>,
+}
+
+struct PendingTransaction {
+    created: Instant,
+    batch: TransactionBatch,
+}
+
+/// Sink wrapper that forwards events as they come and additionally emits one
+/// `TransactionBatch` per transaction signature, `wait` after its first event
+#[derive(Clone)]
+pub struct TransactionBatchSink {
+    inner: Arc<dyn EventSink>,
+    pending: Arc<Mutex<HashMap<String, PendingTransaction>>>,
+    wait: Duration,
+    clock: SharedClock,
+}
+
+impl TransactionBatchSink {
+    pub fn new(inner: Arc<dyn EventSink>, config: &PublishConfig) -> Self {
+        Self {
+            inner,
+            pending: Arc::new(Mutex::new(HashMap::new())),
+            wait: Duration::from_millis(config.transaction_batch_wait_ms),
+            clock: clock::system(),
+        }
+    }
+
+    /// Replaces the clock the wait is measured with
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Emits transactions whose wait is over in a separate task
+    pub fn start(&self) -> JoinHandle<()> {
+        let sink = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = interval(FLUSH_INTERVAL);
+            loop {
+                ticker.tick().await;
+                let ready = sink.take_ready();
+                for batch in ready {
+                    if let Err(e) = sink.inner.publish_transaction_batch(&batch).await {
+                        warn!(
+                            "Failed to publish batch for transaction {}: {}",
+                            batch.signature, e
+                        );
+                        ops::report(
+                            OpsError::new(OpsErrorKind::SinkFailure, "transaction_batch", &e)
+                                .with_transaction(&batch.signature, batch.slot),
+                        );
+                    }
+                }
+            }
+        })
+    }
+
+    fn add(&self, signature: &str, slot: u64, transaction_index: Option<u64>, event: SlotEvent) {
+        let now = self.clock.instant();
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(signature.to_string())
+            .or_insert_with(|| PendingTransaction {
+                created: now,
+                batch: TransactionBatch {
+                    signature: signature.to_string(),
+                    slot,
+                    transaction_index,
+                    events: Vec::new(),
+                },
+            })
+            .batch
+            .events
+            .push(event);
+    }
+
+    /// Removes transactions that waited long enough, oldest first
+    fn take_ready(&self) -> Vec<TransactionBatch> {
+        let now = self.clock.instant();
+        let mut pending = self.pending.lock().unwrap();
+        let signatures: Vec<String> = pending
+            .iter()
+            .filter(|(_, transaction)| now.duration_since(transaction.created) >= self.wait)
+            .map(|(signature, _)| signature.clone())
+            .collect();
+        let mut ready: Vec<PendingTransaction> = signatures
+            .iter()
+            .filter_map(|signature| pending.remove(signature))
+            .collect();
+
+        ready.sort_by_key(|transaction| transaction.created);
+        ready
+            .into_iter()
+            .map(|transaction| transaction.batch)
+            .collect()
+    }
+}
+
+impl EventSink for TransactionBatchSink {
+    fn inner(&self) -> Option<&dyn EventSink> {
+        Some(self.inner.as_ref())
+    }
+
+    fn publish_token_launch<'a>(&'a self, launch: &'a TokenLaunch) -> BoxFuture<'a, Result<()>> {
+        async move {
+            let result = self.inner.publish_token_launch(launch).await;
+            self.add(
+                &launch.signature,
+                launch.slot,
+                launch.transaction_index,
+                SlotEvent::TokenLaunch(launch.clone()),
+            );
+            result
+        }
+        .boxed()
+    }
+
+    fn publish_partial_parse<'a>(&'a self, partial: &'a PartialParse) -> BoxFuture<'a, Result<()>> {
+        async move {
+            let result = self.inner.publish_partial_parse(partial).await;
+            self.add(
+                &partial.signature,
+                partial.slot,
+                partial.transaction_index,
+                SlotEvent::PartialParse(partial.clone()),
+            );
+            result
+        }
+        .boxed()
+    }
+
+    fn publish_fee_claim<'a>(&'a self, claim: &'a FeeClaim) -> BoxFuture<'a, Result<()>> {
+        async move {
+            let result = self.inner.publish_fee_claim(claim).await;
+            self.add(
+                &claim.signature,
+                claim.slot,
+                claim.transaction_index,
+                SlotEvent::FeeClaim(claim.clone()),
+            );
+            result
+        }
+        .boxed()
+    }
+
+    fn publish_migration<'a>(&'a self, migration: &'a Migration) -> BoxFuture<'a, Result<()>> {
+        async move {
+            let result = self.inner.publish_migration(migration).await;
+            self.add(
+                &migration.signature,
+                migration.slot,
+                migration.transaction_index,
+                SlotEvent::Migration(migration.clone()),
+            );
+            result
+        }
+        .boxed()
+    }
+
+    fn publish_graduation<'a>(&'a self, graduation: &'a Graduation) -> BoxFuture<'a, Result<()>> {
+        async move {
+            let result = self.inner.publish_graduation(graduation).await;
+            self.add(
+                &graduation.signature,
+                graduation.slot,
+                graduation.transaction_index,
+                SlotEvent::Graduation(graduation.clone()),
+            );
+            result
+        }
+        .boxed()
+    }
+
+    fn publish_liquidity<'a>(&'a self, liquidity: &'a Liquidity) -> BoxFuture<'a, Result<()>> {
+        async move {
+            let result = self.inner.publish_liquidity(liquidity).await;
+            self.add(
+                &liquidity.signature,
+                liquidity.slot,
+                liquidity.transaction_index,
+                SlotEvent::Liquidity(liquidity.clone()),
+            );
+            result
+        }
+        .boxed()
+    }
+
+    fn publish_lp_burned<'a>(&'a self, burn: &'a LpBurned) -> BoxFuture<'a, Result<()>> {
+        async move {
+            let result = self.inner.publish_lp_burned(burn).await;
+            self.add(
+                &burn.signature,
+                burn.slot,
+                burn.transaction_index,
+                SlotEvent::LpBurned(burn.clone()),
+            );
+            result
+        }
+        .boxed()
+    }
+
+    fn publish_program_event<'a>(&'a self, event: &'a ProgramEvent) -> BoxFuture<'a, Result<()>> {
+        async move {
+            let result = self.inner.publish_program_event(event).await;
+            self.add(
+                &event.signature,
+                event.slot,
+                event.transaction_index,
+                SlotEvent::ProgramEvent(event.clone()),
+            );
+            result
+        }
+        .boxed()
+    }
+
+    fn publish_creator_transfer<'a>(
+        &'a self,
+        transfer: &'a CreatorTransfer,
+    ) -> BoxFuture<'a, Result<()>> {
+        async move {
+            let result = self.inner.publish_creator_transfer(transfer).await;
+            self.add(
+                &transfer.signature,
+                transfer.slot,
+                transfer.transaction_index,
+                SlotEvent::CreatorTransfer(transfer.clone()),
+            );
+            result
+        }
+        .boxed()
+    }
+
+    fn publish_dev_sell<'a>(&'a self, sell: &'a DevSell) -> BoxFuture<'a, Result<()>> {
+        async move {
+            let result = self.inner.publish_dev_sell(sell).await;
+            self.add(
+                &sell.signature,
+                sell.slot,
+                sell.transaction_index,
+                SlotEvent::DevSell(sell.clone()),
+            );
+            result
+        }
+        .boxed()
+    }
+
+    fn publish_authority_revoked<'a>(
+        &'a self,
+        revocation: &'a AuthorityRevoked,
+    ) -> BoxFuture<'a, Result<()>> {
+        async move {
+            let result = self.inner.publish_authority_revoked(revocation).await;
+            self.add(
+                &revocation.signature,
+                revocation.slot,
+                revocation.transaction_index,
+                SlotEvent::AuthorityRevoked(revocation.clone()),
+            );
+            result
+        }
+        .boxed()
+    }
+
+    fn publish_trade<'a>(&'a self, trade: &'a Trade) -> BoxFuture<'a, Result<()>> {
+        async move {
+            let result = self.inner.publish_trade(trade).await;
+            self.add(
+                &trade.signature,
+                trade.slot,
+                trade.transaction_index,
+                SlotEvent::Trade(trade.clone()),
+            );
+            result
+        }
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::{Clock, MockClock};
+    use crate::testing::{RecordingSink, launch, publish_config, start_time};
+
+    #[tokio::test]
+    async fn transaction_expires_after_wait() {
+        let clock = MockClock::new(start_time());
+        let sink = TransactionBatchSink::new(Arc::new(RecordingSink::default()), &publish_config())
+            .with_clock(clock.shared());
+        let first = launch("a", 10, clock.now());
+        sink.publish_token_launch(&first).await.unwrap();
+        sink.publish_token_launch(&first).await.unwrap();
+
+        clock.advance(Duration::from_millis(500));
+        sink.publish_token_launch(&launch("b", 10, clock.now()))
+            .await
+            .unwrap();
+
+        clock.advance(Duration::from_millis(500));
+        let ready = sink.take_ready();
+        assert_eq!(ready.len(), 1);
+        assert_eq!(ready[0].signature, "a");
+        assert_eq!(ready[0].events.len(), 2);
+
+        clock.advance(Duration::from_millis(499));
+        assert!(sink.take_ready().is_empty());
+        clock.advance(Duration::from_millis(1));
+        assert_eq!(sink.take_ready().len(), 1);
+    }
+}
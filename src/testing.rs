@@ -0,0 +1,208 @@
+//! Fixtures shared by the unit tests
+
+use anyhow::Result;
+use chrono::{DateTime, TimeZone, Utc};
+use futures::FutureExt;
+use futures::future::BoxFuture;
+use serde_json::json;
+use std::sync::Mutex;
+
+use crate::config::publish::PublishConfig;
+use crate::geyser::{QueuedTransaction, TransactionInstruction, TransactionVersion};
+use crate::parser::{Graduation, TokenLaunch, Trade};
+use crate::sink::{EventSink, RawTransaction, SlotBatch, TransactionBatch};
+
+/// Time mock clocks start at
+pub(crate) fn start_time() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap()
+}
+
+/// A pump.fun launch received at `timestamp`
+pub(crate) fn launch(signature: &str, slot: u64, timestamp: DateTime<Utc>) -> TokenLaunch {
+    serde_json::from_value(json!({
+        "launchpad": "Pumpfun",
+        "token_address": format!("mint-{signature}"),
+        "signature": signature,
+        "slot": slot,
+        "timestamp": timestamp,
+        "metadata": {},
+    }))
+    .unwrap()
+}
+
+/// A pump.fun trade of `mint-{launch_signature}` by `trader` received at `timestamp`
+pub(crate) fn trade(
+    launch_signature: &str,
+    trader: &str,
+    is_buy: bool,
+    amount: u64,
+    token_amount: u64,
+    timestamp: DateTime<Utc>,
+) -> Trade {
+    serde_json::from_value(json!({
+        "launchpad": "Pumpfun",
+        "token_address": format!("mint-{launch_signature}"),
+        "trader": trader,
+        "amount": amount,
+        "token_amount": token_amount,
+        "is_buy": is_buy,
+        "fees": null,
+        "signature": format!("trade-{trader}-{amount}"),
+        "slot": 1,
+        "timestamp": timestamp,
+    }))
+    .unwrap()
+}
+
+/// Publishing defaults, batching and passthrough disabled
+pub(crate) fn publish_config() -> PublishConfig {
+    PublishConfig {
+        pause_buffer: 100,
+        throttle_buffer: 100,
+        trades_enabled: true,
+        program_events_enabled: false,
+        slot_batch_enabled: false,
+        slot_batch_delay_slots: 2,
+        slot_batch_max_wait_ms: 2000,
+        transaction_batch_enabled: false,
+        transaction_batch_wait_ms: 1000,
+        raw_passthrough_enabled: false,
+        raw_passthrough_buffer: 100,
+    }
+}
+
+/// Sink keeping the launches, graduations and batches it was handed
+#[derive(Default)]
+pub(crate) struct RecordingSink {
+    pub launches: Mutex<Vec<TokenLaunch>>,
+    pub graduations: Mutex<Vec<Graduation>>,
+    pub slot_batches: Mutex<Vec<SlotBatch>>,
+    pub transaction_batches: Mutex<Vec<TransactionBatch>>,
+    pub raw_transactions: Mutex<Vec<RawTransaction>>,
+}
+
+impl EventSink for RecordingSink {
+    fn publish_token_launch<'a>(&'a self, launch: &'a TokenLaunch) -> BoxFuture<'a, Result<()>> {
+        self.launches.lock().unwrap().push(launch.clone());
+        futures::future::ok(()).boxed()
+    }
+
+    fn publish_graduation<'a>(&'a self, graduation: &'a Graduation) -> BoxFuture<'a, Result<()>> {
+        self.graduations.lock().unwrap().push(graduation.clone());
+        futures::future::ok(()).boxed()
+    }
+
+    fn publish_slot_batch<'a>(&'a self, batch: &'a SlotBatch) -> BoxFuture<'a, Result<()>> {
+        self.slot_batches.lock().unwrap().push(batch.clone());
+        futures::future::ok(()).boxed()
+    }
+
+    fn publish_transaction_batch<'a>(
+        &'a self,
+        batch: &'a TransactionBatch,
+    ) -> BoxFuture<'a, Result<()>> {
+        self.transaction_batches.lock().unwrap().push(batch.clone());
+        futures::future::ok(()).boxed()
+    }
+
+    fn publish_raw_transaction<'a>(
+        &'a self,
+        transaction: &'a RawTransaction,
+    ) -> BoxFuture<'a, Result<()>> {
+        self.raw_transactions
+            .lock()
+            .unwrap()
+            .push(transaction.clone());
+        futures::future::ok(()).boxed()
+    }
+}
+
+/// Raw passthrough transaction received at `received_time`
+pub(crate) fn raw_transaction(signature: &str, received_time: DateTime<Utc>) -> RawTransaction {
+    RawTransaction {
+        signature: signature.to_string(),
+        slot: 1,
+        transaction_index: None,
+        filters: Vec::new(),
+        received_time,
+        data: Vec::new(),
+    }
+}
+
+/// Transaction `sig` in slot 1 signed and paid for by the first of `accounts`
+pub(crate) fn transaction(
+    accounts: &[&str],
+    instructions: Vec<TransactionInstruction>,
+    inner_instructions: Vec<TransactionInstruction>,
+) -> QueuedTransaction {
+    QueuedTransaction {
+        signature: "sig".to_string(),
+        slot: 1,
+        transaction_index: None,
+        received_time: start_time(),
+        block_time: None,
+        backfilled: false,
+        is_vote: false,
+        version: TransactionVersion::Legacy,
+        accounts: accounts.iter().map(|account| account.to_string()).collect(),
+        fee_payer: accounts.first().map(|account| account.to_string()),
+        signers: accounts
+            .iter()
+            .take(1)
+            .map(|account| account.to_string())
+            .collect(),
+        instructions,
+        inner_instructions,
+        raw: None,
+    }
+}
+
+/// Instruction of `program_id` over the transaction accounts at `accounts`
+pub(crate) fn instruction(
+    program_id: &str,
+    accounts: &[u8],
+    data: Vec<u8>,
+) -> TransactionInstruction {
+    TransactionInstruction {
+        program_id: program_id.to_string(),
+        accounts: accounts.to_vec(),
+        data,
+        truncated: false,
+    }
+}
+
+/// Borsh encoded string, a little endian u32 length followed by the bytes
+pub(crate) fn borsh_string(value: &str) -> Vec<u8> {
+    let mut data = (value.len() as u32).to_le_bytes().to_vec();
+    data.extend_from_slice(value.as_bytes());
+    data
+}
+
+/// SPL Token `InitializeMint2` of the transaction account at `mint`, with a mint authority
+/// of 32 `9` bytes and no freeze authority
+pub(crate) fn initialize_mint(mint: u8, decimals: u8) -> TransactionInstruction {
+    let mut data = vec![20, decimals];
+    data.extend([9; 32]);
+    data.push(0);
+    instruction("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA", &[mint], data)
+}
+
+/// Metaplex `CreateMetadataAccountV3` creating the metadata account at `metadata` for the
+/// mint at `mint`
+pub(crate) fn create_metadata(
+    metadata: u8,
+    mint: u8,
+    name: &str,
+    symbol: &str,
+    uri: &str,
+) -> TransactionInstruction {
+    let mut data = vec![33];
+    data.extend(borsh_string(name));
+    data.extend(borsh_string(symbol));
+    data.extend(borsh_string(uri));
+    instruction(
+        "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s",
+        &[metadata, mint],
+        data,
+    )
+}